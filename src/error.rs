@@ -0,0 +1,286 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    Row,
+    Col,
+}
+
+impl Axis {
+    fn name(self) -> &'static str {
+        match self {
+            Axis::Row => "Row",
+            Axis::Col => "Column",
+        }
+    }
+
+    fn plural(self) -> &'static str {
+        match self {
+            Axis::Row => "rows",
+            Axis::Col => "columns",
+        }
+    }
+}
+
+// The row, column or block row whose length disagrees with the first one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    Row(usize),
+    Col(usize),
+    BlockRow(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    ShapeMismatch {
+        lhs: (usize, usize),
+        rhs: (usize, usize),
+    },
+    LengthMismatch {
+        part: Option<Part>,
+        expected: usize,
+        found: usize,
+    },
+    IndexOutOfBounds {
+        axis: Axis,
+        index: usize,
+        len: usize,
+    },
+    RangeOutOfBounds {
+        axis: Axis,
+        start: usize,
+        end: usize,
+        len: usize,
+    },
+    EmptyMatrix,
+    NotSquare {
+        nrows: usize,
+        ncols: usize,
+    },
+    PatternMismatch,
+    Unsupported {
+        operation: &'static str,
+    },
+    InvalidAlignment {
+        align: usize,
+    },
+    BufferTooSmall {
+        required: usize,
+        len: usize,
+    },
+    OverlappingStrides {
+        row_stride: isize,
+        col_stride: isize,
+    },
+    Singular {
+        index: usize,
+    },
+    NotPositiveDefinite {
+        index: usize,
+    },
+    NotConverged {
+        iterations: usize,
+    },
+    Io {
+        kind: std::io::ErrorKind,
+        message: String,
+    },
+    Format {
+        message: String,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::ShapeMismatch { lhs, rhs } => {
+                write!(f, "shape mismatch: {:?} vs {:?}", lhs, rhs)
+            }
+            Error::LengthMismatch {
+                part,
+                expected,
+                found,
+            } => match part {
+                None => write!(
+                    f,
+                    "length mismatch: expected {} elements, found {}",
+                    expected, found
+                ),
+                Some(Part::Row(i)) => {
+                    write!(f, "Row {} has {} elements, expected {}", i, found, expected)
+                }
+                Some(Part::Col(j)) => write!(
+                    f,
+                    "Column {} has {} elements, expected {}",
+                    j, found, expected
+                ),
+                Some(Part::BlockRow(i)) => write!(
+                    f,
+                    "Block row {} has {} blocks, expected {}",
+                    i, found, expected
+                ),
+            },
+            Error::IndexOutOfBounds { axis, index, len } => write!(
+                f,
+                "{} index {} out of bounds for {} {}",
+                axis.name(),
+                index,
+                len,
+                axis.plural()
+            ),
+            Error::RangeOutOfBounds {
+                axis,
+                start,
+                end,
+                len,
+            } => write!(
+                f,
+                "{} range {}..{} out of bounds for {} {}",
+                axis.name(),
+                start,
+                end,
+                len,
+                axis.plural()
+            ),
+            Error::EmptyMatrix => write!(f, "operation requires a non-empty matrix"),
+            Error::NotSquare { nrows, ncols } => {
+                write!(f, "matrix must be square, found {}x{}", nrows, ncols)
+            }
+            Error::PatternMismatch => {
+                write!(f, "sparsity pattern does not match the symbolic analysis")
+            }
+            Error::Unsupported { operation } => {
+                write!(f, "operation not supported: {}", operation)
+            }
+            Error::InvalidAlignment { align } => {
+                write!(f, "alignment must be a power of two, found {}", align)
+            }
+            Error::BufferTooSmall { required, len } => write!(
+                f,
+                "buffer of {} elements is too small for a view spanning {}",
+                len, required
+            ),
+            Error::OverlappingStrides {
+                row_stride,
+                col_stride,
+            } => write!(
+                f,
+                "strides ({}, {}) would alias elements of a mutable view",
+                row_stride, col_stride
+            ),
+            Error::Singular { index } => {
+                write!(f, "matrix is singular: zero pivot at index {}", index)
+            }
+            Error::NotPositiveDefinite { index } => write!(
+                f,
+                "matrix is not positive definite: non-positive pivot at index {}",
+                index
+            ),
+            Error::NotConverged { iterations } => {
+                write!(
+                    f,
+                    "algorithm did not converge after {} iterations",
+                    iterations
+                )
+            }
+            Error::Io { message, .. } => write!(f, "I/O error: {}", message),
+            Error::Format { message } => write!(f, "invalid format: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io {
+            kind: err.kind(),
+            message: err.to_string(),
+        }
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+pub(crate) trait OrPanic<T> {
+    fn or_panic(self) -> T;
+}
+
+impl<T> OrPanic<T> for Result<T> {
+    #[inline]
+    #[track_caller]
+    fn or_panic(self) -> T {
+        match self {
+            Ok(value) => value,
+            Err(err) => panic!("{}", err),
+        }
+    }
+}
+
+pub(crate) fn check_index(axis: Axis, index: usize, len: usize) -> Result<()> {
+    if index < len {
+        Ok(())
+    } else {
+        Err(Error::IndexOutOfBounds { axis, index, len })
+    }
+}
+
+pub(crate) fn check_range(axis: Axis, start: usize, end: usize, len: usize) -> Result<()> {
+    if start <= end && end <= len {
+        Ok(())
+    } else {
+        Err(Error::RangeOutOfBounds {
+            axis,
+            start,
+            end,
+            len,
+        })
+    }
+}
+
+pub(crate) fn check_shape(lhs: (usize, usize), rhs: (usize, usize)) -> Result<()> {
+    if lhs == rhs {
+        Ok(())
+    } else {
+        Err(Error::ShapeMismatch { lhs, rhs })
+    }
+}
+
+pub(crate) fn check_len(expected: usize, found: usize) -> Result<()> {
+    check_part_len(None, expected, found)
+}
+
+pub(crate) fn check_part_len(part: Option<Part>, expected: usize, found: usize) -> Result<()> {
+    if expected == found {
+        Ok(())
+    } else {
+        Err(Error::LengthMismatch {
+            part,
+            expected,
+            found,
+        })
+    }
+}
+
+pub(crate) fn check_boundary(axis: Axis, index: usize, len: usize) -> Result<()> {
+    if index <= len {
+        Ok(())
+    } else {
+        Err(Error::IndexOutOfBounds { axis, index, len })
+    }
+}
+
+pub(crate) fn check_square(shape: (usize, usize)) -> Result<()> {
+    let (nrows, ncols) = shape;
+    if nrows == ncols {
+        Ok(())
+    } else {
+        Err(Error::NotSquare { nrows, ncols })
+    }
+}
+
+pub(crate) fn format_error(message: impl Into<String>) -> Error {
+    Error::Format {
+        message: message.into(),
+    }
+}
@@ -0,0 +1,13 @@
+mod csv;
+mod matrix_market;
+mod npy;
+#[cfg(feature = "npz")]
+mod npz;
+
+pub use csv::{CsvOptions, Missing, load_csv, read_csv, read_csv_with_header, save_csv, write_csv};
+pub use matrix_market::{
+    MtxElement, MtxField, MtxFormat, MtxSymmetry, load_mtx, read_mtx, save_mtx, write_mtx,
+};
+pub use npy::{NpyElement, load_npy, read_npy, save_npy, write_npy};
+#[cfg(feature = "npz")]
+pub use npz::{load_npz, read_npz, read_npz_entry, save_npz, write_npz, write_npz_compressed};
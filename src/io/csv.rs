@@ -0,0 +1,254 @@
+use std::borrow::Cow;
+use std::fmt::{self, Display};
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::error::{Error, Result, check_len, format_error};
+use crate::matrix::{Mat, MatRef};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Missing<T> {
+    Error,
+    Fill(T),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvOptions<T> {
+    pub delimiter: char,
+    pub has_header: bool,
+    pub comment: Option<char>,
+    pub missing: Missing<T>,
+}
+
+impl<T> Default for CsvOptions<T> {
+    fn default() -> Self {
+        CsvOptions {
+            delimiter: ',',
+            has_header: false,
+            comment: None,
+            missing: Missing::Error,
+        }
+    }
+}
+
+impl<T> CsvOptions<T> {
+    pub fn tsv() -> Self {
+        CsvOptions {
+            delimiter: '\t',
+            ..Self::default()
+        }
+    }
+}
+
+struct Fields<'a> {
+    rest: Option<&'a str>,
+    delimiter: char,
+}
+
+impl<'a> Iterator for Fields<'a> {
+    type Item = Option<Cow<'a, str>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest.take()?;
+        let Some(quoted) = rest.trim_start_matches(' ').strip_prefix('"') else {
+            return Some(Some(match rest.split_once(self.delimiter) {
+                Some((field, tail)) => {
+                    self.rest = Some(tail);
+                    Cow::Borrowed(field.trim())
+                }
+                None => Cow::Borrowed(rest.trim()),
+            }));
+        };
+        let mut value = String::new();
+        let mut chars = quoted.char_indices();
+        let close = loop {
+            match chars.next() {
+                None => return Some(None),
+                Some((i, '"')) if quoted[i + 1..].starts_with('"') => {
+                    value.push('"');
+                    chars.next();
+                }
+                Some((i, '"')) => break i,
+                Some((_, c)) => value.push(c),
+            }
+        };
+        let tail = quoted[close + 1..].trim_start_matches(' ');
+        if !tail.is_empty() {
+            match tail.strip_prefix(self.delimiter) {
+                Some(tail) => self.rest = Some(tail),
+                None => return Some(None),
+            }
+        }
+        Some(Some(Cow::Owned(value)))
+    }
+}
+
+fn fields(line: &str, delimiter: char) -> Fields<'_> {
+    Fields {
+        rest: Some(line),
+        delimiter,
+    }
+}
+
+fn line_error(line: usize, message: impl Display) -> Error {
+    format_error(format!("line {}: {}", line, message))
+}
+
+fn read_table<T: FromStr + Clone, R: BufRead>(
+    mut reader: R,
+    options: &CsvOptions<T>,
+    capture_header: bool,
+) -> Result<(Option<Vec<String>>, Mat<T>)> {
+    let mut buf = String::new();
+    let mut line = 0;
+    let mut header = None;
+    let mut expect_header = options.has_header || capture_header;
+    let mut mat = Mat::growable();
+    let mut row = Vec::new();
+    let mut width = None;
+
+    loop {
+        buf.clear();
+        if reader.read_line(&mut buf)? == 0 {
+            break;
+        }
+        line += 1;
+        let text = buf.trim_end_matches(['\r', '\n']);
+        let trimmed = text.trim_start();
+        if trimmed.is_empty() || options.comment.is_some_and(|c| trimmed.starts_with(c)) {
+            continue;
+        }
+
+        if expect_header {
+            expect_header = false;
+            let names = fields(text, options.delimiter)
+                .map(|field| field.map(Cow::into_owned))
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(|| line_error(line, "malformed quoted field"))?;
+            width = Some(names.len());
+            header = Some(names);
+            continue;
+        }
+
+        let ncols = *width.get_or_insert_with(|| fields(text, options.delimiter).count());
+        row.clear();
+        let mut count = 0;
+        for (j, field) in fields(text, options.delimiter).enumerate() {
+            let field = field.ok_or_else(|| line_error(line, "malformed quoted field"))?;
+            if j >= ncols {
+                count = j + 1;
+                continue;
+            }
+            let value = if field.is_empty() {
+                match &options.missing {
+                    Missing::Error => {
+                        return Err(line_error(
+                            line,
+                            format!("missing value in column {}", j + 1),
+                        ));
+                    }
+                    Missing::Fill(value) => value.clone(),
+                }
+            } else {
+                field.parse().map_err(|_| {
+                    line_error(
+                        line,
+                        format!("invalid value '{}' in column {}", field, j + 1),
+                    )
+                })?
+            };
+            row.push(value);
+            count = j + 1;
+        }
+        if count != ncols {
+            return Err(line_error(
+                line,
+                format!("expected {} fields, found {}", ncols, count),
+            ));
+        }
+        mat.try_push_row(&row)?;
+    }
+
+    // A header with no data rows still fixes the width.
+    if mat.nrows() == 0 {
+        mat = Mat::from_vec_col(0, width.unwrap_or(0), Vec::new());
+    }
+    mat.pack();
+    Ok((header, mat))
+}
+
+pub fn read_csv<T: FromStr + Clone, R: BufRead>(
+    reader: R,
+    options: &CsvOptions<T>,
+) -> Result<Mat<T>> {
+    read_table(reader, options, false).map(|(_, mat)| mat)
+}
+
+pub fn read_csv_with_header<T: FromStr + Clone, R: BufRead>(
+    reader: R,
+    options: &CsvOptions<T>,
+) -> Result<(Vec<String>, Mat<T>)> {
+    let (header, mat) = read_table(reader, options, true)?;
+    Ok((header.unwrap_or_default(), mat))
+}
+
+fn write_field<W: Write>(writer: &mut W, field: &str, delimiter: char) -> Result<()> {
+    if field.contains([delimiter, '"', '\n', '\r']) || field.trim() != field {
+        write!(writer, "\"{}\"", field.replace('"', "\"\""))?;
+    } else {
+        writer.write_all(field.as_bytes())?;
+    }
+    Ok(())
+}
+
+pub fn write_csv<T: Display, W: Write>(
+    mut writer: W,
+    mat: MatRef<'_, T>,
+    options: &CsvOptions<T>,
+    header: Option<&[&str]>,
+) -> Result<()> {
+    let delimiter = options.delimiter;
+    if let Some(header) = header {
+        check_len(mat.ncols(), header.len())?;
+        for (j, name) in header.iter().enumerate() {
+            if j > 0 {
+                write!(writer, "{}", delimiter)?;
+            }
+            write_field(&mut writer, name, delimiter)?;
+        }
+        writeln!(writer)?;
+    }
+    let mut cell = String::new();
+    for i in 0..mat.nrows() {
+        for j in 0..mat.ncols() {
+            if j > 0 {
+                write!(writer, "{}", delimiter)?;
+            }
+            cell.clear();
+            fmt::Write::write_fmt(&mut cell, format_args!("{}", mat.at(i, j)))
+                .expect("formatting into a String cannot fail");
+            write_field(&mut writer, &cell, delimiter)?;
+        }
+        writeln!(writer)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+pub fn load_csv<T: FromStr + Clone, P: AsRef<Path>>(
+    path: P,
+    options: &CsvOptions<T>,
+) -> Result<Mat<T>> {
+    read_csv(BufReader::new(File::open(path)?), options)
+}
+
+pub fn save_csv<T: Display, P: AsRef<Path>>(
+    path: P,
+    mat: MatRef<'_, T>,
+    options: &CsvOptions<T>,
+    header: Option<&[&str]>,
+) -> Result<()> {
+    write_csv(BufWriter::new(File::create(path)?), mat, options, header)
+}
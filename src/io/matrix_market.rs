@@ -0,0 +1,639 @@
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+use num_complex::Complex;
+
+use crate::error::{Error, Result, format_error};
+use crate::matrix::{Mat, MatRef};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MtxFormat {
+    Array,
+    Coordinate,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MtxField {
+    Real,
+    Integer,
+    Complex,
+    Pattern,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MtxSymmetry {
+    General,
+    Symmetric,
+    SkewSymmetric,
+    Hermitian,
+}
+
+impl fmt::Display for MtxFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MtxFormat::Array => "array",
+            MtxFormat::Coordinate => "coordinate",
+        })
+    }
+}
+
+impl fmt::Display for MtxField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MtxField::Real => "real",
+            MtxField::Integer => "integer",
+            MtxField::Complex => "complex",
+            MtxField::Pattern => "pattern",
+        })
+    }
+}
+
+impl fmt::Display for MtxSymmetry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MtxSymmetry::General => "general",
+            MtxSymmetry::Symmetric => "symmetric",
+            MtxSymmetry::SkewSymmetric => "skew-symmetric",
+            MtxSymmetry::Hermitian => "hermitian",
+        })
+    }
+}
+
+impl MtxField {
+    fn value_count(self) -> usize {
+        match self {
+            MtxField::Pattern => 0,
+            MtxField::Real | MtxField::Integer => 1,
+            MtxField::Complex => 2,
+        }
+    }
+}
+
+pub trait MtxElement: Copy + PartialEq {
+    const FIELD: MtxField;
+
+    fn zero() -> Self;
+
+    fn one() -> Self;
+
+    fn accepts(field: MtxField) -> bool;
+
+    fn parse(field: MtxField, values: &[&str]) -> Option<Self>;
+
+    fn accumulate(self, other: Self) -> Option<Self>;
+
+    fn negate(self) -> Option<Self>;
+
+    fn conjugate(self) -> Self;
+
+    fn write_value<W: Write>(self, writer: &mut W) -> std::io::Result<()>;
+}
+
+macro_rules! impl_mtx_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl MtxElement for $ty {
+                const FIELD: MtxField = MtxField::Integer;
+
+                fn zero() -> Self {
+                    0
+                }
+
+                fn one() -> Self {
+                    1
+                }
+
+                fn accepts(field: MtxField) -> bool {
+                    matches!(field, MtxField::Integer | MtxField::Pattern)
+                }
+
+                fn parse(field: MtxField, values: &[&str]) -> Option<Self> {
+                    match field {
+                        MtxField::Pattern => Some(1),
+                        _ => values[0].parse().ok(),
+                    }
+                }
+
+                fn accumulate(self, other: Self) -> Option<Self> {
+                    self.checked_add(other)
+                }
+
+                fn negate(self) -> Option<Self> {
+                    self.checked_neg()
+                }
+
+                fn conjugate(self) -> Self {
+                    self
+                }
+
+                fn write_value<W: Write>(self, writer: &mut W) -> std::io::Result<()> {
+                    write!(writer, "{}", self)
+                }
+            }
+        )*
+    };
+}
+
+impl_mtx_integer!(i8, i16, i32, i64, isize);
+
+macro_rules! impl_mtx_float {
+    ($($ty:ty),*) => {
+        $(
+            impl MtxElement for $ty {
+                const FIELD: MtxField = MtxField::Real;
+
+                fn zero() -> Self {
+                    0.0
+                }
+
+                fn one() -> Self {
+                    1.0
+                }
+
+                fn accepts(field: MtxField) -> bool {
+                    field != MtxField::Complex
+                }
+
+                fn parse(field: MtxField, values: &[&str]) -> Option<Self> {
+                    match field {
+                        MtxField::Pattern => Some(1.0),
+                        _ => values[0].parse().ok(),
+                    }
+                }
+
+                fn accumulate(self, other: Self) -> Option<Self> {
+                    Some(self + other)
+                }
+
+                fn negate(self) -> Option<Self> {
+                    Some(-self)
+                }
+
+                fn conjugate(self) -> Self {
+                    self
+                }
+
+                fn write_value<W: Write>(self, writer: &mut W) -> std::io::Result<()> {
+                    write!(writer, "{:?}", self)
+                }
+            }
+
+            impl MtxElement for Complex<$ty> {
+                const FIELD: MtxField = MtxField::Complex;
+
+                fn zero() -> Self {
+                    Complex::new(0.0, 0.0)
+                }
+
+                fn one() -> Self {
+                    Complex::new(1.0, 0.0)
+                }
+
+                fn accepts(_field: MtxField) -> bool {
+                    true
+                }
+
+                fn parse(field: MtxField, values: &[&str]) -> Option<Self> {
+                    match field {
+                        MtxField::Pattern => Some(Self::one()),
+                        MtxField::Complex => {
+                            Some(Complex::new(values[0].parse().ok()?, values[1].parse().ok()?))
+                        }
+                        _ => Some(Complex::new(values[0].parse().ok()?, 0.0)),
+                    }
+                }
+
+                fn accumulate(self, other: Self) -> Option<Self> {
+                    Some(self + other)
+                }
+
+                fn negate(self) -> Option<Self> {
+                    Some(-self)
+                }
+
+                fn conjugate(self) -> Self {
+                    self.conj()
+                }
+
+                fn write_value<W: Write>(self, writer: &mut W) -> std::io::Result<()> {
+                    write!(writer, "{:?} {:?}", self.re, self.im)
+                }
+            }
+        )*
+    };
+}
+
+impl_mtx_float!(f32, f64);
+
+impl MtxElement for bool {
+    const FIELD: MtxField = MtxField::Pattern;
+
+    fn zero() -> Self {
+        false
+    }
+
+    fn one() -> Self {
+        true
+    }
+
+    fn accepts(field: MtxField) -> bool {
+        field == MtxField::Pattern
+    }
+
+    fn parse(_field: MtxField, _values: &[&str]) -> Option<Self> {
+        Some(true)
+    }
+
+    fn accumulate(self, other: Self) -> Option<Self> {
+        Some(self || other)
+    }
+
+    fn negate(self) -> Option<Self> {
+        Some(self)
+    }
+
+    fn conjugate(self) -> Self {
+        self
+    }
+
+    fn write_value<W: Write>(self, _writer: &mut W) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+fn check_header(format: MtxFormat, field: MtxField, symmetry: MtxSymmetry) -> Result<()> {
+    let invalid = match (format, field, symmetry) {
+        (MtxFormat::Array, MtxField::Pattern, _) => true,
+        (_, MtxField::Pattern, MtxSymmetry::SkewSymmetric) => true,
+        (_, field, MtxSymmetry::Hermitian) => field != MtxField::Complex,
+        _ => false,
+    };
+    if invalid {
+        return Err(format_error(format!(
+            "invalid Matrix Market header: '{} {} {}' is not a valid combination",
+            format, field, symmetry
+        )));
+    }
+    Ok(())
+}
+
+fn line_error(line: usize, message: impl fmt::Display) -> Error {
+    format_error(format!("line {}: {}", line, message))
+}
+
+struct Lines<R> {
+    reader: R,
+    buf: String,
+    line: usize,
+}
+
+impl<R: BufRead> Lines<R> {
+    fn next_raw(&mut self) -> Result<Option<&str>> {
+        self.buf.clear();
+        if self.reader.read_line(&mut self.buf)? == 0 {
+            return Ok(None);
+        }
+        self.line += 1;
+        Ok(Some(self.buf.trim()))
+    }
+
+    fn advance(&mut self) -> Result<bool> {
+        loop {
+            self.buf.clear();
+            if self.reader.read_line(&mut self.buf)? == 0 {
+                return Ok(false);
+            }
+            self.line += 1;
+            let trimmed = self.buf.trim();
+            if !trimmed.is_empty() && !trimmed.starts_with('%') {
+                return Ok(true);
+            }
+        }
+    }
+
+    fn next_data(&mut self) -> Result<Option<(usize, &str)>> {
+        Ok(self.advance()?.then(|| (self.line, self.buf.trim())))
+    }
+
+    fn expect_data(&mut self, what: &str) -> Result<(usize, &str)> {
+        if !self.advance()? {
+            return Err(line_error(
+                self.line,
+                format!("unexpected end of file, expected {}", what),
+            ));
+        }
+        Ok((self.line, self.buf.trim()))
+    }
+}
+
+fn parse_banner(line: usize, banner: &str) -> Result<(MtxFormat, MtxField, MtxSymmetry)> {
+    let words: Vec<String> = banner
+        .split_whitespace()
+        .map(|word| word.to_ascii_lowercase())
+        .collect();
+    if words.first().map(String::as_str) != Some("%%matrixmarket") {
+        return Err(line_error(line, "missing '%%MatrixMarket' banner"));
+    }
+    if words.len() != 5 {
+        return Err(line_error(
+            line,
+            "banner must be '%%MatrixMarket matrix <format> <field> <symmetry>'",
+        ));
+    }
+    if words[1] != "matrix" {
+        return Err(line_error(
+            line,
+            format!("unsupported object '{}', expected 'matrix'", words[1]),
+        ));
+    }
+    let format = match words[2].as_str() {
+        "array" => MtxFormat::Array,
+        "coordinate" => MtxFormat::Coordinate,
+        other => return Err(line_error(line, format!("unknown format '{}'", other))),
+    };
+    let field = match words[3].as_str() {
+        "real" | "double" => MtxField::Real,
+        "integer" => MtxField::Integer,
+        "complex" => MtxField::Complex,
+        "pattern" => MtxField::Pattern,
+        other => return Err(line_error(line, format!("unknown field '{}'", other))),
+    };
+    let symmetry = match words[4].as_str() {
+        "general" => MtxSymmetry::General,
+        "symmetric" => MtxSymmetry::Symmetric,
+        "skew-symmetric" => MtxSymmetry::SkewSymmetric,
+        "hermitian" => MtxSymmetry::Hermitian,
+        other => return Err(line_error(line, format!("unknown symmetry '{}'", other))),
+    };
+    check_header(format, field, symmetry).map_err(|err| match err {
+        Error::Format { message } => line_error(line, message),
+        err => err,
+    })?;
+    Ok((format, field, symmetry))
+}
+
+fn parse_sizes(line: usize, text: &str, count: usize) -> Result<Vec<usize>> {
+    let sizes = text
+        .split_whitespace()
+        .map(|word| word.parse::<usize>())
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .ok()
+        .filter(|sizes| sizes.len() == count);
+    sizes.ok_or_else(|| {
+        let expected = if count == 2 {
+            "'rows cols'"
+        } else {
+            "'rows cols entries'"
+        };
+        line_error(
+            line,
+            format!("invalid size line '{}', expected {}", text, expected),
+        )
+    })
+}
+
+fn parse_value<T: MtxElement>(line: usize, field: MtxField, words: &[&str]) -> Result<T> {
+    if words.len() != field.value_count() {
+        return Err(line_error(
+            line,
+            format!(
+                "expected {} value(s) for a {} entry, found {}",
+                field.value_count(),
+                field,
+                words.len()
+            ),
+        ));
+    }
+    T::parse(field, words).ok_or_else(|| {
+        line_error(
+            line,
+            format!("invalid {} value '{}'", field, words.join(" ")),
+        )
+    })
+}
+
+fn mirror<T: MtxElement>(symmetry: MtxSymmetry, value: T) -> Option<T> {
+    match symmetry {
+        MtxSymmetry::General | MtxSymmetry::Symmetric => Some(value),
+        MtxSymmetry::SkewSymmetric => value.negate(),
+        MtxSymmetry::Hermitian => Some(value.conjugate()),
+    }
+}
+
+fn overflow_error(line: usize) -> Error {
+    line_error(line, "entry overflows the element type")
+}
+
+// Allocates fallibly, so an absurd size line is reported instead of aborting.
+fn zeros<T: MtxElement>(line: usize, nrows: usize, ncols: usize) -> Result<Mat<T>> {
+    let too_large = || line_error(line, format!("a {}x{} matrix is too large", nrows, ncols));
+    let len = nrows.checked_mul(ncols).ok_or_else(too_large)?;
+    let mut data = Vec::new();
+    data.try_reserve_exact(len).map_err(|_| too_large())?;
+    data.resize(len, T::zero());
+    Ok(Mat::from_vec_col(nrows, ncols, data))
+}
+
+pub fn read_mtx<T: MtxElement, R: BufRead>(reader: R) -> Result<Mat<T>> {
+    let mut lines = Lines {
+        reader,
+        buf: String::new(),
+        line: 0,
+    };
+    let (format, field, symmetry) = match lines.next_raw()? {
+        Some(banner) => parse_banner(1, banner)?,
+        None => return Err(line_error(1, "missing '%%MatrixMarket' banner")),
+    };
+    if !T::accepts(field) {
+        return Err(format_error(format!(
+            "cannot load a {} matrix into elements of type {}",
+            field,
+            std::any::type_name::<T>()
+        )));
+    }
+
+    let count = if format == MtxFormat::Array { 2 } else { 3 };
+    let (line, text) = lines.expect_data("the size line")?;
+    let sizes = parse_sizes(line, text, count)?;
+    let (nrows, ncols) = (sizes[0], sizes[1]);
+    if symmetry != MtxSymmetry::General && nrows != ncols {
+        return Err(line_error(
+            line,
+            format!(
+                "{} matrix must be square, found {}x{}",
+                symmetry, nrows, ncols
+            ),
+        ));
+    }
+    let mut mat = zeros::<T>(line, nrows, ncols)?;
+
+    match format {
+        MtxFormat::Array => {
+            for j in 0..ncols {
+                let start = match symmetry {
+                    MtxSymmetry::General => 0,
+                    MtxSymmetry::SkewSymmetric => j + 1,
+                    _ => j,
+                };
+                for i in start..nrows {
+                    let (line, text) = lines.expect_data("a matrix entry")?;
+                    let words: Vec<&str> = text.split_whitespace().collect();
+                    let value = parse_value(line, field, &words)?;
+                    mat[(i, j)] = value;
+                    if i != j && symmetry != MtxSymmetry::General {
+                        mat[(j, i)] =
+                            mirror(symmetry, value).ok_or_else(|| overflow_error(line))?;
+                    }
+                }
+            }
+        }
+        MtxFormat::Coordinate => {
+            for _ in 0..sizes[2] {
+                let (line, text) = lines.expect_data("a matrix entry")?;
+                let words: Vec<&str> = text.split_whitespace().collect();
+                if words.len() < 2 {
+                    return Err(line_error(line, format!("invalid entry '{}'", text)));
+                }
+                let index = |word: &str, len: usize| {
+                    word.parse::<usize>()
+                        .ok()
+                        .filter(|&index| 1 <= index && index <= len)
+                        .map(|index| index - 1)
+                        .ok_or_else(|| {
+                            line_error(
+                                line,
+                                format!(
+                                    "index '{}' out of range for a {}x{} matrix",
+                                    word, nrows, ncols
+                                ),
+                            )
+                        })
+                };
+                let i = index(words[0], nrows)?;
+                let j = index(words[1], ncols)?;
+                let value: T = parse_value(line, field, &words[2..])?;
+                mat[(i, j)] = mat[(i, j)]
+                    .accumulate(value)
+                    .ok_or_else(|| overflow_error(line))?;
+                if i != j && symmetry != MtxSymmetry::General {
+                    mat[(j, i)] = mirror(symmetry, value)
+                        .and_then(|value| mat[(j, i)].accumulate(value))
+                        .ok_or_else(|| overflow_error(line))?;
+                }
+            }
+        }
+    }
+
+    if let Some((line, text)) = lines.next_data()? {
+        return Err(line_error(
+            line,
+            format!("unexpected data after the last entry: '{}'", text),
+        ));
+    }
+    Ok(mat)
+}
+
+fn check_symmetry<T: MtxElement>(mat: MatRef<'_, T>, symmetry: MtxSymmetry) -> Result<()> {
+    if symmetry == MtxSymmetry::General {
+        return Ok(());
+    }
+    if mat.nrows() != mat.ncols() {
+        return Err(format_error(format!(
+            "{} storage requires a square matrix, found {}x{}",
+            symmetry,
+            mat.nrows(),
+            mat.ncols()
+        )));
+    }
+    for j in 0..mat.ncols() {
+        for i in j..mat.nrows() {
+            let (lower, upper) = (*mat.at(i, j), *mat.at(j, i));
+            let matches = if i == j && symmetry == MtxSymmetry::SkewSymmetric {
+                lower == T::zero()
+            } else {
+                Some(upper) == mirror(symmetry, lower)
+            };
+            if !matches {
+                return Err(format_error(format!(
+                    "matrix is not {}: mismatch at ({}, {})",
+                    symmetry, i, j
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn write_mtx<T: MtxElement, W: Write>(
+    mut writer: W,
+    mat: MatRef<'_, T>,
+    format: MtxFormat,
+    symmetry: MtxSymmetry,
+) -> Result<()> {
+    check_header(format, T::FIELD, symmetry)?;
+    check_symmetry(mat, symmetry)?;
+    let start = |j: usize| match symmetry {
+        MtxSymmetry::General => 0,
+        MtxSymmetry::SkewSymmetric => j + 1,
+        _ => j,
+    };
+
+    writeln!(
+        writer,
+        "%%MatrixMarket matrix {} {} {}",
+        format,
+        T::FIELD,
+        symmetry
+    )?;
+    match format {
+        MtxFormat::Array => {
+            writeln!(writer, "{} {}", mat.nrows(), mat.ncols())?;
+            for j in 0..mat.ncols() {
+                for i in start(j)..mat.nrows() {
+                    mat.at(i, j).write_value(&mut writer)?;
+                    writer.write_all(b"\n")?;
+                }
+            }
+        }
+        MtxFormat::Coordinate => {
+            let nnz = (0..mat.ncols())
+                .map(|j| {
+                    (start(j)..mat.nrows())
+                        .filter(|&i| *mat.at(i, j) != T::zero())
+                        .count()
+                })
+                .sum::<usize>();
+            writeln!(writer, "{} {} {}", mat.nrows(), mat.ncols(), nnz)?;
+            for j in 0..mat.ncols() {
+                for i in start(j)..mat.nrows() {
+                    let value = *mat.at(i, j);
+                    if value != T::zero() {
+                        write!(writer, "{} {}", i + 1, j + 1)?;
+                        if T::FIELD != MtxField::Pattern {
+                            writer.write_all(b" ")?;
+                            value.write_value(&mut writer)?;
+                        }
+                        writer.write_all(b"\n")?;
+                    }
+                }
+            }
+        }
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+pub fn load_mtx<T: MtxElement, P: AsRef<Path>>(path: P) -> Result<Mat<T>> {
+    read_mtx(BufReader::new(File::open(path)?))
+}
+
+pub fn save_mtx<T: MtxElement, P: AsRef<Path>>(
+    path: P,
+    mat: MatRef<'_, T>,
+    format: MtxFormat,
+    symmetry: MtxSymmetry,
+) -> Result<()> {
+    write_mtx(BufWriter::new(File::create(path)?), mat, format, symmetry)
+}
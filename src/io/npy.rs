@@ -0,0 +1,411 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use num_complex::Complex;
+
+use crate::error::{Result, format_error};
+use crate::matrix::{Mat, MatRef};
+
+const MAGIC: &[u8; 6] = b"\x93NUMPY";
+const CHUNK_LEN: usize = 1 << 16;
+
+pub trait NpyElement: Copy {
+    const KIND: char;
+    const SIZE: usize;
+
+    fn decode(bytes: &[u8], big_endian: bool) -> Self;
+
+    fn encode(self, out: &mut Vec<u8>);
+}
+
+macro_rules! impl_npy_primitive {
+    ($($ty:ty => $kind:literal),* $(,)?) => {
+        $(
+            impl NpyElement for $ty {
+                const KIND: char = $kind;
+                const SIZE: usize = std::mem::size_of::<$ty>();
+
+                #[inline]
+                fn decode(bytes: &[u8], big_endian: bool) -> Self {
+                    let bytes = bytes.try_into().unwrap();
+                    if big_endian {
+                        <$ty>::from_be_bytes(bytes)
+                    } else {
+                        <$ty>::from_le_bytes(bytes)
+                    }
+                }
+
+                #[inline]
+                fn encode(self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_npy_primitive!(
+    i8 => 'i', i16 => 'i', i32 => 'i', i64 => 'i', isize => 'i',
+    u8 => 'u', u16 => 'u', u32 => 'u', u64 => 'u', usize => 'u',
+    f32 => 'f', f64 => 'f',
+);
+
+impl NpyElement for bool {
+    const KIND: char = 'b';
+    const SIZE: usize = 1;
+
+    #[inline]
+    fn decode(bytes: &[u8], _big_endian: bool) -> Self {
+        bytes[0] != 0
+    }
+
+    #[inline]
+    fn encode(self, out: &mut Vec<u8>) {
+        out.push(self as u8);
+    }
+}
+
+macro_rules! impl_npy_complex {
+    ($($ty:ty),*) => {
+        $(
+            impl NpyElement for Complex<$ty> {
+                const KIND: char = 'c';
+                const SIZE: usize = 2 * std::mem::size_of::<$ty>();
+
+                #[inline]
+                fn decode(bytes: &[u8], big_endian: bool) -> Self {
+                    let (re, im) = bytes.split_at(std::mem::size_of::<$ty>());
+                    Complex::new(
+                        <$ty>::decode(re, big_endian),
+                        <$ty>::decode(im, big_endian),
+                    )
+                }
+
+                #[inline]
+                fn encode(self, out: &mut Vec<u8>) {
+                    self.re.encode(out);
+                    self.im.encode(out);
+                }
+            }
+        )*
+    };
+}
+
+impl_npy_complex!(f32, f64);
+
+fn descr_of<T: NpyElement>() -> String {
+    let order = if T::SIZE == 1 { '|' } else { '<' };
+    format!("{}{}{}", order, T::KIND, T::SIZE)
+}
+
+struct Header {
+    descr: String,
+    fortran_order: bool,
+    shape: Vec<usize>,
+}
+
+enum Value {
+    Str(String),
+    Bool(bool),
+    Tuple(Vec<usize>),
+}
+
+struct HeaderParser<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+}
+
+impl HeaderParser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|c| c.is_whitespace()).is_some() {}
+    }
+
+    fn expect(&mut self, expected: char) -> Result<()> {
+        self.skip_whitespace();
+        match self.chars.next() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => Err(format_error(format!(
+                "malformed .npy header: expected '{}', found '{}'",
+                expected, c
+            ))),
+            None => Err(format_error(format!(
+                "malformed .npy header: expected '{}', found end of header",
+                expected
+            ))),
+        }
+    }
+
+    fn string(&mut self) -> Result<String> {
+        self.skip_whitespace();
+        let quote = match self.chars.next() {
+            Some(q @ ('\'' | '"')) => q,
+            _ => return Err(format_error("malformed .npy header: expected a string")),
+        };
+        let mut out = String::new();
+        loop {
+            match self.chars.next() {
+                Some(c) if c == quote => return Ok(out),
+                Some(c) => out.push(c),
+                None => return Err(format_error("malformed .npy header: unterminated string")),
+            }
+        }
+    }
+
+    fn word(&mut self) -> String {
+        let mut out = String::new();
+        while let Some(c) = self.chars.next_if(|c| c.is_ascii_alphanumeric()) {
+            out.push(c);
+        }
+        out
+    }
+
+    fn integer(&mut self) -> Result<usize> {
+        self.skip_whitespace();
+        let word = self.word();
+        let digits = word.strip_suffix('L').unwrap_or(&word);
+        digits.parse().map_err(|_| {
+            format_error(format!(
+                "malformed .npy header: invalid dimension '{}'",
+                word
+            ))
+        })
+    }
+
+    fn value(&mut self) -> Result<Value> {
+        self.skip_whitespace();
+        match self.chars.peek() {
+            Some('\'' | '"') => self.string().map(Value::Str),
+            Some('(') => {
+                self.chars.next();
+                let mut dims = Vec::new();
+                loop {
+                    self.skip_whitespace();
+                    if self.chars.next_if_eq(&')').is_some() {
+                        return Ok(Value::Tuple(dims));
+                    }
+                    dims.push(self.integer()?);
+                    self.skip_whitespace();
+                    if self.chars.next_if_eq(&',').is_none() {
+                        self.expect(')')?;
+                        return Ok(Value::Tuple(dims));
+                    }
+                }
+            }
+            _ => match self.word().as_str() {
+                "True" => Ok(Value::Bool(true)),
+                "False" => Ok(Value::Bool(false)),
+                word => Err(format_error(format!(
+                    "malformed .npy header: unexpected value '{}'",
+                    word
+                ))),
+            },
+        }
+    }
+
+    fn header(&mut self) -> Result<Header> {
+        let mut descr = None;
+        let mut fortran_order = None;
+        let mut shape = None;
+        self.expect('{')?;
+        loop {
+            self.skip_whitespace();
+            if self.chars.next_if_eq(&'}').is_some() {
+                break;
+            }
+            let key = self.string()?;
+            self.expect(':')?;
+            match (key.as_str(), self.value()?) {
+                ("descr", Value::Str(value)) => descr = Some(value),
+                ("fortran_order", Value::Bool(value)) => fortran_order = Some(value),
+                ("shape", Value::Tuple(value)) => shape = Some(value),
+                ("descr" | "fortran_order" | "shape", _) => {
+                    return Err(format_error(format!(
+                        "malformed .npy header: invalid value for '{}'",
+                        key
+                    )));
+                }
+                _ => {}
+            }
+            self.skip_whitespace();
+            if self.chars.next_if_eq(&',').is_none() {
+                self.expect('}')?;
+                break;
+            }
+        }
+        let missing = |key| format_error(format!("malformed .npy header: missing '{}'", key));
+        Ok(Header {
+            descr: descr.ok_or_else(|| missing("descr"))?,
+            fortran_order: fortran_order.ok_or_else(|| missing("fortran_order"))?,
+            shape: shape.ok_or_else(|| missing("shape"))?,
+        })
+    }
+}
+
+fn read_header<R: Read>(reader: &mut R) -> Result<Header> {
+    let mut preamble = [0u8; 8];
+    reader.read_exact(&mut preamble)?;
+    if &preamble[..6] != MAGIC {
+        return Err(format_error("not a .npy file: bad magic string"));
+    }
+    let (major, minor) = (preamble[6], preamble[7]);
+    let header_len = match major {
+        1 => {
+            let mut len = [0u8; 2];
+            reader.read_exact(&mut len)?;
+            u16::from_le_bytes(len) as usize
+        }
+        2 | 3 => {
+            let mut len = [0u8; 4];
+            reader.read_exact(&mut len)?;
+            u32::from_le_bytes(len) as usize
+        }
+        _ => {
+            return Err(format_error(format!(
+                "unsupported .npy version {}.{}",
+                major, minor
+            )));
+        }
+    };
+    let mut bytes = vec![0u8; header_len];
+    reader.read_exact(&mut bytes)?;
+    let text = if major == 3 {
+        String::from_utf8(bytes)
+            .map_err(|_| format_error("malformed .npy header: invalid UTF-8"))?
+    } else {
+        bytes.iter().map(|&b| b as char).collect()
+    };
+    HeaderParser {
+        chars: text.chars().peekable(),
+    }
+    .header()
+}
+
+fn check_dtype<T: NpyElement>(descr: &str) -> Result<bool> {
+    let mut chars = descr.chars();
+    let big_endian = match descr.chars().next() {
+        Some('<' | '|') => {
+            chars.next();
+            false
+        }
+        Some('>') => {
+            chars.next();
+            true
+        }
+        Some('=') => {
+            chars.next();
+            cfg!(target_endian = "big")
+        }
+        _ => cfg!(target_endian = "big"),
+    };
+    let kind = chars.next();
+    let size = chars.as_str().parse::<usize>().ok();
+    if kind != Some(T::KIND) || size != Some(T::SIZE) {
+        return Err(format_error(format!(
+            "dtype mismatch: expected '{}', found '{}'",
+            descr_of::<T>(),
+            descr
+        )));
+    }
+    Ok(big_endian)
+}
+
+fn read_elements<T: NpyElement, R: Read>(
+    reader: &mut R,
+    len: usize,
+    big_endian: bool,
+) -> Result<Vec<T>> {
+    let mut data = Vec::new();
+    data.try_reserve_exact(len)
+        .map_err(|_| format_error(format!("cannot allocate {} elements", len)))?;
+    let mut buf = vec![0u8; CHUNK_LEN.min(len) * T::SIZE];
+    let mut remaining = len;
+    while remaining > 0 {
+        let n = remaining.min(CHUNK_LEN);
+        let bytes = &mut buf[..n * T::SIZE];
+        reader.read_exact(bytes)?;
+        data.extend(
+            bytes
+                .chunks_exact(T::SIZE)
+                .map(|chunk| T::decode(chunk, big_endian)),
+        );
+        remaining -= n;
+    }
+    Ok(data)
+}
+
+pub fn read_npy<T: NpyElement, R: Read>(mut reader: R) -> Result<Mat<T>> {
+    let header = read_header(&mut reader)?;
+    let big_endian = check_dtype::<T>(&header.descr)?;
+    let (nrows, ncols) = match header.shape[..] {
+        [] => (1, 1),
+        [n] => (n, 1),
+        [nrows, ncols] => (nrows, ncols),
+        _ => {
+            return Err(format_error(format!(
+                "expected at most 2 dimensions, found shape {:?}",
+                header.shape
+            )));
+        }
+    };
+    let len = nrows
+        .checked_mul(ncols)
+        .filter(|len| len.checked_mul(T::SIZE).is_some())
+        .ok_or_else(|| format_error(format!("shape {:?} is too large", header.shape)))?;
+    let data = read_elements(&mut reader, len, big_endian)?;
+    if header.fortran_order {
+        Ok(Mat::from_vec_col(nrows, ncols, data))
+    } else {
+        Ok(Mat::from_vec_row(nrows, ncols, data))
+    }
+}
+
+pub fn write_npy<T: NpyElement, W: Write>(mut writer: W, mat: MatRef<'_, T>) -> Result<()> {
+    let mut header = format!(
+        "{{'descr': '{}', 'fortran_order': True, 'shape': ({}, {}), }}",
+        descr_of::<T>(),
+        mat.nrows(),
+        mat.ncols()
+    );
+    let (version, prefix_len) = if header.len() + 11 + 64 <= u16::MAX as usize {
+        (1u8, 10)
+    } else {
+        (2u8, 12)
+    };
+    let total = (prefix_len + header.len() + 1).next_multiple_of(64);
+    header.extend(std::iter::repeat_n(
+        ' ',
+        total - prefix_len - header.len() - 1,
+    ));
+    header.push('\n');
+
+    writer.write_all(MAGIC)?;
+    writer.write_all(&[version, 0])?;
+    if version == 1 {
+        writer.write_all(&(header.len() as u16).to_le_bytes())?;
+    } else {
+        writer.write_all(&(header.len() as u32).to_le_bytes())?;
+    }
+    writer.write_all(header.as_bytes())?;
+
+    let mut buf = Vec::with_capacity(CHUNK_LEN.min(mat.size()) * T::SIZE);
+    for j in 0..mat.ncols() {
+        for i in 0..mat.nrows() {
+            mat.at(i, j).encode(&mut buf);
+            if buf.len() >= CHUNK_LEN * T::SIZE {
+                writer.write_all(&buf)?;
+                buf.clear();
+            }
+        }
+    }
+    writer.write_all(&buf)?;
+    writer.flush()?;
+    Ok(())
+}
+
+pub fn load_npy<T: NpyElement, P: AsRef<Path>>(path: P) -> Result<Mat<T>> {
+    read_npy(BufReader::new(File::open(path)?))
+}
+
+pub fn save_npy<T: NpyElement, P: AsRef<Path>>(path: P, mat: MatRef<'_, T>) -> Result<()> {
+    write_npy(BufWriter::new(File::create(path)?), mat)
+}
@@ -0,0 +1,87 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Seek, Write};
+use std::path::Path;
+
+use zip::result::ZipError;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use super::npy::{NpyElement, read_npy, write_npy};
+use crate::error::{Error, Result, format_error};
+use crate::matrix::{Mat, MatRef};
+
+fn zip_error(err: ZipError) -> Error {
+    match err {
+        ZipError::Io(err) => err.into(),
+        err => format_error(err.to_string()),
+    }
+}
+
+pub fn read_npz<T: NpyElement, R: Read + Seek>(reader: R) -> Result<Vec<(String, Mat<T>)>> {
+    let mut archive = ZipArchive::new(reader).map_err(zip_error)?;
+    let mut arrays = Vec::with_capacity(archive.len());
+    for index in 0..archive.len() {
+        let entry = archive.by_index(index).map_err(zip_error)?;
+        if entry.is_dir() {
+            continue;
+        }
+        let name = entry.name();
+        let name = name.strip_suffix(".npy").unwrap_or(name).to_owned();
+        arrays.push((name, read_npy(entry)?));
+    }
+    Ok(arrays)
+}
+
+pub fn read_npz_entry<T: NpyElement, R: Read + Seek>(reader: R, name: &str) -> Result<Mat<T>> {
+    let mut archive = ZipArchive::new(reader).map_err(zip_error)?;
+    let file_name = format!("{}.npy", name);
+    let index = archive
+        .index_for_name(&file_name)
+        .or_else(|| archive.index_for_name(name))
+        .ok_or_else(|| format_error(format!("no array named '{}' in archive", name)))?;
+    read_npy(archive.by_index(index).map_err(zip_error)?)
+}
+
+fn write_archive<T: NpyElement, W: Write + Seek>(
+    writer: W,
+    arrays: &[(&str, MatRef<'_, T>)],
+    method: CompressionMethod,
+) -> Result<()> {
+    let mut zip = ZipWriter::new(writer);
+    for (name, mat) in arrays {
+        let large = (mat.size() as u64).saturating_mul(T::SIZE as u64) > u32::MAX as u64 / 2;
+        let options = SimpleFileOptions::default()
+            .compression_method(method)
+            .large_file(large);
+        zip.start_file(format!("{}.npy", name), options)
+            .map_err(zip_error)?;
+        write_npy(&mut zip, *mat)?;
+    }
+    zip.finish().map_err(zip_error)?.flush()?;
+    Ok(())
+}
+
+pub fn write_npz<T: NpyElement, W: Write + Seek>(
+    writer: W,
+    arrays: &[(&str, MatRef<'_, T>)],
+) -> Result<()> {
+    write_archive(writer, arrays, CompressionMethod::Stored)
+}
+
+pub fn write_npz_compressed<T: NpyElement, W: Write + Seek>(
+    writer: W,
+    arrays: &[(&str, MatRef<'_, T>)],
+) -> Result<()> {
+    write_archive(writer, arrays, CompressionMethod::Deflated)
+}
+
+pub fn load_npz<T: NpyElement, P: AsRef<Path>>(path: P) -> Result<Vec<(String, Mat<T>)>> {
+    read_npz(BufReader::new(File::open(path)?))
+}
+
+pub fn save_npz<T: NpyElement, P: AsRef<Path>>(
+    path: P,
+    arrays: &[(&str, MatRef<'_, T>)],
+) -> Result<()> {
+    write_npz(BufWriter::new(File::create(path)?), arrays)
+}
@@ -0,0 +1,199 @@
+use std::ops::ControlFlow;
+
+use num_traits::{Float, Zero};
+
+use crate::error::{Error, Result, check_shape, check_square};
+use crate::matrix::{Mat, MatMut, MatRef};
+use crate::scalar::ComplexField;
+
+mod bicgstab;
+mod cg;
+mod gmres;
+mod lsqr;
+mod minres;
+mod operator;
+mod precond;
+
+pub use bicgstab::bicgstab;
+pub use cg::cg;
+pub use gmres::gmres;
+pub use lsqr::lsqr;
+pub use minres::minres;
+pub use operator::{FnOperator, LinearOperator};
+pub use precond::{IncompleteCholesky, IncompleteLu, Jacobi};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Iteration<R> {
+    pub column: usize,
+    pub iteration: usize,
+    pub residual_norm: R,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolveInfo<R> {
+    pub iterations: usize,
+    pub residual_norm: R,
+}
+
+type Callback<'a, R> = &'a mut dyn FnMut(&Iteration<R>) -> ControlFlow<()>;
+
+pub struct IterativeOptions<'a, T: ComplexField> {
+    pub rel_tol: T::Real,
+    pub abs_tol: T::Real,
+    pub max_iter: usize,
+    pub preconditioner: Option<&'a dyn LinearOperator<T>>,
+    pub callback: Option<Callback<'a, T::Real>>,
+}
+
+impl<T: ComplexField> Default for IterativeOptions<'_, T> {
+    fn default() -> Self {
+        IterativeOptions {
+            rel_tol: T::Real::epsilon().sqrt(),
+            abs_tol: T::Real::zero(),
+            max_iter: 1000,
+            preconditioner: None,
+            callback: None,
+        }
+    }
+}
+
+struct Monitor<'a, T: ComplexField> {
+    rel_tol: T::Real,
+    abs_tol: T::Real,
+    max_iter: usize,
+    preconditioner: Option<&'a dyn LinearOperator<T>>,
+    callback: Option<Callback<'a, T::Real>>,
+    column: usize,
+    info: SolveInfo<T::Real>,
+}
+
+impl<'a, T: ComplexField> Monitor<'a, T> {
+    fn new(options: IterativeOptions<'a, T>, n: usize) -> Result<Self> {
+        if let Some(m) = options.preconditioner {
+            check_shape((m.nrows(), m.ncols()), (n, n))?;
+        }
+        Ok(Monitor {
+            rel_tol: options.rel_tol,
+            abs_tol: options.abs_tol,
+            max_iter: options.max_iter,
+            preconditioner: options.preconditioner,
+            callback: options.callback,
+            column: 0,
+            info: SolveInfo {
+                iterations: 0,
+                residual_norm: T::Real::zero(),
+            },
+        })
+    }
+
+    fn target(&self, rhs_norm: T::Real) -> T::Real {
+        (self.rel_tol * rhs_norm).max(self.abs_tol)
+    }
+
+    fn precondition(&self, out: &mut Mat<T>, rhs: &Mat<T>) {
+        match self.preconditioner {
+            Some(m) => m.apply(out.as_mut(), rhs.as_ref()),
+            None => out.as_slice_mut().copy_from_slice(rhs.as_slice()),
+        }
+    }
+
+    fn advance(&self, iterations: usize) -> Result<()> {
+        if iterations >= self.max_iter {
+            Err(Error::NotConverged { iterations })
+        } else {
+            Ok(())
+        }
+    }
+
+    fn report(&mut self, iteration: usize, residual_norm: T::Real) -> Result<()> {
+        if let Some(callback) = self.callback.as_mut() {
+            let state = Iteration {
+                column: self.column,
+                iteration,
+                residual_norm,
+            };
+            if callback(&state).is_break() {
+                return Err(Error::NotConverged {
+                    iterations: iteration,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn finish_column(&mut self, iterations: usize, residual_norm: T::Real) {
+        self.info.iterations = self.info.iterations.max(iterations);
+        self.info.residual_norm = self.info.residual_norm.max(residual_norm);
+        self.column += 1;
+    }
+}
+
+fn solve_columns<T, A, F>(
+    mut x: MatMut<'_, T>,
+    a: &A,
+    b: MatRef<'_, T>,
+    options: IterativeOptions<'_, T>,
+    square: bool,
+    mut solve: F,
+) -> Result<SolveInfo<T::Real>>
+where
+    T: ComplexField,
+    A: LinearOperator<T> + ?Sized,
+    F: FnMut(&mut Monitor<'_, T>, &mut Mat<T>, &Mat<T>) -> Result<(usize, T::Real)>,
+{
+    if square {
+        check_square((a.nrows(), a.ncols()))?;
+    }
+    check_shape(b.shape(), (a.nrows(), x.ncols()))?;
+    check_shape(x.shape(), (a.ncols(), b.ncols()))?;
+    let mut monitor = Monitor::new(options, a.ncols())?;
+    for j in 0..b.ncols() {
+        let rhs = b.col(j).to_owned();
+        let mut xj = x.col(j).to_owned();
+        let result = solve(&mut monitor, &mut xj, &rhs);
+        x.rb_mut().col_mut(j).copy_from(xj.as_ref());
+        let (iterations, residual_norm) = result?;
+        monitor.finish_column(iterations, residual_norm);
+    }
+    Ok(monitor.info)
+}
+
+fn dot<T: ComplexField>(a: &Mat<T>, b: &Mat<T>) -> T {
+    let mut sum = T::zero();
+    for (&x, &y) in a.as_slice().iter().zip(b.as_slice()) {
+        sum += x.conj() * y;
+    }
+    sum
+}
+
+fn norm<T: ComplexField>(a: &Mat<T>) -> T::Real {
+    let mut sum = T::Real::zero();
+    for &x in a.as_slice() {
+        sum += x.modulus_sqr();
+    }
+    sum.sqrt()
+}
+
+fn axpy<T: ComplexField>(y: &mut Mat<T>, alpha: T, x: &Mat<T>) {
+    for (y, &x) in y.as_slice_mut().iter_mut().zip(x.as_slice()) {
+        *y += alpha * x;
+    }
+}
+
+fn scale<T: ComplexField>(y: &mut Mat<T>, alpha: T::Real) {
+    for y in y.as_slice_mut() {
+        *y = y.scale_real(alpha);
+    }
+}
+
+fn residual<T: ComplexField, A: LinearOperator<T> + ?Sized>(
+    a: &A,
+    x: &Mat<T>,
+    b: &Mat<T>,
+    work: &mut Mat<T>,
+) -> Mat<T> {
+    a.apply(work.as_mut(), x.as_ref());
+    let mut r = b.clone();
+    axpy(&mut r, -T::one(), work);
+    r
+}
@@ -0,0 +1,81 @@
+use super::{
+    IterativeOptions, LinearOperator, SolveInfo, axpy, dot, norm, residual, solve_columns,
+};
+use crate::error::{Error, Result};
+use crate::matrix::{Mat, MatMut, MatRef};
+use crate::scalar::ComplexField;
+
+// Right-preconditioned BiCGSTAB (van der Vorst).
+pub fn bicgstab<T: ComplexField, A: LinearOperator<T> + ?Sized>(
+    x: MatMut<'_, T>,
+    a: &A,
+    b: MatRef<'_, T>,
+    options: IterativeOptions<'_, T>,
+) -> Result<SolveInfo<T::Real>> {
+    let n = a.nrows();
+    let mut v = Mat::zeros(n, 1);
+    let mut t = Mat::zeros(n, 1);
+    let mut p_hat = Mat::zeros(n, 1);
+    let mut s_hat = Mat::zeros(n, 1);
+    solve_columns(x, a, b, options, true, |monitor, x, b| {
+        let target = monitor.target(norm(b));
+        let mut r = residual(a, x, b, &mut v);
+        let mut rnorm = norm(&r);
+        if rnorm <= target {
+            return Ok((0, rnorm));
+        }
+        let r_hat = r.clone();
+        let mut p = Mat::zeros(n, 1);
+        v.fill(T::zero());
+        let (mut rho, mut alpha, mut omega) = (T::one(), T::one(), T::one());
+        let mut iterations = 0;
+        loop {
+            monitor.advance(iterations)?;
+            iterations += 1;
+            let rho_next = dot(&r_hat, &r);
+            if rho_next.is_zero() || omega.is_zero() {
+                return Err(Error::NotConverged { iterations });
+            }
+            let beta = (rho_next / rho) * (alpha / omega);
+            rho = rho_next;
+            for ((p, &r), &v) in p
+                .as_slice_mut()
+                .iter_mut()
+                .zip(r.as_slice())
+                .zip(v.as_slice())
+            {
+                *p = r + beta * (*p - omega * v);
+            }
+            monitor.precondition(&mut p_hat, &p);
+            a.apply(v.as_mut(), p_hat.as_ref());
+            let denom = dot(&r_hat, &v);
+            if denom.is_zero() {
+                return Err(Error::NotConverged { iterations });
+            }
+            alpha = rho / denom;
+            axpy(x, alpha, &p_hat);
+            axpy(&mut r, -alpha, &v);
+            rnorm = norm(&r);
+            if rnorm <= target {
+                monitor.report(iterations, rnorm)?;
+                return Ok((iterations, rnorm));
+            }
+
+            monitor.precondition(&mut s_hat, &r);
+            a.apply(t.as_mut(), s_hat.as_ref());
+            let tt = dot(&t, &t);
+            omega = if tt.is_zero() {
+                T::zero()
+            } else {
+                dot(&t, &r) / tt
+            };
+            axpy(x, omega, &s_hat);
+            axpy(&mut r, -omega, &t);
+            rnorm = norm(&r);
+            monitor.report(iterations, rnorm)?;
+            if rnorm <= target {
+                return Ok((iterations, rnorm));
+            }
+        }
+    })
+}
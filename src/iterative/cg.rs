@@ -0,0 +1,55 @@
+use num_traits::{Float, Zero};
+
+use super::{
+    IterativeOptions, LinearOperator, SolveInfo, axpy, dot, norm, residual, solve_columns,
+};
+use crate::error::{Error, Result};
+use crate::matrix::{Mat, MatMut, MatRef};
+use crate::scalar::ComplexField;
+
+pub fn cg<T: ComplexField, A: LinearOperator<T> + ?Sized>(
+    x: MatMut<'_, T>,
+    a: &A,
+    b: MatRef<'_, T>,
+    options: IterativeOptions<'_, T>,
+) -> Result<SolveInfo<T::Real>> {
+    let n = a.nrows();
+    let mut q = Mat::zeros(n, 1);
+    let mut z = Mat::zeros(n, 1);
+    solve_columns(x, a, b, options, true, |monitor, x, b| {
+        let target = monitor.target(norm(b));
+        let mut r = residual(a, x, b, &mut q);
+        let mut rnorm = norm(&r);
+        if rnorm <= target {
+            return Ok((0, rnorm));
+        }
+        monitor.precondition(&mut z, &r);
+        let mut p = z.clone();
+        let mut rz = dot(&r, &z).re();
+        let mut iterations = 0;
+        loop {
+            monitor.advance(iterations)?;
+            iterations += 1;
+            a.apply(q.as_mut(), p.as_ref());
+            let pq = dot(&p, &q).re();
+            if pq <= T::Real::zero() || pq.is_nan() {
+                return Err(Error::NotPositiveDefinite { index: iterations });
+            }
+            let alpha = T::from_real(rz / pq);
+            axpy(x, alpha, &p);
+            axpy(&mut r, -alpha, &q);
+            rnorm = norm(&r);
+            monitor.report(iterations, rnorm)?;
+            if rnorm <= target {
+                return Ok((iterations, rnorm));
+            }
+            monitor.precondition(&mut z, &r);
+            let rz_next = dot(&r, &z).re();
+            let beta = T::from_real(rz_next / rz);
+            rz = rz_next;
+            for (p, &z) in p.as_slice_mut().iter_mut().zip(z.as_slice()) {
+                *p = z + beta * *p;
+            }
+        }
+    })
+}
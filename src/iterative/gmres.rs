@@ -0,0 +1,128 @@
+use num_traits::{Float, One, Zero};
+
+use super::{
+    IterativeOptions, LinearOperator, SolveInfo, axpy, dot, norm, residual, scale, solve_columns,
+};
+use crate::error::{Error, Result};
+use crate::matrix::{Mat, MatMut, MatRef};
+use crate::scalar::ComplexField;
+
+// Rotation [c, s; -conj(s), c] with real c that zeroes b in (a, b).
+fn givens<T: ComplexField>(a: T, b: T) -> (T::Real, T) {
+    let (abs_a, abs_b) = (a.modulus(), b.modulus());
+    if abs_b.is_zero() {
+        (T::Real::one(), T::zero())
+    } else if abs_a.is_zero() {
+        (T::Real::zero(), T::one())
+    } else {
+        let rho = abs_a.hypot(abs_b);
+        (
+            abs_a / rho,
+            a.scale_real(T::Real::one() / abs_a) * b.conj().scale_real(T::Real::one() / rho),
+        )
+    }
+}
+
+fn rotate<T: ComplexField>(c: T::Real, s: T, a: &mut T, b: &mut T) {
+    let (x, y) = (*a, *b);
+    *a = x.scale_real(c) + s * y;
+    *b = -(s.conj() * x) + y.scale_real(c);
+}
+
+// Right-preconditioned restarted GMRES(m).
+pub fn gmres<T: ComplexField, A: LinearOperator<T> + ?Sized>(
+    x: MatMut<'_, T>,
+    a: &A,
+    b: MatRef<'_, T>,
+    restart: usize,
+    options: IterativeOptions<'_, T>,
+) -> Result<SolveInfo<T::Real>> {
+    if restart == 0 {
+        return Err(Error::Unsupported {
+            operation: "GMRES with a zero restart length",
+        });
+    }
+    let n = a.nrows();
+    let m = restart.min(n.max(1));
+    let mut work = Mat::zeros(n, 1);
+    let mut z = Mat::zeros(n, 1);
+    solve_columns(x, a, b, options, true, |monitor, x, b| {
+        let target = monitor.target(norm(b));
+        let mut iterations = 0;
+        loop {
+            let mut r = residual(a, x, b, &mut work);
+            let beta = norm(&r);
+            if beta <= target {
+                return Ok((iterations, beta));
+            }
+            monitor.advance(iterations)?;
+
+            scale(&mut r, T::Real::one() / beta);
+            let mut basis = vec![r];
+            let mut h = Mat::<T>::zeros(m + 1, m);
+            let mut rotations = Vec::with_capacity(m);
+            let mut g = vec![T::zero(); m + 1];
+            g[0] = T::from_real(beta);
+            let mut rnorm = beta;
+            let mut k = 0;
+            while k < m {
+                monitor.advance(iterations)?;
+                iterations += 1;
+                monitor.precondition(&mut z, &basis[k]);
+                let mut w = Mat::zeros(n, 1);
+                a.apply(w.as_mut(), z.as_ref());
+                for (i, v) in basis.iter().enumerate() {
+                    let hij = dot(v, &w);
+                    h[(i, k)] = hij;
+                    axpy(&mut w, -hij, v);
+                }
+                let wnorm = norm(&w);
+                h[(k + 1, k)] = T::from_real(wnorm);
+
+                for (i, &(c, s)) in rotations.iter().enumerate() {
+                    let (mut hi, mut hi1) = (h[(i, k)], h[(i + 1, k)]);
+                    rotate(c, s, &mut hi, &mut hi1);
+                    h[(i, k)] = hi;
+                    h[(i + 1, k)] = hi1;
+                }
+                let (c, s) = givens(h[(k, k)], h[(k + 1, k)]);
+                let (mut hk, mut hk1) = (h[(k, k)], h[(k + 1, k)]);
+                rotate(c, s, &mut hk, &mut hk1);
+                h[(k, k)] = hk;
+                h[(k + 1, k)] = T::zero();
+                let (mut gk, mut gk1) = (g[k], g[k + 1]);
+                rotate(c, s, &mut gk, &mut gk1);
+                g[k] = gk;
+                g[k + 1] = gk1;
+                rotations.push((c, s));
+                k += 1;
+
+                rnorm = gk1.modulus();
+                monitor.report(iterations, rnorm)?;
+                if rnorm <= target || wnorm.is_zero() || iterations >= monitor.max_iter {
+                    break;
+                }
+                scale(&mut w, T::Real::one() / wnorm);
+                basis.push(w);
+            }
+
+            let mut y = g;
+            for i in (0..k).rev() {
+                let mut t = y[i];
+                for j in (i + 1)..k {
+                    t -= h[(i, j)] * y[j];
+                }
+                y[i] = t / h[(i, i)];
+            }
+            let mut update = Mat::zeros(n, 1);
+            for (v, &yi) in basis.iter().zip(&y[..k]) {
+                axpy(&mut update, yi, v);
+            }
+            monitor.precondition(&mut z, &update);
+            axpy(x, T::one(), &z);
+            if rnorm <= target {
+                return Ok((iterations, rnorm));
+            }
+        }
+    })
+}
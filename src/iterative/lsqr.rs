@@ -0,0 +1,90 @@
+use num_traits::{Float, One, Zero};
+
+use super::{
+    IterativeOptions, LinearOperator, SolveInfo, axpy, norm, residual, scale, solve_columns,
+};
+use crate::error::{Error, Result};
+use crate::matrix::{Mat, MatMut, MatRef};
+use crate::scalar::ComplexField;
+
+// LSQR (Paige and Saunders) for min ||Ax - b||. Stops once the residual meets
+// the tolerance or the normal-equation residual ||A^H r|| is small relative to
+// ||A|| ||r||, so inconsistent systems converge to the least-squares solution.
+pub fn lsqr<T: ComplexField, A: LinearOperator<T> + ?Sized>(
+    x: MatMut<'_, T>,
+    a: &A,
+    b: MatRef<'_, T>,
+    options: IterativeOptions<'_, T>,
+) -> Result<SolveInfo<T::Real>> {
+    if options.preconditioner.is_some() {
+        return Err(Error::Unsupported {
+            operation: "preconditioned LSQR",
+        });
+    }
+    let (m, n) = (a.nrows(), a.ncols());
+    let mut work = Mat::zeros(m, 1);
+    let mut v_next = Mat::zeros(n, 1);
+    solve_columns(x, a, b, options, false, |monitor, x, b| {
+        let target = monitor.target(norm(b));
+        let mut u = residual(a, x, b, &mut work);
+        let mut beta = norm(&u);
+        if beta <= target {
+            return Ok((0, beta));
+        }
+        scale(&mut u, T::Real::one() / beta);
+        let mut v = Mat::zeros(n, 1);
+        a.apply_adjoint(v.as_mut(), u.as_ref())?;
+        let mut alpha = norm(&v);
+        if alpha.is_zero() {
+            return Ok((0, beta));
+        }
+        scale(&mut v, T::Real::one() / alpha);
+
+        let mut w = v.clone();
+        let (mut phibar, mut rhobar) = (beta, alpha);
+        let mut anorm_sqr = T::Real::zero();
+        let mut iterations = 0;
+        loop {
+            monitor.advance(iterations)?;
+            iterations += 1;
+
+            a.apply(work.as_mut(), v.as_ref());
+            for (u, &av) in u.as_slice_mut().iter_mut().zip(work.as_slice()) {
+                *u = av - u.scale_real(alpha);
+            }
+            beta = norm(&u);
+            anorm_sqr = anorm_sqr + alpha * alpha + beta * beta;
+            if !beta.is_zero() {
+                scale(&mut u, T::Real::one() / beta);
+                a.apply_adjoint(v_next.as_mut(), u.as_ref())?;
+                for (v, &ahu) in v.as_slice_mut().iter_mut().zip(v_next.as_slice()) {
+                    *v = ahu - v.scale_real(beta);
+                }
+                alpha = norm(&v);
+                if !alpha.is_zero() {
+                    scale(&mut v, T::Real::one() / alpha);
+                }
+            }
+
+            let rho = rhobar.hypot(beta);
+            let c = rhobar / rho;
+            let s = beta / rho;
+            let theta = s * alpha;
+            rhobar = -c * alpha;
+            let phi = c * phibar;
+            phibar = s * phibar;
+
+            axpy(x, T::from_real(phi / rho), &w);
+            for (w, &v) in w.as_slice_mut().iter_mut().zip(v.as_slice()) {
+                *w = v - w.scale_real(theta / rho);
+            }
+
+            let rnorm = phibar;
+            let arnorm = phibar * alpha * c.abs();
+            monitor.report(iterations, rnorm)?;
+            if rnorm <= target || arnorm <= monitor.rel_tol * anorm_sqr.sqrt() * rnorm {
+                return Ok((iterations, rnorm));
+            }
+        }
+    })
+}
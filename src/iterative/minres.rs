@@ -0,0 +1,97 @@
+use num_traits::{Float, One, Zero};
+
+use super::{IterativeOptions, LinearOperator, SolveInfo, axpy, dot, residual, solve_columns};
+use crate::error::{Error, Result};
+use crate::matrix::{Mat, MatMut, MatRef};
+use crate::scalar::ComplexField;
+
+// Preconditioned MINRES following Paige and Saunders. All recurrence
+// coefficients are real for a Hermitian operator and preconditioner.
+pub fn minres<T: ComplexField, A: LinearOperator<T> + ?Sized>(
+    x: MatMut<'_, T>,
+    a: &A,
+    b: MatRef<'_, T>,
+    options: IterativeOptions<'_, T>,
+) -> Result<SolveInfo<T::Real>> {
+    let n = a.nrows();
+    let mut y = Mat::zeros(n, 1);
+    solve_columns(x, a, b, options, true, |monitor, x, b| {
+        monitor.precondition(&mut y, b);
+        let target = monitor.target(dot(b, &y).re().max(T::Real::zero()).sqrt());
+
+        let mut r1 = residual(a, x, b, &mut y);
+        monitor.precondition(&mut y, &r1);
+        let beta1 = dot(&r1, &y).re();
+        if beta1 < T::Real::zero() {
+            return Err(Error::NotPositiveDefinite { index: 0 });
+        }
+        let beta1 = beta1.sqrt();
+        if beta1 <= target {
+            return Ok((0, beta1));
+        }
+
+        let zero = T::Real::zero();
+        let one = T::Real::one();
+        let mut r2 = r1.clone();
+        let mut v = Mat::zeros(n, 1);
+        let mut w = Mat::<T>::zeros(n, 1);
+        let mut w1 = Mat::zeros(n, 1);
+        let mut w2 = Mat::zeros(n, 1);
+        let (mut oldb, mut beta) = (zero, beta1);
+        let (mut dbar, mut epsln, mut phibar) = (zero, zero, beta1);
+        let (mut cs, mut sn) = (-one, zero);
+        let mut iterations = 0;
+        loop {
+            monitor.advance(iterations)?;
+            iterations += 1;
+
+            for (v, &y) in v.as_slice_mut().iter_mut().zip(y.as_slice()) {
+                *v = y.scale_real(one / beta);
+            }
+            a.apply(y.as_mut(), v.as_ref());
+            if iterations >= 2 {
+                axpy(&mut y, T::from_real(-beta / oldb), &r1);
+            }
+            let alpha = dot(&v, &y).re();
+            axpy(&mut y, T::from_real(-alpha / beta), &r2);
+            std::mem::swap(&mut r1, &mut r2);
+            r2.as_slice_mut().copy_from_slice(y.as_slice());
+            monitor.precondition(&mut y, &r2);
+            oldb = beta;
+            beta = dot(&r2, &y).re();
+            if beta < zero {
+                return Err(Error::NotPositiveDefinite { index: iterations });
+            }
+            beta = beta.sqrt();
+
+            let oldeps = epsln;
+            let delta = cs * dbar + sn * alpha;
+            let gbar = sn * dbar - cs * alpha;
+            epsln = sn * beta;
+            dbar = -cs * beta;
+            let gamma = gbar.hypot(beta).max(T::Real::epsilon());
+            cs = gbar / gamma;
+            sn = beta / gamma;
+            let phi = cs * phibar;
+            phibar = sn * phibar;
+
+            std::mem::swap(&mut w1, &mut w2);
+            std::mem::swap(&mut w2, &mut w);
+            for (((w, &v), &w1), &w2) in w
+                .as_slice_mut()
+                .iter_mut()
+                .zip(v.as_slice())
+                .zip(w1.as_slice())
+                .zip(w2.as_slice())
+            {
+                *w = (v - w1.scale_real(oldeps) - w2.scale_real(delta)).scale_real(one / gamma);
+            }
+            axpy(x, T::from_real(phi), &w);
+
+            monitor.report(iterations, phibar)?;
+            if phibar <= target || beta.is_zero() {
+                return Ok((iterations, phibar));
+            }
+        }
+    })
+}
@@ -0,0 +1,286 @@
+use crate::error::{Error, OrPanic, Result, check_shape};
+use crate::matrix::{Mat, MatMut, MatRef, matmul_into};
+use crate::scalar::ComplexField;
+use crate::sparse::{SparseColMat, SparseColMatRef, SparseRowMat, SparseRowMatRef};
+
+pub trait LinearOperator<T: ComplexField> {
+    fn nrows(&self) -> usize;
+
+    fn ncols(&self) -> usize;
+
+    fn apply(&self, out: MatMut<'_, T>, rhs: MatRef<'_, T>);
+
+    fn apply_adjoint(&self, out: MatMut<'_, T>, rhs: MatRef<'_, T>) -> Result<()> {
+        let _ = (out, rhs);
+        Err(Error::Unsupported {
+            operation: "adjoint application",
+        })
+    }
+}
+
+impl<T: ComplexField, A: LinearOperator<T> + ?Sized> LinearOperator<T> for &A {
+    fn nrows(&self) -> usize {
+        (**self).nrows()
+    }
+
+    fn ncols(&self) -> usize {
+        (**self).ncols()
+    }
+
+    fn apply(&self, out: MatMut<'_, T>, rhs: MatRef<'_, T>) {
+        (**self).apply(out, rhs)
+    }
+
+    fn apply_adjoint(&self, out: MatMut<'_, T>, rhs: MatRef<'_, T>) -> Result<()> {
+        (**self).apply_adjoint(out, rhs)
+    }
+}
+
+fn dense_adjoint<T: ComplexField>(
+    a: MatRef<'_, T>,
+    mut out: MatMut<'_, T>,
+    rhs: MatRef<'_, T>,
+) -> Result<()> {
+    check_shape(out.shape(), (a.ncols(), rhs.ncols()))?;
+    check_shape(rhs.shape(), (a.nrows(), out.ncols()))?;
+    for c in 0..rhs.ncols() {
+        for j in 0..a.ncols() {
+            let mut sum = T::zero();
+            for i in 0..a.nrows() {
+                sum += a.at(i, j).conj() * *rhs.at(i, c);
+            }
+            *out.at_mut(j, c) = sum;
+        }
+    }
+    Ok(())
+}
+
+impl<T: ComplexField> LinearOperator<T> for MatRef<'_, T> {
+    fn nrows(&self) -> usize {
+        MatRef::nrows(*self)
+    }
+
+    fn ncols(&self) -> usize {
+        MatRef::ncols(*self)
+    }
+
+    #[track_caller]
+    fn apply(&self, out: MatMut<'_, T>, rhs: MatRef<'_, T>) {
+        matmul_into(out, T::one(), *self, rhs, T::zero());
+    }
+
+    fn apply_adjoint(&self, out: MatMut<'_, T>, rhs: MatRef<'_, T>) -> Result<()> {
+        dense_adjoint(*self, out, rhs)
+    }
+}
+
+impl<T: ComplexField> LinearOperator<T> for Mat<T> {
+    fn nrows(&self) -> usize {
+        Mat::nrows(self)
+    }
+
+    fn ncols(&self) -> usize {
+        Mat::ncols(self)
+    }
+
+    #[track_caller]
+    fn apply(&self, out: MatMut<'_, T>, rhs: MatRef<'_, T>) {
+        self.as_ref().apply(out, rhs)
+    }
+
+    fn apply_adjoint(&self, out: MatMut<'_, T>, rhs: MatRef<'_, T>) -> Result<()> {
+        dense_adjoint(self.as_ref(), out, rhs)
+    }
+}
+
+// out = op(A) * rhs where A is stored by columns and op is the identity
+// (scatter) or the conjugate transpose (gather).
+#[track_caller]
+fn sparse_scatter<T: ComplexField>(
+    a: SparseColMatRef<'_, T>,
+    mut out: MatMut<'_, T>,
+    rhs: MatRef<'_, T>,
+    conj: bool,
+) {
+    check_shape(out.shape(), (a.nrows(), rhs.ncols())).or_panic();
+    check_shape(rhs.shape(), (a.ncols(), out.ncols())).or_panic();
+    out.fill(T::zero());
+    for c in 0..rhs.ncols() {
+        for j in 0..a.ncols() {
+            let x = *rhs.at(j, c);
+            let (rows, values) = a.col(j);
+            for (&i, &v) in rows.iter().zip(values) {
+                let v = if conj { v.conj() } else { v };
+                *out.at_mut(i, c) += v * x;
+            }
+        }
+    }
+}
+
+#[track_caller]
+fn sparse_gather<T: ComplexField>(
+    a: SparseColMatRef<'_, T>,
+    mut out: MatMut<'_, T>,
+    rhs: MatRef<'_, T>,
+    conj: bool,
+) {
+    check_shape(out.shape(), (a.ncols(), rhs.ncols())).or_panic();
+    check_shape(rhs.shape(), (a.nrows(), out.ncols())).or_panic();
+    for c in 0..rhs.ncols() {
+        for j in 0..a.ncols() {
+            let (rows, values) = a.col(j);
+            let mut sum = T::zero();
+            for (&i, &v) in rows.iter().zip(values) {
+                let v = if conj { v.conj() } else { v };
+                sum += v * *rhs.at(i, c);
+            }
+            *out.at_mut(j, c) = sum;
+        }
+    }
+}
+
+impl<T: ComplexField> LinearOperator<T> for SparseColMatRef<'_, T> {
+    fn nrows(&self) -> usize {
+        SparseColMatRef::nrows(*self)
+    }
+
+    fn ncols(&self) -> usize {
+        SparseColMatRef::ncols(*self)
+    }
+
+    #[track_caller]
+    fn apply(&self, out: MatMut<'_, T>, rhs: MatRef<'_, T>) {
+        sparse_scatter(*self, out, rhs, false)
+    }
+
+    fn apply_adjoint(&self, out: MatMut<'_, T>, rhs: MatRef<'_, T>) -> Result<()> {
+        check_shape(out.shape(), (self.ncols(), rhs.ncols()))?;
+        check_shape(rhs.shape(), (self.nrows(), out.ncols()))?;
+        sparse_gather(*self, out, rhs, true);
+        Ok(())
+    }
+}
+
+impl<T: ComplexField> LinearOperator<T> for SparseColMat<T> {
+    fn nrows(&self) -> usize {
+        SparseColMat::nrows(self)
+    }
+
+    fn ncols(&self) -> usize {
+        SparseColMat::ncols(self)
+    }
+
+    #[track_caller]
+    fn apply(&self, out: MatMut<'_, T>, rhs: MatRef<'_, T>) {
+        self.as_ref().apply(out, rhs)
+    }
+
+    fn apply_adjoint(&self, out: MatMut<'_, T>, rhs: MatRef<'_, T>) -> Result<()> {
+        self.as_ref().apply_adjoint(out, rhs)
+    }
+}
+
+impl<T: ComplexField> LinearOperator<T> for SparseRowMatRef<'_, T> {
+    fn nrows(&self) -> usize {
+        SparseRowMatRef::nrows(*self)
+    }
+
+    fn ncols(&self) -> usize {
+        SparseRowMatRef::ncols(*self)
+    }
+
+    #[track_caller]
+    fn apply(&self, out: MatMut<'_, T>, rhs: MatRef<'_, T>) {
+        sparse_gather(self.transpose(), out, rhs, false)
+    }
+
+    fn apply_adjoint(&self, out: MatMut<'_, T>, rhs: MatRef<'_, T>) -> Result<()> {
+        check_shape(out.shape(), (self.ncols(), rhs.ncols()))?;
+        check_shape(rhs.shape(), (self.nrows(), out.ncols()))?;
+        sparse_scatter(self.transpose(), out, rhs, true);
+        Ok(())
+    }
+}
+
+impl<T: ComplexField> LinearOperator<T> for SparseRowMat<T> {
+    fn nrows(&self) -> usize {
+        SparseRowMat::nrows(self)
+    }
+
+    fn ncols(&self) -> usize {
+        SparseRowMat::ncols(self)
+    }
+
+    #[track_caller]
+    fn apply(&self, out: MatMut<'_, T>, rhs: MatRef<'_, T>) {
+        self.as_ref().apply(out, rhs)
+    }
+
+    fn apply_adjoint(&self, out: MatMut<'_, T>, rhs: MatRef<'_, T>) -> Result<()> {
+        self.as_ref().apply_adjoint(out, rhs)
+    }
+}
+
+pub struct FnOperator<F, G> {
+    nrows: usize,
+    ncols: usize,
+    apply: F,
+    adjoint: Option<G>,
+}
+
+impl<F> FnOperator<F, F> {
+    pub fn new<T>(nrows: usize, ncols: usize, apply: F) -> Self
+    where
+        F: Fn(MatMut<'_, T>, MatRef<'_, T>),
+    {
+        FnOperator {
+            nrows,
+            ncols,
+            apply,
+            adjoint: None,
+        }
+    }
+}
+
+impl<F, G> FnOperator<F, G> {
+    pub fn with_adjoint<T, H>(self, adjoint: H) -> FnOperator<F, H>
+    where
+        H: Fn(MatMut<'_, T>, MatRef<'_, T>),
+    {
+        FnOperator {
+            nrows: self.nrows,
+            ncols: self.ncols,
+            apply: self.apply,
+            adjoint: Some(adjoint),
+        }
+    }
+}
+
+impl<T, F, G> LinearOperator<T> for FnOperator<F, G>
+where
+    T: ComplexField,
+    F: Fn(MatMut<'_, T>, MatRef<'_, T>),
+    G: Fn(MatMut<'_, T>, MatRef<'_, T>),
+{
+    fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    fn apply(&self, out: MatMut<'_, T>, rhs: MatRef<'_, T>) {
+        (self.apply)(out, rhs)
+    }
+
+    fn apply_adjoint(&self, out: MatMut<'_, T>, rhs: MatRef<'_, T>) -> Result<()> {
+        let adjoint = self.adjoint.as_ref().ok_or(Error::Unsupported {
+            operation: "adjoint application",
+        })?;
+        check_shape(out.shape(), (self.ncols, rhs.ncols()))?;
+        check_shape(rhs.shape(), (self.nrows, out.ncols()))?;
+        adjoint(out, rhs);
+        Ok(())
+    }
+}
@@ -0,0 +1,255 @@
+use num_traits::{Float, One, Zero};
+
+use super::LinearOperator;
+use crate::error::{Error, OrPanic, Result, check_shape, check_square};
+use crate::matrix::{MatMut, MatRef};
+use crate::scalar::ComplexField;
+use crate::sparse::{SparseColMat, SparseColMatRef, SparseRowMat, SparseRowMatRef};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Jacobi<T> {
+    inv_diag: Vec<T>,
+}
+
+impl<T: ComplexField> Jacobi<T> {
+    pub fn new(diag: &[T]) -> Result<Self> {
+        let mut inv_diag = Vec::with_capacity(diag.len());
+        for (index, &d) in diag.iter().enumerate() {
+            if d.is_zero() {
+                return Err(Error::Singular { index });
+            }
+            inv_diag.push(T::one() / d);
+        }
+        Ok(Jacobi { inv_diag })
+    }
+
+    pub fn from_dense(a: MatRef<'_, T>) -> Result<Self> {
+        check_square(a.shape())?;
+        let diag: Vec<T> = (0..a.nrows()).map(|i| *a.at(i, i)).collect();
+        Self::new(&diag)
+    }
+
+    pub fn from_sparse(a: SparseColMatRef<'_, T>) -> Result<Self> {
+        check_square(a.shape())?;
+        let diag: Vec<T> = (0..a.ncols())
+            .map(|j| a.get(j, j).copied().unwrap_or(T::zero()))
+            .collect();
+        Self::new(&diag)
+    }
+}
+
+impl<T: ComplexField> LinearOperator<T> for Jacobi<T> {
+    fn nrows(&self) -> usize {
+        self.inv_diag.len()
+    }
+
+    fn ncols(&self) -> usize {
+        self.inv_diag.len()
+    }
+
+    #[track_caller]
+    fn apply(&self, mut out: MatMut<'_, T>, rhs: MatRef<'_, T>) {
+        check_shape(out.shape(), rhs.shape()).or_panic();
+        check_shape(rhs.shape(), (self.inv_diag.len(), rhs.ncols())).or_panic();
+        for j in 0..rhs.ncols() {
+            for (i, &d) in self.inv_diag.iter().enumerate() {
+                *out.at_mut(i, j) = d * *rhs.at(i, j);
+            }
+        }
+    }
+
+    fn apply_adjoint(&self, mut out: MatMut<'_, T>, rhs: MatRef<'_, T>) -> Result<()> {
+        check_shape(out.shape(), rhs.shape())?;
+        check_shape(rhs.shape(), (self.inv_diag.len(), rhs.ncols()))?;
+        for j in 0..rhs.ncols() {
+            for (i, &d) in self.inv_diag.iter().enumerate() {
+                *out.at_mut(i, j) = d.conj() * *rhs.at(i, j);
+            }
+        }
+        Ok(())
+    }
+}
+
+// Zero fill-in incomplete Cholesky: L keeps the pattern of the lower triangle
+// of A, and only updates that land on that pattern are applied.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncompleteCholesky<T> {
+    l: SparseColMat<T>,
+}
+
+impl<T: ComplexField> IncompleteCholesky<T> {
+    pub fn new(a: SparseColMatRef<'_, T>) -> Result<Self> {
+        check_square(a.shape())?;
+        let n = a.ncols();
+        let lower: Vec<_> = a
+            .triplet_iter()
+            .filter(|&(i, j, _)| i >= j)
+            .map(|(i, j, &v)| (i, j, v))
+            .collect();
+        let (ptr, idx, mut val) = SparseColMat::from_triplets(n, n, &lower).into_parts();
+
+        for k in 0..n {
+            let (start, end) = (ptr[k], ptr[k + 1]);
+            if start == end || idx[start] != k {
+                return Err(Error::NotPositiveDefinite { index: k });
+            }
+            let d = val[start].re();
+            if d <= T::Real::zero() || d.is_nan() {
+                return Err(Error::NotPositiveDefinite { index: k });
+            }
+            let d = d.sqrt();
+            val[start] = T::from_real(d);
+            for v in &mut val[start + 1..end] {
+                *v = v.scale_real(T::Real::one() / d);
+            }
+            for p in start + 1..end {
+                let (j, ljk) = (idx[p], val[p].conj());
+                let column = ptr[j]..ptr[j + 1];
+                for q in p..end {
+                    if let Ok(pos) = idx[column.clone()].binary_search(&idx[q]) {
+                        let lik = val[q];
+                        val[column.start + pos] -= lik * ljk;
+                    }
+                }
+            }
+        }
+        Ok(IncompleteCholesky {
+            l: SparseColMat::new(n, n, ptr, idx, val),
+        })
+    }
+
+    #[inline]
+    pub fn l(&self) -> SparseColMatRef<'_, T> {
+        self.l.as_ref()
+    }
+}
+
+impl<T: ComplexField> LinearOperator<T> for IncompleteCholesky<T> {
+    fn nrows(&self) -> usize {
+        self.l.nrows()
+    }
+
+    fn ncols(&self) -> usize {
+        self.l.ncols()
+    }
+
+    #[track_caller]
+    fn apply(&self, mut out: MatMut<'_, T>, rhs: MatRef<'_, T>) {
+        let n = self.l.nrows();
+        check_shape(out.shape(), rhs.shape()).or_panic();
+        check_shape(rhs.shape(), (n, rhs.ncols())).or_panic();
+        let (ptr, idx, val) = (self.l.col_ptr(), self.l.row_indices(), self.l.values());
+        out.copy_from(rhs);
+        for c in 0..rhs.ncols() {
+            for k in 0..n {
+                let t = *out.at(k, c) / val[ptr[k]];
+                *out.at_mut(k, c) = t;
+                for p in ptr[k] + 1..ptr[k + 1] {
+                    *out.at_mut(idx[p], c) -= val[p] * t;
+                }
+            }
+            for k in (0..n).rev() {
+                let mut t = *out.at(k, c);
+                for p in ptr[k] + 1..ptr[k + 1] {
+                    t -= val[p].conj() * *out.at(idx[p], c);
+                }
+                *out.at_mut(k, c) = t / val[ptr[k]].conj();
+            }
+        }
+    }
+
+    fn apply_adjoint(&self, out: MatMut<'_, T>, rhs: MatRef<'_, T>) -> Result<()> {
+        check_shape(out.shape(), rhs.shape())?;
+        check_shape(rhs.shape(), (self.l.nrows(), rhs.ncols()))?;
+        self.apply(out, rhs);
+        Ok(())
+    }
+}
+
+// Zero fill-in incomplete LU computed row by row on the pattern of A. The unit
+// lower factor and U share one CSR matrix, with `diag` locating U's diagonal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncompleteLu<T> {
+    factors: SparseRowMat<T>,
+    diag: Vec<usize>,
+}
+
+impl<T: ComplexField> IncompleteLu<T> {
+    pub fn new(a: SparseColMatRef<'_, T>) -> Result<Self> {
+        check_square(a.shape())?;
+        let n = a.nrows();
+        let (ptr, idx, mut val) = a.to_row_mat().into_parts();
+        let mut diag = Vec::with_capacity(n);
+        for i in 0..n {
+            match idx[ptr[i]..ptr[i + 1]].binary_search(&i) {
+                Ok(pos) => diag.push(ptr[i] + pos),
+                Err(_) => return Err(Error::Singular { index: i }),
+            }
+        }
+
+        for i in 0..n {
+            let row = ptr[i]..ptr[i + 1];
+            for p in row.start..diag[i] {
+                let k = idx[p];
+                let lik = val[p] / val[diag[k]];
+                val[p] = lik;
+                for q in diag[k] + 1..ptr[k + 1] {
+                    if let Ok(pos) = idx[row.clone()].binary_search(&idx[q]) {
+                        let ukj = val[q];
+                        val[row.start + pos] -= lik * ukj;
+                    }
+                }
+            }
+            if val[diag[i]].is_zero() {
+                return Err(Error::Singular { index: i });
+            }
+        }
+        Ok(IncompleteLu {
+            factors: SparseRowMat::new(n, n, ptr, idx, val),
+            diag,
+        })
+    }
+
+    #[inline]
+    pub fn factors(&self) -> SparseRowMatRef<'_, T> {
+        self.factors.as_ref()
+    }
+}
+
+impl<T: ComplexField> LinearOperator<T> for IncompleteLu<T> {
+    fn nrows(&self) -> usize {
+        self.factors.nrows()
+    }
+
+    fn ncols(&self) -> usize {
+        self.factors.ncols()
+    }
+
+    #[track_caller]
+    fn apply(&self, mut out: MatMut<'_, T>, rhs: MatRef<'_, T>) {
+        let n = self.factors.nrows();
+        check_shape(out.shape(), rhs.shape()).or_panic();
+        check_shape(rhs.shape(), (n, rhs.ncols())).or_panic();
+        let (ptr, idx, val) = (
+            self.factors.row_ptr(),
+            self.factors.col_indices(),
+            self.factors.values(),
+        );
+        for c in 0..rhs.ncols() {
+            for (i, (&start, &diag)) in ptr.iter().zip(&self.diag).enumerate() {
+                let mut t = *rhs.at(i, c);
+                for p in start..diag {
+                    t -= val[p] * *out.at(idx[p], c);
+                }
+                *out.at_mut(i, c) = t;
+            }
+            for i in (0..n).rev() {
+                let mut t = *out.at(i, c);
+                for p in self.diag[i] + 1..ptr[i + 1] {
+                    t -= val[p] * *out.at(idx[p], c);
+                }
+                *out.at_mut(i, c) = t / val[self.diag[i]];
+            }
+        }
+    }
+}
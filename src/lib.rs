@@ -1,4 +1,15 @@
+pub mod error;
+pub mod io;
+pub mod iterative;
+pub mod linalg;
 pub mod matrix;
+pub mod parallel;
+pub mod scalar;
+pub mod sparse;
 
-pub use matrix::{Mat, MatMut, MatRef};
+pub use error::{Axis, Error, Part, Result};
+pub use matrix::{Mat, MatMut, MatRef, SimdLevel, matmul_into, try_matmul_into};
 pub use num_complex::Complex;
+pub use parallel::Parallelism;
+pub use scalar::{ComplexField, RealField};
+pub use sparse::{SparseColMat, SparseColMatRef, SparseRowMat, SparseRowMatRef};
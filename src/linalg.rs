@@ -0,0 +1,28 @@
+mod cholesky;
+mod eigen;
+mod hessenberg;
+mod householder;
+mod ldlt;
+mod lu;
+mod norm;
+mod qr;
+mod schur;
+mod self_adjoint_eigen;
+mod svd;
+mod triangular;
+
+pub use cholesky::Cholesky;
+pub use eigen::Eigen;
+pub use hessenberg::Hessenberg;
+pub use ldlt::Ldlt;
+pub use lu::Lu;
+pub use norm::Norm;
+pub use qr::{ColPivQr, Qr};
+pub use schur::{ComplexSchur, RealSchur};
+pub use self_adjoint_eigen::SelfAdjointEigen;
+pub use svd::Svd;
+pub use triangular::{
+    Diag, Op, lower_triangular_matmul_into, solve_lower_triangular, solve_upper_triangular,
+    try_lower_triangular_matmul_into, try_upper_triangular_matmul_into,
+    upper_triangular_matmul_into,
+};
@@ -0,0 +1,202 @@
+use num_traits::{Float, One, Zero};
+
+use crate::error::{Error, OrPanic, Result, check_shape, check_square};
+use crate::matrix::{Mat, MatMut, MatRef};
+use crate::parallel;
+use crate::scalar::ComplexField;
+
+pub struct Cholesky<T> {
+    l: Mat<T>,
+}
+
+impl<T: ComplexField> Cholesky<T> {
+    #[track_caller]
+    pub fn new(a: MatRef<'_, T>) -> Self {
+        Self::try_new(a).or_panic()
+    }
+
+    pub fn try_new(a: MatRef<'_, T>) -> Result<Self> {
+        check_square(a.shape())?;
+        let n = a.nrows();
+        let mut l: Mat<T> = Mat::zeros(n, n);
+        for j in 0..n {
+            let mut d = a.at(j, j).re();
+            for k in 0..j {
+                d -= l[(j, k)].modulus_sqr();
+            }
+            if d <= T::Real::zero() || d.is_nan() {
+                return Err(Error::NotPositiveDefinite { index: j });
+            }
+            let d = d.sqrt();
+            l[(j, j)] = T::from_real(d);
+            let inv = T::Real::one() / d;
+            let (left, right) = l.split_at_col_mut(j);
+            let left = left.rb();
+            let below = right.col_mut(0).view_mut(j + 1, 0, n - j - 1, 1);
+            parallel::for_each_mut(below, j, |i, _, out| {
+                let i = i + j + 1;
+                let mut s = *a.at(i, j);
+                for k in 0..j {
+                    s -= *left.at(i, k) * left.at(j, k).conj();
+                }
+                *out = s.scale_real(inv);
+            });
+        }
+        Ok(Cholesky { l })
+    }
+
+    #[inline]
+    pub fn dim(&self) -> usize {
+        self.l.nrows()
+    }
+
+    #[inline]
+    pub fn l(&self) -> MatRef<'_, T> {
+        self.l.as_ref()
+    }
+
+    pub fn into_l(self) -> Mat<T> {
+        self.l
+    }
+
+    pub fn solve(&self, b: MatRef<'_, T>) -> Result<Mat<T>> {
+        let mut x = b.to_owned();
+        self.solve_in_place(x.as_mut())?;
+        Ok(x)
+    }
+
+    pub fn solve_in_place(&self, b: MatMut<'_, T>) -> Result<()> {
+        let n = self.dim();
+        check_shape(b.shape(), (n, b.ncols()))?;
+        self.substitute(b);
+        Ok(())
+    }
+
+    fn substitute(&self, b: MatMut<'_, T>) {
+        let n = self.dim();
+        let l = self.l.as_ref();
+        parallel::for_each_col_mut(b, n * n, |_, mut b| {
+            for k in 0..n {
+                let t = *b.at(k, 0) / *l.at(k, k);
+                *b.at_mut(k, 0) = t;
+                for i in (k + 1)..n {
+                    *b.at_mut(i, 0) -= *l.at(i, k) * t;
+                }
+            }
+            for k in (0..n).rev() {
+                let mut t = *b.at(k, 0);
+                for i in (k + 1)..n {
+                    t -= l.at(i, k).conj() * *b.at(i, 0);
+                }
+                *b.at_mut(k, 0) = t / *l.at(k, k);
+            }
+        });
+    }
+
+    pub fn det(&self) -> T::Real {
+        let mut det = T::Real::one();
+        for k in 0..self.dim() {
+            let d = self.l[(k, k)].re();
+            det *= d * d;
+        }
+        det
+    }
+
+    pub fn log_det(&self) -> T::Real {
+        let mut log_det = T::Real::zero();
+        for k in 0..self.dim() {
+            log_det += self.l[(k, k)].re().ln();
+        }
+        log_det + log_det
+    }
+
+    pub fn inverse(&self) -> Mat<T> {
+        let mut inv = Mat::identity(self.dim());
+        self.substitute(inv.as_mut());
+        inv
+    }
+
+    // A NaN or infinite x can still break an update, so both directions are
+    // fallible and leave the factor untouched on error.
+    pub fn rank_one_update(&mut self, x: MatRef<'_, T>) -> Result<()> {
+        self.l = rank_one_modify(self.l.as_ref(), x, false)?;
+        Ok(())
+    }
+
+    pub fn rank_one_downdate(&mut self, x: MatRef<'_, T>) -> Result<()> {
+        self.l = rank_one_modify(self.l.as_ref(), x, true)?;
+        Ok(())
+    }
+}
+
+fn rank_one_modify<T: ComplexField>(
+    l: MatRef<'_, T>,
+    x: MatRef<'_, T>,
+    downdate: bool,
+) -> Result<Mat<T>> {
+    let n = l.nrows();
+    check_shape(x.shape(), (n, 1))?;
+    let mut l = l.to_owned();
+    let mut x = x.to_owned();
+    for k in 0..n {
+        let lkk = l[(k, k)].re();
+        let xk = x[(k, 0)];
+        let r2 = if downdate {
+            lkk * lkk - xk.modulus_sqr()
+        } else {
+            lkk * lkk + xk.modulus_sqr()
+        };
+        if r2 <= T::Real::zero() || r2.is_nan() {
+            return Err(Error::NotPositiveDefinite { index: k });
+        }
+        let r = r2.sqrt();
+        let c = r / lkk;
+        let s = xk.scale_real(T::Real::one() / lkk);
+        let inv_c = T::Real::one() / c;
+        l[(k, k)] = T::from_real(r);
+        for i in (k + 1)..n {
+            let xi = x[(i, 0)];
+            let lik = if downdate {
+                (l[(i, k)] - s.conj() * xi).scale_real(inv_c)
+            } else {
+                (l[(i, k)] + s.conj() * xi).scale_real(inv_c)
+            };
+            l[(i, k)] = lik;
+            x[(i, 0)] = xi.scale_real(c) - s * lik;
+        }
+    }
+    Ok(l)
+}
+
+impl<T: ComplexField> MatRef<'_, T> {
+    #[track_caller]
+    pub fn cholesky(self) -> Cholesky<T> {
+        Cholesky::new(self)
+    }
+
+    pub fn try_cholesky(self) -> Result<Cholesky<T>> {
+        Cholesky::try_new(self)
+    }
+}
+
+impl<T: ComplexField> MatMut<'_, T> {
+    #[track_caller]
+    pub fn cholesky(&self) -> Cholesky<T> {
+        self.rb().cholesky()
+    }
+
+    pub fn try_cholesky(&self) -> Result<Cholesky<T>> {
+        self.rb().try_cholesky()
+    }
+}
+
+impl<T: ComplexField> Mat<T> {
+    #[track_caller]
+    pub fn cholesky(&self) -> Cholesky<T> {
+        self.as_ref().cholesky()
+    }
+
+    pub fn try_cholesky(&self) -> Result<Cholesky<T>> {
+        self.as_ref().try_cholesky()
+    }
+}
@@ -0,0 +1,185 @@
+use num_complex::Complex;
+use num_traits::{One, Zero};
+
+use super::schur::{ComplexSchur, RealSchur};
+use crate::error::{Result, check_square};
+use crate::matrix::{Mat, MatMut, MatRef};
+use crate::scalar::{ComplexField, RealField};
+
+type EigenParts<R> = (Mat<Complex<R>>, Option<Mat<Complex<R>>>);
+
+pub struct Eigen<T: ComplexField> {
+    eigenvalues: Mat<Complex<T::Real>>,
+    eigenvectors: Option<Mat<Complex<T::Real>>>,
+}
+
+impl<T: ComplexField> Eigen<T> {
+    pub fn new(a: MatRef<'_, T>) -> Result<Self> {
+        let (schur, eigenvalues) = complex_schur(a)?;
+        let mut eigenvectors = triangular_eigenvectors(schur.t(), schur.z());
+        if T::is_real_type() {
+            make_real_pairs(eigenvalues.as_ref(), eigenvectors.as_mut());
+        }
+        Ok(Eigen {
+            eigenvalues,
+            eigenvectors: Some(eigenvectors),
+        })
+    }
+
+    pub fn values_only(a: MatRef<'_, T>) -> Result<Self> {
+        check_square(a.shape())?;
+        let eigenvalues = if T::is_real_type() {
+            RealSchur::new(a.map(|x| x.re()).as_ref())?.eigenvalues()
+        } else {
+            ComplexSchur::new(a.map(|x| Complex::new(x.re(), x.im())).as_ref())?.eigenvalues()
+        };
+        Ok(Eigen {
+            eigenvalues,
+            eigenvectors: None,
+        })
+    }
+
+    #[inline]
+    pub fn dim(&self) -> usize {
+        self.eigenvalues.nrows()
+    }
+
+    #[inline]
+    pub fn eigenvalues(&self) -> MatRef<'_, Complex<T::Real>> {
+        self.eigenvalues.as_ref()
+    }
+
+    #[inline]
+    pub fn eigenvectors(&self) -> Option<MatRef<'_, Complex<T::Real>>> {
+        self.eigenvectors.as_ref().map(|v| v.as_ref())
+    }
+
+    pub fn into_parts(self) -> EigenParts<T::Real> {
+        (self.eigenvalues, self.eigenvectors)
+    }
+}
+
+type SchurWithValues<T> = (ComplexSchur<T>, Mat<Complex<T>>);
+
+fn complex_schur<T: ComplexField>(a: MatRef<'_, T>) -> Result<SchurWithValues<T::Real>> {
+    check_square(a.shape())?;
+    if T::is_real_type() {
+        let schur = RealSchur::new(a.map(|x| x.re()).as_ref())?;
+        let values = schur.eigenvalues();
+        Ok((schur.into_complex(), values))
+    } else {
+        let schur = ComplexSchur::new(a.map(|x| Complex::new(x.re(), x.im())).as_ref())?;
+        let values = schur.eigenvalues();
+        Ok((schur, values))
+    }
+}
+
+fn triangular_eigenvectors<T: RealField>(
+    t: MatRef<'_, Complex<T>>,
+    z: MatRef<'_, Complex<T>>,
+) -> Mat<Complex<T>> {
+    let n = t.nrows();
+    let mut norm = T::zero();
+    for j in 0..n {
+        for i in 0..=j {
+            norm = norm.max(t.at(i, j).modulus());
+        }
+    }
+    let small = (T::epsilon() * norm).max(T::min_positive_value());
+    let mut vectors = Mat::zeros(n, n);
+    let mut x: Vec<Complex<T>> = vec![Complex::zero(); n];
+    for k in 0..n {
+        let lambda = *t.at(k, k);
+        x[k] = Complex::one();
+        for i in (0..k).rev() {
+            let mut s = Complex::<T>::zero();
+            for (j, &xj) in x.iter().enumerate().take(k + 1).skip(i + 1) {
+                s += *t.at(i, j) * xj;
+            }
+            let mut d = *t.at(i, i) - lambda;
+            if d.modulus() < small {
+                d = Complex::from_real(small);
+            }
+            x[i] = -s / d;
+        }
+        for i in 0..n {
+            let mut v = Complex::zero();
+            for (j, &xj) in x.iter().enumerate().take(k + 1) {
+                v += *z.at(i, j) * xj;
+            }
+            vectors[(i, k)] = v;
+        }
+        normalize(vectors.col_mut(k));
+    }
+    vectors
+}
+
+fn normalize<T: RealField>(mut v: MatMut<'_, Complex<T>>) {
+    let mut largest = Complex::zero();
+    let mut norm = T::zero();
+    for i in 0..v.nrows() {
+        let vi = *v.at(i, 0);
+        if vi.modulus() > largest.modulus() {
+            largest = vi;
+        }
+        norm = norm.hypot(vi.modulus());
+    }
+    if norm.is_zero() {
+        return;
+    }
+    let phase = largest.conj().unscale(largest.modulus() * norm);
+    for i in 0..v.nrows() {
+        *v.at_mut(i, 0) *= phase;
+    }
+}
+
+fn make_real_pairs<T: RealField>(
+    values: MatRef<'_, Complex<T>>,
+    mut vectors: MatMut<'_, Complex<T>>,
+) {
+    let n = values.nrows();
+    let mut k = 0;
+    while k < n {
+        if values.at(k, 0).im.is_zero() {
+            for i in 0..n {
+                vectors.at_mut(i, k).im = T::zero();
+            }
+            k += 1;
+        } else {
+            for i in 0..n {
+                *vectors.at_mut(i, k + 1) = vectors.at(i, k).conj();
+            }
+            k += 2;
+        }
+    }
+}
+
+impl<T: ComplexField> MatRef<'_, T> {
+    pub fn eig(self) -> Result<Eigen<T>> {
+        Eigen::new(self)
+    }
+
+    pub fn eigvals(self) -> Result<Mat<Complex<T::Real>>> {
+        Ok(Eigen::values_only(self)?.eigenvalues)
+    }
+}
+
+impl<T: ComplexField> MatMut<'_, T> {
+    pub fn eig(&self) -> Result<Eigen<T>> {
+        self.rb().eig()
+    }
+
+    pub fn eigvals(&self) -> Result<Mat<Complex<T::Real>>> {
+        self.rb().eigvals()
+    }
+}
+
+impl<T: ComplexField> Mat<T> {
+    pub fn eig(&self) -> Result<Eigen<T>> {
+        self.as_ref().eig()
+    }
+
+    pub fn eigvals(&self) -> Result<Mat<Complex<T::Real>>> {
+        self.as_ref().eigvals()
+    }
+}
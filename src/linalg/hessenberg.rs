@@ -0,0 +1,111 @@
+use super::householder::{apply_householder_left, apply_householder_right, make_householder};
+use crate::error::{OrPanic, Result, check_shape, check_square};
+use crate::matrix::{Mat, MatMut, MatRef};
+use crate::scalar::ComplexField;
+
+pub struct Hessenberg<T> {
+    householder: Mat<T>,
+    tau: Vec<T>,
+}
+
+impl<T: ComplexField> Hessenberg<T> {
+    #[track_caller]
+    pub fn new(a: MatRef<'_, T>) -> Self {
+        Self::try_new(a).or_panic()
+    }
+
+    pub fn try_new(a: MatRef<'_, T>) -> Result<Self> {
+        check_square(a.shape())?;
+        let n = a.nrows();
+        let mut h = a.to_owned();
+        let mut tau = Vec::with_capacity(n.saturating_sub(2));
+        for k in 0..n.saturating_sub(2) {
+            let m = n - k - 1;
+            let (mut left, mut right) = h.as_mut().split_at_col_mut(k + 1);
+            let t = make_householder(left.rb_mut().view_mut(k + 1, k, m, 1));
+            let essential = left.rb().view(k + 2, k, m - 1, 1);
+            apply_householder_left(essential, t.conj(), right.rb_mut().view_mut(k + 1, 0, m, m));
+            tau.push(t);
+            let essential = h.view(k + 2, k, m - 1, 1).to_owned();
+            apply_householder_right(essential.as_ref(), t, h.view_mut(0, k + 1, n, m));
+        }
+        Ok(Hessenberg {
+            householder: h,
+            tau,
+        })
+    }
+
+    #[inline]
+    pub fn dim(&self) -> usize {
+        self.householder.nrows()
+    }
+
+    pub fn h(&self) -> Mat<T> {
+        let n = self.dim();
+        Mat::from_fn(n, n, |i, j| {
+            if i <= j + 1 {
+                self.householder[(i, j)]
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    pub fn q(&self) -> Mat<T> {
+        let mut q = Mat::identity(self.dim());
+        self.apply_q(q.as_mut());
+        q
+    }
+
+    #[track_caller]
+    pub fn apply_q(&self, x: MatMut<'_, T>) {
+        self.try_apply_q(x).or_panic()
+    }
+
+    pub fn try_apply_q(&self, mut x: MatMut<'_, T>) -> Result<()> {
+        let n = self.dim();
+        check_shape(x.shape(), (n, x.ncols()))?;
+        let ncols = x.ncols();
+        for (k, &t) in self.tau.iter().enumerate().rev() {
+            apply_householder_left(
+                self.householder.view(k + 2, k, n - k - 2, 1),
+                t,
+                x.rb_mut().view_mut(k + 1, 0, n - k - 1, ncols),
+            );
+        }
+        Ok(())
+    }
+}
+
+impl<T: ComplexField> MatRef<'_, T> {
+    #[track_caller]
+    pub fn hessenberg(self) -> Hessenberg<T> {
+        Hessenberg::new(self)
+    }
+
+    pub fn try_hessenberg(self) -> Result<Hessenberg<T>> {
+        Hessenberg::try_new(self)
+    }
+}
+
+impl<T: ComplexField> MatMut<'_, T> {
+    #[track_caller]
+    pub fn hessenberg(&self) -> Hessenberg<T> {
+        self.rb().hessenberg()
+    }
+
+    pub fn try_hessenberg(&self) -> Result<Hessenberg<T>> {
+        self.rb().try_hessenberg()
+    }
+}
+
+impl<T: ComplexField> Mat<T> {
+    #[track_caller]
+    pub fn hessenberg(&self) -> Hessenberg<T> {
+        self.as_ref().hessenberg()
+    }
+
+    pub fn try_hessenberg(&self) -> Result<Hessenberg<T>> {
+        self.as_ref().try_hessenberg()
+    }
+}
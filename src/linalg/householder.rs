@@ -0,0 +1,105 @@
+use num_traits::{Float, One, Zero};
+
+use crate::matrix::{MatMut, MatRef};
+use crate::scalar::ComplexField;
+
+pub(crate) fn stable_norm<T: ComplexField>(x: MatRef<'_, T>) -> T::Real {
+    let mut scale = T::Real::zero();
+    let mut ssq = T::Real::one();
+    for j in 0..x.ncols() {
+        for i in 0..x.nrows() {
+            let v = *x.at(i, j);
+            for part in [v.re(), v.im()] {
+                let abs = part.abs();
+                if abs.is_zero() {
+                    continue;
+                }
+                if scale < abs {
+                    let r = scale / abs;
+                    ssq = T::Real::one() + ssq * r * r;
+                    scale = abs;
+                } else {
+                    let r = abs / scale;
+                    ssq += r * r;
+                }
+            }
+        }
+    }
+    scale * ssq.sqrt()
+}
+
+pub(crate) fn make_householder<T: ComplexField>(mut x: MatMut<'_, T>) -> T {
+    let n = x.nrows();
+    if n == 0 {
+        return T::zero();
+    }
+    let alpha = *x.at(0, 0);
+    let x_norm = stable_norm(x.rb().view(1, 0, n - 1, 1));
+    if x_norm.is_zero() && alpha.im().is_zero() {
+        return T::zero();
+    }
+    let norm = alpha.modulus().hypot(x_norm);
+    let beta = if alpha.re() >= T::Real::zero() {
+        -norm
+    } else {
+        norm
+    };
+    let beta_t = T::from_real(beta);
+    let tau = (beta_t - alpha) / beta_t;
+    let scale = T::one() / (alpha - beta_t);
+    for i in 1..n {
+        *x.at_mut(i, 0) *= scale;
+    }
+    *x.at_mut(0, 0) = beta_t;
+    tau
+}
+
+pub(crate) fn apply_householder_left<T: ComplexField>(
+    essential: MatRef<'_, T>,
+    tau: T,
+    mut a: MatMut<'_, T>,
+) {
+    if tau.is_zero() {
+        return;
+    }
+    debug_assert_eq!(a.nrows(), essential.nrows() + 1);
+    for j in 0..a.ncols() {
+        let mut w = *a.at(0, j);
+        for i in 0..essential.nrows() {
+            w += essential.at(i, 0).conj() * *a.at(i + 1, j);
+        }
+        let w = tau * w;
+        if w.is_zero() {
+            continue;
+        }
+        *a.at_mut(0, j) -= w;
+        for i in 0..essential.nrows() {
+            *a.at_mut(i + 1, j) -= *essential.at(i, 0) * w;
+        }
+    }
+}
+
+pub(crate) fn apply_householder_right<T: ComplexField>(
+    essential: MatRef<'_, T>,
+    tau: T,
+    mut a: MatMut<'_, T>,
+) {
+    if tau.is_zero() {
+        return;
+    }
+    debug_assert_eq!(a.ncols(), essential.nrows() + 1);
+    for i in 0..a.nrows() {
+        let mut w = *a.at(i, 0);
+        for j in 0..essential.nrows() {
+            w += *a.at(i, j + 1) * *essential.at(j, 0);
+        }
+        let w = tau * w;
+        if w.is_zero() {
+            continue;
+        }
+        *a.at_mut(i, 0) -= w;
+        for j in 0..essential.nrows() {
+            *a.at_mut(i, j + 1) -= w * essential.at(j, 0).conj();
+        }
+    }
+}
@@ -0,0 +1,453 @@
+use num_traits::{Float, One, Zero};
+
+use crate::error::{Error, OrPanic, Result, check_shape, check_square};
+use crate::matrix::{Mat, MatMut, MatRef};
+use crate::scalar::ComplexField;
+
+pub struct Ldlt<T> {
+    ldl: Mat<T>,
+    perm: Vec<usize>,
+    block_sizes: Vec<usize>,
+    singular: Option<usize>,
+}
+
+impl<T: ComplexField> Ldlt<T> {
+    #[track_caller]
+    pub fn new(a: MatRef<'_, T>) -> Self {
+        Self::try_new(a).or_panic()
+    }
+
+    pub fn try_new(a: MatRef<'_, T>) -> Result<Self> {
+        check_square(a.shape())?;
+        let n = a.nrows();
+        let mut ldl = Mat::from_fn(n, n, |i, j| {
+            if i > j {
+                *a.at(i, j)
+            } else if i == j {
+                T::from_real(a.at(i, i).re())
+            } else {
+                T::zero()
+            }
+        });
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut block_sizes = Vec::new();
+        let mut singular = None;
+
+        let real = |v: f64| -> T::Real { num_traits::cast(v).unwrap() };
+        let alpha = (T::Real::one() + real(17.0).sqrt()) / real(8.0);
+
+        let mut k = 0;
+        while k < n {
+            let mut kstep = 1;
+            let abs_akk = ldl[(k, k)].re().abs();
+            let (imax, col_max) = max_below(ldl.as_ref(), k, k);
+
+            let kp = if abs_akk.max(col_max).is_zero() {
+                singular.get_or_insert(k);
+                k
+            } else if abs_akk >= alpha * col_max {
+                k
+            } else {
+                let mut row_max = T::Real::zero();
+                for j in k..imax {
+                    row_max = row_max.max(ldl[(imax, j)].modulus());
+                }
+                let (_, below) = max_below(ldl.as_ref(), imax, imax);
+                row_max = row_max.max(below);
+                if abs_akk >= alpha * col_max * (col_max / row_max) {
+                    k
+                } else if ldl[(imax, imax)].re().abs() >= alpha * row_max {
+                    imax
+                } else {
+                    kstep = 2;
+                    imax
+                }
+            };
+
+            let kk = k + kstep - 1;
+            if kp != kk {
+                symmetric_swap(ldl.as_mut(), k, kk, kp);
+                perm.swap(kk, kp);
+            }
+
+            if kstep == 1 {
+                let d = ldl[(k, k)].re();
+                if !d.is_zero() {
+                    let r1 = T::Real::one() / d;
+                    for j in (k + 1)..n {
+                        let ljk = ldl[(j, k)].conj().scale_real(r1);
+                        for i in j..n {
+                            let lik = ldl[(i, k)];
+                            ldl[(i, j)] -= lik * ljk;
+                        }
+                        ldl[(j, j)] = T::from_real(ldl[(j, j)].re());
+                    }
+                    for i in (k + 1)..n {
+                        ldl[(i, k)] = ldl[(i, k)].scale_real(r1);
+                    }
+                }
+            } else if k + 2 < n {
+                let d21 = ldl[(k + 1, k)];
+                let d = d21.modulus();
+                let d11 = ldl[(k + 1, k + 1)].re() / d;
+                let d22 = ldl[(k, k)].re() / d;
+                let tt = T::Real::one() / (d11 * d22 - T::Real::one());
+                let d21 = d21.scale_real(T::Real::one() / d);
+                let d = tt / d;
+                for j in (k + 2)..n {
+                    let wk = (ldl[(j, k)].scale_real(d11) - d21 * ldl[(j, k + 1)]).scale_real(d);
+                    let wkp1 =
+                        (ldl[(j, k + 1)].scale_real(d22) - d21.conj() * ldl[(j, k)]).scale_real(d);
+                    for i in j..n {
+                        let update = ldl[(i, k)] * wk.conj() + ldl[(i, k + 1)] * wkp1.conj();
+                        ldl[(i, j)] -= update;
+                    }
+                    ldl[(j, k)] = wk;
+                    ldl[(j, k + 1)] = wkp1;
+                    ldl[(j, j)] = T::from_real(ldl[(j, j)].re());
+                }
+            }
+            block_sizes.push(kstep);
+            k += kstep;
+        }
+
+        Ok(Ldlt {
+            ldl,
+            perm,
+            block_sizes,
+            singular,
+        })
+    }
+
+    #[inline]
+    pub fn dim(&self) -> usize {
+        self.ldl.nrows()
+    }
+
+    #[inline]
+    pub fn permutation(&self) -> &[usize] {
+        &self.perm
+    }
+
+    #[inline]
+    pub fn block_sizes(&self) -> &[usize] {
+        &self.block_sizes
+    }
+
+    #[inline]
+    pub fn is_singular(&self) -> bool {
+        self.singular.is_some()
+    }
+
+    pub fn l(&self) -> Mat<T> {
+        let n = self.dim();
+        let mut l = Mat::identity(n);
+        let mut k = 0;
+        for &size in &self.block_sizes {
+            for j in k..(k + size) {
+                for i in (k + size)..n {
+                    l[(i, j)] = self.ldl[(i, j)];
+                }
+            }
+            k += size;
+        }
+        l
+    }
+
+    pub fn d(&self) -> Mat<T> {
+        let n = self.dim();
+        let mut d = Mat::zeros(n, n);
+        let mut k = 0;
+        for &size in &self.block_sizes {
+            d[(k, k)] = self.ldl[(k, k)];
+            if size == 2 {
+                d[(k + 1, k)] = self.ldl[(k + 1, k)];
+                d[(k, k + 1)] = self.ldl[(k + 1, k)].conj();
+                d[(k + 1, k + 1)] = self.ldl[(k + 1, k + 1)];
+            }
+            k += size;
+        }
+        d
+    }
+
+    pub fn p(&self) -> Mat<T> {
+        let n = self.dim();
+        let mut p = Mat::zeros(n, n);
+        for (i, &j) in self.perm.iter().enumerate() {
+            p[(i, j)] = T::one();
+        }
+        p
+    }
+
+    pub fn det(&self) -> T::Real {
+        let mut det = T::Real::one();
+        self.for_each_block_det(|d| det *= d);
+        det
+    }
+
+    pub fn log_det(&self) -> (T::Real, T::Real) {
+        let mut sign = T::Real::one();
+        let mut log_abs = T::Real::zero();
+        self.for_each_block_det(|d| {
+            if d < T::Real::zero() {
+                sign = -sign;
+            }
+            log_abs += d.abs().ln();
+        });
+        if self.is_singular() {
+            sign = T::Real::zero();
+        }
+        (sign, log_abs)
+    }
+
+    fn for_each_block_det<F: FnMut(T::Real)>(&self, mut f: F) {
+        let mut k = 0;
+        for &size in &self.block_sizes {
+            if size == 1 {
+                f(self.ldl[(k, k)].re());
+            } else {
+                let a = self.ldl[(k, k)].re();
+                let c = self.ldl[(k + 1, k + 1)].re();
+                f(a * c - self.ldl[(k + 1, k)].modulus_sqr());
+            }
+            k += size;
+        }
+    }
+
+    pub fn solve(&self, b: MatRef<'_, T>) -> Result<Mat<T>> {
+        let mut x = b.to_owned();
+        self.solve_in_place(x.as_mut())?;
+        Ok(x)
+    }
+
+    pub fn solve_in_place(&self, mut b: MatMut<'_, T>) -> Result<()> {
+        let n = self.dim();
+        check_shape(b.shape(), (n, b.ncols()))?;
+        if let Some(index) = self.singular {
+            return Err(Error::Singular { index });
+        }
+        let ldl = self.ldl.as_ref();
+        let mut work = vec![T::zero(); n];
+        for j in 0..b.ncols() {
+            for (i, &p) in self.perm.iter().enumerate() {
+                work[i] = *b.at(p, j);
+            }
+
+            let mut k = 0;
+            for &size in &self.block_sizes {
+                for c in k..(k + size) {
+                    let t = work[c];
+                    for (i, w) in work.iter_mut().enumerate().skip(k + size) {
+                        *w -= *ldl.at(i, c) * t;
+                    }
+                }
+                k += size;
+            }
+
+            let mut k = 0;
+            for &size in &self.block_sizes {
+                if size == 1 {
+                    work[k] = work[k].scale_real(T::Real::one() / ldl.at(k, k).re());
+                } else {
+                    let a = ldl.at(k, k).re();
+                    let c = ldl.at(k + 1, k + 1).re();
+                    let b21 = *ldl.at(k + 1, k);
+                    let det = a * c - b21.modulus_sqr();
+                    let inv = T::Real::one() / det;
+                    let (y0, y1) = (work[k], work[k + 1]);
+                    work[k] = (y0.scale_real(c) - b21.conj() * y1).scale_real(inv);
+                    work[k + 1] = (y1.scale_real(a) - b21 * y0).scale_real(inv);
+                }
+                k += size;
+            }
+
+            let mut k = n;
+            for &size in self.block_sizes.iter().rev() {
+                k -= size;
+                for c in k..(k + size) {
+                    let mut t = work[c];
+                    for (i, &w) in work.iter().enumerate().skip(k + size) {
+                        t -= ldl.at(i, c).conj() * w;
+                    }
+                    work[c] = t;
+                }
+            }
+
+            for (i, &p) in self.perm.iter().enumerate() {
+                *b.at_mut(p, j) = work[i];
+            }
+        }
+        Ok(())
+    }
+
+    pub fn inverse(&self) -> Result<Mat<T>> {
+        let mut inv = Mat::identity(self.dim());
+        self.solve_in_place(inv.as_mut())?;
+        Ok(inv)
+    }
+
+    pub fn rank_one_update(&mut self, x: MatRef<'_, T>) -> Result<()> {
+        self.rank_one_modify(x, T::Real::one())
+    }
+
+    pub fn rank_one_downdate(&mut self, x: MatRef<'_, T>) -> Result<()> {
+        self.rank_one_modify(x, -T::Real::one())
+    }
+
+    fn rank_one_modify(&mut self, x: MatRef<'_, T>, sigma: T::Real) -> Result<()> {
+        let n = self.dim();
+        check_shape(x.shape(), (n, 1))?;
+        if let Some(index) = self.singular {
+            return Err(Error::Singular { index });
+        }
+
+        if let Some(ldl) = self.update_definite(x, sigma) {
+            self.ldl = ldl;
+            return Ok(());
+        }
+
+        // The modified matrix may need different pivots, which an in-place
+        // sweep cannot introduce, so it is rebuilt and factored again.
+        let mut a = self.reconstruct();
+        for j in 0..n {
+            for i in 0..n {
+                a[(i, j)] += (*x.at(i, 0) * x.at(j, 0).conj()).scale_real(sigma);
+            }
+        }
+        let refactored = Ldlt::new(a.as_ref());
+        if let Some(index) = refactored.singular {
+            return Err(Error::Singular { index });
+        }
+        *self = refactored;
+        Ok(())
+    }
+
+    // Unpivoted O(n^2) update, only valid while D is diagonal and keeps a
+    // single sign: the sweep is then a Cholesky update of the definite
+    // matrix +-A. Returns None whenever that does not hold.
+    fn update_definite(&self, x: MatRef<'_, T>, sigma: T::Real) -> Option<Mat<T>> {
+        let n = self.dim();
+        if self.block_sizes.iter().any(|&size| size != 1) {
+            return None;
+        }
+        let positive = n == 0 || self.ldl[(0, 0)].re() > T::Real::zero();
+        let definite = |d: T::Real| {
+            if positive {
+                d > T::Real::zero()
+            } else {
+                d < T::Real::zero()
+            }
+        };
+        if !(0..n).all(|j| definite(self.ldl[(j, j)].re())) {
+            return None;
+        }
+
+        let mut ldl = self.ldl.clone();
+        let mut w: Vec<T> = self.perm.iter().map(|&p| *x.at(p, 0)).collect();
+        let mut alpha = sigma;
+        for j in 0..n {
+            let p = w[j];
+            let d = ldl[(j, j)].re();
+            let d_new = d + alpha * p.modulus_sqr();
+            if !definite(d_new) {
+                return None;
+            }
+            let beta = p.conj().scale_real(alpha / d_new);
+            alpha = alpha * d / d_new;
+            ldl[(j, j)] = T::from_real(d_new);
+            for r in (j + 1)..n {
+                w[r] -= p * ldl[(r, j)];
+                ldl[(r, j)] += beta * w[r];
+            }
+        }
+        Some(ldl)
+    }
+
+    fn reconstruct(&self) -> Mat<T> {
+        let n = self.dim();
+        let l = self.l();
+        let l_adjoint = Mat::from_fn(n, n, |i, j| l[(j, i)].conj());
+        let pap = &l * &self.d() * &l_adjoint;
+        let mut a = Mat::zeros(n, n);
+        for j in 0..n {
+            for i in 0..n {
+                a[(self.perm[i], self.perm[j])] = pap[(i, j)];
+            }
+        }
+        a
+    }
+}
+
+fn max_below<T: ComplexField>(a: MatRef<'_, T>, row: usize, col: usize) -> (usize, T::Real) {
+    let mut imax = row;
+    let mut max = T::Real::zero();
+    for i in (row + 1)..a.nrows() {
+        let v = a.at(i, col).modulus();
+        if v > max {
+            max = v;
+            imax = i;
+        }
+    }
+    (imax, max)
+}
+
+fn symmetric_swap<T: ComplexField>(mut a: MatMut<'_, T>, k: usize, kk: usize, kp: usize) {
+    let n = a.nrows();
+    for j in 0..k {
+        let t = *a.at(kk, j);
+        *a.at_mut(kk, j) = *a.at(kp, j);
+        *a.at_mut(kp, j) = t;
+    }
+    for i in (kp + 1)..n {
+        let t = *a.at(i, kk);
+        *a.at_mut(i, kk) = *a.at(i, kp);
+        *a.at_mut(i, kp) = t;
+    }
+    for j in (kk + 1)..kp {
+        let t = a.at(j, kk).conj();
+        *a.at_mut(j, kk) = a.at(kp, j).conj();
+        *a.at_mut(kp, j) = t;
+    }
+    *a.at_mut(kp, kk) = a.at(kp, kk).conj();
+    let t = *a.at(kk, kk);
+    *a.at_mut(kk, kk) = *a.at(kp, kp);
+    *a.at_mut(kp, kp) = t;
+    if kk != k {
+        let t = *a.at(kk, k);
+        *a.at_mut(kk, k) = *a.at(kp, k);
+        *a.at_mut(kp, k) = t;
+    }
+}
+
+impl<T: ComplexField> MatRef<'_, T> {
+    #[track_caller]
+    pub fn ldlt(self) -> Ldlt<T> {
+        Ldlt::new(self)
+    }
+
+    pub fn try_ldlt(self) -> Result<Ldlt<T>> {
+        Ldlt::try_new(self)
+    }
+}
+
+impl<T: ComplexField> MatMut<'_, T> {
+    #[track_caller]
+    pub fn ldlt(&self) -> Ldlt<T> {
+        self.rb().ldlt()
+    }
+
+    pub fn try_ldlt(&self) -> Result<Ldlt<T>> {
+        self.rb().try_ldlt()
+    }
+}
+
+impl<T: ComplexField> Mat<T> {
+    #[track_caller]
+    pub fn ldlt(&self) -> Ldlt<T> {
+        self.as_ref().ldlt()
+    }
+
+    pub fn try_ldlt(&self) -> Result<Ldlt<T>> {
+        self.as_ref().try_ldlt()
+    }
+}
@@ -0,0 +1,418 @@
+use num_traits::{One, Zero};
+
+use crate::error::{Error, OrPanic, Result, check_shape, check_square};
+use crate::matrix::{Mat, MatMut, MatRef, matmul_into};
+use crate::parallel;
+use crate::scalar::ComplexField;
+
+const BLOCK_SIZE: usize = 32;
+
+pub struct Lu<T: ComplexField> {
+    factors: Mat<T>,
+    pivots: Vec<usize>,
+    perm: Vec<usize>,
+    n_swaps: usize,
+    singular: Option<usize>,
+    norm1: T::Real,
+}
+
+impl<T: ComplexField> Lu<T> {
+    #[track_caller]
+    pub fn new(a: MatRef<'_, T>) -> Self {
+        Self::try_new(a).or_panic()
+    }
+
+    pub fn try_new(a: MatRef<'_, T>) -> Result<Self> {
+        check_square(a.shape())?;
+        let n = a.nrows();
+        let norm1 = norm1(a);
+        let mut factors = a.to_owned();
+        let mut pivots = vec![0; n];
+        let mut singular = None;
+
+        for k0 in (0..n).step_by(BLOCK_SIZE) {
+            let kb = BLOCK_SIZE.min(n - k0);
+            factor_panel(factors.as_mut(), k0, kb, &mut pivots, &mut singular);
+
+            let split = k0 + kb;
+            if split == n {
+                break;
+            }
+            let (left, right) = factors.as_mut().split_at_col_mut(split);
+            let (mut top, bottom) = right.split_at_row_mut(split);
+            let l11 = left.rb().view(k0, k0, kb, kb);
+            let u12 = top.rb_mut().view_mut(k0, 0, kb, n - split);
+            parallel::for_each_col_mut(u12, kb * kb, |_, mut col| {
+                for k in 0..kb {
+                    let t = *col.at(k, 0);
+                    if t.is_zero() {
+                        continue;
+                    }
+                    for i in (k + 1)..kb {
+                        *col.at_mut(i, 0) -= *l11.at(i, k) * t;
+                    }
+                }
+            });
+            let l21 = left.rb().view(split, k0, n - split, kb);
+            matmul_into(
+                bottom,
+                -T::one(),
+                l21,
+                top.rb().view(k0, 0, kb, n - split),
+                T::one(),
+            );
+        }
+
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut n_swaps = 0;
+        for (k, &p) in pivots.iter().enumerate() {
+            if p != k {
+                perm.swap(k, p);
+                n_swaps += 1;
+            }
+        }
+
+        Ok(Lu {
+            factors,
+            pivots,
+            perm,
+            n_swaps,
+            singular,
+            norm1,
+        })
+    }
+
+    #[inline]
+    pub fn dim(&self) -> usize {
+        self.factors.nrows()
+    }
+
+    #[inline]
+    pub fn factors(&self) -> MatRef<'_, T> {
+        self.factors.as_ref()
+    }
+
+    #[inline]
+    pub fn permutation(&self) -> &[usize] {
+        &self.perm
+    }
+
+    #[inline]
+    pub fn is_singular(&self) -> bool {
+        self.singular.is_some()
+    }
+
+    pub fn l(&self) -> Mat<T> {
+        let n = self.dim();
+        Mat::from_fn(n, n, |i, j| {
+            if i == j {
+                T::one()
+            } else if i > j {
+                self.factors[(i, j)]
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    pub fn u(&self) -> Mat<T> {
+        self.factors.triu(0)
+    }
+
+    pub fn p(&self) -> Mat<T> {
+        let n = self.dim();
+        let mut p = Mat::zeros(n, n);
+        for (i, &j) in self.perm.iter().enumerate() {
+            p[(i, j)] = T::one();
+        }
+        p
+    }
+
+    pub fn det(&self) -> T {
+        let mut det = T::one();
+        for k in 0..self.dim() {
+            det *= self.factors[(k, k)];
+        }
+        if self.n_swaps % 2 == 1 { -det } else { det }
+    }
+
+    pub fn solve(&self, b: MatRef<'_, T>) -> Result<Mat<T>> {
+        let mut x = b.to_owned();
+        self.solve_in_place(x.as_mut())?;
+        Ok(x)
+    }
+
+    pub fn solve_in_place(&self, mut b: MatMut<'_, T>) -> Result<()> {
+        let n = self.dim();
+        check_shape(b.shape(), (n, b.ncols()))?;
+        if let Some(index) = self.singular {
+            return Err(Error::Singular { index });
+        }
+        for (k, &p) in self.pivots.iter().enumerate() {
+            b.swap_rows(k, p);
+        }
+        let lu = self.factors.as_ref();
+        parallel::for_each_col_mut(b, n * n, |_, mut b| {
+            for k in 0..n {
+                let t = *b.at(k, 0);
+                if t.is_zero() {
+                    continue;
+                }
+                for i in (k + 1)..n {
+                    *b.at_mut(i, 0) -= *lu.at(i, k) * t;
+                }
+            }
+            for k in (0..n).rev() {
+                let t = *b.at(k, 0) / *lu.at(k, k);
+                *b.at_mut(k, 0) = t;
+                if t.is_zero() {
+                    continue;
+                }
+                for i in 0..k {
+                    *b.at_mut(i, 0) -= *lu.at(i, k) * t;
+                }
+            }
+        });
+        Ok(())
+    }
+
+    pub fn solve_adjoint_in_place(&self, mut b: MatMut<'_, T>) -> Result<()> {
+        let n = self.dim();
+        check_shape(b.shape(), (n, b.ncols()))?;
+        if let Some(index) = self.singular {
+            return Err(Error::Singular { index });
+        }
+        let lu = self.factors.as_ref();
+        parallel::for_each_col_mut(b.rb_mut(), n * n, |_, mut b| {
+            for k in 0..n {
+                let mut t = *b.at(k, 0);
+                for i in 0..k {
+                    t -= lu.at(i, k).conj() * *b.at(i, 0);
+                }
+                *b.at_mut(k, 0) = t / lu.at(k, k).conj();
+            }
+            for k in (0..n).rev() {
+                let mut t = *b.at(k, 0);
+                for i in (k + 1)..n {
+                    t -= lu.at(i, k).conj() * *b.at(i, 0);
+                }
+                *b.at_mut(k, 0) = t;
+            }
+        });
+        for (k, &p) in self.pivots.iter().enumerate().rev() {
+            b.swap_rows(k, p);
+        }
+        Ok(())
+    }
+
+    pub fn inverse(&self) -> Result<Mat<T>> {
+        let mut inv = Mat::identity(self.dim());
+        self.solve_in_place(inv.as_mut())?;
+        Ok(inv)
+    }
+
+    pub fn rcond(&self) -> T::Real {
+        let n = self.dim();
+        if n == 0 {
+            return T::Real::one();
+        }
+        if self.is_singular() || self.norm1.is_zero() {
+            return T::Real::zero();
+        }
+        let inv_norm1 = self.estimate_inverse_norm1();
+        if inv_norm1.is_zero() {
+            return T::Real::zero();
+        }
+        T::Real::one() / (self.norm1 * inv_norm1)
+    }
+
+    fn estimate_inverse_norm1(&self) -> T::Real {
+        let n = self.dim();
+        let real = |v: usize| -> T::Real { num_traits::cast(v).unwrap() };
+
+        let mut x = Mat::full(n, 1, T::from_real(T::Real::one() / real(n)));
+        let mut estimate = T::Real::zero();
+        for iter in 0..5 {
+            let mut y = x.clone();
+            self.solve_in_place(y.as_mut()).unwrap();
+            let y_norm = norm1(y.as_ref());
+            if iter > 0 && y_norm <= estimate {
+                break;
+            }
+            estimate = y_norm;
+
+            let mut z = y.map(|&v| {
+                let m = v.modulus();
+                if m.is_zero() {
+                    T::one()
+                } else {
+                    v.scale_real(T::Real::one() / m)
+                }
+            });
+            self.solve_adjoint_in_place(z.as_mut()).unwrap();
+
+            let mut j_max = 0;
+            let mut z_max = T::Real::zero();
+            let mut z_dot_x = T::Real::zero();
+            for i in 0..n {
+                let m = z[(i, 0)].modulus();
+                if m > z_max {
+                    z_max = m;
+                    j_max = i;
+                }
+                z_dot_x += (z[(i, 0)].conj() * x[(i, 0)]).re();
+            }
+            if iter > 0 && z_max <= z_dot_x {
+                break;
+            }
+            x = Mat::zeros(n, 1);
+            x[(j_max, 0)] = T::one();
+        }
+
+        let mut alt = Mat::from_fn(n, 1, |i, _| {
+            let magnitude = if n > 1 {
+                T::Real::one() + real(i) / real(n - 1)
+            } else {
+                T::Real::one()
+            };
+            let value = T::from_real(magnitude);
+            if i % 2 == 0 { value } else { -value }
+        });
+        self.solve_in_place(alt.as_mut()).unwrap();
+        let alt_estimate = (real(2) * norm1(alt.as_ref())) / (real(3) * real(n));
+        if alt_estimate > estimate {
+            alt_estimate
+        } else {
+            estimate
+        }
+    }
+}
+
+fn factor_panel<T: ComplexField>(
+    mut a: MatMut<'_, T>,
+    k0: usize,
+    kb: usize,
+    pivots: &mut [usize],
+    singular: &mut Option<usize>,
+) {
+    let n = a.nrows();
+    for (k, pivot_index) in pivots.iter_mut().enumerate().skip(k0).take(kb) {
+        let mut p = k;
+        let mut p_val = a.at(k, k).modulus();
+        for i in (k + 1)..n {
+            let v = a.at(i, k).modulus();
+            if v > p_val {
+                p = i;
+                p_val = v;
+            }
+        }
+        *pivot_index = p;
+        a.swap_rows(k, p);
+
+        let pivot = *a.at(k, k);
+        if pivot.is_zero() {
+            singular.get_or_insert(k);
+            continue;
+        }
+        let inv = T::one() / pivot;
+        for i in (k + 1)..n {
+            *a.at_mut(i, k) *= inv;
+        }
+        for j in (k + 1)..(k0 + kb) {
+            let t = *a.at(k, j);
+            if t.is_zero() {
+                continue;
+            }
+            for i in (k + 1)..n {
+                let lik = *a.at(i, k);
+                *a.at_mut(i, j) -= lik * t;
+            }
+        }
+    }
+}
+
+pub(crate) fn norm1<T: ComplexField>(a: MatRef<'_, T>) -> T::Real {
+    let mut max = T::Real::zero();
+    for j in 0..a.ncols() {
+        let mut sum = T::Real::zero();
+        for i in 0..a.nrows() {
+            sum += a.at(i, j).modulus();
+        }
+        if sum > max {
+            max = sum;
+        }
+    }
+    max
+}
+
+impl<T: ComplexField> MatRef<'_, T> {
+    #[track_caller]
+    pub fn lu(self) -> Lu<T> {
+        Lu::new(self)
+    }
+
+    pub fn try_lu(self) -> Result<Lu<T>> {
+        Lu::try_new(self)
+    }
+
+    #[track_caller]
+    pub fn det(self) -> T {
+        self.lu().det()
+    }
+
+    pub fn try_det(self) -> Result<T> {
+        Ok(self.try_lu()?.det())
+    }
+
+    pub fn inverse(self) -> Result<Mat<T>> {
+        self.try_lu()?.inverse()
+    }
+}
+
+impl<T: ComplexField> MatMut<'_, T> {
+    #[track_caller]
+    pub fn lu(&self) -> Lu<T> {
+        self.rb().lu()
+    }
+
+    pub fn try_lu(&self) -> Result<Lu<T>> {
+        self.rb().try_lu()
+    }
+
+    #[track_caller]
+    pub fn det(&self) -> T {
+        self.rb().det()
+    }
+
+    pub fn try_det(&self) -> Result<T> {
+        self.rb().try_det()
+    }
+
+    pub fn inverse(&self) -> Result<Mat<T>> {
+        self.rb().inverse()
+    }
+}
+
+impl<T: ComplexField> Mat<T> {
+    #[track_caller]
+    pub fn lu(&self) -> Lu<T> {
+        self.as_ref().lu()
+    }
+
+    pub fn try_lu(&self) -> Result<Lu<T>> {
+        self.as_ref().try_lu()
+    }
+
+    #[track_caller]
+    pub fn det(&self) -> T {
+        self.as_ref().det()
+    }
+
+    pub fn try_det(&self) -> Result<T> {
+        self.as_ref().try_det()
+    }
+
+    pub fn inverse(&self) -> Result<Mat<T>> {
+        self.as_ref().inverse()
+    }
+}
@@ -0,0 +1,204 @@
+use num_traits::{Float, One, Zero};
+
+use super::householder::stable_norm;
+use super::svd::Svd;
+use crate::error::{Error, OrPanic, Result, check_shape};
+use crate::matrix::{Mat, MatMut, MatRef};
+use crate::scalar::ComplexField;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Norm<R> {
+    Frobenius,
+    One,
+    Inf,
+    MaxAbs,
+    Spectral,
+    Nuclear,
+    P(R),
+}
+
+// Like `Float::max`, but a NaN on either side is the result, so a NaN entry
+// shows up in every norm instead of being skipped.
+fn nan_max<R: Float>(acc: R, x: R) -> R {
+    if x > acc || x.is_nan() { x } else { acc }
+}
+
+impl<T: ComplexField> MatRef<'_, T> {
+    pub fn frobenius_norm(self) -> T::Real {
+        stable_norm(self)
+    }
+
+    pub fn one_norm(self) -> T::Real {
+        let mut max = T::Real::zero();
+        for j in 0..self.ncols() {
+            let mut sum = T::Real::zero();
+            for i in 0..self.nrows() {
+                sum += self.at(i, j).modulus();
+            }
+            max = nan_max(max, sum);
+        }
+        max
+    }
+
+    pub fn inf_norm(self) -> T::Real {
+        let mut sums = vec![T::Real::zero(); self.nrows()];
+        for j in 0..self.ncols() {
+            for (i, sum) in sums.iter_mut().enumerate() {
+                *sum += self.at(i, j).modulus();
+            }
+        }
+        sums.into_iter().fold(T::Real::zero(), nan_max)
+    }
+
+    pub fn max_abs_norm(self) -> T::Real {
+        let mut max = T::Real::zero();
+        for j in 0..self.ncols() {
+            for i in 0..self.nrows() {
+                max = nan_max(max, self.at(i, j).modulus());
+            }
+        }
+        max
+    }
+
+    pub fn nuclear_norm(self) -> Result<T::Real> {
+        let svd = Svd::values_only(self)?;
+        let s = svd.singular_values();
+        Ok((0..s.nrows()).fold(T::Real::zero(), |acc, i| acc + s[(i, 0)]))
+    }
+
+    #[track_caller]
+    pub fn vector_norm(self, p: T::Real) -> T::Real {
+        self.try_vector_norm(p).or_panic()
+    }
+
+    pub fn try_vector_norm(self, p: T::Real) -> Result<T::Real> {
+        if self.nrows() > 1 && self.ncols() > 1 {
+            return Err(Error::Unsupported {
+                operation: "vector norm of a matrix with several rows and columns",
+            });
+        }
+        if p.is_nan() || p <= T::Real::zero() {
+            return Err(Error::Unsupported {
+                operation: "p-norm with p <= 0",
+            });
+        }
+        if p.is_infinite() {
+            return Ok(self.max_abs_norm());
+        }
+        if p == T::Real::one() {
+            return Ok(nan_max(self.one_norm(), self.inf_norm()));
+        }
+        if p == T::Real::one() + T::Real::one() {
+            return Ok(stable_norm(self));
+        }
+        let scale = self.max_abs_norm();
+        if scale.is_zero() || scale.is_infinite() {
+            return Ok(scale);
+        }
+        let mut sum = T::Real::zero();
+        for j in 0..self.ncols() {
+            for i in 0..self.nrows() {
+                sum += (self.at(i, j).modulus() / scale).powf(p);
+            }
+        }
+        Ok(scale * sum.powf(p.recip()))
+    }
+
+    pub fn norm_with(self, kind: Norm<T::Real>) -> Result<T::Real> {
+        Ok(match kind {
+            Norm::Frobenius => self.frobenius_norm(),
+            Norm::One => self.one_norm(),
+            Norm::Inf => self.inf_norm(),
+            Norm::MaxAbs => self.max_abs_norm(),
+            Norm::Spectral => self.spectral_norm()?,
+            Norm::Nuclear => self.nuclear_norm()?,
+            Norm::P(p) => self.try_vector_norm(p)?,
+        })
+    }
+
+    pub fn distance(self, other: MatRef<'_, T>, kind: Norm<T::Real>) -> Result<T::Real> {
+        check_shape(self.shape(), other.shape())?;
+        let diff = Mat::from_fn(self.nrows(), self.ncols(), |i, j| {
+            *self.at(i, j) - *other.at(i, j)
+        });
+        diff.as_ref().norm_with(kind)
+    }
+}
+
+impl<T: ComplexField> MatMut<'_, T> {
+    pub fn frobenius_norm(&self) -> T::Real {
+        self.rb().frobenius_norm()
+    }
+
+    pub fn one_norm(&self) -> T::Real {
+        self.rb().one_norm()
+    }
+
+    pub fn inf_norm(&self) -> T::Real {
+        self.rb().inf_norm()
+    }
+
+    pub fn max_abs_norm(&self) -> T::Real {
+        self.rb().max_abs_norm()
+    }
+
+    pub fn nuclear_norm(&self) -> Result<T::Real> {
+        self.rb().nuclear_norm()
+    }
+
+    #[track_caller]
+    pub fn vector_norm(&self, p: T::Real) -> T::Real {
+        self.rb().vector_norm(p)
+    }
+
+    pub fn try_vector_norm(&self, p: T::Real) -> Result<T::Real> {
+        self.rb().try_vector_norm(p)
+    }
+
+    pub fn norm_with(&self, kind: Norm<T::Real>) -> Result<T::Real> {
+        self.rb().norm_with(kind)
+    }
+
+    pub fn distance(&self, other: MatRef<'_, T>, kind: Norm<T::Real>) -> Result<T::Real> {
+        self.rb().distance(other, kind)
+    }
+}
+
+impl<T: ComplexField> Mat<T> {
+    pub fn frobenius_norm(&self) -> T::Real {
+        self.as_ref().frobenius_norm()
+    }
+
+    pub fn one_norm(&self) -> T::Real {
+        self.as_ref().one_norm()
+    }
+
+    pub fn inf_norm(&self) -> T::Real {
+        self.as_ref().inf_norm()
+    }
+
+    pub fn max_abs_norm(&self) -> T::Real {
+        self.as_ref().max_abs_norm()
+    }
+
+    pub fn nuclear_norm(&self) -> Result<T::Real> {
+        self.as_ref().nuclear_norm()
+    }
+
+    #[track_caller]
+    pub fn vector_norm(&self, p: T::Real) -> T::Real {
+        self.as_ref().vector_norm(p)
+    }
+
+    pub fn try_vector_norm(&self, p: T::Real) -> Result<T::Real> {
+        self.as_ref().try_vector_norm(p)
+    }
+
+    pub fn norm_with(&self, kind: Norm<T::Real>) -> Result<T::Real> {
+        self.as_ref().norm_with(kind)
+    }
+
+    pub fn distance(&self, other: MatRef<'_, T>, kind: Norm<T::Real>) -> Result<T::Real> {
+        self.as_ref().distance(other, kind)
+    }
+}
@@ -0,0 +1,356 @@
+use num_traits::{Float, One, Zero};
+
+use super::householder::{apply_householder_left, make_householder, stable_norm};
+use crate::error::{Axis, Error, OrPanic, Result, check_boundary, check_shape};
+use crate::matrix::{Mat, MatMut, MatRef};
+use crate::scalar::ComplexField;
+
+pub struct Qr<T> {
+    qr: Mat<T>,
+    tau: Vec<T>,
+}
+
+pub struct ColPivQr<T> {
+    qr: Mat<T>,
+    tau: Vec<T>,
+    perm: Vec<usize>,
+}
+
+impl<T: ComplexField> Qr<T> {
+    pub fn new(a: MatRef<'_, T>) -> Self {
+        let mut qr = a.to_owned();
+        let k = a.nrows().min(a.ncols());
+        let mut tau = Vec::with_capacity(k);
+        for j in 0..k {
+            tau.push(reflect_column(qr.as_mut(), j));
+        }
+        Qr { qr, tau }
+    }
+
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.qr.nrows()
+    }
+
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.qr.ncols()
+    }
+
+    #[inline]
+    pub fn householder_factors(&self) -> MatRef<'_, T> {
+        self.qr.as_ref()
+    }
+
+    #[inline]
+    pub fn householder_coeffs(&self) -> &[T] {
+        &self.tau
+    }
+
+    pub fn r(&self) -> Mat<T> {
+        upper_part(self.qr.as_ref())
+    }
+
+    pub fn q_thin(&self) -> Mat<T> {
+        build_q(self.qr.as_ref(), &self.tau, self.tau.len())
+    }
+
+    pub fn q_full(&self) -> Mat<T> {
+        build_q(self.qr.as_ref(), &self.tau, self.nrows())
+    }
+
+    #[track_caller]
+    pub fn apply_q_adjoint(&self, b: MatMut<'_, T>) {
+        self.try_apply_q_adjoint(b).or_panic()
+    }
+
+    pub fn try_apply_q_adjoint(&self, b: MatMut<'_, T>) -> Result<()> {
+        check_shape(b.shape(), (self.nrows(), b.ncols()))?;
+        apply_q_adjoint(self.qr.as_ref(), &self.tau, b);
+        Ok(())
+    }
+
+    #[track_caller]
+    pub fn apply_q(&self, b: MatMut<'_, T>) {
+        self.try_apply_q(b).or_panic()
+    }
+
+    pub fn try_apply_q(&self, b: MatMut<'_, T>) -> Result<()> {
+        check_shape(b.shape(), (self.nrows(), b.ncols()))?;
+        apply_q(self.qr.as_ref(), &self.tau, b);
+        Ok(())
+    }
+
+    pub fn solve_least_squares(&self, b: MatRef<'_, T>) -> Result<Mat<T>> {
+        let (m, n) = self.qr.shape();
+        if m < n {
+            return Err(Error::Unsupported {
+                operation: "least-squares solve with more columns than rows",
+            });
+        }
+        check_shape(b.shape(), (m, b.ncols()))?;
+        let mut y = b.to_owned();
+        apply_q_adjoint(self.qr.as_ref(), &self.tau, y.as_mut());
+        let mut x = y.rows_range(0..n).to_owned();
+        back_substitute(self.qr.as_ref(), n, x.as_mut())?;
+        Ok(x)
+    }
+}
+
+impl<T: ComplexField> ColPivQr<T> {
+    pub fn new(a: MatRef<'_, T>) -> Self {
+        let (m, n) = a.shape();
+        let mut qr = a.to_owned();
+        let k = m.min(n);
+        let mut tau = Vec::with_capacity(k);
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut norms: Vec<T::Real> = (0..n).map(|j| stable_norm(qr.col(j))).collect();
+        let mut saved_norms = norms.clone();
+        let tol = T::Real::epsilon().sqrt();
+
+        for j in 0..k {
+            let mut p = j;
+            for c in (j + 1)..n {
+                if norms[c] > norms[p] {
+                    p = c;
+                }
+            }
+            if p != j {
+                qr.swap_cols(j, p);
+                perm.swap(j, p);
+                norms.swap(j, p);
+                saved_norms.swap(j, p);
+            }
+
+            tau.push(reflect_column(qr.as_mut(), j));
+
+            for c in (j + 1)..n {
+                if norms[c].is_zero() {
+                    continue;
+                }
+                let ratio = qr[(j, c)].modulus() / norms[c];
+                let mut temp = T::Real::one() - ratio * ratio;
+                if temp < T::Real::zero() {
+                    temp = T::Real::zero();
+                }
+                let ratio = norms[c] / saved_norms[c];
+                if temp * ratio * ratio <= tol {
+                    norms[c] = stable_norm(qr.view(j + 1, c, m - j - 1, 1));
+                    saved_norms[c] = norms[c];
+                } else {
+                    norms[c] *= temp.sqrt();
+                }
+            }
+        }
+        ColPivQr { qr, tau, perm }
+    }
+
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.qr.nrows()
+    }
+
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.qr.ncols()
+    }
+
+    #[inline]
+    pub fn permutation(&self) -> &[usize] {
+        &self.perm
+    }
+
+    pub fn p(&self) -> Mat<T> {
+        let n = self.ncols();
+        let mut p = Mat::zeros(n, n);
+        for (j, &i) in self.perm.iter().enumerate() {
+            p[(i, j)] = T::one();
+        }
+        p
+    }
+
+    pub fn r(&self) -> Mat<T> {
+        upper_part(self.qr.as_ref())
+    }
+
+    pub fn q_thin(&self) -> Mat<T> {
+        build_q(self.qr.as_ref(), &self.tau, self.tau.len())
+    }
+
+    pub fn q_full(&self) -> Mat<T> {
+        build_q(self.qr.as_ref(), &self.tau, self.nrows())
+    }
+
+    #[track_caller]
+    pub fn apply_q_adjoint(&self, b: MatMut<'_, T>) {
+        self.try_apply_q_adjoint(b).or_panic()
+    }
+
+    pub fn try_apply_q_adjoint(&self, b: MatMut<'_, T>) -> Result<()> {
+        check_shape(b.shape(), (self.nrows(), b.ncols()))?;
+        apply_q_adjoint(self.qr.as_ref(), &self.tau, b);
+        Ok(())
+    }
+
+    #[track_caller]
+    pub fn apply_q(&self, b: MatMut<'_, T>) {
+        self.try_apply_q(b).or_panic()
+    }
+
+    pub fn try_apply_q(&self, b: MatMut<'_, T>) -> Result<()> {
+        check_shape(b.shape(), (self.nrows(), b.ncols()))?;
+        apply_q(self.qr.as_ref(), &self.tau, b);
+        Ok(())
+    }
+
+    pub fn default_tolerance(&self) -> T::Real {
+        let (m, n) = self.qr.shape();
+        T::Real::epsilon() * num_traits::cast(m.max(n)).unwrap()
+    }
+
+    pub fn rank(&self) -> usize {
+        self.rank_with_tolerance(self.default_tolerance())
+    }
+
+    pub fn rank_with_tolerance(&self, tol: T::Real) -> usize {
+        let k = self.tau.len();
+        if k == 0 {
+            return 0;
+        }
+        let threshold = tol * self.qr[(0, 0)].modulus();
+        (0..k)
+            .take_while(|&i| {
+                let d = self.qr[(i, i)].modulus();
+                d > threshold && !d.is_zero()
+            })
+            .count()
+    }
+
+    pub fn solve_least_squares(&self, b: MatRef<'_, T>) -> Result<Mat<T>> {
+        self.solve_least_squares_with_rank(b, self.rank())
+    }
+
+    pub fn solve_least_squares_with_rank(&self, b: MatRef<'_, T>, rank: usize) -> Result<Mat<T>> {
+        let (m, n) = self.qr.shape();
+        check_shape(b.shape(), (m, b.ncols()))?;
+        check_boundary(Axis::Row, rank, self.tau.len())?;
+        let mut y = b.to_owned();
+        apply_q_adjoint(self.qr.as_ref(), &self.tau, y.as_mut());
+        let mut z = y.rows_range(0..rank).to_owned();
+        back_substitute(self.qr.as_ref(), rank, z.as_mut())?;
+        let mut x = Mat::zeros(n, b.ncols());
+        for j in 0..b.ncols() {
+            for i in 0..rank {
+                x[(self.perm[i], j)] = z[(i, j)];
+            }
+        }
+        Ok(x)
+    }
+}
+
+fn reflect_column<T: ComplexField>(qr: MatMut<'_, T>, j: usize) -> T {
+    let m = qr.nrows();
+    let n = qr.ncols();
+    let (mut left, right) = qr.split_at_col_mut(j + 1);
+    let tau = make_householder(left.rb_mut().view_mut(j, j, m - j, 1));
+    apply_householder_left(
+        left.rb().view(j + 1, j, m - j - 1, 1),
+        tau.conj(),
+        right.view_mut(j, 0, m - j, n - j - 1),
+    );
+    tau
+}
+
+fn upper_part<T: ComplexField>(qr: MatRef<'_, T>) -> Mat<T> {
+    let k = qr.nrows().min(qr.ncols());
+    Mat::from_fn(
+        k,
+        qr.ncols(),
+        |i, j| {
+            if i <= j { *qr.at(i, j) } else { T::zero() }
+        },
+    )
+}
+
+fn build_q<T: ComplexField>(qr: MatRef<'_, T>, tau: &[T], ncols: usize) -> Mat<T> {
+    let m = qr.nrows();
+    let mut q = Mat::eye(m, ncols, 0);
+    apply_q(qr, tau, q.as_mut());
+    q
+}
+
+fn apply_q<T: ComplexField>(qr: MatRef<'_, T>, tau: &[T], mut b: MatMut<'_, T>) {
+    let m = qr.nrows();
+    let ncols = b.ncols();
+    for (k, &t) in tau.iter().enumerate().rev() {
+        apply_householder_left(
+            qr.view(k + 1, k, m - k - 1, 1),
+            t,
+            b.rb_mut().view_mut(k, 0, m - k, ncols),
+        );
+    }
+}
+
+fn apply_q_adjoint<T: ComplexField>(qr: MatRef<'_, T>, tau: &[T], mut b: MatMut<'_, T>) {
+    let m = qr.nrows();
+    let ncols = b.ncols();
+    for (k, &t) in tau.iter().enumerate() {
+        apply_householder_left(
+            qr.view(k + 1, k, m - k - 1, 1),
+            t.conj(),
+            b.rb_mut().view_mut(k, 0, m - k, ncols),
+        );
+    }
+}
+
+fn back_substitute<T: ComplexField>(
+    r: MatRef<'_, T>,
+    n: usize,
+    mut x: MatMut<'_, T>,
+) -> Result<()> {
+    for k in 0..n {
+        if r.at(k, k).is_zero() {
+            return Err(Error::Singular { index: k });
+        }
+    }
+    for j in 0..x.ncols() {
+        for k in (0..n).rev() {
+            let t = *x.at(k, j) / *r.at(k, k);
+            *x.at_mut(k, j) = t;
+            for i in 0..k {
+                *x.at_mut(i, j) -= *r.at(i, k) * t;
+            }
+        }
+    }
+    Ok(())
+}
+
+impl<T: ComplexField> MatRef<'_, T> {
+    pub fn qr(self) -> Qr<T> {
+        Qr::new(self)
+    }
+
+    pub fn col_piv_qr(self) -> ColPivQr<T> {
+        ColPivQr::new(self)
+    }
+}
+
+impl<T: ComplexField> MatMut<'_, T> {
+    pub fn qr(&self) -> Qr<T> {
+        self.rb().qr()
+    }
+
+    pub fn col_piv_qr(&self) -> ColPivQr<T> {
+        self.rb().col_piv_qr()
+    }
+}
+
+impl<T: ComplexField> Mat<T> {
+    pub fn qr(&self) -> Qr<T> {
+        self.as_ref().qr()
+    }
+
+    pub fn col_piv_qr(&self) -> ColPivQr<T> {
+        self.as_ref().col_piv_qr()
+    }
+}
@@ -0,0 +1,418 @@
+use std::ops::Range;
+
+use num_complex::Complex;
+use num_traits::{Float, One, Zero};
+
+use super::hessenberg::Hessenberg;
+use crate::error::{Error, Result};
+use crate::matrix::{Mat, MatRef};
+use crate::scalar::{ComplexField, RealField};
+
+const MAX_ITERATIONS_PER_EIGENVALUE: usize = 40;
+const EXCEPTIONAL_SHIFT_PERIOD: usize = 10;
+
+pub struct RealSchur<T> {
+    t: Mat<T>,
+    z: Mat<T>,
+}
+
+impl<T: RealField> RealSchur<T> {
+    pub fn new(a: MatRef<'_, T>) -> Result<Self> {
+        let hess = Hessenberg::try_new(a)?;
+        let mut t = hess.h();
+        let mut z = hess.q();
+        francis_qr(&mut t, &mut z)?;
+        Ok(RealSchur { t, z })
+    }
+
+    #[inline]
+    pub fn dim(&self) -> usize {
+        self.t.nrows()
+    }
+
+    #[inline]
+    pub fn t(&self) -> MatRef<'_, T> {
+        self.t.as_ref()
+    }
+
+    #[inline]
+    pub fn z(&self) -> MatRef<'_, T> {
+        self.z.as_ref()
+    }
+
+    pub fn into_parts(self) -> (Mat<T>, Mat<T>) {
+        (self.t, self.z)
+    }
+
+    pub fn eigenvalues(&self) -> Mat<Complex<T>> {
+        let n = self.dim();
+        let mut values = Vec::with_capacity(n);
+        let mut k = 0;
+        while k < n {
+            if k + 1 < n && !self.t[(k + 1, k)].is_zero() {
+                let (re, im) = block_eigenvalues(&self.t, k);
+                values.push(Complex::new(re, im));
+                values.push(Complex::new(re, -im));
+                k += 2;
+            } else {
+                values.push(Complex::new(self.t[(k, k)], T::zero()));
+                k += 1;
+            }
+        }
+        Mat::from_vec_col(n, 1, values)
+    }
+
+    pub fn into_complex(self) -> ComplexSchur<T> {
+        let n = self.dim();
+        let mut t = self.t.map(|&x| Complex::new(x, T::zero()));
+        let mut z = self.z.map(|&x| Complex::new(x, T::zero()));
+        let mut k = 0;
+        while k + 1 < n {
+            if self.t[(k + 1, k)].is_zero() {
+                k += 1;
+                continue;
+            }
+            let (re, im) = block_eigenvalues(&self.t, k);
+            let lambda = Complex::new(re, im);
+            let u0 = lambda - t[(k + 1, k + 1)];
+            let u1 = t[(k + 1, k)];
+            let norm = u0.modulus().hypot(u1.modulus());
+            let (u0, u1) = (u0.unscale(norm), u1.unscale(norm));
+            rotate_rows(&mut t, k, u0, u1, k..n);
+            rotate_cols(&mut t, k, u0, u1, 0..k + 2);
+            rotate_cols(&mut z, k, u0, u1, 0..n);
+            t[(k + 1, k)] = Complex::zero();
+            k += 2;
+        }
+        ComplexSchur { t, z }
+    }
+}
+
+pub struct ComplexSchur<T> {
+    t: Mat<Complex<T>>,
+    z: Mat<Complex<T>>,
+}
+
+impl<T: RealField> ComplexSchur<T> {
+    pub fn new(a: MatRef<'_, Complex<T>>) -> Result<Self> {
+        let hess = Hessenberg::try_new(a)?;
+        let mut t = hess.h();
+        let mut z = hess.q();
+        complex_qr(&mut t, &mut z)?;
+        Ok(ComplexSchur { t, z })
+    }
+
+    #[inline]
+    pub fn dim(&self) -> usize {
+        self.t.nrows()
+    }
+
+    #[inline]
+    pub fn t(&self) -> MatRef<'_, Complex<T>> {
+        self.t.as_ref()
+    }
+
+    #[inline]
+    pub fn z(&self) -> MatRef<'_, Complex<T>> {
+        self.z.as_ref()
+    }
+
+    pub fn into_parts(self) -> (Mat<Complex<T>>, Mat<Complex<T>>) {
+        (self.t, self.z)
+    }
+
+    pub fn eigenvalues(&self) -> Mat<Complex<T>> {
+        let n = self.dim();
+        Mat::from_fn(n, 1, |i, _| self.t[(i, i)])
+    }
+}
+
+fn block_eigenvalues<T: RealField>(t: &Mat<T>, k: usize) -> (T, T) {
+    let a = t[(k, k)];
+    let d = t[(k + 1, k + 1)];
+    let two = T::one() + T::one();
+    let p = (a - d) / two;
+    let q = p * p + t[(k, k + 1)] * t[(k + 1, k)];
+    ((a + d) / two, (-q).max(T::zero()).sqrt())
+}
+
+fn negligible<T: ComplexField>(t: &Mat<T>, k: usize, fallback: T::Real) -> bool {
+    let mut s = t[(k - 1, k - 1)].modulus() + t[(k, k)].modulus();
+    if s.is_zero() {
+        s = fallback;
+    }
+    t[(k, k - 1)].modulus() <= T::Real::epsilon() * s
+}
+
+fn active_start<T: ComplexField>(t: &mut Mat<T>, hi: usize, fallback: T::Real) -> usize {
+    let mut l = hi;
+    while l > 0 {
+        if negligible(t, l, fallback) {
+            t[(l, l - 1)] = T::zero();
+            break;
+        }
+        l -= 1;
+    }
+    l
+}
+
+fn abs_sum<T: ComplexField>(t: &Mat<T>) -> T::Real {
+    let mut sum = T::Real::zero();
+    for j in 0..t.ncols() {
+        for i in 0..t.nrows() {
+            sum += t[(i, j)].modulus();
+        }
+    }
+    sum
+}
+
+fn francis_qr<T: RealField>(t: &mut Mat<T>, z: &mut Mat<T>) -> Result<()> {
+    let n = t.nrows();
+    let fallback = abs_sum(t);
+    let mut end = n;
+    let mut iter = 0;
+    let mut total = 0;
+    while end > 0 {
+        let hi = end - 1;
+        let l = active_start(t, hi, fallback);
+        if l == hi {
+            end -= 1;
+            iter = 0;
+            continue;
+        }
+        if l + 1 == hi {
+            split_real_block(t, z, hi - 1);
+            end -= 2;
+            iter = 0;
+            continue;
+        }
+        iter += 1;
+        total += 1;
+        if iter > MAX_ITERATIONS_PER_EIGENVALUE {
+            return Err(Error::NotConverged { iterations: total });
+        }
+        francis_step(t, z, l, hi, iter % EXCEPTIONAL_SHIFT_PERIOD == 0);
+    }
+    Ok(())
+}
+
+fn francis_step<T: RealField>(
+    t: &mut Mat<T>,
+    z: &mut Mat<T>,
+    l: usize,
+    hi: usize,
+    exceptional: bool,
+) {
+    let n = t.nrows();
+    let (s, p) = if exceptional {
+        let e = t[(hi, hi - 1)].abs() + t[(hi - 1, hi - 2)].abs();
+        let scale: T = num_traits::cast(1.5).unwrap();
+        (scale * e, e * e)
+    } else {
+        (
+            t[(hi - 1, hi - 1)] + t[(hi, hi)],
+            t[(hi - 1, hi - 1)] * t[(hi, hi)] - t[(hi - 1, hi)] * t[(hi, hi - 1)],
+        )
+    };
+    let mut x = t[(l, l)] * t[(l, l)] + t[(l, l + 1)] * t[(l + 1, l)] - s * t[(l, l)] + p;
+    let mut y = t[(l + 1, l)] * (t[(l, l)] + t[(l + 1, l + 1)] - s);
+    let mut w = t[(l + 1, l)] * t[(l + 2, l + 1)];
+    for k in l..hi - 1 {
+        let (v, tau) = real_reflector([x, y, w]);
+        reflect_rows(t, k, &v, tau, if k > l { k - 1 } else { l }..n);
+        reflect_cols(t, k, &v, tau, 0..(k + 4).min(hi + 1));
+        reflect_cols(z, k, &v, tau, 0..n);
+        if k > l {
+            t[(k + 1, k - 1)] = T::zero();
+            t[(k + 2, k - 1)] = T::zero();
+        }
+        x = t[(k + 1, k)];
+        y = t[(k + 2, k)];
+        if k + 3 <= hi {
+            w = t[(k + 3, k)];
+        }
+    }
+    let (v, tau) = real_reflector([x, y]);
+    reflect_rows(t, hi - 1, &v, tau, hi - 2..n);
+    reflect_cols(t, hi - 1, &v, tau, 0..hi + 1);
+    reflect_cols(z, hi - 1, &v, tau, 0..n);
+    t[(hi, hi - 2)] = T::zero();
+}
+
+fn split_real_block<T: RealField>(t: &mut Mat<T>, z: &mut Mat<T>, k: usize) {
+    let n = t.nrows();
+    let a = t[(k, k)];
+    let b = t[(k, k + 1)];
+    let c = t[(k + 1, k)];
+    let d = t[(k + 1, k + 1)];
+    let two = T::one() + T::one();
+    let p = (a - d) / two;
+    let q = p * p + b * c;
+    if q < T::zero() {
+        return;
+    }
+    let lambda = d + p + q.sqrt().copysign(p);
+    let (mut u0, mut u1) = (lambda - d, c);
+    if u0.is_zero() && u1.is_zero() {
+        (u0, u1) = (b, lambda - a);
+    }
+    let norm = u0.hypot(u1);
+    if norm.is_zero() {
+        return;
+    }
+    let (u0, u1) = (u0 / norm, u1 / norm);
+    rotate_rows(t, k, u0, u1, k..n);
+    rotate_cols(t, k, u0, u1, 0..k + 2);
+    rotate_cols(z, k, u0, u1, 0..n);
+    t[(k + 1, k)] = T::zero();
+}
+
+fn real_reflector<T: RealField, const N: usize>(x: [T; N]) -> ([T; N], T) {
+    let mut v = [T::zero(); N];
+    v[0] = T::one();
+    let tail = x[1..].iter().fold(T::zero(), |acc, &xi| acc.hypot(xi));
+    if tail.is_zero() {
+        return (v, T::zero());
+    }
+    let norm = x[0].hypot(tail);
+    let beta = if x[0] >= T::zero() { -norm } else { norm };
+    let scale = T::one() / (x[0] - beta);
+    for i in 1..N {
+        v[i] = x[i] * scale;
+    }
+    (v, (beta - x[0]) / beta)
+}
+
+fn reflect_rows<T: RealField>(m: &mut Mat<T>, k: usize, v: &[T], tau: T, cols: Range<usize>) {
+    if tau.is_zero() {
+        return;
+    }
+    for j in cols {
+        let mut w = T::zero();
+        for (i, &vi) in v.iter().enumerate() {
+            w += vi * m[(k + i, j)];
+        }
+        w *= tau;
+        for (i, &vi) in v.iter().enumerate() {
+            m[(k + i, j)] -= w * vi;
+        }
+    }
+}
+
+fn reflect_cols<T: RealField>(m: &mut Mat<T>, k: usize, v: &[T], tau: T, rows: Range<usize>) {
+    if tau.is_zero() {
+        return;
+    }
+    for i in rows {
+        let mut w = T::zero();
+        for (j, &vj) in v.iter().enumerate() {
+            w += m[(i, k + j)] * vj;
+        }
+        w *= tau;
+        for (j, &vj) in v.iter().enumerate() {
+            m[(i, k + j)] -= w * vj;
+        }
+    }
+}
+
+fn complex_qr<T: RealField>(t: &mut Mat<Complex<T>>, z: &mut Mat<Complex<T>>) -> Result<()> {
+    let n = t.nrows();
+    let fallback = abs_sum(t);
+    let mut end = n;
+    let mut iter = 0;
+    let mut total = 0;
+    while end > 0 {
+        let hi = end - 1;
+        let l = active_start(t, hi, fallback);
+        if l == hi {
+            end -= 1;
+            iter = 0;
+            continue;
+        }
+        iter += 1;
+        total += 1;
+        if iter > MAX_ITERATIONS_PER_EIGENVALUE {
+            return Err(Error::NotConverged { iterations: total });
+        }
+        complex_step(t, z, l, hi, iter % EXCEPTIONAL_SHIFT_PERIOD == 0);
+    }
+    Ok(())
+}
+
+fn wilkinson_shift<T: RealField>(t: &Mat<Complex<T>>, hi: usize) -> Complex<T> {
+    let a = t[(hi - 1, hi - 1)];
+    let b = t[(hi - 1, hi)];
+    let c = t[(hi, hi - 1)];
+    let d = t[(hi, hi)];
+    let p = (a - d).unscale(T::one() + T::one());
+    let bc = b * c;
+    let disc = (p * p + bc).complex_sqrt();
+    let denom = if (p + disc).modulus() >= (p - disc).modulus() {
+        p + disc
+    } else {
+        p - disc
+    };
+    if denom.is_zero() { d } else { d - bc / denom }
+}
+
+fn complex_step<T: RealField>(
+    t: &mut Mat<Complex<T>>,
+    z: &mut Mat<Complex<T>>,
+    l: usize,
+    hi: usize,
+    exceptional: bool,
+) {
+    let n = t.nrows();
+    let shift = if exceptional {
+        let scale: T = num_traits::cast(0.75).unwrap();
+        t[(hi, hi)] + Complex::from_real(t[(hi, hi - 1)].modulus() * scale)
+    } else {
+        wilkinson_shift(t, hi)
+    };
+    let mut x = t[(l, l)] - shift;
+    let mut y = t[(l + 1, l)];
+    for k in l..hi {
+        let (u0, u1) = complex_givens(x, y);
+        rotate_rows(t, k, u0, u1, if k > l { k - 1 } else { l }..n);
+        rotate_cols(t, k, u0, u1, 0..(k + 3).min(hi + 1));
+        rotate_cols(z, k, u0, u1, 0..n);
+        if k > l {
+            t[(k + 1, k - 1)] = Complex::zero();
+        }
+        if k + 1 < hi {
+            x = t[(k + 1, k)];
+            y = t[(k + 2, k)];
+        }
+    }
+}
+
+fn complex_givens<T: RealField>(x: Complex<T>, y: Complex<T>) -> (Complex<T>, Complex<T>) {
+    let ym = y.modulus();
+    if ym.is_zero() {
+        return (Complex::one(), Complex::zero());
+    }
+    let xm = x.modulus();
+    if xm.is_zero() {
+        return (Complex::zero(), y.unscale(ym));
+    }
+    let r = xm.hypot(ym);
+    (Complex::from_real(xm / r), (x.conj() * y).unscale(xm * r))
+}
+
+fn rotate_rows<T: ComplexField>(m: &mut Mat<T>, k: usize, u0: T, u1: T, cols: Range<usize>) {
+    for j in cols {
+        let r0 = m[(k, j)];
+        let r1 = m[(k + 1, j)];
+        m[(k, j)] = u0.conj() * r0 + u1.conj() * r1;
+        m[(k + 1, j)] = u0 * r1 - u1 * r0;
+    }
+}
+
+fn rotate_cols<T: ComplexField>(m: &mut Mat<T>, k: usize, u0: T, u1: T, rows: Range<usize>) {
+    for i in rows {
+        let c0 = m[(i, k)];
+        let c1 = m[(i, k + 1)];
+        m[(i, k)] = u0 * c0 + u1 * c1;
+        m[(i, k + 1)] = u0.conj() * c1 - u1.conj() * c0;
+    }
+}
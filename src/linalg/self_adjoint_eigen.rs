@@ -0,0 +1,446 @@
+use std::ops::Range;
+
+use num_traits::{Float, Zero};
+
+use super::householder::{apply_householder_left, make_householder};
+use crate::error::{Axis, Error, Result, check_range, check_square};
+use crate::matrix::{Mat, MatMut, MatRef};
+use crate::scalar::{ComplexField, RealField};
+
+const MAX_SWEEPS_PER_EIGENVALUE: usize = 30;
+const INVERSE_ITERATIONS: usize = 3;
+
+pub struct SelfAdjointEigen<T: ComplexField> {
+    eigenvalues: Mat<T::Real>,
+    eigenvectors: Option<Mat<T>>,
+}
+
+impl<T: ComplexField> SelfAdjointEigen<T> {
+    pub fn new(a: MatRef<'_, T>) -> Result<Self> {
+        let tri = Tridiagonal::new(a)?;
+        let n = tri.dim();
+        let mut diag = tri.diag.clone();
+        let mut offdiag = tri.offdiag.clone();
+        let mut z = tri.q();
+        tridiagonal_ql(&mut diag, &mut offdiag, |i, c, s| {
+            for k in 0..n {
+                let f = z[(k, i + 1)];
+                let zi = z[(k, i)];
+                z[(k, i + 1)] = zi.scale_real(s) + f.scale_real(c);
+                z[(k, i)] = zi.scale_real(c) - f.scale_real(s);
+            }
+        })?;
+        let order = ascending_order(&diag);
+        Ok(SelfAdjointEigen {
+            eigenvalues: Mat::from_fn(n, 1, |i, _| diag[order[i]]),
+            eigenvectors: Some(z.take_cols(&order)),
+        })
+    }
+
+    pub fn values_only(a: MatRef<'_, T>) -> Result<Self> {
+        let tri = Tridiagonal::new(a)?;
+        let values = tri.eigenvalues()?;
+        Ok(SelfAdjointEigen {
+            eigenvalues: Mat::from_vec_col(values.len(), 1, values),
+            eigenvectors: None,
+        })
+    }
+
+    pub fn subset(a: MatRef<'_, T>, range: Range<usize>, compute_vectors: bool) -> Result<Self> {
+        check_range(Axis::Row, range.start, range.end, a.nrows())?;
+        let tri = Tridiagonal::new(a)?;
+        let all = tri.eigenvalues()?;
+        let selected = all[range].to_vec();
+        let eigenvectors = if compute_vectors {
+            let x = tri.inverse_iteration(&selected);
+            let mut v = x.map(|&r| T::from_real(r));
+            tri.apply_q(v.as_mut());
+            Some(v)
+        } else {
+            None
+        };
+        Ok(SelfAdjointEigen {
+            eigenvalues: Mat::from_vec_col(selected.len(), 1, selected),
+            eigenvectors,
+        })
+    }
+
+    #[inline]
+    pub fn eigenvalues(&self) -> MatRef<'_, T::Real> {
+        self.eigenvalues.as_ref()
+    }
+
+    #[inline]
+    pub fn eigenvectors(&self) -> Option<MatRef<'_, T>> {
+        self.eigenvectors.as_ref().map(|v| v.as_ref())
+    }
+
+    pub fn into_parts(self) -> (Mat<T::Real>, Option<Mat<T>>) {
+        (self.eigenvalues, self.eigenvectors)
+    }
+}
+
+struct Tridiagonal<T: ComplexField> {
+    householder: Mat<T>,
+    tau: Vec<T>,
+    diag: Vec<T::Real>,
+    offdiag: Vec<T::Real>,
+}
+
+impl<T: ComplexField> Tridiagonal<T> {
+    fn new(a: MatRef<'_, T>) -> Result<Self> {
+        check_square(a.shape())?;
+        let n = a.nrows();
+        let mut h = Mat::from_fn(n, n, |i, j| {
+            if i > j {
+                *a.at(i, j)
+            } else if i == j {
+                T::from_real(a.at(i, i).re())
+            } else {
+                a.at(j, i).conj()
+            }
+        });
+        let mut tau = Vec::with_capacity(n.saturating_sub(1));
+        let mut offdiag = Vec::with_capacity(n);
+        let half = T::from_real(num_traits::cast(0.5).unwrap());
+
+        for k in 0..n.saturating_sub(1) {
+            let m = n - k - 1;
+            let t = make_householder(h.view_mut(k + 1, k, m, 1));
+            tau.push(t);
+            offdiag.push(h[(k + 1, k)].re());
+            if t.is_zero() {
+                continue;
+            }
+            let v: Vec<T> = (0..m)
+                .map(|i| if i == 0 { T::one() } else { h[(k + 1 + i, k)] })
+                .collect();
+            let mut x = vec![T::zero(); m];
+            for (j, &vj) in v.iter().enumerate() {
+                for (i, xi) in x.iter_mut().enumerate() {
+                    *xi += h[(k + 1 + i, k + 1 + j)] * vj;
+                }
+            }
+            let mut xv = T::zero();
+            for (xi, &vi) in x.iter_mut().zip(&v) {
+                *xi *= t;
+                xv += xi.conj() * vi;
+            }
+            let alpha = -(half * t * xv);
+            for (xi, &vi) in x.iter_mut().zip(&v) {
+                *xi += alpha * vi;
+            }
+            for j in 0..m {
+                for i in 0..m {
+                    h[(k + 1 + i, k + 1 + j)] -= v[i] * x[j].conj() + x[i] * v[j].conj();
+                }
+            }
+        }
+        offdiag.push(T::Real::zero());
+        let diag = (0..n).map(|k| h[(k, k)].re()).collect();
+
+        Ok(Tridiagonal {
+            householder: h,
+            tau,
+            diag,
+            offdiag,
+        })
+    }
+
+    fn dim(&self) -> usize {
+        self.diag.len()
+    }
+
+    fn q(&self) -> Mat<T> {
+        let mut q = Mat::identity(self.dim());
+        self.apply_q(q.as_mut());
+        q
+    }
+
+    fn apply_q(&self, mut x: MatMut<'_, T>) {
+        let n = self.dim();
+        let ncols = x.ncols();
+        for (k, &t) in self.tau.iter().enumerate().rev() {
+            apply_householder_left(
+                self.householder.view(k + 2, k, n - k - 2, 1),
+                t,
+                x.rb_mut().view_mut(k + 1, 0, n - k - 1, ncols),
+            );
+        }
+    }
+
+    fn eigenvalues(&self) -> Result<Vec<T::Real>> {
+        let mut diag = self.diag.clone();
+        let mut offdiag = self.offdiag.clone();
+        tridiagonal_ql(&mut diag, &mut offdiag, |_, _, _| {})?;
+        diag.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        Ok(diag)
+    }
+
+    fn inverse_iteration(&self, eigenvalues: &[T::Real]) -> Mat<T::Real> {
+        let n = self.dim();
+        let (d, e) = (&self.diag, &self.offdiag);
+        let eps = T::Real::epsilon();
+        let ten: T::Real = num_traits::cast(10.0).unwrap();
+        let mut norm = T::Real::zero();
+        for i in 0..n {
+            let mut row = d[i].abs() + e[i].abs();
+            if i > 0 {
+                row += e[i - 1].abs();
+            }
+            norm = norm.max(row);
+        }
+        let cluster_tol = norm * num_traits::cast(1e-3).unwrap();
+        let pivot_floor = if norm.is_zero() {
+            T::Real::min_positive_value()
+        } else {
+            eps * norm
+        };
+
+        let mut vectors = Mat::zeros(n, eigenvalues.len());
+        let mut seed = 0x2545_f491_4f6c_dd1du64;
+        let mut prev: Option<T::Real> = None;
+        let mut cluster_start = 0;
+        for (col, &value) in eigenvalues.iter().enumerate() {
+            let mut lambda = value;
+            if let Some(p) = prev {
+                if (lambda - p).abs() > cluster_tol {
+                    cluster_start = col;
+                }
+                if lambda - p < ten * eps * lambda.abs() {
+                    lambda = p + ten * eps * lambda.abs().max(pivot_floor);
+                }
+            }
+            prev = Some(lambda);
+
+            let lu = TridiagonalLu::new(d, e, lambda, pivot_floor);
+            let mut x: Vec<T::Real> = (0..n)
+                .map(|_| {
+                    seed ^= seed << 13;
+                    seed ^= seed >> 7;
+                    seed ^= seed << 17;
+                    num_traits::cast::<f64, T::Real>((seed >> 11) as f64 / (1u64 << 53) as f64)
+                        .unwrap()
+                        - num_traits::cast(0.5).unwrap()
+                })
+                .collect();
+            for _ in 0..INVERSE_ITERATIONS {
+                lu.solve(&mut x);
+                for other in cluster_start..col {
+                    let mut dot = T::Real::zero();
+                    for (i, &xi) in x.iter().enumerate() {
+                        dot += vectors[(i, other)] * xi;
+                    }
+                    for (i, xi) in x.iter_mut().enumerate() {
+                        *xi -= dot * vectors[(i, other)];
+                    }
+                }
+                let mut nrm = T::Real::zero();
+                for &xi in &x {
+                    nrm = nrm.hypot(xi);
+                }
+                if nrm.is_zero() {
+                    break;
+                }
+                for xi in x.iter_mut() {
+                    *xi /= nrm;
+                }
+            }
+            for (i, &xi) in x.iter().enumerate() {
+                vectors[(i, col)] = xi;
+            }
+        }
+        vectors
+    }
+}
+
+struct TridiagonalLu<R> {
+    u0: Vec<R>,
+    u1: Vec<R>,
+    u2: Vec<R>,
+    multipliers: Vec<R>,
+    swapped: Vec<bool>,
+}
+
+impl<R: RealField> TridiagonalLu<R> {
+    fn new(d: &[R], e: &[R], shift: R, pivot_floor: R) -> Self {
+        let n = d.len();
+        let mut u0 = vec![R::zero(); n];
+        let mut u1 = vec![R::zero(); n];
+        let mut u2 = vec![R::zero(); n];
+        let mut multipliers = vec![R::zero(); n];
+        let mut swapped = vec![false; n];
+        if n == 0 {
+            return TridiagonalLu {
+                u0,
+                u1,
+                u2,
+                multipliers,
+                swapped,
+            };
+        }
+        let mut p0 = d[0] - shift;
+        let mut p1 = e[0];
+        for i in 0..(n - 1) {
+            let sub = e[i];
+            let q1 = d[i + 1] - shift;
+            let q2 = if i + 2 < n { e[i + 1] } else { R::zero() };
+            if p0.abs() >= sub.abs() {
+                if p0.is_zero() {
+                    p0 = pivot_floor;
+                }
+                let m = sub / p0;
+                u0[i] = p0;
+                u1[i] = p1;
+                multipliers[i] = m;
+                p0 = q1 - m * p1;
+                p1 = q2;
+            } else {
+                let m = p0 / sub;
+                u0[i] = sub;
+                u1[i] = q1;
+                u2[i] = q2;
+                multipliers[i] = m;
+                swapped[i] = true;
+                p0 = p1 - m * q1;
+                p1 = -m * q2;
+            }
+        }
+        u0[n - 1] = if p0.is_zero() { pivot_floor } else { p0 };
+        TridiagonalLu {
+            u0,
+            u1,
+            u2,
+            multipliers,
+            swapped,
+        }
+    }
+
+    fn solve(&self, x: &mut [R]) {
+        let n = x.len();
+        if n == 0 {
+            return;
+        }
+        for i in 0..(n - 1) {
+            if self.swapped[i] {
+                x.swap(i, i + 1);
+            }
+            x[i + 1] -= self.multipliers[i] * x[i];
+        }
+        for i in (0..n).rev() {
+            let mut t = x[i];
+            if i + 1 < n {
+                t -= self.u1[i] * x[i + 1];
+            }
+            if i + 2 < n {
+                t -= self.u2[i] * x[i + 2];
+            }
+            x[i] = t / self.u0[i];
+        }
+    }
+}
+
+fn tridiagonal_ql<R, F>(d: &mut [R], e: &mut [R], mut rotate: F) -> Result<()>
+where
+    R: RealField,
+    F: FnMut(usize, R, R),
+{
+    let n = d.len();
+    let eps = R::epsilon();
+    let two = R::one() + R::one();
+    for l in 0..n {
+        let mut iterations = 0;
+        loop {
+            let mut m = l;
+            while m + 1 < n {
+                let dd = d[m].abs() + d[m + 1].abs();
+                if e[m].abs() <= eps * dd {
+                    break;
+                }
+                m += 1;
+            }
+            if m == l {
+                break;
+            }
+            iterations += 1;
+            if iterations > MAX_SWEEPS_PER_EIGENVALUE {
+                return Err(Error::NotConverged { iterations });
+            }
+
+            let mut g = (d[l + 1] - d[l]) / (two * e[l]);
+            let mut r = g.hypot(R::one());
+            g = d[m] - d[l] + e[l] / (g + if g >= R::zero() { r } else { -r });
+            let (mut s, mut c, mut p) = (R::one(), R::one(), R::zero());
+            let mut deflated = false;
+            let mut i = m;
+            while i > l {
+                i -= 1;
+                let f = s * e[i];
+                let b = c * e[i];
+                r = f.hypot(g);
+                e[i + 1] = r;
+                if r.is_zero() {
+                    d[i + 1] -= p;
+                    e[m] = R::zero();
+                    deflated = true;
+                    break;
+                }
+                s = f / r;
+                c = g / r;
+                g = d[i + 1] - p;
+                r = (d[i] - g) * s + two * c * b;
+                p = s * r;
+                d[i + 1] = g + p;
+                g = c * r - b;
+                rotate(i, c, s);
+            }
+            if deflated {
+                continue;
+            }
+            d[l] -= p;
+            e[l] = g;
+            e[m] = R::zero();
+        }
+    }
+    Ok(())
+}
+
+fn ascending_order<R: RealField>(values: &[R]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|&a, &b| {
+        values[a]
+            .partial_cmp(&values[b])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    order
+}
+
+impl<T: ComplexField> MatRef<'_, T> {
+    pub fn eigh(self) -> Result<SelfAdjointEigen<T>> {
+        SelfAdjointEigen::new(self)
+    }
+
+    pub fn eigvalsh(self) -> Result<Mat<T::Real>> {
+        Ok(SelfAdjointEigen::values_only(self)?.eigenvalues)
+    }
+}
+
+impl<T: ComplexField> MatMut<'_, T> {
+    pub fn eigh(&self) -> Result<SelfAdjointEigen<T>> {
+        self.rb().eigh()
+    }
+
+    pub fn eigvalsh(&self) -> Result<Mat<T::Real>> {
+        self.rb().eigvalsh()
+    }
+}
+
+impl<T: ComplexField> Mat<T> {
+    pub fn eigh(&self) -> Result<SelfAdjointEigen<T>> {
+        self.as_ref().eigh()
+    }
+
+    pub fn eigvalsh(&self) -> Result<Mat<T::Real>> {
+        self.as_ref().eigvalsh()
+    }
+}
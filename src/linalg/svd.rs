@@ -0,0 +1,475 @@
+use num_traits::{Float, One, Zero};
+
+use super::qr::Qr;
+use crate::error::{Error, OrPanic, Result};
+use crate::matrix::{Mat, MatMut, MatRef};
+use crate::scalar::ComplexField;
+
+const MAX_SWEEPS: usize = 60;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Thin,
+    Full,
+    ValuesOnly,
+}
+
+type SvdParts<T> = (
+    Option<Mat<T>>,
+    Mat<<T as ComplexField>::Real>,
+    Option<Mat<T>>,
+);
+
+pub struct Svd<T: ComplexField> {
+    nrows: usize,
+    ncols: usize,
+    s: Mat<T::Real>,
+    u: Option<Mat<T>>,
+    v: Option<Mat<T>>,
+}
+
+impl<T: ComplexField> Svd<T> {
+    pub fn new(a: MatRef<'_, T>) -> Result<Self> {
+        Self::compute(a, Mode::Thin)
+    }
+
+    pub fn full(a: MatRef<'_, T>) -> Result<Self> {
+        Self::compute(a, Mode::Full)
+    }
+
+    pub fn values_only(a: MatRef<'_, T>) -> Result<Self> {
+        Self::compute(a, Mode::ValuesOnly)
+    }
+
+    fn compute(a: MatRef<'_, T>, mode: Mode) -> Result<Self> {
+        let (m, n) = a.shape();
+        let transposed = m < n;
+        let mut b = if transposed {
+            Mat::from_fn(n, m, |i, j| a.at(j, i).conj())
+        } else {
+            a.to_owned()
+        };
+        // Working on A / max|a_ij| keeps the squared column norms and Jacobi
+        // dot products from overflowing or underflowing; the singular values
+        // are scaled back at the end.
+        let mut scale = a.max_abs_norm();
+        if scale.is_zero() || !scale.is_finite() {
+            scale = T::Real::one();
+        } else {
+            divide_entries(b.as_mut(), scale);
+        }
+        let (p, q) = b.shape();
+        let qr = Qr::new(b.as_ref());
+        let mut w = qr.r();
+        let mut vr = (mode != Mode::ValuesOnly).then(|| Mat::identity(q));
+        one_sided_jacobi(w.as_mut(), vr.as_mut().map(|v| v.as_mut()))?;
+
+        let norms: Vec<T::Real> = (0..q)
+            .map(|j| {
+                let mut sum = T::Real::zero();
+                for i in 0..q {
+                    sum += w[(i, j)].modulus_sqr();
+                }
+                sum.sqrt()
+            })
+            .collect();
+        let mut order: Vec<usize> = (0..q).collect();
+        order.sort_by(|&x, &y| norms[y].partial_cmp(&norms[x]).unwrap());
+        let s = Mat::from_fn(q, 1, |i, _| norms[order[i]] * scale);
+        if mode == Mode::ValuesOnly {
+            return Ok(Svd {
+                nrows: m,
+                ncols: n,
+                s,
+                u: None,
+                v: None,
+            });
+        }
+
+        let mut ur = Mat::zeros(q, q);
+        let mut valid = vec![false; q];
+        for (k, &j) in order.iter().enumerate() {
+            let sigma = norms[j];
+            // Negligible columns, as in the Jacobi sweeps, are left for
+            // `complete_orthonormal`.
+            if sigma * sigma >= T::Real::min_positive_value() {
+                for i in 0..q {
+                    ur[(i, k)] = w[(i, j)].scale_real(sigma.recip());
+                }
+                valid[k] = true;
+            }
+        }
+        complete_orthonormal(ur.as_mut(), &mut valid);
+        let vb = vr.unwrap().take_cols(&order);
+
+        let ub_cols = if mode == Mode::Full { p } else { q };
+        let mut ub = Mat::eye(p, ub_cols, 0);
+        ub.view_mut(0, 0, q, q).copy_from(ur.as_ref());
+        qr.apply_q(ub.as_mut());
+
+        let (u, v) = if transposed { (vb, ub) } else { (ub, vb) };
+        Ok(Svd {
+            nrows: m,
+            ncols: n,
+            s,
+            u: Some(u),
+            v: Some(v),
+        })
+    }
+
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    #[inline]
+    pub fn singular_values(&self) -> MatRef<'_, T::Real> {
+        self.s.as_ref()
+    }
+
+    #[inline]
+    pub fn u(&self) -> Option<MatRef<'_, T>> {
+        self.u.as_ref().map(|u| u.as_ref())
+    }
+
+    #[inline]
+    pub fn v(&self) -> Option<MatRef<'_, T>> {
+        self.v.as_ref().map(|v| v.as_ref())
+    }
+
+    pub fn into_parts(self) -> SvdParts<T> {
+        (self.u, self.s, self.v)
+    }
+
+    pub fn spectral_norm(&self) -> T::Real {
+        if self.s.nrows() == 0 {
+            T::Real::zero()
+        } else {
+            self.s[(0, 0)]
+        }
+    }
+
+    pub fn cond(&self) -> T::Real {
+        let k = self.s.nrows();
+        if k == 0 {
+            return T::Real::zero();
+        }
+        let smallest = self.s[(k - 1, 0)];
+        if smallest.is_zero() {
+            T::Real::infinity()
+        } else {
+            self.s[(0, 0)] / smallest
+        }
+    }
+
+    pub fn default_tolerance(&self) -> T::Real {
+        let dim: T::Real = num_traits::cast(self.nrows.max(self.ncols)).unwrap();
+        dim * T::Real::epsilon() * self.spectral_norm()
+    }
+
+    pub fn rank(&self, tol: T::Real) -> usize {
+        (0..self.s.nrows())
+            .take_while(|&i| self.s[(i, 0)] > tol)
+            .count()
+    }
+
+    #[track_caller]
+    pub fn pinv(&self) -> Mat<T> {
+        self.try_pinv().or_panic()
+    }
+
+    pub fn try_pinv(&self) -> Result<Mat<T>> {
+        let (u, v) = self.vectors("pseudoinverse without singular vectors")?;
+        let r = self.rank(self.default_tolerance());
+        let (m, n) = (self.nrows, self.ncols);
+        let mut x = Mat::zeros(n, m);
+        for k in 0..r {
+            let inv = self.s[(k, 0)].recip();
+            for j in 0..m {
+                let uj = u[(j, k)].conj().scale_real(inv);
+                if uj.is_zero() {
+                    continue;
+                }
+                for i in 0..n {
+                    x[(i, j)] += v[(i, k)] * uj;
+                }
+            }
+        }
+        Ok(x)
+    }
+
+    #[track_caller]
+    pub fn range(&self) -> Mat<T> {
+        self.try_range().or_panic()
+    }
+
+    pub fn try_range(&self) -> Result<Mat<T>> {
+        let (u, _) = self.vectors("range without singular vectors")?;
+        let r = self.rank(self.default_tolerance());
+        Ok(u.view(0, 0, self.nrows, r).to_owned())
+    }
+
+    #[track_caller]
+    pub fn null_space(&self) -> Mat<T> {
+        self.try_null_space().or_panic()
+    }
+
+    pub fn try_null_space(&self) -> Result<Mat<T>> {
+        let (_, v) = self.vectors("null space without singular vectors")?;
+        if v.ncols() != self.ncols {
+            return Err(Error::Unsupported {
+                operation: "null space of a wide matrix from a thin SVD",
+            });
+        }
+        let r = self.rank(self.default_tolerance());
+        Ok(v.view(0, r, self.ncols, self.ncols - r).to_owned())
+    }
+
+    fn vectors(&self, operation: &'static str) -> Result<(&Mat<T>, &Mat<T>)> {
+        match (&self.u, &self.v) {
+            (Some(u), Some(v)) => Ok((u, v)),
+            _ => Err(Error::Unsupported { operation }),
+        }
+    }
+}
+
+// Divides every entry by `scale`, in two steps when `1 / scale` would
+// overflow.
+fn divide_entries<T: ComplexField>(mut a: MatMut<'_, T>, scale: T::Real) {
+    let mut pre = T::Real::one();
+    let mut factor = scale.recip();
+    if factor.is_infinite() {
+        pre = T::Real::epsilon().recip();
+        factor = (scale * pre).recip();
+    }
+    for j in 0..a.ncols() {
+        for i in 0..a.nrows() {
+            let x = a.at_mut(i, j);
+            *x = x.scale_real(pre).scale_real(factor);
+        }
+    }
+}
+
+fn one_sided_jacobi<T: ComplexField>(
+    mut w: MatMut<'_, T>,
+    mut v: Option<MatMut<'_, T>>,
+) -> Result<()> {
+    let n = w.ncols();
+    let eps = T::Real::epsilon();
+    let two = T::Real::one() + T::Real::one();
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let mut alpha = T::Real::zero();
+                let mut beta = T::Real::zero();
+                let mut gamma = T::zero();
+                for i in 0..w.nrows() {
+                    let wp = *w.at(i, p);
+                    let wq = *w.at(i, q);
+                    alpha += wp.modulus_sqr();
+                    beta += wq.modulus_sqr();
+                    gamma += wp.conj() * wq;
+                }
+                // With the entries scaled to at most one, a column whose squared
+                // norm is subnormal is negligible, and rotating it against
+                // another never converges because its norm has lost precision.
+                let g = gamma.modulus();
+                if g.is_zero()
+                    || alpha.min(beta) < T::Real::min_positive_value()
+                    || g <= eps * alpha.sqrt() * beta.sqrt()
+                {
+                    continue;
+                }
+                rotated = true;
+                let phase = gamma.conj().scale_real(g.recip());
+                let zeta = (beta - alpha) / (two * g);
+                let t = zeta.signum() / (zeta.abs() + (T::Real::one() + zeta * zeta).sqrt());
+                let c = (T::Real::one() + t * t).sqrt().recip();
+                let s = c * t;
+                rotate_columns(w.rb_mut(), p, q, phase, c, s);
+                if let Some(v) = v.as_mut() {
+                    rotate_columns(v.rb_mut(), p, q, phase, c, s);
+                }
+            }
+        }
+        if !rotated {
+            return Ok(());
+        }
+    }
+    Err(Error::NotConverged {
+        iterations: MAX_SWEEPS,
+    })
+}
+
+fn rotate_columns<T: ComplexField>(
+    mut a: MatMut<'_, T>,
+    p: usize,
+    q: usize,
+    phase: T,
+    c: T::Real,
+    s: T::Real,
+) {
+    for i in 0..a.nrows() {
+        let ap = *a.at(i, p);
+        let aq = *a.at(i, q) * phase;
+        *a.at_mut(i, p) = ap.scale_real(c) - aq.scale_real(s);
+        *a.at_mut(i, q) = ap.scale_real(s) + aq.scale_real(c);
+    }
+}
+
+fn complete_orthonormal<T: ComplexField>(mut u: MatMut<'_, T>, valid: &mut [bool]) {
+    let n = u.nrows();
+    for k in 0..valid.len() {
+        if valid[k] {
+            continue;
+        }
+        let mut best = (T::Real::zero(), vec![T::zero(); n]);
+        for e in 0..n {
+            let mut x = vec![T::zero(); n];
+            x[e] = T::one();
+            for _ in 0..2 {
+                for j in (0..valid.len()).filter(|&j| valid[j]) {
+                    let mut dot = T::zero();
+                    for (i, &xi) in x.iter().enumerate() {
+                        dot += u.at(i, j).conj() * xi;
+                    }
+                    for (i, xi) in x.iter_mut().enumerate() {
+                        *xi -= *u.at(i, j) * dot;
+                    }
+                }
+            }
+            let norm = x
+                .iter()
+                .fold(T::Real::zero(), |acc, xi| acc + xi.modulus_sqr())
+                .sqrt();
+            if norm > best.0 {
+                best = (norm, x);
+            }
+        }
+        let (norm, x) = best;
+        for (i, &xi) in x.iter().enumerate() {
+            *u.at_mut(i, k) = xi.scale_real(norm.recip());
+        }
+        valid[k] = true;
+    }
+}
+
+impl<T: ComplexField> MatRef<'_, T> {
+    pub fn svd(self) -> Result<Svd<T>> {
+        Svd::new(self)
+    }
+
+    pub fn svd_full(self) -> Result<Svd<T>> {
+        Svd::full(self)
+    }
+
+    pub fn singular_values(self) -> Result<Mat<T::Real>> {
+        Ok(Svd::values_only(self)?.s)
+    }
+
+    pub fn pinv(self) -> Result<Mat<T>> {
+        Svd::new(self)?.try_pinv()
+    }
+
+    pub fn rank(self, tol: T::Real) -> Result<usize> {
+        Ok(Svd::values_only(self)?.rank(tol))
+    }
+
+    pub fn null_space(self) -> Result<Mat<T>> {
+        Svd::full(self)?.try_null_space()
+    }
+
+    pub fn range(self) -> Result<Mat<T>> {
+        Svd::new(self)?.try_range()
+    }
+
+    pub fn spectral_norm(self) -> Result<T::Real> {
+        Ok(Svd::values_only(self)?.spectral_norm())
+    }
+
+    pub fn cond(self) -> Result<T::Real> {
+        Ok(Svd::values_only(self)?.cond())
+    }
+}
+
+impl<T: ComplexField> MatMut<'_, T> {
+    pub fn svd(&self) -> Result<Svd<T>> {
+        self.rb().svd()
+    }
+
+    pub fn svd_full(&self) -> Result<Svd<T>> {
+        self.rb().svd_full()
+    }
+
+    pub fn singular_values(&self) -> Result<Mat<T::Real>> {
+        self.rb().singular_values()
+    }
+
+    pub fn pinv(&self) -> Result<Mat<T>> {
+        self.rb().pinv()
+    }
+
+    pub fn rank(&self, tol: T::Real) -> Result<usize> {
+        self.rb().rank(tol)
+    }
+
+    pub fn null_space(&self) -> Result<Mat<T>> {
+        self.rb().null_space()
+    }
+
+    pub fn range(&self) -> Result<Mat<T>> {
+        self.rb().range()
+    }
+
+    pub fn spectral_norm(&self) -> Result<T::Real> {
+        self.rb().spectral_norm()
+    }
+
+    pub fn cond(&self) -> Result<T::Real> {
+        self.rb().cond()
+    }
+}
+
+impl<T: ComplexField> Mat<T> {
+    pub fn svd(&self) -> Result<Svd<T>> {
+        self.as_ref().svd()
+    }
+
+    pub fn svd_full(&self) -> Result<Svd<T>> {
+        self.as_ref().svd_full()
+    }
+
+    pub fn singular_values(&self) -> Result<Mat<T::Real>> {
+        self.as_ref().singular_values()
+    }
+
+    pub fn pinv(&self) -> Result<Mat<T>> {
+        self.as_ref().pinv()
+    }
+
+    pub fn rank(&self, tol: T::Real) -> Result<usize> {
+        self.as_ref().rank(tol)
+    }
+
+    pub fn null_space(&self) -> Result<Mat<T>> {
+        self.as_ref().null_space()
+    }
+
+    pub fn range(&self) -> Result<Mat<T>> {
+        self.as_ref().range()
+    }
+
+    pub fn spectral_norm(&self) -> Result<T::Real> {
+        self.as_ref().spectral_norm()
+    }
+
+    pub fn cond(&self) -> Result<T::Real> {
+        self.as_ref().cond()
+    }
+}
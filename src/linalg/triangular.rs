@@ -0,0 +1,164 @@
+use crate::error::{Error, OrPanic, Result, check_shape, check_square};
+use crate::matrix::{MatMut, MatRef};
+use crate::scalar::ComplexField;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Diag {
+    NonUnit,
+    Unit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Plain,
+    Transpose,
+    Adjoint,
+}
+
+pub fn solve_lower_triangular<T: ComplexField>(
+    a: MatRef<'_, T>,
+    b: MatMut<'_, T>,
+    diag: Diag,
+    op: Op,
+) -> Result<()> {
+    solve_triangular(a, b, true, diag, op)
+}
+
+pub fn solve_upper_triangular<T: ComplexField>(
+    a: MatRef<'_, T>,
+    b: MatMut<'_, T>,
+    diag: Diag,
+    op: Op,
+) -> Result<()> {
+    solve_triangular(a, b, false, diag, op)
+}
+
+#[track_caller]
+pub fn lower_triangular_matmul_into<T: ComplexField>(
+    dst: MatMut<'_, T>,
+    a: MatRef<'_, T>,
+    diag: Diag,
+    b: MatRef<'_, T>,
+) {
+    try_lower_triangular_matmul_into(dst, a, diag, b).or_panic()
+}
+
+pub fn try_lower_triangular_matmul_into<T: ComplexField>(
+    dst: MatMut<'_, T>,
+    a: MatRef<'_, T>,
+    diag: Diag,
+    b: MatRef<'_, T>,
+) -> Result<()> {
+    triangular_matmul_into(dst, a, true, diag, b)
+}
+
+#[track_caller]
+pub fn upper_triangular_matmul_into<T: ComplexField>(
+    dst: MatMut<'_, T>,
+    a: MatRef<'_, T>,
+    diag: Diag,
+    b: MatRef<'_, T>,
+) {
+    try_upper_triangular_matmul_into(dst, a, diag, b).or_panic()
+}
+
+pub fn try_upper_triangular_matmul_into<T: ComplexField>(
+    dst: MatMut<'_, T>,
+    a: MatRef<'_, T>,
+    diag: Diag,
+    b: MatRef<'_, T>,
+) -> Result<()> {
+    triangular_matmul_into(dst, a, false, diag, b)
+}
+
+fn solve_triangular<T: ComplexField>(
+    a: MatRef<'_, T>,
+    mut b: MatMut<'_, T>,
+    lower: bool,
+    diag: Diag,
+    op: Op,
+) -> Result<()> {
+    check_square(a.shape())?;
+    check_shape(b.shape(), (a.nrows(), b.ncols()))?;
+    let n = a.nrows();
+    if diag == Diag::NonUnit
+        && let Some(index) = (0..n).find(|&i| a.at(i, i).is_zero())
+    {
+        return Err(Error::Singular { index });
+    }
+    let (a, lower) = match op {
+        Op::Plain => (a, lower),
+        Op::Transpose | Op::Adjoint => (a.transpose(), !lower),
+    };
+    let conj = op == Op::Adjoint;
+    let get = |i: usize, j: usize| {
+        let x = *a.at(i, j);
+        if conj { x.conj() } else { x }
+    };
+    for c in 0..b.ncols() {
+        if lower {
+            for j in 0..n {
+                let mut x = *b.at(j, c);
+                if diag == Diag::NonUnit {
+                    x /= get(j, j);
+                    *b.at_mut(j, c) = x;
+                }
+                if x.is_zero() {
+                    continue;
+                }
+                for i in j + 1..n {
+                    *b.at_mut(i, c) -= get(i, j) * x;
+                }
+            }
+        } else {
+            for j in (0..n).rev() {
+                let mut x = *b.at(j, c);
+                if diag == Diag::NonUnit {
+                    x /= get(j, j);
+                    *b.at_mut(j, c) = x;
+                }
+                if x.is_zero() {
+                    continue;
+                }
+                for i in 0..j {
+                    *b.at_mut(i, c) -= get(i, j) * x;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn triangular_matmul_into<T: ComplexField>(
+    mut dst: MatMut<'_, T>,
+    a: MatRef<'_, T>,
+    lower: bool,
+    diag: Diag,
+    b: MatRef<'_, T>,
+) -> Result<()> {
+    check_square(a.shape())?;
+    check_shape(b.shape(), (a.ncols(), b.ncols()))?;
+    check_shape(dst.shape(), (a.nrows(), b.ncols()))?;
+    let n = a.nrows();
+    for c in 0..b.ncols() {
+        for i in 0..n {
+            *dst.at_mut(i, c) = T::zero();
+        }
+        for k in 0..n {
+            let x = *b.at(k, c);
+            if x.is_zero() {
+                continue;
+            }
+            let rows = if lower { k + 1..n } else { 0..k };
+            for i in rows {
+                *dst.at_mut(i, c) += *a.at(i, k) * x;
+            }
+            let d = match diag {
+                Diag::Unit => T::one(),
+                Diag::NonUnit => *a.at(k, k),
+            };
+            *dst.at_mut(k, c) += d * x;
+        }
+    }
+    Ok(())
+}
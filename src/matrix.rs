@@ -1,19 +1,50 @@
+mod approx;
+mod broadcast;
 mod complex;
+#[cfg(feature = "faer")]
+mod faer_impl;
+mod mask;
 mod mat;
 mod mat_mut;
 mod mat_ref;
+mod matmul;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impl;
+#[cfg(feature = "ndarray")]
+mod ndarray_impl;
 mod ops;
+#[cfg(feature = "rayon")]
+mod par_iter;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod simd;
+mod storage;
 
 #[cfg(test)]
 mod test;
 
 use std::marker::PhantomData;
 
+use storage::Storage;
+
+use crate::error::{Error, Result};
+
+#[doc(hidden)]
+pub use approx::assert_approx_eq_impl;
+pub use matmul::{matmul_into, try_matmul_into};
+#[cfg(feature = "rayon")]
+pub use par_iter::{ParColIter, ParColIterMut};
+pub use simd::{SimdLevel, set_simd_level, simd_level};
+
 pub struct Mat<T> {
-    data: Vec<T>,
+    data: Storage<T>,
     nrows: usize,
     ncols: usize,
     col_stride: usize,
+    // Whether columns may be padded. Only matrices created by
+    // `with_capacity_aligned` opt into this; all others keep `col_stride ==
+    // nrows` so their elements stay contiguous.
+    padded: bool,
 }
 
 pub struct MatRef<'a, T> {
@@ -90,6 +121,65 @@ pub struct MatEnumerate<'a, T> {
     length: usize,
 }
 
+// Checks that a `nrows` by `ncols` view with the given strides fits in a
+// buffer of `len` elements, and returns the offset of its first element, which
+// puts the lowest address at the start of the buffer. Mutable views also need
+// every index to map to its own element; that is only accepted when one axis
+// steps over the whole extent of the other.
+pub(crate) fn strided_origin(
+    len: usize,
+    (nrows, ncols): (usize, usize),
+    (row_stride, col_stride): (isize, isize),
+    unique: bool,
+) -> Result<usize> {
+    if nrows == 0 || ncols == 0 {
+        return Ok(0);
+    }
+    let too_small = Error::BufferTooSmall {
+        required: usize::MAX,
+        len,
+    };
+    let span = |n: usize, stride: isize| {
+        isize::try_from(n - 1)
+            .ok()
+            .and_then(|n| n.checked_mul(stride))
+    };
+    let (Some(rows), Some(cols)) = (span(nrows, row_stride), span(ncols, col_stride)) else {
+        return Err(too_small);
+    };
+    let (Some(low), Some(high)) = (
+        rows.min(0).checked_add(cols.min(0)),
+        rows.max(0).checked_add(cols.max(0)),
+    ) else {
+        return Err(too_small);
+    };
+    let required = high.abs_diff(low) + 1;
+    if required > len {
+        return Err(Error::BufferTooSmall { required, len });
+    }
+    if unique {
+        let mut axes = [
+            (nrows, row_stride.unsigned_abs()),
+            (ncols, col_stride.unsigned_abs()),
+        ];
+        axes.sort_by_key(|&(_, stride)| stride);
+        let distinct = match axes {
+            [(1, _), (1, _)] => true,
+            [(1, _), (_, stride)] | [(_, stride), (1, _)] => stride > 0,
+            [(n, inner), (_, outer)] => {
+                inner > 0 && n.checked_mul(inner).is_some_and(|n| outer >= n)
+            }
+        };
+        if !distinct {
+            return Err(Error::OverlappingStrides {
+                row_stride,
+                col_stride,
+            });
+        }
+    }
+    Ok(low.unsigned_abs())
+}
+
 pub(crate) fn fmt_matrix<T: std::fmt::Display>(
     matrix: MatRef<'_, T>,
     f: &mut std::fmt::Formatter<'_>,
@@ -163,6 +253,40 @@ macro_rules! block {
     }};
 }
 
+#[macro_export]
+macro_rules! assert_mat_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {{
+        $crate::matrix::assert_approx_eq_impl(
+            (&$left).into(),
+            (&$right).into(),
+            None,
+            stringify!($left),
+            stringify!($right),
+        )
+    }};
+
+    ($left:expr, $right:expr, abs_tol = $abs_tol:expr, rel_tol = $rel_tol:expr $(,)?) => {{
+        $crate::matrix::assert_approx_eq_impl(
+            (&$left).into(),
+            (&$right).into(),
+            Some(($abs_tol, $rel_tol)),
+            stringify!($left),
+            stringify!($right),
+        )
+    }};
+
+    ($left:expr, $right:expr, $tol:expr $(,)?) => {{
+        let tol = $tol;
+        $crate::matrix::assert_approx_eq_impl(
+            (&$left).into(),
+            (&$right).into(),
+            Some((tol, tol)),
+            stringify!($left),
+            stringify!($right),
+        )
+    }};
+}
+
 #[macro_export]
 macro_rules! col {
     [$($elem:expr),* $(,)?] => {{
@@ -0,0 +1,190 @@
+use num_traits::Float;
+
+use super::{Mat, MatMut, MatRef};
+use crate::error::{OrPanic, Result, check_shape};
+use crate::scalar::{ComplexField, RealField};
+
+fn close<T: ComplexField>(a: T, b: T, abs_tol: T::Real, rel_tol: T::Real) -> bool {
+    if a == b {
+        return true;
+    }
+    let diff = (a - b).modulus();
+    diff.is_finite() && diff <= abs_tol.max(rel_tol * a.modulus().max(b.modulus()))
+}
+
+fn same_or_both_nan<R: RealField>(a: R, b: R) -> bool {
+    a == b || (a.is_nan() && b.is_nan())
+}
+
+pub(crate) fn default_tolerance<R: RealField>() -> R {
+    R::epsilon().sqrt()
+}
+
+impl<T: ComplexField> MatRef<'_, T> {
+    #[track_caller]
+    pub fn first_approx_mismatch(
+        self,
+        other: MatRef<'_, T>,
+        abs_tol: T::Real,
+        rel_tol: T::Real,
+    ) -> Option<(usize, usize)> {
+        self.try_first_approx_mismatch(other, abs_tol, rel_tol)
+            .or_panic()
+    }
+
+    pub fn try_first_approx_mismatch(
+        self,
+        other: MatRef<'_, T>,
+        abs_tol: T::Real,
+        rel_tol: T::Real,
+    ) -> Result<Option<(usize, usize)>> {
+        check_shape(self.shape(), other.shape())?;
+        for j in 0..self.ncols() {
+            for i in 0..self.nrows() {
+                if !close(*self.at(i, j), *other.at(i, j), abs_tol, rel_tol) {
+                    return Ok(Some((i, j)));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    pub fn approx_eq(self, other: MatRef<'_, T>, abs_tol: T::Real, rel_tol: T::Real) -> bool {
+        self.shape() == other.shape()
+            && self
+                .first_approx_mismatch(other, abs_tol, rel_tol)
+                .is_none()
+    }
+
+    pub fn ulps_eq(self, other: MatRef<'_, T>, max_ulps: u64) -> bool {
+        if self.shape() != other.shape() {
+            return false;
+        }
+        for j in 0..self.ncols() {
+            for i in 0..self.nrows() {
+                let (a, b) = (*self.at(i, j), *other.at(i, j));
+                if a.re().ulps_distance(b.re()) > max_ulps
+                    || a.im().ulps_distance(b.im()) > max_ulps
+                {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    pub fn nan_eq(self, other: MatRef<'_, T>) -> bool {
+        if self.shape() != other.shape() {
+            return false;
+        }
+        for j in 0..self.ncols() {
+            for i in 0..self.nrows() {
+                let (a, b) = (*self.at(i, j), *other.at(i, j));
+                if !same_or_both_nan(a.re(), b.re()) || !same_or_both_nan(a.im(), b.im()) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+impl<T: ComplexField> MatMut<'_, T> {
+    pub fn first_approx_mismatch(
+        &self,
+        other: MatRef<'_, T>,
+        abs_tol: T::Real,
+        rel_tol: T::Real,
+    ) -> Option<(usize, usize)> {
+        self.rb().first_approx_mismatch(other, abs_tol, rel_tol)
+    }
+
+    pub fn try_first_approx_mismatch(
+        &self,
+        other: MatRef<'_, T>,
+        abs_tol: T::Real,
+        rel_tol: T::Real,
+    ) -> Result<Option<(usize, usize)>> {
+        self.rb().try_first_approx_mismatch(other, abs_tol, rel_tol)
+    }
+
+    pub fn approx_eq(&self, other: MatRef<'_, T>, abs_tol: T::Real, rel_tol: T::Real) -> bool {
+        self.rb().approx_eq(other, abs_tol, rel_tol)
+    }
+
+    pub fn ulps_eq(&self, other: MatRef<'_, T>, max_ulps: u64) -> bool {
+        self.rb().ulps_eq(other, max_ulps)
+    }
+
+    pub fn nan_eq(&self, other: MatRef<'_, T>) -> bool {
+        self.rb().nan_eq(other)
+    }
+}
+
+impl<T: ComplexField> Mat<T> {
+    pub fn first_approx_mismatch(
+        &self,
+        other: MatRef<'_, T>,
+        abs_tol: T::Real,
+        rel_tol: T::Real,
+    ) -> Option<(usize, usize)> {
+        self.as_ref().first_approx_mismatch(other, abs_tol, rel_tol)
+    }
+
+    pub fn try_first_approx_mismatch(
+        &self,
+        other: MatRef<'_, T>,
+        abs_tol: T::Real,
+        rel_tol: T::Real,
+    ) -> Result<Option<(usize, usize)>> {
+        self.as_ref()
+            .try_first_approx_mismatch(other, abs_tol, rel_tol)
+    }
+
+    pub fn approx_eq(&self, other: MatRef<'_, T>, abs_tol: T::Real, rel_tol: T::Real) -> bool {
+        self.as_ref().approx_eq(other, abs_tol, rel_tol)
+    }
+
+    pub fn ulps_eq(&self, other: MatRef<'_, T>, max_ulps: u64) -> bool {
+        self.as_ref().ulps_eq(other, max_ulps)
+    }
+
+    pub fn nan_eq(&self, other: MatRef<'_, T>) -> bool {
+        self.as_ref().nan_eq(other)
+    }
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_approx_eq_impl<T: ComplexField>(
+    left: MatRef<'_, T>,
+    right: MatRef<'_, T>,
+    tol: Option<(T::Real, T::Real)>,
+    left_expr: &str,
+    right_expr: &str,
+) {
+    let (abs_tol, rel_tol) = tol.unwrap_or((default_tolerance(), default_tolerance()));
+    if left.shape() != right.shape() {
+        panic!(
+            "assertion `{} ≈ {}` failed: shape mismatch: {:?} vs {:?}",
+            left_expr,
+            right_expr,
+            left.shape(),
+            right.shape()
+        );
+    }
+    if let Some((i, j)) = left.first_approx_mismatch(right, abs_tol, rel_tol) {
+        panic!(
+            "assertion `{} ≈ {}` failed (abs_tol = {:?}, rel_tol = {:?})\n  shape: {:?}\n  index: ({}, {})\n   left: {:?}\n  right: {:?}",
+            left_expr,
+            right_expr,
+            abs_tol,
+            rel_tol,
+            left.shape(),
+            i,
+            j,
+            left.at(i, j),
+            right.at(i, j)
+        );
+    }
+}
@@ -0,0 +1,288 @@
+use std::ops::{Div, Mul, Rem};
+
+use num_traits::Pow;
+
+use super::simd::{self, Binary};
+use super::{Mat, MatMut, MatRef};
+use crate::error::{Error, OrPanic, Result};
+
+pub(crate) fn broadcast_shape(lhs: (usize, usize), rhs: (usize, usize)) -> Result<(usize, usize)> {
+    let dim = |x: usize, y: usize| {
+        if x == y || y == 1 {
+            Some(x)
+        } else if x == 1 {
+            Some(y)
+        } else {
+            None
+        }
+    };
+    match (dim(lhs.0, rhs.0), dim(lhs.1, rhs.1)) {
+        (Some(nrows), Some(ncols)) => Ok((nrows, ncols)),
+        _ => Err(Error::ShapeMismatch { lhs, rhs }),
+    }
+}
+
+pub(crate) fn check_broadcasts_into(dst: (usize, usize), src: (usize, usize)) -> Result<()> {
+    let fits = |d: usize, s: usize| d == s || s == 1;
+    if fits(dst.0, src.0) && fits(dst.1, src.1) {
+        Ok(())
+    } else {
+        Err(Error::ShapeMismatch { lhs: dst, rhs: src })
+    }
+}
+
+#[inline]
+pub(crate) fn broadcast_index(len: usize, i: usize) -> usize {
+    if len == 1 { 0 } else { i }
+}
+
+pub(crate) fn broadcast_zip_map<A, B, U, F: FnMut(&A, &B) -> U>(
+    lhs: MatRef<'_, A>,
+    rhs: MatRef<'_, B>,
+    mut f: F,
+) -> Result<Mat<U>> {
+    let (nrows, ncols) = broadcast_shape(lhs.shape(), rhs.shape())?;
+    let mut data = Vec::with_capacity(nrows * ncols);
+    for j in 0..ncols {
+        for i in 0..nrows {
+            data.push(f(
+                lhs.at(
+                    broadcast_index(lhs.nrows(), i),
+                    broadcast_index(lhs.ncols(), j),
+                ),
+                rhs.at(
+                    broadcast_index(rhs.nrows(), i),
+                    broadcast_index(rhs.ncols(), j),
+                ),
+            ));
+        }
+    }
+    Ok(Mat::from_vec_col(nrows, ncols, data))
+}
+
+impl<T: Clone> MatRef<'_, T> {
+    #[track_caller]
+    pub fn hadamard(self, rhs: MatRef<'_, T>) -> Mat<T>
+    where
+        T: Mul<Output = T>,
+    {
+        self.try_hadamard(rhs).or_panic()
+    }
+
+    pub fn try_hadamard(self, rhs: MatRef<'_, T>) -> Result<Mat<T>>
+    where
+        T: Mul<Output = T>,
+    {
+        if let Some(out) = simd::zip(Binary::Mul, self, rhs) {
+            return Ok(out);
+        }
+        broadcast_zip_map(self, rhs, |x, y| x.clone() * y.clone())
+    }
+
+    #[track_caller]
+    pub fn elem_mul(self, rhs: MatRef<'_, T>) -> Mat<T>
+    where
+        T: Mul<Output = T>,
+    {
+        self.try_elem_mul(rhs).or_panic()
+    }
+
+    pub fn try_elem_mul(self, rhs: MatRef<'_, T>) -> Result<Mat<T>>
+    where
+        T: Mul<Output = T>,
+    {
+        self.try_hadamard(rhs)
+    }
+
+    #[track_caller]
+    pub fn elem_div(self, rhs: MatRef<'_, T>) -> Mat<T>
+    where
+        T: Div<Output = T>,
+    {
+        self.try_elem_div(rhs).or_panic()
+    }
+
+    pub fn try_elem_div(self, rhs: MatRef<'_, T>) -> Result<Mat<T>>
+    where
+        T: Div<Output = T>,
+    {
+        if let Some(out) = simd::zip(Binary::Div, self, rhs) {
+            return Ok(out);
+        }
+        broadcast_zip_map(self, rhs, |x, y| x.clone() / y.clone())
+    }
+
+    #[track_caller]
+    pub fn elem_rem(self, rhs: MatRef<'_, T>) -> Mat<T>
+    where
+        T: Rem<Output = T>,
+    {
+        self.try_elem_rem(rhs).or_panic()
+    }
+
+    pub fn try_elem_rem(self, rhs: MatRef<'_, T>) -> Result<Mat<T>>
+    where
+        T: Rem<Output = T>,
+    {
+        broadcast_zip_map(self, rhs, |x, y| x.clone() % y.clone())
+    }
+
+    #[track_caller]
+    pub fn elem_pow(self, rhs: MatRef<'_, T>) -> Mat<T>
+    where
+        T: Pow<T, Output = T>,
+    {
+        self.try_elem_pow(rhs).or_panic()
+    }
+
+    pub fn try_elem_pow(self, rhs: MatRef<'_, T>) -> Result<Mat<T>>
+    where
+        T: Pow<T, Output = T>,
+    {
+        broadcast_zip_map(self, rhs, |x, y| x.clone().pow(y.clone()))
+    }
+}
+
+impl<T: Clone> MatMut<'_, T> {
+    pub fn hadamard(&self, rhs: MatRef<'_, T>) -> Mat<T>
+    where
+        T: Mul<Output = T>,
+    {
+        self.rb().hadamard(rhs)
+    }
+
+    pub fn try_hadamard(&self, rhs: MatRef<'_, T>) -> Result<Mat<T>>
+    where
+        T: Mul<Output = T>,
+    {
+        self.rb().try_hadamard(rhs)
+    }
+
+    pub fn elem_mul(&self, rhs: MatRef<'_, T>) -> Mat<T>
+    where
+        T: Mul<Output = T>,
+    {
+        self.rb().elem_mul(rhs)
+    }
+
+    pub fn try_elem_mul(&self, rhs: MatRef<'_, T>) -> Result<Mat<T>>
+    where
+        T: Mul<Output = T>,
+    {
+        self.rb().try_elem_mul(rhs)
+    }
+
+    pub fn elem_div(&self, rhs: MatRef<'_, T>) -> Mat<T>
+    where
+        T: Div<Output = T>,
+    {
+        self.rb().elem_div(rhs)
+    }
+
+    pub fn try_elem_div(&self, rhs: MatRef<'_, T>) -> Result<Mat<T>>
+    where
+        T: Div<Output = T>,
+    {
+        self.rb().try_elem_div(rhs)
+    }
+
+    pub fn elem_rem(&self, rhs: MatRef<'_, T>) -> Mat<T>
+    where
+        T: Rem<Output = T>,
+    {
+        self.rb().elem_rem(rhs)
+    }
+
+    pub fn try_elem_rem(&self, rhs: MatRef<'_, T>) -> Result<Mat<T>>
+    where
+        T: Rem<Output = T>,
+    {
+        self.rb().try_elem_rem(rhs)
+    }
+
+    pub fn elem_pow(&self, rhs: MatRef<'_, T>) -> Mat<T>
+    where
+        T: Pow<T, Output = T>,
+    {
+        self.rb().elem_pow(rhs)
+    }
+
+    pub fn try_elem_pow(&self, rhs: MatRef<'_, T>) -> Result<Mat<T>>
+    where
+        T: Pow<T, Output = T>,
+    {
+        self.rb().try_elem_pow(rhs)
+    }
+}
+
+impl<T: Clone> Mat<T> {
+    pub fn hadamard(&self, rhs: MatRef<'_, T>) -> Mat<T>
+    where
+        T: Mul<Output = T>,
+    {
+        self.as_ref().hadamard(rhs)
+    }
+
+    pub fn try_hadamard(&self, rhs: MatRef<'_, T>) -> Result<Mat<T>>
+    where
+        T: Mul<Output = T>,
+    {
+        self.as_ref().try_hadamard(rhs)
+    }
+
+    pub fn elem_mul(&self, rhs: MatRef<'_, T>) -> Mat<T>
+    where
+        T: Mul<Output = T>,
+    {
+        self.as_ref().elem_mul(rhs)
+    }
+
+    pub fn try_elem_mul(&self, rhs: MatRef<'_, T>) -> Result<Mat<T>>
+    where
+        T: Mul<Output = T>,
+    {
+        self.as_ref().try_elem_mul(rhs)
+    }
+
+    pub fn elem_div(&self, rhs: MatRef<'_, T>) -> Mat<T>
+    where
+        T: Div<Output = T>,
+    {
+        self.as_ref().elem_div(rhs)
+    }
+
+    pub fn try_elem_div(&self, rhs: MatRef<'_, T>) -> Result<Mat<T>>
+    where
+        T: Div<Output = T>,
+    {
+        self.as_ref().try_elem_div(rhs)
+    }
+
+    pub fn elem_rem(&self, rhs: MatRef<'_, T>) -> Mat<T>
+    where
+        T: Rem<Output = T>,
+    {
+        self.as_ref().elem_rem(rhs)
+    }
+
+    pub fn try_elem_rem(&self, rhs: MatRef<'_, T>) -> Result<Mat<T>>
+    where
+        T: Rem<Output = T>,
+    {
+        self.as_ref().try_elem_rem(rhs)
+    }
+
+    pub fn elem_pow(&self, rhs: MatRef<'_, T>) -> Mat<T>
+    where
+        T: Pow<T, Output = T>,
+    {
+        self.as_ref().elem_pow(rhs)
+    }
+
+    pub fn try_elem_pow(&self, rhs: MatRef<'_, T>) -> Result<Mat<T>>
+    where
+        T: Pow<T, Output = T>,
+    {
+        self.as_ref().try_elem_pow(rhs)
+    }
+}
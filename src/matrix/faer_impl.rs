@@ -0,0 +1,70 @@
+use super::{Mat, MatMut, MatRef};
+
+impl<'a, T> From<MatRef<'a, T>> for faer::MatRef<'a, T> {
+    fn from(matrix: MatRef<'a, T>) -> Self {
+        unsafe {
+            faer::MatRef::from_raw_parts(
+                matrix.ptr,
+                matrix.nrows,
+                matrix.ncols,
+                matrix.row_stride,
+                matrix.col_stride,
+            )
+        }
+    }
+}
+
+impl<'a, T> From<MatMut<'a, T>> for faer::MatMut<'a, T> {
+    fn from(matrix: MatMut<'a, T>) -> Self {
+        unsafe {
+            faer::MatMut::from_raw_parts_mut(
+                matrix.ptr,
+                matrix.nrows,
+                matrix.ncols,
+                matrix.row_stride,
+                matrix.col_stride,
+            )
+        }
+    }
+}
+
+impl<'a, T> From<faer::MatRef<'a, T>> for MatRef<'a, T> {
+    fn from(matrix: faer::MatRef<'a, T>) -> Self {
+        unsafe {
+            MatRef::from_raw_parts(
+                matrix.as_ptr(),
+                matrix.nrows(),
+                matrix.ncols(),
+                matrix.row_stride(),
+                matrix.col_stride(),
+            )
+        }
+    }
+}
+
+impl<'a, T> From<faer::MatMut<'a, T>> for MatMut<'a, T> {
+    fn from(matrix: faer::MatMut<'a, T>) -> Self {
+        unsafe {
+            MatMut::from_raw_parts(
+                matrix.as_ptr_mut(),
+                matrix.nrows(),
+                matrix.ncols(),
+                matrix.row_stride(),
+                matrix.col_stride(),
+            )
+        }
+    }
+}
+
+// faer owns its own aligned buffer, so owned matrices are copied.
+impl<T: Clone> From<Mat<T>> for faer::Mat<T> {
+    fn from(matrix: Mat<T>) -> Self {
+        faer::Mat::from_fn(matrix.nrows, matrix.ncols, |i, j| matrix[(i, j)].clone())
+    }
+}
+
+impl<T: Clone> From<faer::Mat<T>> for Mat<T> {
+    fn from(matrix: faer::Mat<T>) -> Self {
+        MatRef::from(matrix.as_ref()).to_owned()
+    }
+}
@@ -0,0 +1,250 @@
+use super::broadcast::{
+    broadcast_index, broadcast_shape, broadcast_zip_map, check_broadcasts_into,
+};
+use super::{Mat, MatMut, MatRef};
+use crate::error::{OrPanic, Result};
+
+macro_rules! impl_compare {
+    ($(($name:ident, $try_name:ident, $scalar_name:ident, $op:tt, [$($bound:tt)+])),* $(,)?) => {
+        impl<T> MatRef<'_, T> {
+            $(
+                #[track_caller]
+                pub fn $name(self, rhs: MatRef<'_, T>) -> Mat<bool>
+                where
+                    T: $($bound)+,
+                {
+                    self.$try_name(rhs).or_panic()
+                }
+
+                pub fn $try_name(self, rhs: MatRef<'_, T>) -> Result<Mat<bool>>
+                where
+                    T: $($bound)+,
+                {
+                    broadcast_zip_map(self, rhs, |x, y| x $op y)
+                }
+
+                pub fn $scalar_name(self, value: T) -> Mat<bool>
+                where
+                    T: $($bound)+,
+                {
+                    self.map(|x| *x $op value)
+                }
+            )*
+        }
+
+        impl<T> MatMut<'_, T> {
+            $(
+                pub fn $name(&self, rhs: MatRef<'_, T>) -> Mat<bool>
+                where
+                    T: $($bound)+,
+                {
+                    self.rb().$name(rhs)
+                }
+
+                pub fn $try_name(&self, rhs: MatRef<'_, T>) -> Result<Mat<bool>>
+                where
+                    T: $($bound)+,
+                {
+                    self.rb().$try_name(rhs)
+                }
+
+                pub fn $scalar_name(&self, value: T) -> Mat<bool>
+                where
+                    T: $($bound)+,
+                {
+                    self.rb().$scalar_name(value)
+                }
+            )*
+        }
+
+        impl<T> Mat<T> {
+            $(
+                pub fn $name(&self, rhs: MatRef<'_, T>) -> Mat<bool>
+                where
+                    T: $($bound)+,
+                {
+                    self.as_ref().$name(rhs)
+                }
+
+                pub fn $try_name(&self, rhs: MatRef<'_, T>) -> Result<Mat<bool>>
+                where
+                    T: $($bound)+,
+                {
+                    self.as_ref().$try_name(rhs)
+                }
+
+                pub fn $scalar_name(&self, value: T) -> Mat<bool>
+                where
+                    T: $($bound)+,
+                {
+                    self.as_ref().$scalar_name(value)
+                }
+            )*
+        }
+    };
+}
+
+impl_compare!(
+    (eq_elem, try_eq_elem, eq_scalar, ==, [PartialEq + Copy]),
+    (ne_elem, try_ne_elem, ne_scalar, !=, [PartialEq + Copy]),
+    (lt, try_lt, lt_scalar, <, [PartialOrd + Copy]),
+    (le, try_le, le_scalar, <=, [PartialOrd + Copy]),
+    (gt, try_gt, gt_scalar, >, [PartialOrd + Copy]),
+    (ge, try_ge, ge_scalar, >=, [PartialOrd + Copy]),
+);
+
+impl MatRef<'_, bool> {
+    pub fn count_true(self) -> usize {
+        let mut count = 0;
+        for j in 0..self.ncols() {
+            for i in 0..self.nrows() {
+                if *self.at(i, j) {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    #[track_caller]
+    pub fn where_<T: Clone>(self, a: MatRef<'_, T>, b: MatRef<'_, T>) -> Mat<T> {
+        Mat::select(self, a, b)
+    }
+
+    pub fn try_where<T: Clone>(self, a: MatRef<'_, T>, b: MatRef<'_, T>) -> Result<Mat<T>> {
+        Mat::try_select(self, a, b)
+    }
+}
+
+impl MatMut<'_, bool> {
+    pub fn count_true(&self) -> usize {
+        self.rb().count_true()
+    }
+
+    pub fn where_<T: Clone>(&self, a: MatRef<'_, T>, b: MatRef<'_, T>) -> Mat<T> {
+        self.rb().where_(a, b)
+    }
+
+    pub fn try_where<T: Clone>(&self, a: MatRef<'_, T>, b: MatRef<'_, T>) -> Result<Mat<T>> {
+        self.rb().try_where(a, b)
+    }
+}
+
+impl Mat<bool> {
+    pub fn count_true(&self) -> usize {
+        self.as_ref().count_true()
+    }
+
+    pub fn where_<T: Clone>(&self, a: MatRef<'_, T>, b: MatRef<'_, T>) -> Mat<T> {
+        self.as_ref().where_(a, b)
+    }
+
+    pub fn try_where<T: Clone>(&self, a: MatRef<'_, T>, b: MatRef<'_, T>) -> Result<Mat<T>> {
+        self.as_ref().try_where(a, b)
+    }
+}
+
+impl<T: Clone> Mat<T> {
+    #[track_caller]
+    pub fn select(mask: MatRef<'_, bool>, a: MatRef<'_, T>, b: MatRef<'_, T>) -> Mat<T> {
+        Self::try_select(mask, a, b).or_panic()
+    }
+
+    pub fn try_select(
+        mask: MatRef<'_, bool>,
+        a: MatRef<'_, T>,
+        b: MatRef<'_, T>,
+    ) -> Result<Mat<T>> {
+        let (nrows, ncols) = broadcast_shape(broadcast_shape(mask.shape(), a.shape())?, b.shape())?;
+        let mut data = Vec::with_capacity(nrows * ncols);
+        for j in 0..ncols {
+            for i in 0..nrows {
+                let source = if *mask.at(
+                    broadcast_index(mask.nrows(), i),
+                    broadcast_index(mask.ncols(), j),
+                ) {
+                    a
+                } else {
+                    b
+                };
+                data.push(
+                    source
+                        .at(
+                            broadcast_index(source.nrows(), i),
+                            broadcast_index(source.ncols(), j),
+                        )
+                        .clone(),
+                );
+            }
+        }
+        Ok(Mat::from_vec_col(nrows, ncols, data))
+    }
+}
+
+impl<T: Clone> MatMut<'_, T> {
+    #[track_caller]
+    pub fn masked_fill(&mut self, mask: MatRef<'_, bool>, value: T) {
+        self.try_masked_fill(mask, value).or_panic()
+    }
+
+    pub fn try_masked_fill(&mut self, mask: MatRef<'_, bool>, value: T) -> Result<()> {
+        check_broadcasts_into(self.shape(), mask.shape())?;
+        let (nrows, ncols) = self.shape();
+        for j in 0..ncols {
+            for i in 0..nrows {
+                if *mask.at(
+                    broadcast_index(mask.nrows(), i),
+                    broadcast_index(mask.ncols(), j),
+                ) {
+                    *self.at_mut(i, j) = value.clone();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[track_caller]
+    pub fn masked_assign(&mut self, mask: MatRef<'_, bool>, src: MatRef<'_, T>) {
+        self.try_masked_assign(mask, src).or_panic()
+    }
+
+    pub fn try_masked_assign(&mut self, mask: MatRef<'_, bool>, src: MatRef<'_, T>) -> Result<()> {
+        check_broadcasts_into(self.shape(), mask.shape())?;
+        check_broadcasts_into(self.shape(), src.shape())?;
+        let (nrows, ncols) = self.shape();
+        for j in 0..ncols {
+            for i in 0..nrows {
+                if *mask.at(
+                    broadcast_index(mask.nrows(), i),
+                    broadcast_index(mask.ncols(), j),
+                ) {
+                    *self.at_mut(i, j) = src
+                        .at(
+                            broadcast_index(src.nrows(), i),
+                            broadcast_index(src.ncols(), j),
+                        )
+                        .clone();
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: Clone> Mat<T> {
+    pub fn masked_fill(&mut self, mask: MatRef<'_, bool>, value: T) {
+        self.as_mut().masked_fill(mask, value)
+    }
+
+    pub fn try_masked_fill(&mut self, mask: MatRef<'_, bool>, value: T) -> Result<()> {
+        self.as_mut().try_masked_fill(mask, value)
+    }
+
+    pub fn masked_assign(&mut self, mask: MatRef<'_, bool>, src: MatRef<'_, T>) {
+        self.as_mut().masked_assign(mask, src)
+    }
+
+    pub fn try_masked_assign(&mut self, mask: MatRef<'_, bool>, src: MatRef<'_, T>) -> Result<()> {
+        self.as_mut().try_masked_assign(mask, src)
+    }
+}
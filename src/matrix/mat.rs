@@ -1,9 +1,16 @@
 use std::fmt;
 use std::marker::PhantomData;
+use std::mem;
 use std::ops::{Index, IndexMut, Range};
+use std::{ptr, slice};
 
 use num_traits::{One, Zero};
 
+use crate::error::{Axis, Error, OrPanic, Part, Result, check_index, check_len, check_part_len};
+use crate::parallel::{self, cast_ref, cast_vec, from_element, to_element};
+
+use super::simd;
+use super::storage::{DEFAULT_ALIGN, Storage, buffer_len, padded_stride};
 use super::{
     ColIter, ColIterMut, DiagIter, Mat, MatEnumerate, MatMut, MatRef, RowIter, RowIterMut,
     fmt_matrix, fmt_matrix_debug,
@@ -12,10 +19,74 @@ use super::{
 impl<T> Mat<T> {
     pub fn new() -> Self {
         Mat {
-            data: Vec::new(),
+            data: Storage::new(DEFAULT_ALIGN),
+            nrows: 0,
+            ncols: 0,
+            col_stride: 0,
+            padded: false,
+        }
+    }
+
+    pub fn with_capacity(nrows: usize, ncols: usize) -> Self {
+        Self::empty(nrows, ncols, DEFAULT_ALIGN, false)
+    }
+
+    // An empty matrix that keeps spare rows between its columns while it is
+    // built up one row at a time, so each push is amortized O(ncols). `pack`
+    // makes the result contiguous.
+    pub(crate) fn growable() -> Self {
+        Mat {
+            data: Storage::new(DEFAULT_ALIGN),
             nrows: 0,
             ncols: 0,
             col_stride: 0,
+            padded: true,
+        }
+    }
+
+    pub(crate) fn pack(&mut self) {
+        self.padded = false;
+        self.shrink_to_fit();
+    }
+
+    // Like `with_capacity`, but every column starts on an `align`-byte
+    // boundary, so columns may be padded and `as_slice` can fail.
+    #[track_caller]
+    pub fn with_capacity_aligned(nrows: usize, ncols: usize, align: usize) -> Self {
+        Self::try_with_capacity_aligned(nrows, ncols, align).or_panic()
+    }
+
+    pub fn try_with_capacity_aligned(nrows: usize, ncols: usize, align: usize) -> Result<Self> {
+        if !align.is_power_of_two() {
+            return Err(Error::InvalidAlignment { align });
+        }
+        Ok(Self::empty(nrows, ncols, align, true))
+    }
+
+    // A 0x0 matrix with room for `nrows` by `ncols` elements.
+    fn empty(nrows: usize, ncols: usize, align: usize, padded: bool) -> Self {
+        let col_stride = if padded {
+            padded_stride::<T>(nrows, align)
+        } else {
+            nrows
+        };
+        let capacity = buffer_len::<T>(col_stride, ncols);
+        Mat {
+            data: Storage::with_capacity(capacity, align),
+            nrows: 0,
+            ncols: 0,
+            col_stride,
+            padded,
+        }
+    }
+
+    // The column stride for `nrows` rows, padded only if the matrix asked for
+    // aligned columns.
+    fn stride_for(&self, nrows: usize) -> usize {
+        if self.padded {
+            padded_stride::<T>(nrows, self.alignment())
+        } else {
+            nrows
         }
     }
 
@@ -49,6 +120,23 @@ impl<T> Mat<T> {
         self.col_stride as isize
     }
 
+    #[inline]
+    pub fn alignment(&self) -> usize {
+        self.data.align()
+    }
+
+    // The number of rows and columns the matrix can hold without reallocating.
+    pub fn capacity(&self) -> (usize, usize) {
+        (self.col_stride, self.col_capacity())
+    }
+
+    fn col_capacity(&self) -> usize {
+        match self.col_stride {
+            0 => usize::MAX,
+            stride => self.data.capacity() / stride,
+        }
+    }
+
     #[inline]
     pub fn as_ref(&self) -> MatRef<'_, T> {
         MatRef {
@@ -73,20 +161,48 @@ impl<T> Mat<T> {
         }
     }
 
+    // Whether the elements are stored back to back, with no padding between
+    // columns.
     #[inline]
+    pub fn is_contiguous(&self) -> bool {
+        self.ncols <= 1 || self.col_stride == self.nrows
+    }
+
+    #[track_caller]
     pub fn as_slice(&self) -> &[T] {
-        &self.data
+        self.try_as_slice().or_panic()
     }
 
-    #[inline]
+    // The elements in column-major order, which requires contiguous storage.
+    pub fn try_as_slice(&self) -> Result<&[T]> {
+        self.check_contiguous()?;
+        Ok(unsafe { slice::from_raw_parts(self.data.as_ptr(), self.size()) })
+    }
+
+    #[track_caller]
     pub fn as_slice_mut(&mut self) -> &mut [T] {
-        &mut self.data
+        self.try_as_slice_mut().or_panic()
+    }
+
+    pub fn try_as_slice_mut(&mut self) -> Result<&mut [T]> {
+        self.check_contiguous()?;
+        Ok(unsafe { slice::from_raw_parts_mut(self.data.as_mut_ptr(), self.size()) })
+    }
+
+    fn check_contiguous(&self) -> Result<()> {
+        if self.is_contiguous() {
+            Ok(())
+        } else {
+            Err(Error::Unsupported {
+                operation: "slice over padded columns",
+            })
+        }
     }
 
     #[inline]
     pub fn get(&self, i: usize, j: usize) -> Option<&T> {
         if i < self.nrows && j < self.ncols {
-            Some(&self.data[i + j * self.col_stride])
+            Some(unsafe { &*self.data.as_ptr().add(i + j * self.col_stride) })
         } else {
             None
         }
@@ -95,242 +211,356 @@ impl<T> Mat<T> {
     #[inline]
     pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
         if i < self.nrows && j < self.ncols {
-            Some(&mut self.data[i + j * self.col_stride])
+            Some(unsafe { &mut *self.data.as_mut_ptr().add(i + j * self.col_stride) })
         } else {
             None
         }
     }
 
+    #[track_caller]
     pub fn from_vec_col(nrows: usize, ncols: usize, data: Vec<T>) -> Self {
-        assert_eq!(
-            data.len(),
-            nrows * ncols,
-            "Data length {} does not match {}x{}",
-            data.len(),
-            nrows,
-            ncols
-        );
-        Mat {
-            data,
+        Self::try_from_vec_col(nrows, ncols, data).or_panic()
+    }
+
+    pub fn try_from_vec_col(nrows: usize, ncols: usize, data: Vec<T>) -> Result<Self> {
+        check_len(nrows * ncols, data.len())?;
+        // The matrix takes over the vector's buffer, and with it the elements.
+        Ok(Mat {
+            data: Storage::from_vec(data),
             nrows,
             ncols,
             col_stride: nrows,
+            padded: false,
+        })
+    }
+
+    // Moves the elements out in column-major order, dropping any padding.
+    // Contiguous matrices with the element type's own alignment, such as
+    // those built by `from_vec_col`, hand over their buffer without copying.
+    pub fn into_vec_col(mut self) -> Vec<T> {
+        let (nrows, ncols) = self.shape();
+        if self.is_contiguous() {
+            let data = mem::replace(&mut self.data, Storage::new(DEFAULT_ALIGN));
+            match data.into_vec(nrows * ncols) {
+                Ok(data) => {
+                    self.nrows = 0;
+                    self.ncols = 0;
+                    return data;
+                }
+                Err(data) => self.data = data,
+            }
         }
+        let mut data: Vec<T> = Vec::with_capacity(self.size());
+        unsafe {
+            for j in 0..ncols {
+                ptr::copy_nonoverlapping(
+                    self.data.as_ptr().add(j * self.col_stride),
+                    data.as_mut_ptr().add(j * nrows),
+                    nrows,
+                );
+            }
+            data.set_len(nrows * ncols);
+        }
+        // The elements belong to `data` now, so only the buffer is freed.
+        self.nrows = 0;
+        self.ncols = 0;
+        data
     }
 
-    pub fn from_fn<F>(nrows: usize, ncols: usize, mut f: F) -> Self
+    pub fn from_fn<F>(nrows: usize, ncols: usize, f: F) -> Self
     where
         F: FnMut(usize, usize) -> T,
     {
-        let mut data = Vec::with_capacity(nrows * ncols);
-        for j in 0..ncols {
-            for i in 0..nrows {
-                data.push(f(i, j));
+        let mut m = Self::empty(nrows, ncols, DEFAULT_ALIGN, false);
+        m.nrows = nrows;
+        unsafe { m.write_cols(ncols, f) };
+        m
+    }
+
+    // Writes columns `self.ncols..ncols`, which must fit in the capacity.
+    // Columns are committed one at a time so a panic in `f` only leaks the
+    // column being written.
+    unsafe fn write_cols(&mut self, ncols: usize, mut f: impl FnMut(usize, usize) -> T) {
+        debug_assert!(ncols <= self.col_capacity());
+        let ptr = self.data.as_mut_ptr();
+        for j in self.ncols..ncols {
+            for i in 0..self.nrows {
+                unsafe { ptr.add(i + j * self.col_stride).write(f(i, j)) };
             }
+            self.ncols = j + 1;
         }
-        Mat {
-            data,
-            nrows,
-            ncols,
-            col_stride: nrows,
+    }
+
+    // Writes rows `self.nrows..nrows` of every column, which must fit in the
+    // column stride. On panic the rows written so far are leaked.
+    unsafe fn write_rows(&mut self, nrows: usize, mut f: impl FnMut(usize, usize) -> T) {
+        debug_assert!(nrows <= self.col_stride);
+        let ptr = self.data.as_mut_ptr();
+        for j in 0..self.ncols {
+            for i in self.nrows..nrows {
+                unsafe { ptr.add(i + j * self.col_stride).write(f(i, j)) };
+            }
         }
+        self.nrows = nrows;
     }
-}
 
-impl<T: Clone> Mat<T> {
-    pub fn full(nrows: usize, ncols: usize, value: T) -> Self {
-        Mat {
-            data: vec![value; nrows * ncols],
-            nrows,
-            ncols,
-            col_stride: nrows,
+    // Drops everything past the first `nrows` rows and `ncols` columns. The
+    // shape is updated first so a panicking destructor leaks instead of
+    // dropping twice.
+    fn drop_tail(&mut self, nrows: usize, ncols: usize) {
+        let (old_nrows, old_ncols) = self.shape();
+        self.nrows = nrows;
+        self.ncols = ncols;
+        if !mem::needs_drop::<T>() {
+            return;
+        }
+        let ptr = self.data.as_mut_ptr();
+        for j in 0..old_ncols {
+            let start = if j < ncols { nrows } else { 0 };
+            unsafe {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                    ptr.add(start + j * self.col_stride),
+                    old_nrows - start,
+                ));
+            }
         }
     }
 
-    pub fn from_vec_row(nrows: usize, ncols: usize, data: Vec<T>) -> Self {
-        assert_eq!(
-            data.len(),
-            nrows * ncols,
-            "Data length {} does not match {}x{}",
-            data.len(),
-            nrows,
-            ncols
-        );
-        let mut col_major = Vec::with_capacity(nrows * ncols);
-        for j in 0..ncols {
-            for i in 0..nrows {
-                col_major.push(data[i * ncols + j].clone());
+    pub fn reserve(&mut self, additional_cols: usize) {
+        let required = self
+            .ncols
+            .checked_add(additional_cols)
+            .expect("capacity overflow");
+        let capacity = self.col_capacity();
+        if required > capacity {
+            self.grow(self.col_stride, required.max(capacity * 2));
+        }
+    }
+
+    // Packed matrices only grow the buffer, since their columns have to move
+    // whenever the number of rows changes anyway.
+    pub fn reserve_rows(&mut self, additional_rows: usize) {
+        let required = self
+            .nrows
+            .checked_add(additional_rows)
+            .expect("capacity overflow");
+        let col_capacity = match self.col_stride {
+            0 => self.ncols,
+            _ => self.col_capacity(),
+        };
+        if !self.padded {
+            let capacity = buffer_len::<T>(required, col_capacity);
+            if capacity > self.data.capacity() {
+                self.data.reallocate(capacity);
             }
+        } else if required > self.col_stride {
+            let col_stride = self.stride_for(required.max(self.col_stride * 2));
+            self.grow(col_stride, col_capacity);
         }
-        Mat {
-            data: col_major,
-            nrows,
-            ncols,
-            col_stride: nrows,
+    }
+
+    // Makes the column stride fit `nrows` rows before they are written or
+    // after rows were dropped. Packed matrices move their columns to exactly
+    // that stride, reallocating only when the buffer is too small; padded ones
+    // keep spare rows and grow the stride geometrically.
+    fn fit_rows(&mut self, nrows: usize) {
+        if self.padded {
+            if nrows > self.col_stride {
+                self.reserve_rows(nrows - self.nrows);
+            }
+            return;
+        }
+        let capacity = buffer_len::<T>(nrows, self.ncols);
+        if capacity > self.data.capacity() {
+            self.data
+                .reallocate(capacity.max(self.data.capacity().saturating_mul(2)));
+        }
+        self.move_cols(nrows);
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        let col_stride = self.stride_for(self.nrows);
+        self.restride(col_stride, self.ncols);
+    }
+
+    fn grow(&mut self, col_stride: usize, col_capacity: usize) {
+        debug_assert!(col_stride >= self.col_stride);
+        self.restride(col_stride, col_capacity);
+    }
+
+    // Moves the columns to a new stride and resizes the buffer to hold
+    // `col_capacity` columns, reallocating at most once.
+    fn restride(&mut self, col_stride: usize, col_capacity: usize) {
+        debug_assert!(col_capacity >= self.ncols);
+        let capacity = buffer_len::<T>(col_stride, col_capacity);
+        if capacity > self.data.capacity() {
+            self.data.reallocate(capacity);
+        }
+        self.move_cols(col_stride);
+        if capacity < self.data.capacity() {
+            self.data.reallocate(capacity);
         }
     }
 
+    // Moves the columns to a new stride within the buffer, which must already
+    // be large enough.
+    fn move_cols(&mut self, col_stride: usize) {
+        debug_assert!(col_stride >= self.nrows);
+        debug_assert!(
+            self.ncols == 0 || buffer_len::<T>(col_stride, self.ncols) <= self.data.capacity()
+        );
+        let old_stride = self.col_stride;
+        let ptr = self.data.as_mut_ptr();
+        // Moving away from the start of the buffer has to go back to front so
+        // no column is overwritten before it moves.
+        let move_col = |j: usize| unsafe {
+            ptr::copy(ptr.add(j * old_stride), ptr.add(j * col_stride), self.nrows)
+        };
+        if col_stride > old_stride {
+            (1..self.ncols).rev().for_each(move_col);
+        } else if col_stride < old_stride {
+            (1..self.ncols).for_each(move_col);
+        }
+        self.col_stride = col_stride;
+    }
+}
+
+impl<T> Drop for Mat<T> {
+    fn drop(&mut self) {
+        self.drop_tail(0, 0);
+    }
+}
+
+impl<T: Clone> Mat<T> {
+    pub fn full(nrows: usize, ncols: usize, value: T) -> Self {
+        Mat::from_fn(nrows, ncols, |_, _| value.clone())
+    }
+
+    #[track_caller]
+    pub fn from_vec_row(nrows: usize, ncols: usize, data: Vec<T>) -> Self {
+        Self::try_from_vec_row(nrows, ncols, data).or_panic()
+    }
+
+    pub fn try_from_vec_row(nrows: usize, ncols: usize, data: Vec<T>) -> Result<Self> {
+        check_len(nrows * ncols, data.len())?;
+        Ok(Mat::from_fn(nrows, ncols, |i, j| {
+            data[i * ncols + j].clone()
+        }))
+    }
+
+    #[track_caller]
     pub fn from_rows(rows: &[&[T]]) -> Self {
+        Self::try_from_rows(rows).or_panic()
+    }
+
+    pub fn try_from_rows(rows: &[&[T]]) -> Result<Self> {
         let nrows = rows.len();
         if nrows == 0 {
-            return Self::new();
+            return Ok(Self::new());
         }
         let ncols = rows[0].len();
         for (i, row) in rows.iter().enumerate() {
-            assert_eq!(
-                row.len(),
-                ncols,
-                "Row {} has {} elements, expected {}",
-                i,
-                row.len(),
-                ncols
-            );
-        }
-        let mut data = Vec::with_capacity(nrows * ncols);
-        for j in 0..ncols {
-            for row in rows {
-                data.push(row[j].clone());
-            }
-        }
-        Mat {
-            data,
-            nrows,
-            ncols,
-            col_stride: nrows,
+            check_part_len(Some(Part::Row(i)), ncols, row.len())?;
         }
+        Ok(Mat::from_fn(nrows, ncols, |i, j| rows[i][j].clone()))
     }
 
+    #[track_caller]
     pub fn from_cols(cols: &[&[T]]) -> Self {
+        Self::try_from_cols(cols).or_panic()
+    }
+
+    pub fn try_from_cols(cols: &[&[T]]) -> Result<Self> {
         let ncols = cols.len();
         if ncols == 0 {
-            return Self::new();
+            return Ok(Self::new());
         }
         let nrows = cols[0].len();
         for (j, col) in cols.iter().enumerate() {
-            assert_eq!(
-                col.len(),
-                nrows,
-                "Column {} has {} elements, expected {}",
-                j,
-                col.len(),
-                nrows
-            );
-        }
-        let mut data = Vec::with_capacity(nrows * ncols);
-        for col in cols {
-            data.extend_from_slice(col);
-        }
-        Mat {
-            data,
-            nrows,
-            ncols,
-            col_stride: nrows,
+            check_part_len(Some(Part::Col(j)), nrows, col.len())?;
         }
+        Ok(Mat::from_fn(nrows, ncols, |i, j| cols[j][i].clone()))
     }
 
+    #[track_caller]
     pub fn from_nested_vec(rows: Vec<Vec<T>>) -> Self {
+        Self::try_from_nested_vec(rows).or_panic()
+    }
+
+    pub fn try_from_nested_vec(rows: Vec<Vec<T>>) -> Result<Self> {
         let nrows = rows.len();
         if nrows == 0 {
-            return Self::new();
+            return Ok(Self::new());
         }
         let ncols = rows[0].len();
         for (i, row) in rows.iter().enumerate() {
-            assert_eq!(
-                row.len(),
-                ncols,
-                "Row {} has {} elements, expected {}",
-                i,
-                row.len(),
-                ncols
-            );
-        }
-        let mut data = Vec::with_capacity(nrows * ncols);
-        for j in 0..ncols {
-            for row in &rows {
-                data.push(row[j].clone());
-            }
-        }
-        Mat {
-            data,
-            nrows,
-            ncols,
-            col_stride: nrows,
+            check_part_len(Some(Part::Row(i)), ncols, row.len())?;
         }
+        Ok(Mat::from_fn(nrows, ncols, |i, j| rows[i][j].clone()))
     }
 
+    #[track_caller]
     pub fn from_iter<I>(nrows: usize, ncols: usize, iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self::try_from_iter(nrows, ncols, iter).or_panic()
+    }
+
+    pub fn try_from_iter<I>(nrows: usize, ncols: usize, iter: I) -> Result<Self>
     where
         I: IntoIterator<Item = T>,
     {
         let data: Vec<T> = iter.into_iter().take(nrows * ncols).collect();
-        assert_eq!(
-            data.len(),
-            nrows * ncols,
-            "Iterator produced {} elements, expected {}",
-            data.len(),
-            nrows * ncols
-        );
-        Mat::from_vec_col(nrows, ncols, data)
+        Mat::try_from_vec_col(nrows, ncols, data)
     }
 
+    #[track_caller]
     pub fn from_blocks(block_rows: &[&[MatRef<'_, T>]]) -> Self {
+        Self::try_from_blocks(block_rows).or_panic()
+    }
+
+    pub fn try_from_blocks(block_rows: &[&[MatRef<'_, T>]]) -> Result<Self> {
         if block_rows.is_empty() {
-            return Self::new();
+            return Ok(Self::new());
         }
 
         let num_block_cols = block_rows[0].len();
         for (i, row) in block_rows.iter().enumerate() {
-            assert_eq!(
-                row.len(),
-                num_block_cols,
-                "Block row {} has {} blocks, expected {}",
-                i,
-                row.len(),
-                num_block_cols
-            );
+            check_part_len(Some(Part::BlockRow(i)), num_block_cols, row.len())?;
         }
 
         if num_block_cols == 0 {
-            return Self::new();
-        }
-
-        let row_heights: Vec<usize> = block_rows
-            .iter()
-            .enumerate()
-            .map(|(i, row)| {
-                let height = row[0].nrows();
-                for (j, block) in row.iter().enumerate().skip(1) {
-                    assert_eq!(
-                        block.nrows(),
-                        height,
-                        "Block ({}, {}) has {} rows, expected {}",
-                        i,
-                        j,
-                        block.nrows(),
-                        height
-                    );
+            return Ok(Self::new());
+        }
+
+        let mut row_heights = Vec::with_capacity(block_rows.len());
+        for row in block_rows {
+            let height = row[0].nrows();
+            for block in &row[1..] {
+                if block.nrows() != height {
+                    return Err(Error::ShapeMismatch {
+                        lhs: row[0].shape(),
+                        rhs: block.shape(),
+                    });
                 }
-                height
-            })
-            .collect();
-
-        let col_widths: Vec<usize> = (0..num_block_cols)
-            .map(|j| {
-                let width = block_rows[0][j].ncols();
-                for (i, row) in block_rows.iter().enumerate().skip(1) {
-                    assert_eq!(
-                        row[j].ncols(),
-                        width,
-                        "Block ({}, {}) has {} columns, expected {}",
-                        i,
-                        j,
-                        row[j].ncols(),
-                        width
-                    );
+            }
+            row_heights.push(height);
+        }
+
+        let mut col_widths = Vec::with_capacity(num_block_cols);
+        for j in 0..num_block_cols {
+            let width = block_rows[0][j].ncols();
+            for row in &block_rows[1..] {
+                if row[j].ncols() != width {
+                    return Err(Error::ShapeMismatch {
+                        lhs: block_rows[0][j].shape(),
+                        rhs: row[j].shape(),
+                    });
                 }
-                width
-            })
-            .collect();
+            }
+            col_widths.push(width);
+        }
 
         let total_rows: usize = row_heights.iter().sum();
         let total_cols: usize = col_widths.iter().sum();
@@ -347,7 +577,7 @@ impl<T: Clone> Mat<T> {
             }
         }
 
-        Mat::from_vec_col(total_rows, total_cols, data)
+        Ok(Mat::from_vec_col(total_rows, total_cols, data))
     }
 }
 
@@ -356,6 +586,10 @@ impl<T: Clone> Mat<T> {
         self.as_mut().copy_from(src)
     }
 
+    pub fn try_copy_from(&mut self, src: MatRef<'_, T>) -> Result<()> {
+        self.as_mut().try_copy_from(src)
+    }
+
     pub fn fill(&mut self, value: T) {
         self.as_mut().fill(value)
     }
@@ -364,14 +598,26 @@ impl<T: Clone> Mat<T> {
         self.as_ref().take_rows(indices)
     }
 
+    pub fn try_take_rows(&self, indices: &[usize]) -> Result<Mat<T>> {
+        self.as_ref().try_take_rows(indices)
+    }
+
     pub fn take_cols(&self, indices: &[usize]) -> Mat<T> {
         self.as_ref().take_cols(indices)
     }
 
+    pub fn try_take_cols(&self, indices: &[usize]) -> Result<Mat<T>> {
+        self.as_ref().try_take_cols(indices)
+    }
+
     pub fn reshape(&self, nrows: usize, ncols: usize) -> Mat<T> {
         self.as_ref().reshape(nrows, ncols)
     }
 
+    pub fn try_reshape(&self, nrows: usize, ncols: usize) -> Result<Mat<T>> {
+        self.as_ref().try_reshape(nrows, ncols)
+    }
+
     pub fn flatten(&self) -> Mat<T> {
         self.as_ref().flatten()
     }
@@ -392,26 +638,50 @@ impl<T: Clone> Mat<T> {
         self.as_ref().insert_row(i, row)
     }
 
+    pub fn try_insert_row(&self, i: usize, row: &[T]) -> Result<Mat<T>> {
+        self.as_ref().try_insert_row(i, row)
+    }
+
     pub fn insert_col(&self, j: usize, col: &[T]) -> Mat<T> {
         self.as_ref().insert_col(j, col)
     }
 
+    pub fn try_insert_col(&self, j: usize, col: &[T]) -> Result<Mat<T>> {
+        self.as_ref().try_insert_col(j, col)
+    }
+
     pub fn remove_row(&self, i: usize) -> Mat<T> {
         self.as_ref().remove_row(i)
     }
 
+    pub fn try_remove_row(&self, i: usize) -> Result<Mat<T>> {
+        self.as_ref().try_remove_row(i)
+    }
+
     pub fn remove_col(&self, j: usize) -> Mat<T> {
         self.as_ref().remove_col(j)
     }
 
+    pub fn try_remove_col(&self, j: usize) -> Result<Mat<T>> {
+        self.as_ref().try_remove_col(j)
+    }
+
     pub fn append_row(&self, row: &[T]) -> Mat<T> {
         self.as_ref().append_row(row)
     }
 
+    pub fn try_append_row(&self, row: &[T]) -> Result<Mat<T>> {
+        self.as_ref().try_append_row(row)
+    }
+
     pub fn append_col(&self, col: &[T]) -> Mat<T> {
         self.as_ref().append_col(col)
     }
 
+    pub fn try_append_col(&self, col: &[T]) -> Result<Mat<T>> {
+        self.as_ref().try_append_col(col)
+    }
+
     pub fn clamp(&self, min: T, max: T) -> Mat<T>
     where
         T: PartialOrd,
@@ -419,20 +689,23 @@ impl<T: Clone> Mat<T> {
         self.as_ref().clamp(min, max)
     }
 
+    #[track_caller]
     pub fn vstack(matrices: &[MatRef<'_, T>]) -> Mat<T> {
+        Self::try_vstack(matrices).or_panic()
+    }
+
+    pub fn try_vstack(matrices: &[MatRef<'_, T>]) -> Result<Mat<T>> {
         if matrices.is_empty() {
-            return Mat::new();
+            return Ok(Mat::new());
         }
         let ncols = matrices[0].ncols();
-        for (i, m) in matrices.iter().enumerate().skip(1) {
-            assert_eq!(
-                m.ncols(),
-                ncols,
-                "Matrix {} has {} columns, expected {}",
-                i,
-                m.ncols(),
-                ncols
-            );
+        for m in &matrices[1..] {
+            if m.ncols() != ncols {
+                return Err(Error::ShapeMismatch {
+                    lhs: matrices[0].shape(),
+                    rhs: m.shape(),
+                });
+            }
         }
         let total_rows: usize = matrices.iter().map(|m| m.nrows()).sum();
         let mut data = Vec::with_capacity(total_rows * ncols);
@@ -443,23 +716,26 @@ impl<T: Clone> Mat<T> {
                 }
             }
         }
-        Mat::from_vec_col(total_rows, ncols, data)
+        Ok(Mat::from_vec_col(total_rows, ncols, data))
     }
 
+    #[track_caller]
     pub fn hstack(matrices: &[MatRef<'_, T>]) -> Mat<T> {
+        Self::try_hstack(matrices).or_panic()
+    }
+
+    pub fn try_hstack(matrices: &[MatRef<'_, T>]) -> Result<Mat<T>> {
         if matrices.is_empty() {
-            return Mat::new();
+            return Ok(Mat::new());
         }
         let nrows = matrices[0].nrows();
-        for (i, m) in matrices.iter().enumerate().skip(1) {
-            assert_eq!(
-                m.nrows(),
-                nrows,
-                "Matrix {} has {} rows, expected {}",
-                i,
-                m.nrows(),
-                nrows
-            );
+        for m in &matrices[1..] {
+            if m.nrows() != nrows {
+                return Err(Error::ShapeMismatch {
+                    lhs: matrices[0].shape(),
+                    rhs: m.shape(),
+                });
+            }
         }
         let total_cols: usize = matrices.iter().map(|m| m.ncols()).sum();
         let mut data = Vec::with_capacity(nrows * total_cols);
@@ -470,63 +746,78 @@ impl<T: Clone> Mat<T> {
                 }
             }
         }
-        Mat::from_vec_col(nrows, total_cols, data)
+        Ok(Mat::from_vec_col(nrows, total_cols, data))
     }
 
     pub fn resize(&mut self, nrows: usize, ncols: usize, fill_value: T) {
-        let mut new_data = vec![fill_value; nrows * ncols];
-        let copy_rows = self.nrows.min(nrows);
-        let copy_cols = self.ncols.min(ncols);
-        for j in 0..copy_cols {
-            for i in 0..copy_rows {
-                new_data[i + j * nrows] = self.data[i + j * self.col_stride].clone();
-            }
+        self.drop_tail(self.nrows.min(nrows), self.ncols.min(ncols));
+        self.fit_rows(nrows);
+        if nrows > self.nrows {
+            unsafe { self.write_rows(nrows, |_, _| fill_value.clone()) };
+        }
+        if ncols > self.ncols {
+            self.reserve(ncols - self.ncols);
+            unsafe { self.write_cols(ncols, |_, _| fill_value.clone()) };
         }
-        self.data = new_data;
-        self.nrows = nrows;
-        self.ncols = ncols;
-        self.col_stride = nrows;
     }
 
-    pub fn truncate(&mut self, nrows: usize, ncols: usize) {
-        assert!(
-            nrows <= self.nrows && ncols <= self.ncols,
-            "Cannot truncate {}x{} matrix to {}x{}",
-            self.nrows,
-            self.ncols,
-            nrows,
-            ncols
-        );
-        if nrows == self.nrows && ncols == self.ncols {
-            return;
+    #[track_caller]
+    pub fn push_col(&mut self, col: &[T]) {
+        self.try_push_col(col).or_panic()
+    }
+
+    // Appends a column in place. An empty 0x0 matrix takes its height from
+    // the column.
+    pub fn try_push_col(&mut self, col: &[T]) -> Result<()> {
+        if self.shape() == (0, 0) {
+            self.fit_rows(col.len());
+            self.nrows = col.len();
         }
-        let mut new_data = Vec::with_capacity(nrows * ncols);
-        for j in 0..ncols {
-            for i in 0..nrows {
-                new_data.push(self.data[i + j * self.col_stride].clone());
-            }
+        check_len(self.nrows, col.len())?;
+        self.reserve(1);
+        unsafe { self.write_cols(self.ncols + 1, |i, _| col[i].clone()) };
+        Ok(())
+    }
+
+    #[track_caller]
+    pub fn push_row(&mut self, row: &[T]) {
+        self.try_push_row(row).or_panic()
+    }
+
+    pub fn try_push_row(&mut self, row: &[T]) -> Result<()> {
+        if self.shape() == (0, 0) {
+            self.reserve(row.len());
+            self.ncols = row.len();
         }
-        self.data = new_data;
-        self.nrows = nrows;
-        self.ncols = ncols;
-        self.col_stride = nrows;
+        check_len(self.ncols, row.len())?;
+        self.fit_rows(self.nrows + 1);
+        unsafe { self.write_rows(self.nrows + 1, |_, j| row[j].clone()) };
+        Ok(())
     }
 }
 
 impl<T> Mat<T> {
-    pub fn reserve(&mut self, additional_cols: usize) {
-        self.data.reserve(additional_cols * self.col_stride);
+    #[track_caller]
+    pub fn truncate(&mut self, nrows: usize, ncols: usize) {
+        self.try_truncate(nrows, ncols).or_panic()
+    }
+
+    pub fn try_truncate(&mut self, nrows: usize, ncols: usize) -> Result<()> {
+        if nrows > self.nrows || ncols > self.ncols {
+            return Err(Error::ShapeMismatch {
+                lhs: self.shape(),
+                rhs: (nrows, ncols),
+            });
+        }
+        self.drop_tail(nrows, ncols);
+        self.fit_rows(nrows);
+        Ok(())
     }
 }
 
 impl<T: Clone + Zero> Mat<T> {
     pub fn zeros(nrows: usize, ncols: usize) -> Self {
-        Mat {
-            data: vec![T::zero(); nrows * ncols],
-            nrows,
-            ncols,
-            col_stride: nrows,
-        }
+        Mat::from_fn(nrows, ncols, |_, _| T::zero())
     }
 
     pub fn tril(&self, k: isize) -> Mat<T> {
@@ -540,12 +831,7 @@ impl<T: Clone + Zero> Mat<T> {
 
 impl<T: Clone + One> Mat<T> {
     pub fn ones(nrows: usize, ncols: usize) -> Self {
-        Mat {
-            data: vec![T::one(); nrows * ncols],
-            nrows,
-            ncols,
-            col_stride: nrows,
-        }
+        Mat::from_fn(nrows, ncols, |_, _| T::one())
     }
 }
 
@@ -553,7 +839,7 @@ impl<T: Clone + Zero + One> Mat<T> {
     pub fn identity(n: usize) -> Self {
         let mut m = Self::zeros(n, n);
         for i in 0..n {
-            m.data[i + i * n] = T::one();
+            m[(i, i)] = T::one();
         }
         m
     }
@@ -565,7 +851,7 @@ impl<T: Clone + Zero + One> Mat<T> {
             if j_signed >= 0 {
                 let j = j_signed as usize;
                 if j < ncols {
-                    m.data[i + j * nrows] = T::one();
+                    m[(i, j)] = T::one();
                 }
             }
         }
@@ -576,7 +862,7 @@ impl<T: Clone + Zero + One> Mat<T> {
         let n = values.len();
         let mut m = Self::zeros(n, n);
         for (k, v) in values.iter().enumerate() {
-            m.data[k + k * n] = v.clone();
+            m[(k, k)] = v.clone();
         }
         m
     }
@@ -637,11 +923,21 @@ impl<T> Mat<T> {
         self.as_ref().row(i)
     }
 
+    #[inline]
+    pub fn try_row(&self, i: usize) -> Result<MatRef<'_, T>> {
+        self.as_ref().try_row(i)
+    }
+
     #[inline]
     pub fn col(&self, j: usize) -> MatRef<'_, T> {
         self.as_ref().col(j)
     }
 
+    #[inline]
+    pub fn try_col(&self, j: usize) -> Result<MatRef<'_, T>> {
+        self.as_ref().try_col(j)
+    }
+
     #[inline]
     pub fn diagonal(&self) -> MatRef<'_, T> {
         self.as_ref().diagonal()
@@ -658,26 +954,57 @@ impl<T> Mat<T> {
         self.as_ref().view(row_start, col_start, nrows, ncols)
     }
 
+    #[inline]
+    pub fn try_view(
+        &self,
+        row_start: usize,
+        col_start: usize,
+        nrows: usize,
+        ncols: usize,
+    ) -> Result<MatRef<'_, T>> {
+        self.as_ref().try_view(row_start, col_start, nrows, ncols)
+    }
+
     #[inline]
     pub fn rows_range(&self, range: Range<usize>) -> MatRef<'_, T> {
         self.as_ref().rows_range(range)
     }
 
+    #[inline]
+    pub fn try_rows_range(&self, range: Range<usize>) -> Result<MatRef<'_, T>> {
+        self.as_ref().try_rows_range(range)
+    }
+
     #[inline]
     pub fn cols_range(&self, range: Range<usize>) -> MatRef<'_, T> {
         self.as_ref().cols_range(range)
     }
 
+    #[inline]
+    pub fn try_cols_range(&self, range: Range<usize>) -> Result<MatRef<'_, T>> {
+        self.as_ref().try_cols_range(range)
+    }
+
     #[inline]
     pub fn split_at_row(&self, i: usize) -> (MatRef<'_, T>, MatRef<'_, T>) {
         self.as_ref().split_at_row(i)
     }
 
+    #[inline]
+    pub fn try_split_at_row(&self, i: usize) -> Result<(MatRef<'_, T>, MatRef<'_, T>)> {
+        self.as_ref().try_split_at_row(i)
+    }
+
     #[inline]
     pub fn split_at_col(&self, j: usize) -> (MatRef<'_, T>, MatRef<'_, T>) {
         self.as_ref().split_at_col(j)
     }
 
+    #[inline]
+    pub fn try_split_at_col(&self, j: usize) -> Result<(MatRef<'_, T>, MatRef<'_, T>)> {
+        self.as_ref().try_split_at_col(j)
+    }
+
     #[inline]
     pub fn transpose(&self) -> MatRef<'_, T> {
         self.as_ref().transpose()
@@ -698,11 +1025,21 @@ impl<T> Mat<T> {
         self.as_mut().row_mut(i)
     }
 
+    #[inline]
+    pub fn try_row_mut(&mut self, i: usize) -> Result<MatMut<'_, T>> {
+        self.as_mut().try_row_mut(i)
+    }
+
     #[inline]
     pub fn col_mut(&mut self, j: usize) -> MatMut<'_, T> {
         self.as_mut().col_mut(j)
     }
 
+    #[inline]
+    pub fn try_col_mut(&mut self, j: usize) -> Result<MatMut<'_, T>> {
+        self.as_mut().try_col_mut(j)
+    }
+
     #[inline]
     pub fn diagonal_mut(&mut self) -> MatMut<'_, T> {
         self.as_mut().diagonal_mut()
@@ -719,24 +1056,54 @@ impl<T> Mat<T> {
         self.as_mut().view_mut(row_start, col_start, nrows, ncols)
     }
 
+    #[inline]
+    pub fn try_view_mut(
+        &mut self,
+        row_start: usize,
+        col_start: usize,
+        nrows: usize,
+        ncols: usize,
+    ) -> Result<MatMut<'_, T>> {
+        self.as_mut()
+            .try_view_mut(row_start, col_start, nrows, ncols)
+    }
+
     #[inline]
     pub fn rows_range_mut(&mut self, range: Range<usize>) -> MatMut<'_, T> {
         self.as_mut().rows_range_mut(range)
     }
 
+    #[inline]
+    pub fn try_rows_range_mut(&mut self, range: Range<usize>) -> Result<MatMut<'_, T>> {
+        self.as_mut().try_rows_range_mut(range)
+    }
+
     #[inline]
     pub fn cols_range_mut(&mut self, range: Range<usize>) -> MatMut<'_, T> {
         self.as_mut().cols_range_mut(range)
     }
 
+    #[inline]
+    pub fn try_cols_range_mut(&mut self, range: Range<usize>) -> Result<MatMut<'_, T>> {
+        self.as_mut().try_cols_range_mut(range)
+    }
+
     pub fn split_at_row_mut(&mut self, i: usize) -> (MatMut<'_, T>, MatMut<'_, T>) {
         self.as_mut().split_at_row_mut(i)
     }
 
+    pub fn try_split_at_row_mut(&mut self, i: usize) -> Result<(MatMut<'_, T>, MatMut<'_, T>)> {
+        self.as_mut().try_split_at_row_mut(i)
+    }
+
     pub fn split_at_col_mut(&mut self, j: usize) -> (MatMut<'_, T>, MatMut<'_, T>) {
         self.as_mut().split_at_col_mut(j)
     }
 
+    pub fn try_split_at_col_mut(&mut self, j: usize) -> Result<(MatMut<'_, T>, MatMut<'_, T>)> {
+        self.as_mut().try_split_at_col_mut(j)
+    }
+
     #[inline]
     pub fn transpose_mut(&mut self) -> MatMut<'_, T> {
         self.as_mut().transpose_mut()
@@ -760,10 +1127,18 @@ impl<T> Mat<T> {
         self.as_mut().swap_rows(i1, i2)
     }
 
+    pub fn try_swap_rows(&mut self, i1: usize, i2: usize) -> Result<()> {
+        self.as_mut().try_swap_rows(i1, i2)
+    }
+
     pub fn swap_cols(&mut self, j1: usize, j2: usize) {
         self.as_mut().swap_cols(j1, j2)
     }
 
+    pub fn try_swap_cols(&mut self, j1: usize, j2: usize) -> Result<()> {
+        self.as_mut().try_swap_cols(j1, j2)
+    }
+
     pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Mat<U> {
         self.as_ref().map(f)
     }
@@ -771,6 +1146,14 @@ impl<T> Mat<T> {
     pub fn zip_map<U, F: FnMut(&T, &T) -> U>(&self, other: MatRef<'_, T>, f: F) -> Mat<U> {
         self.as_ref().zip_map(other, f)
     }
+
+    pub fn try_zip_map<U, F: FnMut(&T, &T) -> U>(
+        &self,
+        other: MatRef<'_, T>,
+        f: F,
+    ) -> Result<Mat<U>> {
+        self.as_ref().try_zip_map(other, f)
+    }
 }
 
 impl<T: num_traits::Signed + Clone> Mat<T> {
@@ -872,29 +1255,63 @@ impl<T: num_traits::Float> Mat<T> {
         self.as_ref().round()
     }
 
+    #[track_caller]
     pub fn mean(&self) -> T {
-        assert!(!self.is_empty(), "cannot compute mean of empty matrix");
+        self.try_mean().or_panic()
+    }
+
+    pub fn try_mean(&self) -> Result<T> {
+        if self.is_empty() {
+            return Err(Error::EmptyMatrix);
+        }
         let sum = self.sum();
         let n = T::from(self.size()).unwrap();
-        sum / n
+        Ok(sum / n)
     }
 
+    #[track_caller]
     pub fn variance(&self) -> T {
-        assert!(!self.is_empty(), "cannot compute variance of empty matrix");
+        self.try_variance().or_panic()
+    }
+
+    pub fn try_variance(&self) -> Result<T> {
+        if self.is_empty() {
+            return Err(Error::EmptyMatrix);
+        }
         let mean = self.mean();
-        let mut sum_sq_diff = T::zero();
-        for j in 0..self.ncols {
-            for i in 0..self.nrows {
-                let diff = self[(i, j)] - mean;
-                sum_sq_diff = sum_sq_diff + diff * diff;
+        let sum_sq_diff = parallel::as_element!(T, E => {
+            let mean = to_element::<T, E>(mean);
+            from_element::<E, T>(parallel::reduce(
+                cast_ref::<T, E>(self.as_ref()),
+                E::zero(),
+                |acc, &x| {
+                    let diff = x - mean;
+                    acc + diff * diff
+                },
+                |acc, partial| acc + partial,
+            ))
+        })
+        .unwrap_or_else(|| {
+            let mut sum_sq_diff = T::zero();
+            for j in 0..self.ncols {
+                for i in 0..self.nrows {
+                    let diff = self[(i, j)] - mean;
+                    sum_sq_diff = sum_sq_diff + diff * diff;
+                }
             }
-        }
+            sum_sq_diff
+        });
         let n = T::from(self.size()).unwrap();
-        sum_sq_diff / n
+        Ok(sum_sq_diff / n)
     }
 
+    #[track_caller]
     pub fn std_dev(&self) -> T {
-        self.variance().sqrt()
+        self.try_std_dev().or_panic()
+    }
+
+    pub fn try_std_dev(&self) -> Result<T> {
+        Ok(self.try_variance()?.sqrt())
     }
 
     pub fn mean_rows(&self) -> Mat<T> {
@@ -928,6 +1345,9 @@ impl<T: num_traits::Float> Mat<T> {
 
 impl<T: Clone + std::ops::Add<Output = T> + Zero> Mat<T> {
     pub fn sum(&self) -> T {
+        if let Some(sum) = simd::sum(self.as_ref()) {
+            return sum;
+        }
         let mut result = T::zero();
         for j in 0..self.ncols {
             for i in 0..self.nrows {
@@ -939,28 +1359,54 @@ impl<T: Clone + std::ops::Add<Output = T> + Zero> Mat<T> {
 
     pub fn sum_rows(&self) -> Mat<T> {
         let (nrows, ncols) = self.shape();
-        let mut result = Mat::zeros(nrows, 1);
-        for i in 0..nrows {
-            let mut sum = T::zero();
-            for j in 0..ncols {
-                sum = sum + self[(i, j)].clone();
+        let data = parallel::as_element!(T, E => {
+            let a = cast_ref::<T, E>(self.as_ref());
+            cast_vec::<E, T>(parallel::collect(nrows, 1, ncols, |i, _| {
+                let mut sum = E::zero();
+                for j in 0..ncols {
+                    sum += *a.at(i, j);
+                }
+                sum
+            }))
+        })
+        .unwrap_or_else(|| {
+            let mut data = Vec::with_capacity(nrows);
+            for i in 0..nrows {
+                let mut sum = T::zero();
+                for j in 0..ncols {
+                    sum = sum + self[(i, j)].clone();
+                }
+                data.push(sum);
             }
-            result[(i, 0)] = sum;
-        }
-        result
+            data
+        });
+        Mat::from_vec_col(nrows, 1, data)
     }
 
     pub fn sum_cols(&self) -> Mat<T> {
         let (nrows, ncols) = self.shape();
-        let mut result = Mat::zeros(1, ncols);
-        for j in 0..ncols {
-            let mut sum = T::zero();
-            for i in 0..nrows {
-                sum = sum + self[(i, j)].clone();
+        let data = parallel::as_element!(T, E => {
+            let a = cast_ref::<T, E>(self.as_ref());
+            cast_vec::<E, T>(parallel::collect(1, ncols, nrows, |_, j| {
+                let mut sum = E::zero();
+                for i in 0..nrows {
+                    sum += *a.at(i, j);
+                }
+                sum
+            }))
+        })
+        .unwrap_or_else(|| {
+            let mut data = Vec::with_capacity(ncols);
+            for j in 0..ncols {
+                let mut sum = T::zero();
+                for i in 0..nrows {
+                    sum = sum + self[(i, j)].clone();
+                }
+                data.push(sum);
             }
-            result[(0, j)] = sum;
-        }
-        result
+            data
+        });
+        Mat::from_vec_col(1, ncols, data)
     }
 
     pub fn cumsum_col(&self) -> Mat<T> {
@@ -992,6 +1438,15 @@ impl<T: Clone + std::ops::Add<Output = T> + Zero> Mat<T> {
 
 impl<T: Clone + std::ops::Mul<Output = T> + One> Mat<T> {
     pub fn prod(&self) -> T {
+        let prod = parallel::as_element!(T, E => from_element::<E, T>(parallel::reduce(
+            cast_ref::<T, E>(self.as_ref()),
+            to_element::<T, E>(T::one()),
+            |acc, &x| acc * x,
+            |acc, partial| acc * partial,
+        )));
+        if let Some(prod) = prod {
+            return prod;
+        }
         let mut result = T::one();
         for j in 0..self.ncols {
             for i in 0..self.nrows {
@@ -1029,55 +1484,64 @@ impl<T: Clone + std::ops::Mul<Output = T> + One> Mat<T> {
 }
 
 impl<T: PartialOrd + Clone> Mat<T> {
+    #[track_caller]
     pub fn min(&self) -> T {
-        assert!(!self.is_empty(), "cannot compute min of empty matrix");
-        let mut min_val = self[(0, 0)].clone();
-        for j in 0..self.ncols {
-            for i in 0..self.nrows {
-                if self[(i, j)] < min_val {
-                    min_val = self[(i, j)].clone();
-                }
-            }
-        }
-        min_val
+        self.try_min().or_panic()
+    }
+
+    pub fn try_min(&self) -> Result<T> {
+        self.try_min_max().map(|(min_val, _)| min_val)
     }
 
+    #[track_caller]
     pub fn max(&self) -> T {
-        assert!(!self.is_empty(), "cannot compute max of empty matrix");
-        let mut max_val = self[(0, 0)].clone();
-        for j in 0..self.ncols {
-            for i in 0..self.nrows {
-                if self[(i, j)] > max_val {
-                    max_val = self[(i, j)].clone();
-                }
-            }
-        }
-        max_val
+        self.try_max().or_panic()
     }
 
-    pub fn min_max(&self) -> (T, T) {
-        assert!(!self.is_empty(), "cannot compute min_max of empty matrix");
-        let mut min_val = self[(0, 0)].clone();
-        let mut max_val = self[(0, 0)].clone();
-        for j in 0..self.ncols {
-            for i in 0..self.nrows {
-                if self[(i, j)] < min_val {
-                    min_val = self[(i, j)].clone();
-                }
-                if self[(i, j)] > max_val {
-                    max_val = self[(i, j)].clone();
-                }
-            }
-        }
-        (min_val, max_val)
+    pub fn try_max(&self) -> Result<T> {
+        self.try_min_max().map(|(_, max_val)| max_val)
     }
 
+    #[track_caller]
+    pub fn min_max(&self) -> (T, T) {
+        self.try_min_max().or_panic()
+    }
+
+    // Incomparable values such as NaN are skipped unless they come first, in
+    // which case they are the result, as with a plain sequential scan.
+    pub fn try_min_max(&self) -> Result<(T, T)> {
+        if self.is_empty() {
+            return Err(Error::EmptyMatrix);
+        }
+        let first = &self[(0, 0)];
+        if first.partial_cmp(first).is_none() {
+            return Ok((first.clone(), first.clone()));
+        }
+        let fold = |acc: Option<(T, T)>, x: &T| match acc {
+            None if x.partial_cmp(x).is_some() => Some((x.clone(), x.clone())),
+            None => None,
+            Some((min_val, max_val)) => Some((
+                if *x < min_val { x.clone() } else { min_val },
+                if *x > max_val { x.clone() } else { max_val },
+            )),
+        };
+        let bounds = match simd::min_max(self.as_ref()) {
+            Some(bounds) => bounds,
+            None => parallel::fold_range(self.as_ref(), 0..self.size(), None, fold),
+        };
+        Ok(bounds.expect("the first element is comparable"))
+    }
+
+    #[track_caller]
     pub fn min_rows(&self) -> Mat<T> {
+        self.try_min_rows().or_panic()
+    }
+
+    pub fn try_min_rows(&self) -> Result<Mat<T>> {
         let (nrows, ncols) = self.shape();
-        assert!(
-            ncols > 0,
-            "cannot compute min_rows of matrix with 0 columns"
-        );
+        if ncols == 0 {
+            return Err(Error::EmptyMatrix);
+        }
         let mut result = Vec::with_capacity(nrows);
         for i in 0..nrows {
             let mut min_val = self[(i, 0)].clone();
@@ -1088,12 +1552,19 @@ impl<T: PartialOrd + Clone> Mat<T> {
             }
             result.push(min_val);
         }
-        Mat::from_vec_col(nrows, 1, result)
+        Ok(Mat::from_vec_col(nrows, 1, result))
     }
 
+    #[track_caller]
     pub fn min_cols(&self) -> Mat<T> {
+        self.try_min_cols().or_panic()
+    }
+
+    pub fn try_min_cols(&self) -> Result<Mat<T>> {
         let (nrows, ncols) = self.shape();
-        assert!(nrows > 0, "cannot compute min_cols of matrix with 0 rows");
+        if nrows == 0 {
+            return Err(Error::EmptyMatrix);
+        }
         let mut result = Vec::with_capacity(ncols);
         for j in 0..ncols {
             let mut min_val = self[(0, j)].clone();
@@ -1104,15 +1575,19 @@ impl<T: PartialOrd + Clone> Mat<T> {
             }
             result.push(min_val);
         }
-        Mat::from_vec_row(1, ncols, result)
+        Ok(Mat::from_vec_row(1, ncols, result))
     }
 
+    #[track_caller]
     pub fn max_rows(&self) -> Mat<T> {
+        self.try_max_rows().or_panic()
+    }
+
+    pub fn try_max_rows(&self) -> Result<Mat<T>> {
         let (nrows, ncols) = self.shape();
-        assert!(
-            ncols > 0,
-            "cannot compute max_rows of matrix with 0 columns"
-        );
+        if ncols == 0 {
+            return Err(Error::EmptyMatrix);
+        }
         let mut result = Vec::with_capacity(nrows);
         for i in 0..nrows {
             let mut max_val = self[(i, 0)].clone();
@@ -1123,12 +1598,19 @@ impl<T: PartialOrd + Clone> Mat<T> {
             }
             result.push(max_val);
         }
-        Mat::from_vec_col(nrows, 1, result)
+        Ok(Mat::from_vec_col(nrows, 1, result))
     }
 
+    #[track_caller]
     pub fn max_cols(&self) -> Mat<T> {
+        self.try_max_cols().or_panic()
+    }
+
+    pub fn try_max_cols(&self) -> Result<Mat<T>> {
         let (nrows, ncols) = self.shape();
-        assert!(nrows > 0, "cannot compute max_cols of matrix with 0 rows");
+        if nrows == 0 {
+            return Err(Error::EmptyMatrix);
+        }
         let mut result = Vec::with_capacity(ncols);
         for j in 0..ncols {
             let mut max_val = self[(0, j)].clone();
@@ -1139,11 +1621,18 @@ impl<T: PartialOrd + Clone> Mat<T> {
             }
             result.push(max_val);
         }
-        Mat::from_vec_row(1, ncols, result)
+        Ok(Mat::from_vec_row(1, ncols, result))
     }
 
+    #[track_caller]
     pub fn argmin(&self) -> (usize, usize) {
-        assert!(!self.is_empty(), "cannot compute argmin of empty matrix");
+        self.try_argmin().or_panic()
+    }
+
+    pub fn try_argmin(&self) -> Result<(usize, usize)> {
+        if self.is_empty() {
+            return Err(Error::EmptyMatrix);
+        }
         let mut min_pos = (0, 0);
         let mut min_val = &self[(0, 0)];
         for j in 0..self.ncols {
@@ -1154,11 +1643,18 @@ impl<T: PartialOrd + Clone> Mat<T> {
                 }
             }
         }
-        min_pos
+        Ok(min_pos)
     }
 
+    #[track_caller]
     pub fn argmax(&self) -> (usize, usize) {
-        assert!(!self.is_empty(), "cannot compute argmax of empty matrix");
+        self.try_argmax().or_panic()
+    }
+
+    pub fn try_argmax(&self) -> Result<(usize, usize)> {
+        if self.is_empty() {
+            return Err(Error::EmptyMatrix);
+        }
         let mut max_pos = (0, 0);
         let mut max_val = &self[(0, 0)];
         for j in 0..self.ncols {
@@ -1169,12 +1665,19 @@ impl<T: PartialOrd + Clone> Mat<T> {
                 }
             }
         }
-        max_pos
+        Ok(max_pos)
     }
 
+    #[track_caller]
     pub fn argmin_col(&self) -> Vec<usize> {
+        self.try_argmin_col().or_panic()
+    }
+
+    pub fn try_argmin_col(&self) -> Result<Vec<usize>> {
         let (nrows, ncols) = self.shape();
-        assert!(nrows > 0, "cannot compute argmin_col of matrix with 0 rows");
+        if nrows == 0 {
+            return Err(Error::EmptyMatrix);
+        }
         let mut result = Vec::with_capacity(ncols);
         for j in 0..ncols {
             let mut min_idx = 0;
@@ -1187,12 +1690,19 @@ impl<T: PartialOrd + Clone> Mat<T> {
             }
             result.push(min_idx);
         }
-        result
+        Ok(result)
     }
 
+    #[track_caller]
     pub fn argmax_col(&self) -> Vec<usize> {
+        self.try_argmax_col().or_panic()
+    }
+
+    pub fn try_argmax_col(&self) -> Result<Vec<usize>> {
         let (nrows, ncols) = self.shape();
-        assert!(nrows > 0, "cannot compute argmax_col of matrix with 0 rows");
+        if nrows == 0 {
+            return Err(Error::EmptyMatrix);
+        }
         let mut result = Vec::with_capacity(ncols);
         for j in 0..ncols {
             let mut max_idx = 0;
@@ -1205,15 +1715,19 @@ impl<T: PartialOrd + Clone> Mat<T> {
             }
             result.push(max_idx);
         }
-        result
+        Ok(result)
     }
 
+    #[track_caller]
     pub fn argmin_row(&self) -> Vec<usize> {
+        self.try_argmin_row().or_panic()
+    }
+
+    pub fn try_argmin_row(&self) -> Result<Vec<usize>> {
         let (nrows, ncols) = self.shape();
-        assert!(
-            ncols > 0,
-            "cannot compute argmin_row of matrix with 0 columns"
-        );
+        if ncols == 0 {
+            return Err(Error::EmptyMatrix);
+        }
         let mut result = Vec::with_capacity(nrows);
         for i in 0..nrows {
             let mut min_idx = 0;
@@ -1226,15 +1740,19 @@ impl<T: PartialOrd + Clone> Mat<T> {
             }
             result.push(min_idx);
         }
-        result
+        Ok(result)
     }
 
+    #[track_caller]
     pub fn argmax_row(&self) -> Vec<usize> {
+        self.try_argmax_row().or_panic()
+    }
+
+    pub fn try_argmax_row(&self) -> Result<Vec<usize>> {
         let (nrows, ncols) = self.shape();
-        assert!(
-            ncols > 0,
-            "cannot compute argmax_row of matrix with 0 columns"
-        );
+        if ncols == 0 {
+            return Err(Error::EmptyMatrix);
+        }
         let mut result = Vec::with_capacity(nrows);
         for i in 0..nrows {
             let mut max_idx = 0;
@@ -1247,16 +1765,16 @@ impl<T: PartialOrd + Clone> Mat<T> {
             }
             result.push(max_idx);
         }
-        result
+        Ok(result)
     }
 
+    #[track_caller]
     pub fn sort_rows_by_col(&self, j: usize) -> Mat<T> {
-        assert!(
-            j < self.ncols,
-            "column index {} out of bounds for matrix with {} columns",
-            j,
-            self.ncols
-        );
+        self.try_sort_rows_by_col(j).or_panic()
+    }
+
+    pub fn try_sort_rows_by_col(&self, j: usize) -> Result<Mat<T>> {
+        check_index(Axis::Col, j, self.ncols)?;
         let nrows = self.nrows;
         let mut indices: Vec<usize> = (0..nrows).collect();
         indices.sort_by(|&i1, &i2| {
@@ -1264,16 +1782,16 @@ impl<T: PartialOrd + Clone> Mat<T> {
                 .partial_cmp(&self[(i2, j)])
                 .unwrap_or(std::cmp::Ordering::Equal)
         });
-        self.take_rows(&indices)
+        Ok(self.take_rows(&indices))
     }
 
+    #[track_caller]
     pub fn sort_cols_by_row(&self, i: usize) -> Mat<T> {
-        assert!(
-            i < self.nrows,
-            "row index {} out of bounds for matrix with {} rows",
-            i,
-            self.nrows
-        );
+        self.try_sort_cols_by_row(i).or_panic()
+    }
+
+    pub fn try_sort_cols_by_row(&self, i: usize) -> Result<Mat<T>> {
+        check_index(Axis::Row, i, self.nrows)?;
         let ncols = self.ncols;
         let mut indices: Vec<usize> = (0..ncols).collect();
         indices.sort_by(|&j1, &j2| {
@@ -1281,7 +1799,7 @@ impl<T: PartialOrd + Clone> Mat<T> {
                 .partial_cmp(&self[(i, j2)])
                 .unwrap_or(std::cmp::Ordering::Equal)
         });
-        self.take_cols(&indices)
+        Ok(self.take_cols(&indices))
     }
 }
 
@@ -1348,12 +1866,10 @@ impl<T> Default for Mat<T> {
 
 impl<T: Clone> Clone for Mat<T> {
     fn clone(&self) -> Self {
-        Mat {
-            data: self.data.clone(),
-            nrows: self.nrows,
-            ncols: self.ncols,
-            col_stride: self.col_stride,
-        }
+        let mut m = Self::empty(self.nrows, self.ncols, self.alignment(), self.padded);
+        m.nrows = self.nrows;
+        unsafe { m.write_cols(self.ncols, |i, j| self[(i, j)].clone()) };
+        m
     }
 }
 
@@ -1389,7 +1905,7 @@ impl<T> Index<(usize, usize)> for Mat<T> {
             self.nrows,
             self.ncols
         );
-        &self.data[i + j * self.col_stride]
+        unsafe { &*self.data.as_ptr().add(i + j * self.col_stride) }
     }
 }
 
@@ -1403,7 +1919,7 @@ impl<T> IndexMut<(usize, usize)> for Mat<T> {
             self.nrows,
             self.ncols
         );
-        &mut self.data[i + j * self.col_stride]
+        unsafe { &mut *self.data.as_mut_ptr().add(i + j * self.col_stride) }
     }
 }
 
@@ -1424,3 +1940,9 @@ impl<T: Clone> From<MatRef<'_, T>> for Mat<T> {
         mat_ref.to_owned()
     }
 }
+
+impl<'a, T> From<&'a Mat<T>> for MatRef<'a, T> {
+    fn from(mat: &'a Mat<T>) -> Self {
+        mat.as_ref()
+    }
+}
@@ -2,9 +2,13 @@ use std::fmt;
 use std::marker::PhantomData;
 use std::ops::{Index, IndexMut, Range};
 
+use crate::error::{
+    Axis, OrPanic, Result, check_boundary, check_index, check_len, check_range, check_shape,
+};
+
 use super::{
     ColIter, ColIterMut, DiagIter, Mat, MatEnumerate, MatMut, MatRef, RowIter, RowIterMut,
-    fmt_matrix, fmt_matrix_debug,
+    fmt_matrix, fmt_matrix_debug, strided_origin,
 };
 
 impl<'a, T> MatMut<'a, T> {
@@ -34,6 +38,69 @@ impl<'a, T> MatMut<'a, T> {
         unsafe { &mut *self.ptr_at_mut(i, j) }
     }
 
+    /// # Safety
+    ///
+    /// Every element of the view must be valid for reads and writes and must
+    /// not be accessed through any other pointer for `'a`, and no two indices
+    /// may map to the same element.
+    #[inline]
+    pub unsafe fn from_raw_parts(
+        ptr: *mut T,
+        nrows: usize,
+        ncols: usize,
+        row_stride: isize,
+        col_stride: isize,
+    ) -> Self {
+        MatMut {
+            ptr,
+            nrows,
+            ncols,
+            row_stride,
+            col_stride,
+            _marker: PhantomData,
+        }
+    }
+
+    #[track_caller]
+    pub fn from_slice_col_major(data: &'a mut [T], nrows: usize, ncols: usize) -> Self {
+        Self::try_from_slice_col_major(data, nrows, ncols).or_panic()
+    }
+
+    pub fn try_from_slice_col_major(data: &'a mut [T], nrows: usize, ncols: usize) -> Result<Self> {
+        check_len(nrows.saturating_mul(ncols), data.len())?;
+        Self::try_from_slice_with_strides(data, nrows, ncols, 1, nrows as isize)
+    }
+
+    #[track_caller]
+    pub fn from_slice_with_strides(
+        data: &'a mut [T],
+        nrows: usize,
+        ncols: usize,
+        row_stride: isize,
+        col_stride: isize,
+    ) -> Self {
+        Self::try_from_slice_with_strides(data, nrows, ncols, row_stride, col_stride).or_panic()
+    }
+
+    pub fn try_from_slice_with_strides(
+        data: &'a mut [T],
+        nrows: usize,
+        ncols: usize,
+        row_stride: isize,
+        col_stride: isize,
+    ) -> Result<Self> {
+        let origin = strided_origin(data.len(), (nrows, ncols), (row_stride, col_stride), true)?;
+        Ok(unsafe {
+            Self::from_raw_parts(
+                data.as_mut_ptr().add(origin),
+                nrows,
+                ncols,
+                row_stride,
+                col_stride,
+            )
+        })
+    }
+
     #[inline]
     pub fn nrows(&self) -> usize {
         self.nrows
@@ -176,11 +243,21 @@ impl<'a, T> MatMut<'a, T> {
         self.rb().row(i)
     }
 
+    #[inline]
+    pub fn try_row(&self, i: usize) -> Result<MatRef<'_, T>> {
+        self.rb().try_row(i)
+    }
+
     #[inline]
     pub fn col(&self, j: usize) -> MatRef<'_, T> {
         self.rb().col(j)
     }
 
+    #[inline]
+    pub fn try_col(&self, j: usize) -> Result<MatRef<'_, T>> {
+        self.rb().try_col(j)
+    }
+
     #[inline]
     pub fn diagonal(&self) -> MatRef<'_, T> {
         self.rb().diagonal()
@@ -197,26 +274,57 @@ impl<'a, T> MatMut<'a, T> {
         self.rb().view(row_start, col_start, nrows, ncols)
     }
 
+    #[inline]
+    pub fn try_view(
+        &self,
+        row_start: usize,
+        col_start: usize,
+        nrows: usize,
+        ncols: usize,
+    ) -> Result<MatRef<'_, T>> {
+        self.rb().try_view(row_start, col_start, nrows, ncols)
+    }
+
     #[inline]
     pub fn rows_range(&self, range: Range<usize>) -> MatRef<'_, T> {
         self.rb().rows_range(range)
     }
 
+    #[inline]
+    pub fn try_rows_range(&self, range: Range<usize>) -> Result<MatRef<'_, T>> {
+        self.rb().try_rows_range(range)
+    }
+
     #[inline]
     pub fn cols_range(&self, range: Range<usize>) -> MatRef<'_, T> {
         self.rb().cols_range(range)
     }
 
+    #[inline]
+    pub fn try_cols_range(&self, range: Range<usize>) -> Result<MatRef<'_, T>> {
+        self.rb().try_cols_range(range)
+    }
+
     #[inline]
     pub fn split_at_row(&self, i: usize) -> (MatRef<'_, T>, MatRef<'_, T>) {
         self.rb().split_at_row(i)
     }
 
+    #[inline]
+    pub fn try_split_at_row(&self, i: usize) -> Result<(MatRef<'_, T>, MatRef<'_, T>)> {
+        self.rb().try_split_at_row(i)
+    }
+
     #[inline]
     pub fn split_at_col(&self, j: usize) -> (MatRef<'_, T>, MatRef<'_, T>) {
         self.rb().split_at_col(j)
     }
 
+    #[inline]
+    pub fn try_split_at_col(&self, j: usize) -> Result<(MatRef<'_, T>, MatRef<'_, T>)> {
+        self.rb().try_split_at_col(j)
+    }
+
     #[inline]
     pub fn transpose(&self) -> MatRef<'_, T> {
         self.rb().transpose()
@@ -233,39 +341,41 @@ impl<'a, T> MatMut<'a, T> {
     }
 
     #[inline]
+    #[track_caller]
     pub fn row_mut(self, i: usize) -> MatMut<'a, T> {
-        assert!(
-            i < self.nrows,
-            "Row index {} out of bounds for {} rows",
-            i,
-            self.nrows
-        );
-        MatMut {
+        self.try_row_mut(i).or_panic()
+    }
+
+    #[inline]
+    pub fn try_row_mut(self, i: usize) -> Result<MatMut<'a, T>> {
+        check_index(Axis::Row, i, self.nrows)?;
+        Ok(MatMut {
             ptr: unsafe { self.ptr.offset(i as isize * self.row_stride) },
             nrows: 1,
             ncols: self.ncols,
             row_stride: self.row_stride,
             col_stride: self.col_stride,
             _marker: PhantomData,
-        }
+        })
     }
 
     #[inline]
+    #[track_caller]
     pub fn col_mut(self, j: usize) -> MatMut<'a, T> {
-        assert!(
-            j < self.ncols,
-            "Column index {} out of bounds for {} columns",
-            j,
-            self.ncols
-        );
-        MatMut {
+        self.try_col_mut(j).or_panic()
+    }
+
+    #[inline]
+    pub fn try_col_mut(self, j: usize) -> Result<MatMut<'a, T>> {
+        check_index(Axis::Col, j, self.ncols)?;
+        Ok(MatMut {
             ptr: unsafe { self.ptr.offset(j as isize * self.col_stride) },
             nrows: self.nrows,
             ncols: 1,
             row_stride: self.row_stride,
             col_stride: self.col_stride,
             _marker: PhantomData,
-        }
+        })
     }
 
     #[inline]
@@ -282,6 +392,7 @@ impl<'a, T> MatMut<'a, T> {
     }
 
     #[inline]
+    #[track_caller]
     pub fn view_mut(
         self,
         row_start: usize,
@@ -289,21 +400,21 @@ impl<'a, T> MatMut<'a, T> {
         nrows: usize,
         ncols: usize,
     ) -> MatMut<'a, T> {
-        assert!(
-            row_start + nrows <= self.nrows,
-            "Row range {}..{} out of bounds for {} rows",
-            row_start,
-            row_start + nrows,
-            self.nrows
-        );
-        assert!(
-            col_start + ncols <= self.ncols,
-            "Column range {}..{} out of bounds for {} columns",
-            col_start,
-            col_start + ncols,
-            self.ncols
-        );
-        MatMut {
+        self.try_view_mut(row_start, col_start, nrows, ncols)
+            .or_panic()
+    }
+
+    #[inline]
+    pub fn try_view_mut(
+        self,
+        row_start: usize,
+        col_start: usize,
+        nrows: usize,
+        ncols: usize,
+    ) -> Result<MatMut<'a, T>> {
+        check_range(Axis::Row, row_start, row_start + nrows, self.nrows)?;
+        check_range(Axis::Col, col_start, col_start + ncols, self.ncols)?;
+        Ok(MatMut {
             ptr: unsafe {
                 self.ptr.offset(
                     row_start as isize * self.row_stride + col_start as isize * self.col_stride,
@@ -314,40 +425,42 @@ impl<'a, T> MatMut<'a, T> {
             row_stride: self.row_stride,
             col_stride: self.col_stride,
             _marker: PhantomData,
-        }
+        })
     }
 
     #[inline]
+    #[track_caller]
     pub fn rows_range_mut(self, range: Range<usize>) -> MatMut<'a, T> {
-        assert!(
-            range.start <= range.end,
-            "Invalid range: start {} > end {}",
-            range.start,
-            range.end
-        );
+        self.try_rows_range_mut(range).or_panic()
+    }
+
+    #[inline]
+    pub fn try_rows_range_mut(self, range: Range<usize>) -> Result<MatMut<'a, T>> {
+        check_range(Axis::Row, range.start, range.end, self.nrows)?;
         let ncols = self.ncols;
-        self.view_mut(range.start, 0, range.end - range.start, ncols)
+        self.try_view_mut(range.start, 0, range.end - range.start, ncols)
     }
 
     #[inline]
+    #[track_caller]
     pub fn cols_range_mut(self, range: Range<usize>) -> MatMut<'a, T> {
-        assert!(
-            range.start <= range.end,
-            "Invalid range: start {} > end {}",
-            range.start,
-            range.end
-        );
+        self.try_cols_range_mut(range).or_panic()
+    }
+
+    #[inline]
+    pub fn try_cols_range_mut(self, range: Range<usize>) -> Result<MatMut<'a, T>> {
+        check_range(Axis::Col, range.start, range.end, self.ncols)?;
         let nrows = self.nrows;
-        self.view_mut(0, range.start, nrows, range.end - range.start)
+        self.try_view_mut(0, range.start, nrows, range.end - range.start)
     }
 
+    #[track_caller]
     pub fn split_at_row_mut(self, i: usize) -> (MatMut<'a, T>, MatMut<'a, T>) {
-        assert!(
-            i <= self.nrows,
-            "Split index {} out of bounds for {} rows",
-            i,
-            self.nrows
-        );
+        self.try_split_at_row_mut(i).or_panic()
+    }
+
+    pub fn try_split_at_row_mut(self, i: usize) -> Result<(MatMut<'a, T>, MatMut<'a, T>)> {
+        check_boundary(Axis::Row, i, self.nrows)?;
         let top = MatMut {
             ptr: self.ptr,
             nrows: i,
@@ -364,16 +477,16 @@ impl<'a, T> MatMut<'a, T> {
             col_stride: self.col_stride,
             _marker: PhantomData,
         };
-        (top, bottom)
+        Ok((top, bottom))
     }
 
+    #[track_caller]
     pub fn split_at_col_mut(self, j: usize) -> (MatMut<'a, T>, MatMut<'a, T>) {
-        assert!(
-            j <= self.ncols,
-            "Split index {} out of bounds for {} columns",
-            j,
-            self.ncols
-        );
+        self.try_split_at_col_mut(j).or_panic()
+    }
+
+    pub fn try_split_at_col_mut(self, j: usize) -> Result<(MatMut<'a, T>, MatMut<'a, T>)> {
+        check_boundary(Axis::Col, j, self.ncols)?;
         let left = MatMut {
             ptr: self.ptr,
             nrows: self.nrows,
@@ -390,7 +503,7 @@ impl<'a, T> MatMut<'a, T> {
             col_stride: self.col_stride,
             _marker: PhantomData,
         };
-        (left, right)
+        Ok((left, right))
     }
 
     #[inline]
@@ -435,24 +548,25 @@ impl<'a, T> MatMut<'a, T> {
         }
     }
 
+    #[track_caller]
     pub fn copy_from(&mut self, src: MatRef<'_, T>)
     where
         T: Clone,
     {
-        assert_eq!(
-            (self.nrows, self.ncols),
-            (src.nrows(), src.ncols()),
-            "Shape mismatch: destination is {}x{} but source is {}x{}",
-            self.nrows,
-            self.ncols,
-            src.nrows(),
-            src.ncols()
-        );
+        self.try_copy_from(src).or_panic()
+    }
+
+    pub fn try_copy_from(&mut self, src: MatRef<'_, T>) -> Result<()>
+    where
+        T: Clone,
+    {
+        check_shape(self.shape(), src.shape())?;
         for j in 0..self.ncols {
             for i in 0..self.nrows {
                 *self.at_mut(i, j) = src.at(i, j).clone();
             }
         }
+        Ok(())
     }
 
     pub fn fill(&mut self, value: T)
@@ -474,40 +588,42 @@ impl<'a, T> MatMut<'a, T> {
         }
     }
 
+    #[track_caller]
     pub fn swap_rows(&mut self, i1: usize, i2: usize) {
-        assert!(
-            i1 < self.nrows && i2 < self.nrows,
-            "Row indices ({}, {}) out of bounds for {} rows",
-            i1,
-            i2,
-            self.nrows
-        );
+        self.try_swap_rows(i1, i2).or_panic()
+    }
+
+    pub fn try_swap_rows(&mut self, i1: usize, i2: usize) -> Result<()> {
+        check_index(Axis::Row, i1, self.nrows)?;
+        check_index(Axis::Row, i2, self.nrows)?;
         if i1 == i2 {
-            return;
+            return Ok(());
         }
         for j in 0..self.ncols {
             unsafe {
                 std::ptr::swap(self.ptr_at_mut(i1, j), self.ptr_at_mut(i2, j));
             }
         }
+        Ok(())
     }
 
+    #[track_caller]
     pub fn swap_cols(&mut self, j1: usize, j2: usize) {
-        assert!(
-            j1 < self.ncols && j2 < self.ncols,
-            "Column indices ({}, {}) out of bounds for {} columns",
-            j1,
-            j2,
-            self.ncols
-        );
+        self.try_swap_cols(j1, j2).or_panic()
+    }
+
+    pub fn try_swap_cols(&mut self, j1: usize, j2: usize) -> Result<()> {
+        check_index(Axis::Col, j1, self.ncols)?;
+        check_index(Axis::Col, j2, self.ncols)?;
         if j1 == j2 {
-            return;
+            return Ok(());
         }
         for i in 0..self.nrows {
             unsafe {
                 std::ptr::swap(self.ptr_at_mut(i, j1), self.ptr_at_mut(i, j2));
             }
         }
+        Ok(())
     }
 }
 
@@ -557,14 +673,26 @@ impl<T: Clone> MatMut<'_, T> {
         self.rb().take_rows(indices)
     }
 
+    pub fn try_take_rows(&self, indices: &[usize]) -> Result<Mat<T>> {
+        self.rb().try_take_rows(indices)
+    }
+
     pub fn take_cols(&self, indices: &[usize]) -> Mat<T> {
         self.rb().take_cols(indices)
     }
 
+    pub fn try_take_cols(&self, indices: &[usize]) -> Result<Mat<T>> {
+        self.rb().try_take_cols(indices)
+    }
+
     pub fn reshape(&self, nrows: usize, ncols: usize) -> Mat<T> {
         self.rb().reshape(nrows, ncols)
     }
 
+    pub fn try_reshape(&self, nrows: usize, ncols: usize) -> Result<Mat<T>> {
+        self.rb().try_reshape(nrows, ncols)
+    }
+
     pub fn flatten(&self) -> Mat<T> {
         self.rb().flatten()
     }
@@ -585,26 +713,50 @@ impl<T: Clone> MatMut<'_, T> {
         self.rb().insert_row(i, row)
     }
 
+    pub fn try_insert_row(&self, i: usize, row: &[T]) -> Result<Mat<T>> {
+        self.rb().try_insert_row(i, row)
+    }
+
     pub fn insert_col(&self, j: usize, col: &[T]) -> Mat<T> {
         self.rb().insert_col(j, col)
     }
 
+    pub fn try_insert_col(&self, j: usize, col: &[T]) -> Result<Mat<T>> {
+        self.rb().try_insert_col(j, col)
+    }
+
     pub fn remove_row(&self, i: usize) -> Mat<T> {
         self.rb().remove_row(i)
     }
 
+    pub fn try_remove_row(&self, i: usize) -> Result<Mat<T>> {
+        self.rb().try_remove_row(i)
+    }
+
     pub fn remove_col(&self, j: usize) -> Mat<T> {
         self.rb().remove_col(j)
     }
 
+    pub fn try_remove_col(&self, j: usize) -> Result<Mat<T>> {
+        self.rb().try_remove_col(j)
+    }
+
     pub fn append_row(&self, row: &[T]) -> Mat<T> {
         self.rb().append_row(row)
     }
 
+    pub fn try_append_row(&self, row: &[T]) -> Result<Mat<T>> {
+        self.rb().try_append_row(row)
+    }
+
     pub fn append_col(&self, col: &[T]) -> Mat<T> {
         self.rb().append_col(col)
     }
 
+    pub fn try_append_col(&self, col: &[T]) -> Result<Mat<T>> {
+        self.rb().try_append_col(col)
+    }
+
     pub fn clamp(&self, min: T, max: T) -> Mat<T>
     where
         T: PartialOrd,
@@ -721,6 +873,14 @@ impl<'a, T> MatMut<'a, T> {
     pub fn zip_map<U, F: FnMut(&T, &T) -> U>(&self, other: MatRef<'_, T>, f: F) -> Mat<U> {
         self.rb().zip_map(other, f)
     }
+
+    pub fn try_zip_map<U, F: FnMut(&T, &T) -> U>(
+        &self,
+        other: MatRef<'_, T>,
+        f: F,
+    ) -> Result<Mat<U>> {
+        self.rb().try_zip_map(other, f)
+    }
 }
 
 impl<T> Index<(usize, usize)> for MatMut<'_, T> {
@@ -791,6 +951,23 @@ impl<'a, T> Iterator for ColIterMut<'a, T> {
     }
 }
 
+impl<T> DoubleEndedIterator for ColIterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.col >= self.ncols {
+            return None;
+        }
+        self.ncols -= 1;
+        Some(MatMut {
+            ptr: unsafe { self.ptr.offset(self.ncols as isize * self.col_stride) },
+            nrows: self.nrows,
+            ncols: 1,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+            _marker: PhantomData,
+        })
+    }
+}
+
 impl<T> ExactSizeIterator for ColIterMut<'_, T> {}
 
 impl<'a, T> Iterator for RowIterMut<'a, T> {
@@ -820,3 +997,9 @@ impl<'a, T> Iterator for RowIterMut<'a, T> {
 }
 
 impl<T> ExactSizeIterator for RowIterMut<'_, T> {}
+
+impl<'a, T> From<&'a MatMut<'_, T>> for MatRef<'a, T> {
+    fn from(mat_mut: &'a MatMut<'_, T>) -> Self {
+        mat_mut.rb()
+    }
+}
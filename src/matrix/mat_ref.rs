@@ -4,7 +4,15 @@ use std::ops::{Index, Range};
 
 use num_traits::{One, Zero};
 
-use super::{ColIter, DiagIter, Mat, MatEnumerate, MatRef, RowIter, fmt_matrix, fmt_matrix_debug};
+use crate::error::{
+    Axis, Error, OrPanic, Result, check_boundary, check_index, check_len, check_range, check_shape,
+};
+
+use super::simd::{self, Unary};
+use super::{
+    ColIter, DiagIter, Mat, MatEnumerate, MatRef, RowIter, fmt_matrix, fmt_matrix_debug,
+    strided_origin,
+};
 
 impl<'a, T> MatRef<'a, T> {
     #[inline(always)]
@@ -20,6 +28,70 @@ impl<'a, T> MatRef<'a, T> {
         unsafe { &*self.ptr_at(i, j) }
     }
 
+    /// # Safety
+    ///
+    /// Every element of the view must be valid for reads and must not be
+    /// mutated for `'a`.
+    #[inline]
+    pub unsafe fn from_raw_parts(
+        ptr: *const T,
+        nrows: usize,
+        ncols: usize,
+        row_stride: isize,
+        col_stride: isize,
+    ) -> Self {
+        MatRef {
+            ptr,
+            nrows,
+            ncols,
+            row_stride,
+            col_stride,
+            _marker: PhantomData,
+        }
+    }
+
+    #[track_caller]
+    pub fn from_slice_col_major(data: &'a [T], nrows: usize, ncols: usize) -> Self {
+        Self::try_from_slice_col_major(data, nrows, ncols).or_panic()
+    }
+
+    pub fn try_from_slice_col_major(data: &'a [T], nrows: usize, ncols: usize) -> Result<Self> {
+        check_len(nrows.saturating_mul(ncols), data.len())?;
+        Self::try_from_slice_with_strides(data, nrows, ncols, 1, nrows as isize)
+    }
+
+    #[track_caller]
+    pub fn from_slice_with_strides(
+        data: &'a [T],
+        nrows: usize,
+        ncols: usize,
+        row_stride: isize,
+        col_stride: isize,
+    ) -> Self {
+        Self::try_from_slice_with_strides(data, nrows, ncols, row_stride, col_stride).or_panic()
+    }
+
+    // Negative strides are measured from the element at the highest address,
+    // so the view always starts no earlier than `data`.
+    pub fn try_from_slice_with_strides(
+        data: &'a [T],
+        nrows: usize,
+        ncols: usize,
+        row_stride: isize,
+        col_stride: isize,
+    ) -> Result<Self> {
+        let origin = strided_origin(data.len(), (nrows, ncols), (row_stride, col_stride), false)?;
+        Ok(unsafe {
+            Self::from_raw_parts(
+                data.as_ptr().add(origin),
+                nrows,
+                ncols,
+                row_stride,
+                col_stride,
+            )
+        })
+    }
+
     #[inline]
     pub fn nrows(self) -> usize {
         self.nrows
@@ -129,39 +201,41 @@ impl<'a, T> MatRef<'a, T> {
     }
 
     #[inline]
+    #[track_caller]
     pub fn row(self, i: usize) -> MatRef<'a, T> {
-        assert!(
-            i < self.nrows,
-            "Row index {} out of bounds for {} rows",
-            i,
-            self.nrows
-        );
-        MatRef {
+        self.try_row(i).or_panic()
+    }
+
+    #[inline]
+    pub fn try_row(self, i: usize) -> Result<MatRef<'a, T>> {
+        check_index(Axis::Row, i, self.nrows)?;
+        Ok(MatRef {
             ptr: self.ptr_at(i, 0),
             nrows: 1,
             ncols: self.ncols,
             row_stride: self.row_stride,
             col_stride: self.col_stride,
             _marker: PhantomData,
-        }
+        })
     }
 
     #[inline]
+    #[track_caller]
     pub fn col(self, j: usize) -> MatRef<'a, T> {
-        assert!(
-            j < self.ncols,
-            "Column index {} out of bounds for {} columns",
-            j,
-            self.ncols
-        );
-        MatRef {
+        self.try_col(j).or_panic()
+    }
+
+    #[inline]
+    pub fn try_col(self, j: usize) -> Result<MatRef<'a, T>> {
+        check_index(Axis::Col, j, self.ncols)?;
+        Ok(MatRef {
             ptr: self.ptr_at(0, j),
             nrows: self.nrows,
             ncols: 1,
             row_stride: self.row_stride,
             col_stride: self.col_stride,
             _marker: PhantomData,
-        }
+        })
     }
 
     #[inline]
@@ -178,6 +252,7 @@ impl<'a, T> MatRef<'a, T> {
     }
 
     #[inline]
+    #[track_caller]
     pub fn view(
         self,
         row_start: usize,
@@ -185,78 +260,81 @@ impl<'a, T> MatRef<'a, T> {
         nrows: usize,
         ncols: usize,
     ) -> MatRef<'a, T> {
-        assert!(
-            row_start + nrows <= self.nrows,
-            "Row range {}..{} out of bounds for {} rows",
-            row_start,
-            row_start + nrows,
-            self.nrows
-        );
-        assert!(
-            col_start + ncols <= self.ncols,
-            "Column range {}..{} out of bounds for {} columns",
-            col_start,
-            col_start + ncols,
-            self.ncols
-        );
-        MatRef {
+        self.try_view(row_start, col_start, nrows, ncols).or_panic()
+    }
+
+    #[inline]
+    pub fn try_view(
+        self,
+        row_start: usize,
+        col_start: usize,
+        nrows: usize,
+        ncols: usize,
+    ) -> Result<MatRef<'a, T>> {
+        check_range(Axis::Row, row_start, row_start + nrows, self.nrows)?;
+        check_range(Axis::Col, col_start, col_start + ncols, self.ncols)?;
+        Ok(MatRef {
             ptr: self.ptr_at(row_start, col_start),
             nrows,
             ncols,
             row_stride: self.row_stride,
             col_stride: self.col_stride,
             _marker: PhantomData,
-        }
+        })
     }
 
     #[inline]
+    #[track_caller]
     pub fn rows_range(self, range: Range<usize>) -> MatRef<'a, T> {
-        assert!(
-            range.start <= range.end,
-            "Invalid range: start {} > end {}",
-            range.start,
-            range.end
-        );
-        self.view(range.start, 0, range.end - range.start, self.ncols)
+        self.try_rows_range(range).or_panic()
     }
 
     #[inline]
+    pub fn try_rows_range(self, range: Range<usize>) -> Result<MatRef<'a, T>> {
+        check_range(Axis::Row, range.start, range.end, self.nrows)?;
+        self.try_view(range.start, 0, range.end - range.start, self.ncols)
+    }
+
+    #[inline]
+    #[track_caller]
     pub fn cols_range(self, range: Range<usize>) -> MatRef<'a, T> {
-        assert!(
-            range.start <= range.end,
-            "Invalid range: start {} > end {}",
-            range.start,
-            range.end
-        );
-        self.view(0, range.start, self.nrows, range.end - range.start)
+        self.try_cols_range(range).or_panic()
     }
 
     #[inline]
+    pub fn try_cols_range(self, range: Range<usize>) -> Result<MatRef<'a, T>> {
+        check_range(Axis::Col, range.start, range.end, self.ncols)?;
+        self.try_view(0, range.start, self.nrows, range.end - range.start)
+    }
+
+    #[inline]
+    #[track_caller]
     pub fn split_at_row(self, i: usize) -> (MatRef<'a, T>, MatRef<'a, T>) {
-        assert!(
-            i <= self.nrows,
-            "Split index {} out of bounds for {} rows",
-            i,
-            self.nrows
-        );
-        (
+        self.try_split_at_row(i).or_panic()
+    }
+
+    #[inline]
+    pub fn try_split_at_row(self, i: usize) -> Result<(MatRef<'a, T>, MatRef<'a, T>)> {
+        check_boundary(Axis::Row, i, self.nrows)?;
+        Ok((
             self.view(0, 0, i, self.ncols),
             self.view(i, 0, self.nrows - i, self.ncols),
-        )
+        ))
     }
 
     #[inline]
+    #[track_caller]
     pub fn split_at_col(self, j: usize) -> (MatRef<'a, T>, MatRef<'a, T>) {
-        assert!(
-            j <= self.ncols,
-            "Split index {} out of bounds for {} columns",
-            j,
-            self.ncols
-        );
-        (
+        self.try_split_at_col(j).or_panic()
+    }
+
+    #[inline]
+    pub fn try_split_at_col(self, j: usize) -> Result<(MatRef<'a, T>, MatRef<'a, T>)> {
+        check_boundary(Axis::Col, j, self.ncols)?;
+        Ok((
             self.view(0, 0, self.nrows, j),
             self.view(0, j, self.nrows, self.ncols - j),
-        )
+        ))
     }
 
     #[inline]
@@ -312,14 +390,17 @@ impl<'a, T> MatRef<'a, T> {
         Mat::from_vec_col(nrows, ncols, data)
     }
 
-    pub fn zip_map<U, F: FnMut(&T, &T) -> U>(self, other: MatRef<'_, T>, mut f: F) -> Mat<U> {
-        assert_eq!(
-            self.shape(),
-            other.shape(),
-            "shape mismatch: {:?} vs {:?}",
-            self.shape(),
-            other.shape()
-        );
+    #[track_caller]
+    pub fn zip_map<U, F: FnMut(&T, &T) -> U>(self, other: MatRef<'_, T>, f: F) -> Mat<U> {
+        self.try_zip_map(other, f).or_panic()
+    }
+
+    pub fn try_zip_map<U, F: FnMut(&T, &T) -> U>(
+        self,
+        other: MatRef<'_, T>,
+        mut f: F,
+    ) -> Result<Mat<U>> {
+        check_shape(self.shape(), other.shape())?;
         let (nrows, ncols) = self.shape();
         let mut data = Vec::with_capacity(self.size());
         for j in 0..ncols {
@@ -327,7 +408,7 @@ impl<'a, T> MatRef<'a, T> {
                 data.push(f(self.at(i, j), other.at(i, j)));
             }
         }
-        Mat::from_vec_col(nrows, ncols, data)
+        Ok(Mat::from_vec_col(nrows, ncols, data))
     }
 }
 
@@ -444,14 +525,14 @@ impl<'a, T: Clone + Zero> MatRef<'a, T> {
 }
 
 impl<'a, T: Clone> MatRef<'a, T> {
+    #[track_caller]
     pub fn take_rows(self, indices: &[usize]) -> Mat<T> {
+        self.try_take_rows(indices).or_panic()
+    }
+
+    pub fn try_take_rows(self, indices: &[usize]) -> Result<Mat<T>> {
         for &i in indices {
-            assert!(
-                i < self.nrows,
-                "Row index {} out of bounds for {} rows",
-                i,
-                self.nrows
-            );
+            check_index(Axis::Row, i, self.nrows)?;
         }
         let nrows = indices.len();
         let mut data = Vec::with_capacity(nrows * self.ncols);
@@ -460,17 +541,17 @@ impl<'a, T: Clone> MatRef<'a, T> {
                 data.push(self.at(i, j).clone());
             }
         }
-        Mat::from_vec_col(nrows, self.ncols, data)
+        Ok(Mat::from_vec_col(nrows, self.ncols, data))
     }
 
+    #[track_caller]
     pub fn take_cols(self, indices: &[usize]) -> Mat<T> {
+        self.try_take_cols(indices).or_panic()
+    }
+
+    pub fn try_take_cols(self, indices: &[usize]) -> Result<Mat<T>> {
         for &j in indices {
-            assert!(
-                j < self.ncols,
-                "Column index {} out of bounds for {} columns",
-                j,
-                self.ncols
-            );
+            check_index(Axis::Col, j, self.ncols)?;
         }
         let ncols = indices.len();
         let mut data = Vec::with_capacity(self.nrows * ncols);
@@ -479,28 +560,28 @@ impl<'a, T: Clone> MatRef<'a, T> {
                 data.push(self.at(i, j).clone());
             }
         }
-        Mat::from_vec_col(self.nrows, ncols, data)
+        Ok(Mat::from_vec_col(self.nrows, ncols, data))
     }
 
+    #[track_caller]
     pub fn reshape(self, nrows: usize, ncols: usize) -> Mat<T> {
-        assert_eq!(
-            self.nrows * self.ncols,
-            nrows * ncols,
-            "Cannot reshape {}x{} ({} elements) to {}x{} ({} elements)",
-            self.nrows,
-            self.ncols,
-            self.nrows * self.ncols,
-            nrows,
-            ncols,
-            nrows * ncols
-        );
+        self.try_reshape(nrows, ncols).or_panic()
+    }
+
+    pub fn try_reshape(self, nrows: usize, ncols: usize) -> Result<Mat<T>> {
+        if self.nrows * self.ncols != nrows * ncols {
+            return Err(Error::ShapeMismatch {
+                lhs: self.shape(),
+                rhs: (nrows, ncols),
+            });
+        }
         let mut data = Vec::with_capacity(nrows * ncols);
         for j in 0..self.ncols {
             for i in 0..self.nrows {
                 data.push(self.at(i, j).clone());
             }
         }
-        Mat::from_vec_col(nrows, ncols, data)
+        Ok(Mat::from_vec_col(nrows, ncols, data))
     }
 
     pub fn flatten(self) -> Mat<T> {
@@ -521,20 +602,14 @@ impl<'a, T: Clone> MatRef<'a, T> {
         self.flatten_row()
     }
 
+    #[track_caller]
     pub fn insert_row(self, i: usize, row: &[T]) -> Mat<T> {
-        assert!(
-            i <= self.nrows,
-            "Row insert index {} out of bounds for {} rows",
-            i,
-            self.nrows
-        );
-        assert_eq!(
-            row.len(),
-            self.ncols,
-            "Row length {} does not match {} columns",
-            row.len(),
-            self.ncols
-        );
+        self.try_insert_row(i, row).or_panic()
+    }
+
+    pub fn try_insert_row(self, i: usize, row: &[T]) -> Result<Mat<T>> {
+        check_boundary(Axis::Row, i, self.nrows)?;
+        check_len(self.ncols, row.len())?;
         let new_nrows = self.nrows + 1;
         let mut data = Vec::with_capacity(new_nrows * self.ncols);
         for (j, val) in row.iter().enumerate() {
@@ -546,23 +621,17 @@ impl<'a, T: Clone> MatRef<'a, T> {
                 data.push(self.at(r, j).clone());
             }
         }
-        Mat::from_vec_col(new_nrows, self.ncols, data)
+        Ok(Mat::from_vec_col(new_nrows, self.ncols, data))
     }
 
+    #[track_caller]
     pub fn insert_col(self, j: usize, col: &[T]) -> Mat<T> {
-        assert!(
-            j <= self.ncols,
-            "Column insert index {} out of bounds for {} columns",
-            j,
-            self.ncols
-        );
-        assert_eq!(
-            col.len(),
-            self.nrows,
-            "Column length {} does not match {} rows",
-            col.len(),
-            self.nrows
-        );
+        self.try_insert_col(j, col).or_panic()
+    }
+
+    pub fn try_insert_col(self, j: usize, col: &[T]) -> Result<Mat<T>> {
+        check_boundary(Axis::Col, j, self.ncols)?;
+        check_len(self.nrows, col.len())?;
         let new_ncols = self.ncols + 1;
         let mut data = Vec::with_capacity(self.nrows * new_ncols);
         for c in 0..j {
@@ -578,16 +647,16 @@ impl<'a, T: Clone> MatRef<'a, T> {
                 data.push(self.at(r, c).clone());
             }
         }
-        Mat::from_vec_col(self.nrows, new_ncols, data)
+        Ok(Mat::from_vec_col(self.nrows, new_ncols, data))
     }
 
+    #[track_caller]
     pub fn remove_row(self, i: usize) -> Mat<T> {
-        assert!(
-            i < self.nrows,
-            "Row index {} out of bounds for {} rows",
-            i,
-            self.nrows
-        );
+        self.try_remove_row(i).or_panic()
+    }
+
+    pub fn try_remove_row(self, i: usize) -> Result<Mat<T>> {
+        check_index(Axis::Row, i, self.nrows)?;
         let new_nrows = self.nrows - 1;
         let mut data = Vec::with_capacity(new_nrows * self.ncols);
         for j in 0..self.ncols {
@@ -597,16 +666,16 @@ impl<'a, T: Clone> MatRef<'a, T> {
                 }
             }
         }
-        Mat::from_vec_col(new_nrows, self.ncols, data)
+        Ok(Mat::from_vec_col(new_nrows, self.ncols, data))
     }
 
+    #[track_caller]
     pub fn remove_col(self, j: usize) -> Mat<T> {
-        assert!(
-            j < self.ncols,
-            "Column index {} out of bounds for {} columns",
-            j,
-            self.ncols
-        );
+        self.try_remove_col(j).or_panic()
+    }
+
+    pub fn try_remove_col(self, j: usize) -> Result<Mat<T>> {
+        check_index(Axis::Col, j, self.ncols)?;
         let new_ncols = self.ncols - 1;
         let mut data = Vec::with_capacity(self.nrows * new_ncols);
         for c in 0..self.ncols {
@@ -616,17 +685,25 @@ impl<'a, T: Clone> MatRef<'a, T> {
                 }
             }
         }
-        Mat::from_vec_col(self.nrows, new_ncols, data)
+        Ok(Mat::from_vec_col(self.nrows, new_ncols, data))
     }
 
+    #[track_caller]
     pub fn append_row(self, row: &[T]) -> Mat<T> {
-        let nrows = self.nrows;
-        self.insert_row(nrows, row)
+        self.try_append_row(row).or_panic()
     }
 
+    pub fn try_append_row(self, row: &[T]) -> Result<Mat<T>> {
+        self.try_insert_row(self.nrows, row)
+    }
+
+    #[track_caller]
     pub fn append_col(self, col: &[T]) -> Mat<T> {
-        let ncols = self.ncols;
-        self.insert_col(ncols, col)
+        self.try_append_col(col).or_panic()
+    }
+
+    pub fn try_append_col(self, col: &[T]) -> Result<Mat<T>> {
+        self.try_insert_col(self.ncols, col)
     }
 
     pub fn clamp(self, min: T, max: T) -> Mat<T>
@@ -647,6 +724,9 @@ impl<'a, T: Clone> MatRef<'a, T> {
 
 impl<'a, T: num_traits::Signed + Clone> MatRef<'a, T> {
     pub fn abs(self) -> Mat<T> {
+        if let Some(out) = simd::map_unary(Unary::Abs, self) {
+            return out;
+        }
         self.map(|x| x.abs())
     }
 
@@ -661,6 +741,9 @@ impl<'a, T: num_traits::Float> MatRef<'a, T> {
     }
 
     pub fn sqrt(self) -> Mat<T> {
+        if let Some(out) = simd::map_unary(Unary::Sqrt, self) {
+            return out;
+        }
         self.map(|x| x.sqrt())
     }
 
@@ -669,6 +752,9 @@ impl<'a, T: num_traits::Float> MatRef<'a, T> {
     }
 
     pub fn exp(self) -> Mat<T> {
+        if let Some(out) = simd::map_unary(Unary::Exp, self) {
+            return out;
+        }
         self.map(|x| x.exp())
     }
 
@@ -798,6 +884,23 @@ impl<'a, T> Iterator for ColIter<'a, T> {
     }
 }
 
+impl<T> DoubleEndedIterator for ColIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.col >= self.matrix.ncols {
+            return None;
+        }
+        self.matrix.ncols -= 1;
+        Some(MatRef {
+            ptr: self.matrix.ptr_at(0, self.matrix.ncols),
+            nrows: self.matrix.nrows,
+            ncols: 1,
+            row_stride: self.matrix.row_stride,
+            col_stride: self.matrix.col_stride,
+            _marker: PhantomData,
+        })
+    }
+}
+
 impl<T> ExactSizeIterator for ColIter<'_, T> {}
 
 impl<'a, T> Iterator for RowIter<'a, T> {
@@ -871,3 +974,9 @@ impl<'a, T> Iterator for MatEnumerate<'a, T> {
 }
 
 impl<T> ExactSizeIterator for MatEnumerate<'_, T> {}
+
+impl<'a, T> From<&MatRef<'a, T>> for MatRef<'a, T> {
+    fn from(mat_ref: &MatRef<'a, T>) -> Self {
+        *mat_ref
+    }
+}
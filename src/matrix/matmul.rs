@@ -0,0 +1,244 @@
+use std::ops::{Add, Mul};
+
+use num_traits::Zero;
+
+use super::{MatMut, MatRef};
+use crate::error::{Error, OrPanic, Result, check_shape};
+#[cfg(feature = "rayon")]
+use crate::parallel;
+
+const MR: usize = 4;
+const NR: usize = 4;
+const MC: usize = 64;
+const KC: usize = 256;
+const NC: usize = 1024;
+
+#[track_caller]
+pub fn matmul_into<T>(dst: MatMut<'_, T>, alpha: T, a: MatRef<'_, T>, b: MatRef<'_, T>, beta: T)
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    try_matmul_into(dst, alpha, a, b, beta).or_panic()
+}
+
+pub fn try_matmul_into<T>(
+    dst: MatMut<'_, T>,
+    alpha: T,
+    a: MatRef<'_, T>,
+    b: MatRef<'_, T>,
+    beta: T,
+) -> Result<()>
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    if a.ncols() != b.nrows() {
+        return Err(Error::ShapeMismatch {
+            lhs: a.shape(),
+            rhs: b.shape(),
+        });
+    }
+    check_shape(dst.shape(), (a.nrows(), b.ncols()))?;
+
+    let (m, n, k) = (a.nrows(), b.ncols(), a.ncols());
+    if m == 0 || n == 0 {
+        return Ok(());
+    }
+    if k == 0 || alpha.is_zero() {
+        scale(dst, beta);
+        return Ok(());
+    }
+
+    // The kernel is chosen the same way whether or not the work is split, so
+    // splitting never changes the arithmetic performed for any entry of `dst`.
+    // Only the float and complex element types are handed to the thread pool.
+    #[cfg(feature = "rayon")]
+    let mut dst = dst;
+    #[cfg(feature = "rayon")]
+    {
+        let tasks = parallel::task_count(m.saturating_mul(n).saturating_mul(k));
+        if tasks > 1 {
+            let split = parallel::as_element!(T, E => {
+                let kernel: Kernel<E> = if n == 1 { gemv } else { gemm };
+                split_matmul(
+                    parallel::cast_mut::<T, E>(dst.rb_mut()),
+                    parallel::to_element::<T, E>(alpha),
+                    parallel::cast_ref::<T, E>(a),
+                    parallel::cast_ref::<T, E>(b),
+                    parallel::to_element::<T, E>(beta),
+                    tasks,
+                    kernel,
+                )
+            });
+            if split.is_some() {
+                return Ok(());
+            }
+        }
+    }
+    let kernel: Kernel<T> = if n == 1 { gemv } else { gemm };
+    kernel(dst, alpha, a, b, beta);
+    Ok(())
+}
+
+type Kernel<T> = fn(MatMut<'_, T>, T, MatRef<'_, T>, MatRef<'_, T>, T);
+
+#[cfg(feature = "rayon")]
+fn split_matmul<T>(
+    dst: MatMut<'_, T>,
+    alpha: T,
+    a: MatRef<'_, T>,
+    b: MatRef<'_, T>,
+    beta: T,
+    tasks: usize,
+    kernel: Kernel<T>,
+) where
+    T: Copy + Send + Sync,
+{
+    let (m, n) = dst.shape();
+    let left = tasks / 2;
+    if tasks > 1 && n >= m && n >= 2 * NR {
+        let j = (n * left / tasks / NR).max(1) * NR;
+        let (dst_left, dst_right) = dst.split_at_col_mut(j);
+        let (b_left, b_right) = b.split_at_col(j);
+        rayon::join(
+            || split_matmul(dst_left, alpha, a, b_left, beta, left, kernel),
+            || split_matmul(dst_right, alpha, a, b_right, beta, tasks - left, kernel),
+        );
+    } else if tasks > 1 && m >= 2 * MR {
+        let i = (m * left / tasks / MR).max(1) * MR;
+        let (dst_top, dst_bottom) = dst.split_at_row_mut(i);
+        let (a_top, a_bottom) = a.split_at_row(i);
+        rayon::join(
+            || split_matmul(dst_top, alpha, a_top, b, beta, left, kernel),
+            || split_matmul(dst_bottom, alpha, a_bottom, b, beta, tasks - left, kernel),
+        );
+    } else {
+        kernel(dst, alpha, a, b, beta);
+    }
+}
+
+fn gemm<T>(mut dst: MatMut<'_, T>, alpha: T, a: MatRef<'_, T>, b: MatRef<'_, T>, beta: T)
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    let (m, n, k) = (a.nrows(), b.ncols(), a.ncols());
+    let mut packed_a = vec![T::zero(); MC.min(m).div_ceil(MR) * MR * KC.min(k)];
+    let mut packed_b = vec![T::zero(); NC.min(n).div_ceil(NR) * NR * KC.min(k)];
+
+    for jc in (0..n).step_by(NC) {
+        let nc = NC.min(n - jc);
+        for pc in (0..k).step_by(KC) {
+            let kc = KC.min(k - pc);
+            pack_b(&mut packed_b, b.view(pc, jc, kc, nc));
+            let first = pc == 0;
+            for ic in (0..m).step_by(MC) {
+                let mc = MC.min(m - ic);
+                pack_a(&mut packed_a, a.view(ic, pc, mc, kc));
+                for jr in (0..nc).step_by(NR) {
+                    let nr = NR.min(nc - jr);
+                    let b_sliver = &packed_b[jr * kc..(jr + NR) * kc];
+                    for ir in (0..mc).step_by(MR) {
+                        let mr = MR.min(mc - ir);
+                        let a_sliver = &packed_a[ir * kc..(ir + MR) * kc];
+                        let acc = micro_kernel(kc, a_sliver, b_sliver);
+                        for (jj, acc_col) in acc.iter().enumerate().take(nr) {
+                            for (ii, &value) in acc_col.iter().enumerate().take(mr) {
+                                let out = dst.at_mut(ic + ir + ii, jc + jr + jj);
+                                *out = if !first {
+                                    *out + alpha * value
+                                } else if beta.is_zero() {
+                                    alpha * value
+                                } else {
+                                    beta * *out + alpha * value
+                                };
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[inline(always)]
+fn micro_kernel<T>(kc: usize, a: &[T], b: &[T]) -> [[T; MR]; NR]
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    let mut acc = [[T::zero(); MR]; NR];
+    for (a_p, b_p) in a.chunks_exact(MR).zip(b.chunks_exact(NR)).take(kc) {
+        for (acc_col, &b_val) in acc.iter_mut().zip(b_p) {
+            for (acc_val, &a_val) in acc_col.iter_mut().zip(a_p) {
+                *acc_val = *acc_val + a_val * b_val;
+            }
+        }
+    }
+    acc
+}
+
+fn pack_a<T: Copy + Zero>(buf: &mut [T], a: MatRef<'_, T>) {
+    let (mc, kc) = a.shape();
+    for ir in (0..mc).step_by(MR) {
+        let mr = MR.min(mc - ir);
+        let sliver = &mut buf[ir * kc..(ir + MR) * kc];
+        for p in 0..kc {
+            let dst = &mut sliver[p * MR..(p + 1) * MR];
+            for (ii, d) in dst.iter_mut().enumerate() {
+                *d = if ii < mr {
+                    *a.at(ir + ii, p)
+                } else {
+                    T::zero()
+                };
+            }
+        }
+    }
+}
+
+fn pack_b<T: Copy + Zero>(buf: &mut [T], b: MatRef<'_, T>) {
+    let (kc, nc) = b.shape();
+    for jr in (0..nc).step_by(NR) {
+        let nr = NR.min(nc - jr);
+        let sliver = &mut buf[jr * kc..(jr + NR) * kc];
+        for p in 0..kc {
+            let dst = &mut sliver[p * NR..(p + 1) * NR];
+            for (jj, d) in dst.iter_mut().enumerate() {
+                *d = if jj < nr {
+                    *b.at(p, jr + jj)
+                } else {
+                    T::zero()
+                };
+            }
+        }
+    }
+}
+
+fn gemv<T>(mut dst: MatMut<'_, T>, alpha: T, a: MatRef<'_, T>, x: MatRef<'_, T>, beta: T)
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    let m = a.nrows();
+    scale(dst.rb_mut(), beta);
+    for p in 0..a.ncols() {
+        let xp = alpha * *x.at(p, 0);
+        for i in 0..m {
+            let out = dst.at_mut(i, 0);
+            *out = *out + *a.at(i, p) * xp;
+        }
+    }
+}
+
+fn scale<T>(mut dst: MatMut<'_, T>, beta: T)
+where
+    T: Copy + Zero + Mul<Output = T>,
+{
+    let (nrows, ncols) = dst.shape();
+    for j in 0..ncols {
+        for i in 0..nrows {
+            let out = dst.at_mut(i, j);
+            *out = if beta.is_zero() {
+                T::zero()
+            } else {
+                beta * *out
+            };
+        }
+    }
+}
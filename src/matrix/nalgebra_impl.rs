@@ -0,0 +1,108 @@
+use nalgebra::{
+    DMatrix, DMatrixView, DMatrixViewMut, Dim, Dyn, VecStorage, ViewStorage, ViewStorageMut,
+};
+
+use crate::error::{Error, Result};
+
+use super::{Mat, MatMut, MatRef};
+
+// nalgebra strides are unsigned, and may be fixed by the view type.
+fn view_strides<RStride: Dim, CStride: Dim>(
+    row_stride: isize,
+    col_stride: isize,
+) -> Result<(RStride, CStride)> {
+    let (Ok(row_stride), Ok(col_stride)) =
+        (usize::try_from(row_stride), usize::try_from(col_stride))
+    else {
+        return Err(Error::Unsupported {
+            operation: "negative strides in nalgebra views",
+        });
+    };
+    let fits = |fixed: Option<usize>, stride| fixed.is_none_or(|fixed| fixed == stride);
+    if !fits(RStride::try_to_usize(), row_stride) || !fits(CStride::try_to_usize(), col_stride) {
+        return Err(Error::Unsupported {
+            operation: "strides other than those of the nalgebra view type",
+        });
+    }
+    Ok((
+        RStride::from_usize(row_stride),
+        CStride::from_usize(col_stride),
+    ))
+}
+
+impl<'a, T, RStride: Dim, CStride: Dim> TryFrom<MatRef<'a, T>>
+    for DMatrixView<'a, T, RStride, CStride>
+{
+    type Error = Error;
+
+    fn try_from(matrix: MatRef<'a, T>) -> Result<Self> {
+        let strides = view_strides(matrix.row_stride, matrix.col_stride)?;
+        let shape = (Dyn(matrix.nrows), Dyn(matrix.ncols));
+        let storage = unsafe { ViewStorage::from_raw_parts(matrix.ptr, shape, strides) };
+        Ok(DMatrixView::from_data(storage))
+    }
+}
+
+impl<'a, T, RStride: Dim, CStride: Dim> TryFrom<MatMut<'a, T>>
+    for DMatrixViewMut<'a, T, RStride, CStride>
+{
+    type Error = Error;
+
+    fn try_from(matrix: MatMut<'a, T>) -> Result<Self> {
+        let strides = view_strides(matrix.row_stride, matrix.col_stride)?;
+        let shape = (Dyn(matrix.nrows), Dyn(matrix.ncols));
+        let storage = unsafe { ViewStorageMut::from_raw_parts(matrix.ptr, shape, strides) };
+        Ok(DMatrixViewMut::from_data(storage))
+    }
+}
+
+impl<'a, T, RStride: Dim, CStride: Dim> From<DMatrixView<'a, T, RStride, CStride>>
+    for MatRef<'a, T>
+{
+    fn from(view: DMatrixView<'a, T, RStride, CStride>) -> Self {
+        let (nrows, ncols) = view.shape();
+        let (row_stride, col_stride) = view.strides();
+        unsafe {
+            MatRef::from_raw_parts(
+                view.as_ptr(),
+                nrows,
+                ncols,
+                row_stride as isize,
+                col_stride as isize,
+            )
+        }
+    }
+}
+
+impl<'a, T, RStride: Dim, CStride: Dim> From<DMatrixViewMut<'a, T, RStride, CStride>>
+    for MatMut<'a, T>
+{
+    fn from(mut view: DMatrixViewMut<'a, T, RStride, CStride>) -> Self {
+        let (nrows, ncols) = view.shape();
+        let (row_stride, col_stride) = view.strides();
+        unsafe {
+            MatMut::from_raw_parts(
+                view.as_mut_ptr(),
+                nrows,
+                ncols,
+                row_stride as isize,
+                col_stride as isize,
+            )
+        }
+    }
+}
+
+impl<T> From<Mat<T>> for DMatrix<T> {
+    fn from(matrix: Mat<T>) -> Self {
+        let (nrows, ncols) = matrix.shape();
+        let storage = VecStorage::new(Dyn(nrows), Dyn(ncols), matrix.into_vec_col());
+        DMatrix::from_data(storage)
+    }
+}
+
+impl<T> From<DMatrix<T>> for Mat<T> {
+    fn from(matrix: DMatrix<T>) -> Self {
+        let (nrows, ncols) = matrix.shape();
+        Mat::from_vec_col(nrows, ncols, matrix.data.into())
+    }
+}
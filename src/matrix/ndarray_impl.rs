@@ -0,0 +1,88 @@
+use ndarray::{Array2, ArrayView2, ArrayViewMut2, ShapeBuilder};
+
+use super::{Mat, MatMut, MatRef};
+
+// ndarray only builds views from non-negative strides, so a view starts at its
+// lowest address and the axes with negative strides are flipped afterwards.
+// Empty views get zero strides, since ndarray may step along an empty axis.
+fn lowest<T>(
+    ptr: *const T,
+    (nrows, ncols): (usize, usize),
+    (row_stride, col_stride): (isize, isize),
+) -> (*const T, (usize, usize), [bool; 2]) {
+    if nrows == 0 || ncols == 0 {
+        return (ptr, (0, 0), [false, false]);
+    }
+    let back = |n: usize, stride: isize| (n as isize - 1) * stride.min(0);
+    let ptr = ptr.wrapping_offset(back(nrows, row_stride) + back(ncols, col_stride));
+    (
+        ptr,
+        (row_stride.unsigned_abs(), col_stride.unsigned_abs()),
+        [row_stride < 0, col_stride < 0],
+    )
+}
+
+fn flip<S: ndarray::RawData>(array: &mut ndarray::ArrayBase<S, ndarray::Ix2>, flipped: [bool; 2]) {
+    for (axis, flipped) in flipped.into_iter().enumerate() {
+        if flipped {
+            array.invert_axis(ndarray::Axis(axis));
+        }
+    }
+}
+
+impl<'a, T> From<MatRef<'a, T>> for ArrayView2<'a, T> {
+    fn from(matrix: MatRef<'a, T>) -> Self {
+        let (ptr, strides, flipped) = lowest(
+            matrix.ptr,
+            matrix.shape(),
+            (matrix.row_stride, matrix.col_stride),
+        );
+        let shape = matrix.shape().strides(strides);
+        let mut view = unsafe { ArrayView2::from_shape_ptr(shape, ptr) };
+        flip(&mut view, flipped);
+        view
+    }
+}
+
+impl<'a, T> From<MatMut<'a, T>> for ArrayViewMut2<'a, T> {
+    fn from(matrix: MatMut<'a, T>) -> Self {
+        let (ptr, strides, flipped) = lowest(
+            matrix.ptr,
+            matrix.shape(),
+            (matrix.row_stride, matrix.col_stride),
+        );
+        let shape = matrix.shape().strides(strides);
+        let mut view = unsafe { ArrayViewMut2::from_shape_ptr(shape, ptr.cast_mut()) };
+        flip(&mut view, flipped);
+        view
+    }
+}
+
+impl<'a, T> From<ArrayView2<'a, T>> for MatRef<'a, T> {
+    fn from(array: ArrayView2<'a, T>) -> Self {
+        let (nrows, ncols) = array.dim();
+        let strides = array.strides();
+        unsafe { MatRef::from_raw_parts(array.as_ptr(), nrows, ncols, strides[0], strides[1]) }
+    }
+}
+
+impl<'a, T> From<ArrayViewMut2<'a, T>> for MatMut<'a, T> {
+    fn from(mut array: ArrayViewMut2<'a, T>) -> Self {
+        let (nrows, ncols) = array.dim();
+        let (row_stride, col_stride) = (array.strides()[0], array.strides()[1]);
+        unsafe { MatMut::from_raw_parts(array.as_mut_ptr(), nrows, ncols, row_stride, col_stride) }
+    }
+}
+
+impl<T> From<Mat<T>> for Array2<T> {
+    fn from(matrix: Mat<T>) -> Self {
+        let shape = matrix.shape().f();
+        Array2::from_shape_vec(shape, matrix.into_vec_col()).unwrap()
+    }
+}
+
+impl<T: Clone> From<Array2<T>> for Mat<T> {
+    fn from(array: Array2<T>) -> Self {
+        MatRef::from(array.view()).to_owned()
+    }
+}
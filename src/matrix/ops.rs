@@ -1,169 +1,195 @@
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Neg, Not, Sub, SubAssign,
+};
 
 use num_complex::Complex;
+use num_traits::{One, Zero};
 
-use super::{Mat, MatMut, MatRef};
+use super::broadcast::{broadcast_index, broadcast_zip_map, check_broadcasts_into};
+use super::simd::{self, Binary, Unary};
+use super::{Mat, MatMut, MatRef, try_matmul_into};
+use crate::error::{Error, OrPanic, Result};
 
 macro_rules! impl_mat_mat_binop {
-    ($OpTrait:ident, $op_fn:ident) => {
+    ($OpTrait:ident, $op_fn:ident, $try_fn:ident, $simd:expr) => {
+        impl<T: Clone + $OpTrait<Output = T>> MatRef<'_, T> {
+            pub fn $try_fn(self, rhs: MatRef<'_, T>) -> Result<Mat<T>> {
+                if let Some(op) = $simd
+                    && let Some(out) = simd::zip(op, self, rhs)
+                {
+                    return Ok(out);
+                }
+                broadcast_zip_map(self, rhs, |x, y| $OpTrait::$op_fn(x.clone(), y.clone()))
+            }
+        }
+
+        impl_try_forward!($try_fn, [Clone + $OpTrait<Output = T>]);
+
         impl<T: Clone + $OpTrait<Output = T>> $OpTrait<MatRef<'_, T>> for MatRef<'_, T> {
             type Output = Mat<T>;
+            #[track_caller]
             fn $op_fn(self, rhs: MatRef<'_, T>) -> Mat<T> {
-                assert_eq!(
-                    self.shape(),
-                    rhs.shape(),
-                    "shape mismatch: {:?} vs {:?}",
-                    self.shape(),
-                    rhs.shape()
-                );
-                let (nrows, ncols) = self.shape();
-                let mut data = Vec::with_capacity(self.size());
-                for j in 0..ncols {
-                    for i in 0..nrows {
-                        data.push($OpTrait::$op_fn(
-                            self.at(i, j).clone(),
-                            rhs.at(i, j).clone(),
-                        ));
-                    }
-                }
-                Mat::from_vec_col(nrows, ncols, data)
+                self.$try_fn(rhs).or_panic()
+            }
+        }
+
+        impl_mat_mat_forward!($OpTrait, $op_fn, [Clone + $OpTrait<Output = T>]);
+    };
+}
+
+macro_rules! impl_try_forward {
+    ($try_fn:ident, [$($bound:tt)+]) => {
+        impl<T: $($bound)+> MatMut<'_, T> {
+            pub fn $try_fn(&self, rhs: MatRef<'_, T>) -> Result<Mat<T>> {
+                self.rb().$try_fn(rhs)
+            }
+        }
+
+        impl<T: $($bound)+> Mat<T> {
+            pub fn $try_fn(&self, rhs: MatRef<'_, T>) -> Result<Mat<T>> {
+                self.as_ref().$try_fn(rhs)
             }
         }
+    };
+}
 
-        impl<T: Clone + $OpTrait<Output = T>> $OpTrait<&Mat<T>> for &Mat<T> {
+macro_rules! impl_mat_mat_forward {
+    ($OpTrait:ident, $op_fn:ident, [$($bound:tt)+]) => {
+        impl<T: $($bound)+> $OpTrait<&Mat<T>> for &Mat<T> {
             type Output = Mat<T>;
             fn $op_fn(self, rhs: &Mat<T>) -> Mat<T> {
                 $OpTrait::$op_fn(self.as_ref(), rhs.as_ref())
             }
         }
 
-        impl<T: Clone + $OpTrait<Output = T>> $OpTrait for Mat<T> {
+        impl<T: $($bound)+> $OpTrait for Mat<T> {
             type Output = Mat<T>;
             fn $op_fn(self, rhs: Mat<T>) -> Mat<T> {
                 $OpTrait::$op_fn(self.as_ref(), rhs.as_ref())
             }
         }
 
-        impl<T: Clone + $OpTrait<Output = T>> $OpTrait<&Mat<T>> for Mat<T> {
+        impl<T: $($bound)+> $OpTrait<&Mat<T>> for Mat<T> {
             type Output = Mat<T>;
             fn $op_fn(self, rhs: &Mat<T>) -> Mat<T> {
                 $OpTrait::$op_fn(self.as_ref(), rhs.as_ref())
             }
         }
 
-        impl<T: Clone + $OpTrait<Output = T>> $OpTrait<Mat<T>> for &Mat<T> {
+        impl<T: $($bound)+> $OpTrait<Mat<T>> for &Mat<T> {
             type Output = Mat<T>;
             fn $op_fn(self, rhs: Mat<T>) -> Mat<T> {
                 $OpTrait::$op_fn(self.as_ref(), rhs.as_ref())
             }
         }
 
-        impl<T: Clone + $OpTrait<Output = T>> $OpTrait<&Mat<T>> for MatRef<'_, T> {
+        impl<T: $($bound)+> $OpTrait<&Mat<T>> for MatRef<'_, T> {
             type Output = Mat<T>;
             fn $op_fn(self, rhs: &Mat<T>) -> Mat<T> {
                 $OpTrait::$op_fn(self, rhs.as_ref())
             }
         }
 
-        impl<T: Clone + $OpTrait<Output = T>> $OpTrait<MatRef<'_, T>> for &Mat<T> {
+        impl<T: $($bound)+> $OpTrait<MatRef<'_, T>> for &Mat<T> {
             type Output = Mat<T>;
             fn $op_fn(self, rhs: MatRef<'_, T>) -> Mat<T> {
                 $OpTrait::$op_fn(self.as_ref(), rhs)
             }
         }
 
-        impl<T: Clone + $OpTrait<Output = T>> $OpTrait<MatRef<'_, T>> for Mat<T> {
+        impl<T: $($bound)+> $OpTrait<MatRef<'_, T>> for Mat<T> {
             type Output = Mat<T>;
             fn $op_fn(self, rhs: MatRef<'_, T>) -> Mat<T> {
                 $OpTrait::$op_fn(self.as_ref(), rhs)
             }
         }
 
-        impl<T: Clone + $OpTrait<Output = T>> $OpTrait<Mat<T>> for MatRef<'_, T> {
+        impl<T: $($bound)+> $OpTrait<Mat<T>> for MatRef<'_, T> {
             type Output = Mat<T>;
             fn $op_fn(self, rhs: Mat<T>) -> Mat<T> {
                 $OpTrait::$op_fn(self, rhs.as_ref())
             }
         }
 
-        impl<T: Clone + $OpTrait<Output = T>> $OpTrait<MatRef<'_, T>> for MatMut<'_, T> {
+        impl<T: $($bound)+> $OpTrait<MatRef<'_, T>> for MatMut<'_, T> {
             type Output = Mat<T>;
             fn $op_fn(self, rhs: MatRef<'_, T>) -> Mat<T> {
                 $OpTrait::$op_fn(self.rb(), rhs)
             }
         }
 
-        impl<T: Clone + $OpTrait<Output = T>> $OpTrait<MatMut<'_, T>> for MatRef<'_, T> {
+        impl<T: $($bound)+> $OpTrait<MatMut<'_, T>> for MatRef<'_, T> {
             type Output = Mat<T>;
             fn $op_fn(self, rhs: MatMut<'_, T>) -> Mat<T> {
                 $OpTrait::$op_fn(self, rhs.rb())
             }
         }
 
-        impl<T: Clone + $OpTrait<Output = T>> $OpTrait<MatMut<'_, T>> for MatMut<'_, T> {
+        impl<T: $($bound)+> $OpTrait<MatMut<'_, T>> for MatMut<'_, T> {
             type Output = Mat<T>;
             fn $op_fn(self, rhs: MatMut<'_, T>) -> Mat<T> {
                 $OpTrait::$op_fn(self.rb(), rhs.rb())
             }
         }
 
-        impl<T: Clone + $OpTrait<Output = T>> $OpTrait<MatRef<'_, T>> for &MatMut<'_, T> {
+        impl<T: $($bound)+> $OpTrait<MatRef<'_, T>> for &MatMut<'_, T> {
             type Output = Mat<T>;
             fn $op_fn(self, rhs: MatRef<'_, T>) -> Mat<T> {
                 $OpTrait::$op_fn(self.rb(), rhs)
             }
         }
 
-        impl<T: Clone + $OpTrait<Output = T>> $OpTrait<&MatMut<'_, T>> for MatRef<'_, T> {
+        impl<T: $($bound)+> $OpTrait<&MatMut<'_, T>> for MatRef<'_, T> {
             type Output = Mat<T>;
             fn $op_fn(self, rhs: &MatMut<'_, T>) -> Mat<T> {
                 $OpTrait::$op_fn(self, rhs.rb())
             }
         }
 
-        impl<T: Clone + $OpTrait<Output = T>> $OpTrait<&MatMut<'_, T>> for &MatMut<'_, T> {
+        impl<T: $($bound)+> $OpTrait<&MatMut<'_, T>> for &MatMut<'_, T> {
             type Output = Mat<T>;
             fn $op_fn(self, rhs: &MatMut<'_, T>) -> Mat<T> {
                 $OpTrait::$op_fn(self.rb(), rhs.rb())
             }
         }
 
-        impl<T: Clone + $OpTrait<Output = T>> $OpTrait<&Mat<T>> for MatMut<'_, T> {
+        impl<T: $($bound)+> $OpTrait<&Mat<T>> for MatMut<'_, T> {
             type Output = Mat<T>;
             fn $op_fn(self, rhs: &Mat<T>) -> Mat<T> {
                 $OpTrait::$op_fn(self.rb(), rhs.as_ref())
             }
         }
 
-        impl<T: Clone + $OpTrait<Output = T>> $OpTrait<MatMut<'_, T>> for &Mat<T> {
+        impl<T: $($bound)+> $OpTrait<MatMut<'_, T>> for &Mat<T> {
             type Output = Mat<T>;
             fn $op_fn(self, rhs: MatMut<'_, T>) -> Mat<T> {
                 $OpTrait::$op_fn(self.as_ref(), rhs.rb())
             }
         }
 
-        impl<T: Clone + $OpTrait<Output = T>> $OpTrait<&Mat<T>> for &MatMut<'_, T> {
+        impl<T: $($bound)+> $OpTrait<&Mat<T>> for &MatMut<'_, T> {
             type Output = Mat<T>;
             fn $op_fn(self, rhs: &Mat<T>) -> Mat<T> {
                 $OpTrait::$op_fn(self.rb(), rhs.as_ref())
             }
         }
 
-        impl<T: Clone + $OpTrait<Output = T>> $OpTrait<&MatMut<'_, T>> for &Mat<T> {
+        impl<T: $($bound)+> $OpTrait<&MatMut<'_, T>> for &Mat<T> {
             type Output = Mat<T>;
             fn $op_fn(self, rhs: &MatMut<'_, T>) -> Mat<T> {
                 $OpTrait::$op_fn(self.as_ref(), rhs.rb())
             }
         }
 
-        impl<T: Clone + $OpTrait<Output = T>> $OpTrait<Mat<T>> for MatMut<'_, T> {
+        impl<T: $($bound)+> $OpTrait<Mat<T>> for MatMut<'_, T> {
             type Output = Mat<T>;
             fn $op_fn(self, rhs: Mat<T>) -> Mat<T> {
                 $OpTrait::$op_fn(self.rb(), rhs.as_ref())
             }
         }
 
-        impl<T: Clone + $OpTrait<Output = T>> $OpTrait<MatMut<'_, T>> for Mat<T> {
+        impl<T: $($bound)+> $OpTrait<MatMut<'_, T>> for Mat<T> {
             type Output = Mat<T>;
             fn $op_fn(self, rhs: MatMut<'_, T>) -> Mat<T> {
                 $OpTrait::$op_fn(self.as_ref(), rhs.rb())
@@ -172,14 +198,59 @@ macro_rules! impl_mat_mat_binop {
     };
 }
 
-impl_mat_mat_binop!(Add, add);
-impl_mat_mat_binop!(Sub, sub);
+impl_mat_mat_binop!(Add, add, try_add, Some(Binary::Add));
+impl_mat_mat_binop!(Sub, sub, try_sub, Some(Binary::Sub));
+impl_mat_mat_binop!(BitAnd, bitand, try_bitand, None);
+impl_mat_mat_binop!(BitOr, bitor, try_bitor, None);
+impl_mat_mat_binop!(BitXor, bitxor, try_bitxor, None);
+
+impl<T> MatRef<'_, T>
+where
+    T: Copy + Zero + One + Add<Output = T> + Mul<Output = T>,
+{
+    pub fn try_mul(self, rhs: MatRef<'_, T>) -> Result<Mat<T>> {
+        if self.ncols() != rhs.nrows() {
+            return Err(Error::ShapeMismatch {
+                lhs: self.shape(),
+                rhs: rhs.shape(),
+            });
+        }
+        let mut out = Mat::zeros(self.nrows(), rhs.ncols());
+        try_matmul_into(out.as_mut(), T::one(), self, rhs, T::zero())?;
+        Ok(out)
+    }
+}
+
+impl_try_forward!(
+    try_mul,
+    [Copy + Zero + One + Add<Output = T> + Mul<Output = T>]
+);
+
+impl<T> Mul<MatRef<'_, T>> for MatRef<'_, T>
+where
+    T: Copy + Zero + One + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = Mat<T>;
+    #[track_caller]
+    fn mul(self, rhs: MatRef<'_, T>) -> Mat<T> {
+        self.try_mul(rhs).or_panic()
+    }
+}
+
+impl_mat_mat_forward!(
+    Mul,
+    mul,
+    [Copy + Zero + One + Add<Output = T> + Mul<Output = T>]
+);
 
 macro_rules! impl_scalar_rmul {
-    ($OpTrait:ident, $op_fn:ident) => {
+    ($OpTrait:ident, $op_fn:ident, $simd:expr) => {
         impl<T: Clone + $OpTrait<Output = T>> $OpTrait<T> for MatRef<'_, T> {
             type Output = Mat<T>;
             fn $op_fn(self, rhs: T) -> Mat<T> {
+                if let Some(out) = simd::zip_scalar($simd, self, &rhs) {
+                    return out;
+                }
                 let (nrows, ncols) = self.shape();
                 let mut data = Vec::with_capacity(self.size());
                 for j in 0..ncols {
@@ -221,8 +292,8 @@ macro_rules! impl_scalar_rmul {
     };
 }
 
-impl_scalar_rmul!(Mul, mul);
-impl_scalar_rmul!(Div, div);
+impl_scalar_rmul!(Mul, mul, Binary::Mul);
+impl_scalar_rmul!(Div, div, Binary::Div);
 
 macro_rules! impl_scalar_lmul {
     ($scalar:ty) => {
@@ -280,65 +351,97 @@ impl_scalar_lmul!(usize);
 impl_scalar_lmul!(Complex<f32>);
 impl_scalar_lmul!(Complex<f64>);
 
-impl<T: Clone + Neg<Output = T>> Neg for MatRef<'_, T> {
-    type Output = Mat<T>;
-    fn neg(self) -> Mat<T> {
-        let (nrows, ncols) = self.shape();
-        let mut data = Vec::with_capacity(self.size());
-        for j in 0..ncols {
-            for i in 0..nrows {
-                data.push(Neg::neg(self.at(i, j).clone()));
+macro_rules! impl_mat_unop {
+    ($OpTrait:ident, $op_fn:ident, $simd:expr) => {
+        impl<T: Clone + $OpTrait<Output = T>> $OpTrait for MatRef<'_, T> {
+            type Output = Mat<T>;
+            fn $op_fn(self) -> Mat<T> {
+                if let Some(op) = $simd
+                    && let Some(out) = simd::map_unary(op, self)
+                {
+                    return out;
+                }
+                let (nrows, ncols) = self.shape();
+                let mut data = Vec::with_capacity(self.size());
+                for j in 0..ncols {
+                    for i in 0..nrows {
+                        data.push($OpTrait::$op_fn(self.at(i, j).clone()));
+                    }
+                }
+                Mat::from_vec_col(nrows, ncols, data)
             }
         }
-        Mat::from_vec_col(nrows, ncols, data)
-    }
-}
 
-impl<T: Clone + Neg<Output = T>> Neg for &Mat<T> {
-    type Output = Mat<T>;
-    fn neg(self) -> Mat<T> {
-        Neg::neg(self.as_ref())
-    }
-}
+        impl<T: Clone + $OpTrait<Output = T>> $OpTrait for &Mat<T> {
+            type Output = Mat<T>;
+            fn $op_fn(self) -> Mat<T> {
+                $OpTrait::$op_fn(self.as_ref())
+            }
+        }
 
-impl<T: Clone + Neg<Output = T>> Neg for Mat<T> {
-    type Output = Mat<T>;
-    fn neg(self) -> Mat<T> {
-        Neg::neg(self.as_ref())
-    }
-}
+        impl<T: Clone + $OpTrait<Output = T>> $OpTrait for Mat<T> {
+            type Output = Mat<T>;
+            fn $op_fn(self) -> Mat<T> {
+                $OpTrait::$op_fn(self.as_ref())
+            }
+        }
 
-impl<T: Clone + Neg<Output = T>> Neg for MatMut<'_, T> {
-    type Output = Mat<T>;
-    fn neg(self) -> Mat<T> {
-        Neg::neg(self.rb())
-    }
-}
+        impl<T: Clone + $OpTrait<Output = T>> $OpTrait for MatMut<'_, T> {
+            type Output = Mat<T>;
+            fn $op_fn(self) -> Mat<T> {
+                $OpTrait::$op_fn(self.rb())
+            }
+        }
 
-impl<T: Clone + Neg<Output = T>> Neg for &MatMut<'_, T> {
-    type Output = Mat<T>;
-    fn neg(self) -> Mat<T> {
-        Neg::neg(self.rb())
-    }
+        impl<T: Clone + $OpTrait<Output = T>> $OpTrait for &MatMut<'_, T> {
+            type Output = Mat<T>;
+            fn $op_fn(self) -> Mat<T> {
+                $OpTrait::$op_fn(self.rb())
+            }
+        }
+    };
 }
 
+impl_mat_unop!(Neg, neg, Some(Unary::Neg));
+impl_mat_unop!(Not, not, None);
+
 macro_rules! impl_mat_assign_op {
-    ($OpTrait:ident, $op_fn:ident) => {
-        impl<T: Clone + $OpTrait> $OpTrait<MatRef<'_, T>> for MatMut<'_, T> {
-            fn $op_fn(&mut self, rhs: MatRef<'_, T>) {
-                assert_eq!(
-                    self.shape(),
-                    rhs.shape(),
-                    "shape mismatch: {:?} vs {:?}",
-                    self.shape(),
-                    rhs.shape()
-                );
+    ($OpTrait:ident, $op_fn:ident, $try_fn:ident, $simd:expr) => {
+        impl<T: Clone + $OpTrait> MatMut<'_, T> {
+            pub fn $try_fn(&mut self, rhs: MatRef<'_, T>) -> Result<()> {
+                check_broadcasts_into(self.shape(), rhs.shape())?;
+                if let Some(op) = $simd
+                    && simd::zip_assign(op, self.rb_mut(), rhs)
+                {
+                    return Ok(());
+                }
                 let (nrows, ncols) = self.shape();
                 for j in 0..ncols {
                     for i in 0..nrows {
-                        $OpTrait::$op_fn(self.at_mut(i, j), rhs.at(i, j).clone());
+                        $OpTrait::$op_fn(
+                            self.at_mut(i, j),
+                            rhs.at(
+                                broadcast_index(rhs.nrows(), i),
+                                broadcast_index(rhs.ncols(), j),
+                            )
+                            .clone(),
+                        );
                     }
                 }
+                Ok(())
+            }
+        }
+
+        impl<T: Clone + $OpTrait> Mat<T> {
+            pub fn $try_fn(&mut self, rhs: MatRef<'_, T>) -> Result<()> {
+                self.as_mut().$try_fn(rhs)
+            }
+        }
+
+        impl<T: Clone + $OpTrait> $OpTrait<MatRef<'_, T>> for MatMut<'_, T> {
+            #[track_caller]
+            fn $op_fn(&mut self, rhs: MatRef<'_, T>) {
+                self.$try_fn(rhs).or_panic()
             }
         }
 
@@ -380,13 +483,19 @@ macro_rules! impl_mat_assign_op {
     };
 }
 
-impl_mat_assign_op!(AddAssign, add_assign);
-impl_mat_assign_op!(SubAssign, sub_assign);
+impl_mat_assign_op!(AddAssign, add_assign, try_add_assign, Some(Binary::Add));
+impl_mat_assign_op!(SubAssign, sub_assign, try_sub_assign, Some(Binary::Sub));
+impl_mat_assign_op!(BitAndAssign, bitand_assign, try_bitand_assign, None);
+impl_mat_assign_op!(BitOrAssign, bitor_assign, try_bitor_assign, None);
+impl_mat_assign_op!(BitXorAssign, bitxor_assign, try_bitxor_assign, None);
 
 macro_rules! impl_scalar_assign_op {
-    ($OpTrait:ident, $op_fn:ident) => {
+    ($OpTrait:ident, $op_fn:ident, $simd:expr) => {
         impl<T: Clone + $OpTrait> $OpTrait<T> for MatMut<'_, T> {
             fn $op_fn(&mut self, rhs: T) {
+                if simd::zip_scalar_assign($simd, self.rb_mut(), &rhs) {
+                    return;
+                }
                 let (nrows, ncols) = self.shape();
                 for j in 0..ncols {
                     for i in 0..nrows {
@@ -404,5 +513,5 @@ macro_rules! impl_scalar_assign_op {
     };
 }
 
-impl_scalar_assign_op!(MulAssign, mul_assign);
-impl_scalar_assign_op!(DivAssign, div_assign);
+impl_scalar_assign_op!(MulAssign, mul_assign, Binary::Mul);
+impl_scalar_assign_op!(DivAssign, div_assign, Binary::Div);
@@ -0,0 +1,204 @@
+use rayon::iter::plumbing::{Consumer, Producer, ProducerCallback, UnindexedConsumer, bridge};
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+
+use super::{ColIter, ColIterMut, Mat, MatMut, MatRef};
+use crate::error::{OrPanic, Result, check_shape};
+use crate::parallel;
+
+pub struct ParColIter<'a, T> {
+    matrix: MatRef<'a, T>,
+}
+
+pub struct ParColIterMut<'a, T> {
+    matrix: MatMut<'a, T>,
+}
+
+struct ColProducer<'a, T>(MatRef<'a, T>);
+
+struct ColProducerMut<'a, T>(MatMut<'a, T>);
+
+impl<'a, T: Sync> Producer for ColProducer<'a, T> {
+    type Item = MatRef<'a, T>;
+    type IntoIter = ColIter<'a, T>;
+
+    fn into_iter(self) -> ColIter<'a, T> {
+        self.0.col_iter()
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.0.split_at_col(index);
+        (ColProducer(left), ColProducer(right))
+    }
+}
+
+impl<'a, T: Send> Producer for ColProducerMut<'a, T> {
+    type Item = MatMut<'a, T>;
+    type IntoIter = ColIterMut<'a, T>;
+
+    fn into_iter(self) -> ColIterMut<'a, T> {
+        self.0.col_iter_mut()
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.0.split_at_col_mut(index);
+        (ColProducerMut(left), ColProducerMut(right))
+    }
+}
+
+impl<'a, T: Sync> ParallelIterator for ParColIter<'a, T> {
+    type Item = MatRef<'a, T>;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.matrix.ncols())
+    }
+}
+
+impl<T: Sync> IndexedParallelIterator for ParColIter<'_, T> {
+    fn len(&self) -> usize {
+        self.matrix.ncols()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(ColProducer(self.matrix))
+    }
+}
+
+impl<'a, T: Send> ParallelIterator for ParColIterMut<'a, T> {
+    type Item = MatMut<'a, T>;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.matrix.ncols())
+    }
+}
+
+impl<T: Send> IndexedParallelIterator for ParColIterMut<'_, T> {
+    fn len(&self) -> usize {
+        self.matrix.ncols()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(ColProducerMut(self.matrix))
+    }
+}
+
+impl<'a, T: Sync> MatRef<'a, T> {
+    pub fn par_col_iter(self) -> ParColIter<'a, T> {
+        ParColIter { matrix: self }
+    }
+
+    pub fn par_map<U, F>(self, f: F) -> Mat<U>
+    where
+        U: Send + Sync,
+        F: Fn(&T) -> U + Send + Sync,
+    {
+        let (nrows, ncols) = self.shape();
+        let data = parallel::collect(nrows, ncols, 1, |i, j| f(self.at(i, j)));
+        Mat::from_vec_col(nrows, ncols, data)
+    }
+
+    #[track_caller]
+    pub fn par_zip_map<U, F>(self, other: MatRef<'_, T>, f: F) -> Mat<U>
+    where
+        U: Send + Sync,
+        F: Fn(&T, &T) -> U + Send + Sync,
+    {
+        self.try_par_zip_map(other, f).or_panic()
+    }
+
+    pub fn try_par_zip_map<U, F>(self, other: MatRef<'_, T>, f: F) -> Result<Mat<U>>
+    where
+        U: Send + Sync,
+        F: Fn(&T, &T) -> U + Send + Sync,
+    {
+        check_shape(self.shape(), other.shape())?;
+        let (nrows, ncols) = self.shape();
+        let data = parallel::collect(nrows, ncols, 1, |i, j| f(self.at(i, j), other.at(i, j)));
+        Ok(Mat::from_vec_col(nrows, ncols, data))
+    }
+}
+
+impl<'a, T: Send + Sync> MatMut<'a, T> {
+    pub fn par_col_iter(&self) -> ParColIter<'_, T> {
+        self.rb().par_col_iter()
+    }
+
+    pub fn par_col_iter_mut(self) -> ParColIterMut<'a, T> {
+        ParColIterMut { matrix: self }
+    }
+
+    pub fn par_map<U, F>(&self, f: F) -> Mat<U>
+    where
+        U: Send + Sync,
+        F: Fn(&T) -> U + Send + Sync,
+    {
+        self.rb().par_map(f)
+    }
+
+    #[track_caller]
+    pub fn par_zip_map<U, F>(&self, other: MatRef<'_, T>, f: F) -> Mat<U>
+    where
+        U: Send + Sync,
+        F: Fn(&T, &T) -> U + Send + Sync,
+    {
+        self.rb().par_zip_map(other, f)
+    }
+
+    pub fn try_par_zip_map<U, F>(&self, other: MatRef<'_, T>, f: F) -> Result<Mat<U>>
+    where
+        U: Send + Sync,
+        F: Fn(&T, &T) -> U + Send + Sync,
+    {
+        self.rb().try_par_zip_map(other, f)
+    }
+}
+
+impl<T: Send + Sync> Mat<T> {
+    pub fn par_col_iter(&self) -> ParColIter<'_, T> {
+        self.as_ref().par_col_iter()
+    }
+
+    pub fn par_col_iter_mut(&mut self) -> ParColIterMut<'_, T> {
+        self.as_mut().par_col_iter_mut()
+    }
+
+    pub fn par_map<U, F>(&self, f: F) -> Mat<U>
+    where
+        U: Send + Sync,
+        F: Fn(&T) -> U + Send + Sync,
+    {
+        self.as_ref().par_map(f)
+    }
+
+    #[track_caller]
+    pub fn par_zip_map<U, F>(&self, other: MatRef<'_, T>, f: F) -> Mat<U>
+    where
+        U: Send + Sync,
+        F: Fn(&T, &T) -> U + Send + Sync,
+    {
+        self.as_ref().par_zip_map(other, f)
+    }
+
+    pub fn try_par_zip_map<U, F>(&self, other: MatRef<'_, T>, f: F) -> Result<Mat<U>>
+    where
+        U: Send + Sync,
+        F: Fn(&T, &T) -> U + Send + Sync,
+    {
+        self.as_ref().try_par_zip_map(other, f)
+    }
+}
@@ -0,0 +1,164 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeSeq, SerializeStruct, Serializer};
+
+use super::{Mat, MatMut, MatRef};
+
+const FIELDS: &[&str] = &["nrows", "ncols", "data"];
+
+struct ColMajor<'a, T>(MatRef<'a, T>);
+
+impl<T: Serialize> Serialize for ColMajor<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let matrix = self.0;
+        let mut seq = serializer.serialize_seq(Some(matrix.size()))?;
+        for j in 0..matrix.ncols() {
+            for i in 0..matrix.nrows() {
+                seq.serialize_element(matrix.at(i, j))?;
+            }
+        }
+        seq.end()
+    }
+}
+
+impl<T: Serialize> Serialize for MatRef<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Mat", 3)?;
+        state.serialize_field("nrows", &self.nrows())?;
+        state.serialize_field("ncols", &self.ncols())?;
+        state.serialize_field("data", &ColMajor(*self))?;
+        state.end()
+    }
+}
+
+impl<T: Serialize> Serialize for MatMut<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.rb().serialize(serializer)
+    }
+}
+
+impl<T: Serialize> Serialize for Mat<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_ref().serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de> + Clone> Deserialize<'de> for Mat<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(MatVisitor {
+                nested_rows: true,
+                _marker: PhantomData,
+            })
+        } else {
+            deserializer.deserialize_struct(
+                "Mat",
+                FIELDS,
+                MatVisitor {
+                    nested_rows: false,
+                    _marker: PhantomData,
+                },
+            )
+        }
+    }
+}
+
+enum Field {
+    Nrows,
+    Ncols,
+    Data,
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldVisitor;
+
+        impl Visitor<'_> for FieldVisitor {
+            type Value = Field;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "`nrows`, `ncols` or `data`")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Field, E> {
+                match value {
+                    "nrows" => Ok(Field::Nrows),
+                    "ncols" => Ok(Field::Ncols),
+                    "data" => Ok(Field::Data),
+                    _ => Err(de::Error::unknown_field(value, FIELDS)),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+struct MatVisitor<T> {
+    nested_rows: bool,
+    _marker: PhantomData<T>,
+}
+
+fn build<T, E: de::Error>(nrows: usize, ncols: usize, data: Vec<T>) -> Result<Mat<T>, E> {
+    Mat::try_from_vec_col(nrows, ncols, data).map_err(de::Error::custom)
+}
+
+impl<'de, T: Deserialize<'de> + Clone> Visitor<'de> for MatVisitor<T> {
+    type Value = Mat<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.nested_rows {
+            write!(f, "a matrix as {{nrows, ncols, data}} or as a list of rows")
+        } else {
+            write!(f, "a matrix as {{nrows, ncols, data}}")
+        }
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Mat<T>, A::Error> {
+        if self.nested_rows {
+            let mut rows: Vec<Vec<T>> = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(row) = seq.next_element()? {
+                rows.push(row);
+            }
+            return Mat::try_from_nested_vec(rows).map_err(de::Error::custom);
+        }
+        let nrows = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let ncols = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let data = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+        build(nrows, ncols, data)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Mat<T>, A::Error> {
+        let mut nrows = None;
+        let mut ncols = None;
+        let mut data = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                Field::Nrows if nrows.is_some() => {
+                    return Err(de::Error::duplicate_field("nrows"));
+                }
+                Field::Ncols if ncols.is_some() => {
+                    return Err(de::Error::duplicate_field("ncols"));
+                }
+                Field::Data if data.is_some() => {
+                    return Err(de::Error::duplicate_field("data"));
+                }
+                Field::Nrows => nrows = Some(map.next_value()?),
+                Field::Ncols => ncols = Some(map.next_value()?),
+                Field::Data => data = Some(map.next_value()?),
+            }
+        }
+        let nrows = nrows.ok_or_else(|| de::Error::missing_field("nrows"))?;
+        let ncols = ncols.ok_or_else(|| de::Error::missing_field("ncols"))?;
+        let data = data.ok_or_else(|| de::Error::missing_field("data"))?;
+        build(nrows, ncols, data)
+    }
+}
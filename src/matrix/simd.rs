@@ -0,0 +1,944 @@
+use std::mem::size_of;
+use std::ops::Range;
+use std::slice;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use num_complex::Complex;
+use num_traits::{Float, Zero};
+
+use super::{Mat, MatMut, MatRef};
+use crate::error::{Error, Result};
+use crate::parallel::{self, cast_vec, from_element};
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+mod aarch64;
+#[cfg(target_arch = "x86_64")]
+mod x86;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SimdLevel {
+    Scalar,
+    Sse2,
+    Avx2,
+    Avx512,
+    Neon,
+}
+
+const LEVELS: [SimdLevel; 5] = [
+    SimdLevel::Scalar,
+    SimdLevel::Sse2,
+    SimdLevel::Avx2,
+    SimdLevel::Avx512,
+    SimdLevel::Neon,
+];
+
+impl SimdLevel {
+    pub fn is_supported(self) -> bool {
+        match self {
+            SimdLevel::Scalar => true,
+            #[cfg(target_arch = "x86_64")]
+            SimdLevel::Sse2 => true,
+            #[cfg(target_arch = "x86_64")]
+            SimdLevel::Avx2 => std::arch::is_x86_feature_detected!("avx2"),
+            #[cfg(target_arch = "x86_64")]
+            SimdLevel::Avx512 => std::arch::is_x86_feature_detected!("avx512f"),
+            #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+            SimdLevel::Neon => true,
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            SimdLevel::Scalar => "scalar kernels",
+            SimdLevel::Sse2 => "SSE2 kernels",
+            SimdLevel::Avx2 => "AVX2 kernels",
+            SimdLevel::Avx512 => "AVX-512 kernels",
+            SimdLevel::Neon => "NEON kernels",
+        }
+    }
+}
+
+// Index into `LEVELS`, or `UNDETECTED` until the first kernel runs.
+const UNDETECTED: u8 = u8::MAX;
+
+static LEVEL: AtomicU8 = AtomicU8::new(UNDETECTED);
+
+// The instruction set used by the floating point kernels, which defaults to
+// the widest one the CPU supports.
+pub fn simd_level() -> SimdLevel {
+    match LEVEL.load(Ordering::Relaxed) {
+        UNDETECTED => {
+            let level = [
+                SimdLevel::Avx512,
+                SimdLevel::Avx2,
+                SimdLevel::Sse2,
+                SimdLevel::Neon,
+            ]
+            .into_iter()
+            .find(|level| level.is_supported())
+            .unwrap_or(SimdLevel::Scalar);
+            LEVEL.store(level as u8, Ordering::Relaxed);
+            level
+        }
+        index => LEVELS[index as usize],
+    }
+}
+
+// Every level produces bitwise identical results, so this only affects speed.
+pub fn set_simd_level(level: SimdLevel) -> Result<()> {
+    if !level.is_supported() {
+        return Err(Error::Unsupported {
+            operation: level.name(),
+        });
+    }
+    LEVEL.store(level as u8, Ordering::Relaxed);
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Binary {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Unary {
+    Neg,
+    Abs,
+    Sqrt,
+    Exp,
+}
+
+// Widest vector, in elements, of any supported instruction set.
+const MAX_LANES: usize = 16;
+
+trait Real: Float + Send + Sync + 'static {
+    // Number of interleaved accumulators used by reductions, independent of
+    // the vector width so that every level rounds the same way.
+    const LANES: usize;
+    // The polynomial approximation of `exp` is used for |x| <= EXP_LIMIT,
+    // where neither 2^n nor the result leaves the normal range.
+    const EXP_LIMIT: Self;
+    // Adding this rounds to an integer held in the low mantissa bits.
+    const EXP_MAGIC: Self;
+    const LOG2_E: Self;
+    // ln(2) split so that n * LN_2_HI is exact for every n in range.
+    const LN_2_HI: Self;
+    const LN_2_LO: Self;
+    // Taylor coefficients of exp(r), highest degree first.
+    const EXP_COEFFS: &'static [Self];
+
+    // 2^n for the n held in the low mantissa bits of `t`.
+    fn pow2(t: Self) -> Self;
+
+    fn dispatch<K: Kernel<Self>>(kernel: K) -> K::Output;
+}
+
+// A packed vector of `LANES` reals. Callers of the unsafe methods must ensure
+// the CPU supports the instruction set the implementation uses.
+trait Vector: Copy {
+    type Real: Real;
+    const LANES: usize;
+
+    unsafe fn splat(value: Self::Real) -> Self;
+    unsafe fn load(ptr: *const Self::Real) -> Self;
+    unsafe fn store(self, ptr: *mut Self::Real);
+    unsafe fn add(self, rhs: Self) -> Self;
+    unsafe fn sub(self, rhs: Self) -> Self;
+    unsafe fn mul(self, rhs: Self) -> Self;
+    unsafe fn div(self, rhs: Self) -> Self;
+    unsafe fn sqrt(self) -> Self;
+    unsafe fn abs(self) -> Self;
+    unsafe fn neg(self) -> Self;
+    // Per lane, `other` if it is smaller or `self` is NaN, otherwise `self`.
+    unsafe fn keep_min(self, other: Self) -> Self;
+    unsafe fn keep_max(self, other: Self) -> Self;
+    unsafe fn pow2(self) -> Self;
+    // Whether |x| <= limit in every lane, which fails for NaN.
+    unsafe fn all_within(self, limit: Self) -> bool;
+}
+
+trait Kernel<E: Real> {
+    type Output;
+
+    unsafe fn run<V: Vector<Real = E>>(self) -> Self::Output;
+}
+
+#[derive(Clone, Copy)]
+struct Scalar<E>(E);
+
+impl<E: Real> Vector for Scalar<E> {
+    type Real = E;
+    const LANES: usize = 1;
+
+    #[inline(always)]
+    unsafe fn splat(value: E) -> Self {
+        Scalar(value)
+    }
+
+    #[inline(always)]
+    unsafe fn load(ptr: *const E) -> Self {
+        Scalar(unsafe { *ptr })
+    }
+
+    #[inline(always)]
+    unsafe fn store(self, ptr: *mut E) {
+        unsafe { *ptr = self.0 }
+    }
+
+    #[inline(always)]
+    unsafe fn add(self, rhs: Self) -> Self {
+        Scalar(self.0 + rhs.0)
+    }
+
+    #[inline(always)]
+    unsafe fn sub(self, rhs: Self) -> Self {
+        Scalar(self.0 - rhs.0)
+    }
+
+    #[inline(always)]
+    unsafe fn mul(self, rhs: Self) -> Self {
+        Scalar(self.0 * rhs.0)
+    }
+
+    #[inline(always)]
+    unsafe fn div(self, rhs: Self) -> Self {
+        Scalar(self.0 / rhs.0)
+    }
+
+    #[inline(always)]
+    unsafe fn sqrt(self) -> Self {
+        Scalar(self.0.sqrt())
+    }
+
+    #[inline(always)]
+    unsafe fn abs(self) -> Self {
+        Scalar(self.0.abs())
+    }
+
+    #[inline(always)]
+    unsafe fn neg(self) -> Self {
+        Scalar(-self.0)
+    }
+
+    #[inline(always)]
+    unsafe fn keep_min(self, other: Self) -> Self {
+        Scalar(keep_min(self.0, other.0))
+    }
+
+    #[inline(always)]
+    unsafe fn keep_max(self, other: Self) -> Self {
+        Scalar(keep_max(self.0, other.0))
+    }
+
+    #[inline(always)]
+    unsafe fn pow2(self) -> Self {
+        Scalar(E::pow2(self.0))
+    }
+
+    #[inline(always)]
+    unsafe fn all_within(self, limit: Self) -> bool {
+        self.0.abs() <= limit.0
+    }
+}
+
+#[inline(always)]
+fn keep_min<E: Real>(acc: E, x: E) -> E {
+    if x < acc || acc.is_nan() { x } else { acc }
+}
+
+#[inline(always)]
+fn keep_max<E: Real>(acc: E, x: E) -> E {
+    if x > acc || acc.is_nan() { x } else { acc }
+}
+
+macro_rules! dispatch {
+    ($kernel:expr, $E:ty, $Sse2:ident, $avx2:ident, $avx512:ident, $Neon:ident) => {
+        match simd_level() {
+            #[cfg(target_arch = "x86_64")]
+            SimdLevel::Avx512 => unsafe { x86::$avx512($kernel) },
+            #[cfg(target_arch = "x86_64")]
+            SimdLevel::Avx2 => unsafe { x86::$avx2($kernel) },
+            #[cfg(target_arch = "x86_64")]
+            SimdLevel::Sse2 => unsafe { $kernel.run::<x86::$Sse2>() },
+            #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+            SimdLevel::Neon => unsafe { $kernel.run::<aarch64::$Neon>() },
+            _ => unsafe { $kernel.run::<Scalar<$E>>() },
+        }
+    };
+}
+
+impl Real for f32 {
+    const LANES: usize = 16;
+    const EXP_LIMIT: f32 = 86.0;
+    const EXP_MAGIC: f32 = 12582912.0;
+    const LOG2_E: f32 = std::f32::consts::LOG2_E;
+    const LN_2_HI: f32 = f32::from_bits(0x3f31_8000);
+    const LN_2_LO: f32 = f32::from_bits(0xb95e_8083);
+    const EXP_COEFFS: &'static [f32] = &[
+        1.0 / 5040.0,
+        1.0 / 720.0,
+        1.0 / 120.0,
+        1.0 / 24.0,
+        1.0 / 6.0,
+        0.5,
+        1.0,
+        1.0,
+    ];
+
+    #[inline(always)]
+    fn pow2(t: f32) -> f32 {
+        f32::from_bits(t.to_bits().wrapping_add(127) << 23)
+    }
+
+    fn dispatch<K: Kernel<f32>>(kernel: K) -> K::Output {
+        dispatch!(kernel, f32, Sse2F32, avx2_f32, avx512_f32, NeonF32)
+    }
+}
+
+impl Real for f64 {
+    const LANES: usize = 8;
+    const EXP_LIMIT: f64 = 708.0;
+    const EXP_MAGIC: f64 = 6755399441055744.0;
+    const LOG2_E: f64 = std::f64::consts::LOG2_E;
+    const LN_2_HI: f64 = f64::from_bits(0x3fe6_2e42_fee0_0000);
+    const LN_2_LO: f64 = f64::from_bits(0x3dea_39ef_3579_3c76);
+    const EXP_COEFFS: &'static [f64] = &[
+        1.0 / 6227020800.0,
+        1.0 / 479001600.0,
+        1.0 / 39916800.0,
+        1.0 / 3628800.0,
+        1.0 / 362880.0,
+        1.0 / 40320.0,
+        1.0 / 5040.0,
+        1.0 / 720.0,
+        1.0 / 120.0,
+        1.0 / 24.0,
+        1.0 / 6.0,
+        0.5,
+        1.0,
+        1.0,
+    ];
+
+    #[inline(always)]
+    fn pow2(t: f64) -> f64 {
+        f64::from_bits(t.to_bits().wrapping_add(1023) << 52)
+    }
+
+    fn dispatch<K: Kernel<f64>>(kernel: K) -> K::Output {
+        dispatch!(kernel, f64, Sse2F64, avx2_f64, avx512_f64, NeonF64)
+    }
+}
+
+// exp(x) = 2^n * exp(r) with n = round(x / ln 2) and |r| <= ln(2) / 2. Lanes
+// outside the range of the approximation fall back to the standard library.
+#[inline(always)]
+unsafe fn exp<V: Vector>(x: V) -> V {
+    unsafe {
+        let splat = V::splat;
+        let t = x.mul(splat(V::Real::LOG2_E)).add(splat(V::Real::EXP_MAGIC));
+        let n = t.sub(splat(V::Real::EXP_MAGIC));
+        let r = x
+            .sub(n.mul(splat(V::Real::LN_2_HI)))
+            .sub(n.mul(splat(V::Real::LN_2_LO)));
+        let coeffs = V::Real::EXP_COEFFS;
+        let mut p = splat(coeffs[0]);
+        for &c in &coeffs[1..] {
+            p = p.mul(r).add(splat(c));
+        }
+        let y = p.mul(t.pow2());
+        let limit = V::Real::EXP_LIMIT;
+        if x.all_within(splat(limit)) {
+            return y;
+        }
+        let mut xs = [V::Real::zero(); MAX_LANES];
+        let mut ys = [V::Real::zero(); MAX_LANES];
+        x.store(xs.as_mut_ptr());
+        y.store(ys.as_mut_ptr());
+        for (x, y) in xs.iter().zip(&mut ys).take(V::LANES) {
+            if x.abs() > limit || x.is_nan() {
+                *y = x.exp();
+            }
+        }
+        V::load(ys.as_ptr())
+    }
+}
+
+// Writes `f` of the inputs to `len` elements at `out`, which may alias an
+// input. The tail is padded to a full vector so every element goes through the
+// same instructions.
+#[inline(always)]
+unsafe fn map<V: Vector, const N: usize>(
+    out: *mut V::Real,
+    inputs: [*const V::Real; N],
+    len: usize,
+    f: impl Fn([V; N]) -> V,
+) {
+    unsafe {
+        let body = len - len % V::LANES;
+        let mut k = 0;
+        while k < body {
+            f(inputs.map(|x| V::load(x.add(k)))).store(out.add(k));
+            k += V::LANES;
+        }
+        if k < len {
+            let tail = len - k;
+            let mut buffers = [[V::Real::zero(); MAX_LANES]; N];
+            for (buffer, x) in buffers.iter_mut().zip(inputs) {
+                buffer[..tail].copy_from_slice(slice::from_raw_parts(x.add(k), tail));
+            }
+            let mut result = [V::Real::zero(); MAX_LANES];
+            f(buffers.map(|buffer| V::load(buffer.as_ptr()))).store(result.as_mut_ptr());
+            slice::from_raw_parts_mut(out.add(k), tail).copy_from_slice(&result[..tail]);
+        }
+    }
+}
+
+#[inline(always)]
+unsafe fn binary<V: Vector>(op: Binary, x: V, y: V) -> V {
+    unsafe {
+        match op {
+            Binary::Add => x.add(y),
+            Binary::Sub => x.sub(y),
+            Binary::Mul => x.mul(y),
+            Binary::Div => x.div(y),
+        }
+    }
+}
+
+// The right hand side of a binary operation.
+#[derive(Clone, Copy)]
+enum Rhs<P, E> {
+    Elements(P),
+    Scalar(E),
+}
+
+// `out = a op b`, where `out` may alias `a`.
+struct Zip<E> {
+    op: Binary,
+    out: *mut E,
+    a: *const E,
+    b: Rhs<*const E, E>,
+    len: usize,
+}
+
+impl<E: Real> Kernel<E> for Zip<E> {
+    type Output = ();
+
+    #[inline(always)]
+    unsafe fn run<V: Vector<Real = E>>(self) {
+        let Zip { op, out, a, b, len } = self;
+        unsafe {
+            match (op, b) {
+                (Binary::Add, Rhs::Elements(b)) => map::<V, 2>(out, [a, b], len, |[x, y]| x.add(y)),
+                (Binary::Sub, Rhs::Elements(b)) => map::<V, 2>(out, [a, b], len, |[x, y]| x.sub(y)),
+                (Binary::Mul, Rhs::Elements(b)) => map::<V, 2>(out, [a, b], len, |[x, y]| x.mul(y)),
+                (Binary::Div, Rhs::Elements(b)) => map::<V, 2>(out, [a, b], len, |[x, y]| x.div(y)),
+                (op, Rhs::Scalar(s)) => {
+                    let s = V::splat(s);
+                    map::<V, 1>(out, [a], len, |[x]| binary(op, x, s))
+                }
+            }
+        }
+    }
+}
+
+struct Map<E> {
+    op: Unary,
+    out: *mut E,
+    a: *const E,
+    len: usize,
+}
+
+impl<E: Real> Kernel<E> for Map<E> {
+    type Output = ();
+
+    #[inline(always)]
+    unsafe fn run<V: Vector<Real = E>>(self) {
+        let Map { op, out, a, len } = self;
+        unsafe {
+            match op {
+                Unary::Neg => map::<V, 1>(out, [a], len, |[x]| x.neg()),
+                Unary::Abs => map::<V, 1>(out, [a], len, |[x]| x.abs()),
+                Unary::Sqrt => map::<V, 1>(out, [a], len, |[x]| x.sqrt()),
+                Unary::Exp => {
+                    // Too large to be inlined by default, and a closure outside the
+                    // trampoline would be compiled without the target features.
+                    map::<V, 1>(
+                        out,
+                        [a],
+                        len,
+                        #[inline(always)]
+                        |[x]| exp(x),
+                    )
+                }
+            }
+        }
+    }
+}
+
+// Adds `data` to the lanes, where `pos` counts the elements added so far.
+struct Sum<'a, E> {
+    lanes: &'a mut [E; MAX_LANES],
+    pos: usize,
+    data: &'a [E],
+}
+
+impl<E: Real> Kernel<E> for Sum<'_, E> {
+    type Output = usize;
+
+    #[inline(always)]
+    unsafe fn run<V: Vector<Real = E>>(self) -> usize {
+        let Sum { lanes, pos, data } = self;
+        unsafe { accumulate::<V>(lanes, pos, data, |acc, x| acc.add(x), |acc, x| acc + x) }
+    }
+}
+
+struct MinMax<'a, E> {
+    lanes: [&'a mut [E; MAX_LANES]; 2],
+    pos: usize,
+    data: &'a [E],
+}
+
+impl<E: Real> Kernel<E> for MinMax<'_, E> {
+    type Output = usize;
+
+    #[inline(always)]
+    unsafe fn run<V: Vector<Real = E>>(self) -> usize {
+        let MinMax { lanes, pos, data } = self;
+        let [min, max] = lanes;
+        unsafe {
+            accumulate::<V>(max, pos, data, |acc, x| acc.keep_max(x), keep_max);
+            accumulate::<V>(min, pos, data, |acc, x| acc.keep_min(x), keep_min)
+        }
+    }
+}
+
+// Folds element k of `data` into lane (pos + k) % E::LANES of each state.
+#[inline(always)]
+unsafe fn accumulate<V: Vector>(
+    lanes: &mut [V::Real; MAX_LANES],
+    mut pos: usize,
+    mut data: &[V::Real],
+    fold: impl Fn(V, V) -> V,
+    fold_scalar: impl Fn(V::Real, V::Real) -> V::Real,
+) -> usize {
+    let width = V::Real::LANES;
+    while !pos.is_multiple_of(width) && !data.is_empty() {
+        lanes[pos % width] = fold_scalar(lanes[pos % width], data[0]);
+        data = &data[1..];
+        pos += 1;
+    }
+    let blocks = data.len() / width;
+    if blocks > 0 {
+        let regs = width / V::LANES;
+        unsafe {
+            let mut acc = [V::splat(V::Real::zero()); MAX_LANES];
+            for (r, acc) in acc.iter_mut().enumerate().take(regs) {
+                *acc = V::load(lanes.as_ptr().add(r * V::LANES));
+            }
+            for block in data.chunks_exact(width) {
+                for (r, acc) in acc.iter_mut().enumerate().take(regs) {
+                    *acc = fold(*acc, V::load(block.as_ptr().add(r * V::LANES)));
+                }
+            }
+            for (r, acc) in acc.iter().enumerate().take(regs) {
+                acc.store(lanes.as_mut_ptr().add(r * V::LANES));
+            }
+        }
+        data = &data[blocks * width..];
+        pos += blocks * width;
+    }
+    for &x in data {
+        lanes[pos % width] = fold_scalar(lanes[pos % width], x);
+        pos += 1;
+    }
+    pos
+}
+
+// Combines lanes pairwise, halving their number until `stop` remain.
+fn fold_lanes<E: Copy>(lanes: &mut [E], stop: usize, f: impl Fn(E, E) -> E) {
+    let mut width = lanes.len();
+    while width > stop {
+        width /= 2;
+        for i in 0..width {
+            lanes[i] = f(lanes[i], lanes[i + width]);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    F32,
+    F64,
+    C32,
+    C64,
+}
+
+impl Kind {
+    fn of<T>() -> Option<Kind> {
+        if parallel::is_type::<T, f32>() {
+            Some(Kind::F32)
+        } else if parallel::is_type::<T, f64>() {
+            Some(Kind::F64)
+        } else if parallel::is_type::<T, Complex<f32>>() {
+            Some(Kind::C32)
+        } else if parallel::is_type::<T, Complex<f64>>() {
+            Some(Kind::C64)
+        } else {
+            None
+        }
+    }
+
+    fn is_complex(self) -> bool {
+        matches!(self, Kind::C32 | Kind::C64)
+    }
+}
+
+macro_rules! with_real {
+    ($kind:expr, $E:ident => $body:expr) => {
+        match $kind {
+            Kind::F32 | Kind::C32 => {
+                type $E = f32;
+                $body
+            }
+            Kind::F64 | Kind::C64 => {
+                type $E = f64;
+                $body
+            }
+        }
+    };
+}
+
+fn zeros<T>(kind: Kind, nrows: usize, ncols: usize) -> Mat<T> {
+    let len = nrows * ncols;
+    let data = match kind {
+        Kind::F32 => cast_vec(vec![0f32; len]),
+        Kind::F64 => cast_vec(vec![0f64; len]),
+        Kind::C32 => cast_vec(vec![Complex::new(0f32, 0.0); len]),
+        Kind::C64 => cast_vec(vec![Complex::new(0f64, 0.0); len]),
+    };
+    Mat::from_vec_col(nrows, ncols, data)
+}
+
+// Complex elements are handled as pairs of reals, which needs unit row stride.
+fn supports_layout<T>(kind: Kind, a: MatRef<'_, T>) -> bool {
+    !kind.is_complex() || a.row_stride == 1 || a.nrows <= 1
+}
+
+// Views the components of a matrix over `T`, which must be `E` or
+// `Complex<E>`, with `supports_layout` holding.
+unsafe fn real<T, E>(a: MatRef<'_, T>) -> MatRef<'_, E> {
+    let factor = size_of::<T>() / size_of::<E>();
+    MatRef {
+        ptr: a.ptr.cast(),
+        nrows: a.nrows * factor,
+        ncols: a.ncols,
+        row_stride: if factor == 1 { a.row_stride } else { 1 },
+        col_stride: a.col_stride * factor as isize,
+        _marker: std::marker::PhantomData,
+    }
+}
+
+unsafe fn real_mut<T, E>(a: MatMut<'_, T>) -> MatMut<'_, E> {
+    let factor = size_of::<T>() / size_of::<E>();
+    MatMut {
+        ptr: a.ptr.cast(),
+        nrows: a.nrows * factor,
+        ncols: a.ncols,
+        row_stride: if factor == 1 { a.row_stride } else { 1 },
+        col_stride: a.col_stride * factor as isize,
+        _marker: std::marker::PhantomData,
+    }
+}
+
+// Runs the kernel `make` builds for each run of elements that is contiguous in
+// `out` and every input, merging columns when none is padded. Strided views
+// run the scalar kernel one element at a time, which rounds the same way.
+fn for_each_run<E: Real, K: Kernel<E, Output = ()>, const N: usize>(
+    out: MatMut<'_, E>,
+    inputs: [MatRef<'_, E>; N],
+    mut make: impl FnMut(*mut E, [*const E; N], usize) -> K,
+) {
+    let (m, n) = (out.nrows, out.ncols);
+    if m == 0 || n == 0 {
+        return;
+    }
+    let unit = |row_stride: isize| m == 1 || row_stride == 1;
+    if !unit(out.row_stride) || !inputs.iter().all(|x| unit(x.row_stride)) {
+        for j in 0..n {
+            for i in 0..m {
+                let kernel = make(
+                    out.ptr_at(i, j).cast_mut(),
+                    inputs.map(|x| x.ptr_at(i, j)),
+                    1,
+                );
+                unsafe { kernel.run::<Scalar<E>>() };
+            }
+        }
+        return;
+    }
+    let packed = |col_stride: isize| n == 1 || col_stride == m as isize;
+    let (len, runs) = if packed(out.col_stride) && inputs.iter().all(|x| packed(x.col_stride)) {
+        (m * n, 1)
+    } else {
+        (m, n)
+    };
+    for j in 0..runs {
+        let j = j as isize;
+        let kernel = unsafe {
+            make(
+                out.ptr.offset(j * out.col_stride),
+                inputs.map(|x| x.ptr.offset(j * x.col_stride)),
+                len,
+            )
+        };
+        E::dispatch(kernel);
+    }
+}
+
+fn zip_real<E: Real>(op: Binary, out: MatMut<'_, E>, a: MatRef<'_, E>, b: Rhs<MatRef<'_, E>, E>) {
+    parallel::for_each_block_mut(out, 1, |(i, j), block| {
+        let (m, n) = block.shape();
+        let a = a.view(i, j, m, n);
+        match b {
+            Rhs::Elements(b) => {
+                for_each_run(block, [a, b.view(i, j, m, n)], |out, [a, b], len| Zip {
+                    op,
+                    out,
+                    a,
+                    b: Rhs::Elements(b),
+                    len,
+                })
+            }
+            Rhs::Scalar(s) => for_each_run(block, [a], |out, [a], len| Zip {
+                op,
+                out,
+                a,
+                b: Rhs::Scalar(s),
+                len,
+            }),
+        }
+    });
+}
+
+fn zip_assign_real<E: Real>(op: Binary, out: MatMut<'_, E>, b: Rhs<MatRef<'_, E>, E>) {
+    parallel::for_each_block_mut(out, 1, |(i, j), block| {
+        let (m, n) = block.shape();
+        match b {
+            Rhs::Elements(b) => for_each_run(block, [b.view(i, j, m, n)], |out, [b], len| Zip {
+                op,
+                out,
+                a: out,
+                b: Rhs::Elements(b),
+                len,
+            }),
+            Rhs::Scalar(s) => for_each_run(block, [], |out, [], len| Zip {
+                op,
+                out,
+                a: out,
+                b: Rhs::Scalar(s),
+                len,
+            }),
+        }
+    });
+}
+
+fn supports(kind: Kind, op: Binary) -> bool {
+    !kind.is_complex() || matches!(op, Binary::Add | Binary::Sub)
+}
+
+// `a op b` for matrices of equal shape, or `None` when there is no kernel for
+// the element type or layout.
+pub(crate) fn zip<T>(op: Binary, a: MatRef<'_, T>, b: MatRef<'_, T>) -> Option<Mat<T>> {
+    let kind = Kind::of::<T>().filter(|&kind| supports(kind, op))?;
+    if a.shape() != b.shape() || !supports_layout(kind, a) || !supports_layout(kind, b) {
+        return None;
+    }
+    let mut out = zeros::<T>(kind, a.nrows, a.ncols);
+    with_real!(kind, E => unsafe {
+        zip_real::<E>(op, real_mut(out.as_mut()), real(a), Rhs::Elements(real(b)))
+    });
+    Some(out)
+}
+
+// `a op s` for real elements.
+pub(crate) fn zip_scalar<T>(op: Binary, a: MatRef<'_, T>, s: &T) -> Option<Mat<T>> {
+    let kind = Kind::of::<T>().filter(|kind| !kind.is_complex())?;
+    let mut out = zeros::<T>(kind, a.nrows, a.ncols);
+    with_real!(kind, E => unsafe {
+        let s = *(s as *const T).cast::<E>();
+        zip_real::<E>(op, real_mut(out.as_mut()), real(a), Rhs::Scalar(s))
+    });
+    Some(out)
+}
+
+// `dst op= b` for matrices of equal shape, returning false when there is no
+// kernel.
+pub(crate) fn zip_assign<T>(op: Binary, dst: MatMut<'_, T>, b: MatRef<'_, T>) -> bool {
+    let Some(kind) = Kind::of::<T>().filter(|&kind| supports(kind, op)) else {
+        return false;
+    };
+    if dst.shape() != b.shape() || !supports_layout(kind, dst.rb()) || !supports_layout(kind, b) {
+        return false;
+    }
+    with_real!(kind, E => unsafe {
+        zip_assign_real::<E>(op, real_mut(dst), Rhs::Elements(real(b)))
+    });
+    true
+}
+
+pub(crate) fn zip_scalar_assign<T>(op: Binary, dst: MatMut<'_, T>, s: &T) -> bool {
+    let Some(kind) = Kind::of::<T>().filter(|kind| !kind.is_complex()) else {
+        return false;
+    };
+    with_real!(kind, E => unsafe {
+        let s = *(s as *const T).cast::<E>();
+        zip_assign_real::<E>(op, real_mut(dst), Rhs::Scalar(s))
+    });
+    true
+}
+
+pub(crate) fn map_unary<T>(op: Unary, a: MatRef<'_, T>) -> Option<Mat<T>> {
+    let kind = Kind::of::<T>().filter(|kind| !kind.is_complex() || op == Unary::Neg)?;
+    if !supports_layout(kind, a) {
+        return None;
+    }
+    let weight = if op == Unary::Exp { 8 } else { 1 };
+    let mut out = zeros::<T>(kind, a.nrows, a.ncols);
+    with_real!(kind, E => {
+        let a = unsafe { real::<T, E>(a) };
+        let out = unsafe { real_mut::<T, E>(out.as_mut()) };
+        parallel::for_each_block_mut(out, weight, |(i, j), block| {
+            let (m, n) = block.shape();
+            for_each_run(block, [a.view(i, j, m, n)], |out, [a], len| Map { op, out, a, len });
+        });
+    });
+    Some(out)
+}
+
+// Calls `f` with the components of the elements in a range of column-major
+// indices, flagging runs long enough to be worth a vector kernel.
+fn for_each_segment<T, E>(a: MatRef<'_, T>, range: Range<usize>, mut f: impl FnMut(&[E], bool)) {
+    let factor = size_of::<T>() / size_of::<E>();
+    let nrows = a.nrows;
+    if range.is_empty() {
+        return;
+    }
+    if a.row_stride == 1 && (a.ncols == 1 || a.col_stride == nrows as isize) {
+        let data =
+            unsafe { slice::from_raw_parts(a.ptr.add(range.start).cast(), range.len() * factor) };
+        return f(data, true);
+    }
+    let (mut i, mut j) = (range.start % nrows, range.start / nrows);
+    let mut remaining = range.len();
+    while remaining > 0 {
+        let len = (nrows - i).min(remaining);
+        unsafe {
+            if a.row_stride == 1 {
+                f(
+                    slice::from_raw_parts(a.ptr_at(i, j).cast(), len * factor),
+                    true,
+                );
+            } else {
+                for k in 0..len {
+                    f(
+                        slice::from_raw_parts(a.ptr_at(i + k, j).cast(), factor),
+                        false,
+                    );
+                }
+            }
+        }
+        remaining -= len;
+        i = 0;
+        j += 1;
+    }
+}
+
+// Sums the elements over fixed-size chunks of column-major indices, which are
+// combined in order, so the result does not depend on the thread count.
+pub(crate) fn sum<T>(a: MatRef<'_, T>) -> Option<T> {
+    let kind = Kind::of::<T>()?;
+    parallel::as_element!(T, C => from_element::<C, T>(parallel::reduce_chunks(
+        parallel::cast_ref::<T, C>(a),
+        C::zero(),
+        |a, range| sum_range(kind, a, range),
+        |acc, partial| acc + partial,
+    )))
+}
+
+// The components are accumulated in E::LANES interleaved lanes that are then
+// combined pairwise, so the result is the same for every instruction set and
+// layout.
+fn sum_range<T>(kind: Kind, a: MatRef<'_, T>, range: Range<usize>) -> T {
+    with_real!(kind, E => {
+        let mut lanes = [E::zero(); MAX_LANES];
+        let mut pos = 0;
+        for_each_segment::<T, E>(a, range, |data, vector| {
+            if vector {
+                pos = E::dispatch(Sum { lanes: &mut lanes, pos, data });
+            } else {
+                for &x in data {
+                    lanes[pos % E::LANES] += x;
+                    pos += 1;
+                }
+            }
+        });
+        let lanes = &mut lanes[..E::LANES];
+        if kind.is_complex() {
+            fold_lanes(lanes, 2, |x, y| x + y);
+            from_element::<Complex<E>, T>(Complex::new(lanes[0], lanes[1]))
+        } else {
+            fold_lanes(lanes, 1, |x, y| x + y);
+            from_element::<E, T>(lanes[0])
+        }
+    })
+}
+
+// The smallest and largest non-NaN elements, or `Some(None)` if there are
+// none. Which of two equal elements, such as -0.0 and 0.0, is returned is
+// unspecified.
+pub(crate) fn min_max<T>(a: MatRef<'_, T>) -> Option<Option<(T, T)>> {
+    let kind = Kind::of::<T>().filter(|kind| !kind.is_complex())?;
+    with_real!(kind, E => {
+        let bounds = parallel::reduce_chunks(
+            parallel::cast_ref::<T, E>(a),
+            None,
+            min_max_range,
+            |acc, partial| match (acc, partial) {
+                (Some((min, max)), Some((lo, hi))) => Some((keep_min(min, lo), keep_max(max, hi))),
+                (acc, None) => acc,
+                (None, partial) => partial,
+            },
+        );
+        Some(bounds.map(|(min, max)| (from_element::<E, T>(min), from_element::<E, T>(max))))
+    })
+}
+
+fn min_max_range<E: Real>(a: MatRef<'_, E>, range: Range<usize>) -> Option<(E, E)> {
+    let mut min = [E::nan(); MAX_LANES];
+    let mut max = [E::nan(); MAX_LANES];
+    let mut pos = 0;
+    for_each_segment::<E, E>(a, range, |data, vector| {
+        if vector {
+            pos = E::dispatch(MinMax {
+                lanes: [&mut min, &mut max],
+                pos,
+                data,
+            });
+        } else {
+            for &x in data {
+                min[pos % E::LANES] = keep_min(min[pos % E::LANES], x);
+                max[pos % E::LANES] = keep_max(max[pos % E::LANES], x);
+                pos += 1;
+            }
+        }
+    });
+    fold_lanes(&mut min[..E::LANES], 1, keep_min);
+    fold_lanes(&mut max[..E::LANES], 1, keep_max);
+    (!min[0].is_nan()).then_some((min[0], max[0]))
+}
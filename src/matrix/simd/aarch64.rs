@@ -0,0 +1,188 @@
+use std::arch::aarch64::*;
+
+use super::Vector;
+
+#[derive(Clone, Copy)]
+pub(super) struct NeonF32(float32x4_t);
+
+#[derive(Clone, Copy)]
+pub(super) struct NeonF64(float64x2_t);
+
+// NEON is part of the aarch64 baseline, so depending on the toolchain its
+// arithmetic intrinsics may not need the unsafe blocks.
+#[allow(unused_unsafe)]
+impl Vector for NeonF32 {
+    type Real = f32;
+    const LANES: usize = 4;
+
+    #[inline(always)]
+    unsafe fn splat(value: f32) -> Self {
+        unsafe { Self(vdupq_n_f32(value)) }
+    }
+
+    #[inline(always)]
+    unsafe fn load(ptr: *const f32) -> Self {
+        unsafe { Self(vld1q_f32(ptr)) }
+    }
+
+    #[inline(always)]
+    unsafe fn store(self, ptr: *mut f32) {
+        unsafe { vst1q_f32(ptr, self.0) }
+    }
+
+    #[inline(always)]
+    unsafe fn add(self, rhs: Self) -> Self {
+        unsafe { Self(vaddq_f32(self.0, rhs.0)) }
+    }
+
+    #[inline(always)]
+    unsafe fn sub(self, rhs: Self) -> Self {
+        unsafe { Self(vsubq_f32(self.0, rhs.0)) }
+    }
+
+    #[inline(always)]
+    unsafe fn mul(self, rhs: Self) -> Self {
+        unsafe { Self(vmulq_f32(self.0, rhs.0)) }
+    }
+
+    #[inline(always)]
+    unsafe fn div(self, rhs: Self) -> Self {
+        unsafe { Self(vdivq_f32(self.0, rhs.0)) }
+    }
+
+    #[inline(always)]
+    unsafe fn sqrt(self) -> Self {
+        unsafe { Self(vsqrtq_f32(self.0)) }
+    }
+
+    #[inline(always)]
+    unsafe fn abs(self) -> Self {
+        unsafe { Self(vabsq_f32(self.0)) }
+    }
+
+    #[inline(always)]
+    unsafe fn neg(self) -> Self {
+        unsafe { Self(vnegq_f32(self.0)) }
+    }
+
+    #[inline(always)]
+    unsafe fn keep_min(self, other: Self) -> Self {
+        unsafe {
+            let nan = veorq_u32(vceqq_f32(self.0, self.0), vdupq_n_u32(u32::MAX));
+            let mask = vorrq_u32(vcltq_f32(other.0, self.0), nan);
+            Self(vbslq_f32(mask, other.0, self.0))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn keep_max(self, other: Self) -> Self {
+        unsafe {
+            let nan = veorq_u32(vceqq_f32(self.0, self.0), vdupq_n_u32(u32::MAX));
+            let mask = vorrq_u32(vcltq_f32(self.0, other.0), nan);
+            Self(vbslq_f32(mask, other.0, self.0))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn pow2(self) -> Self {
+        unsafe {
+            let bits = vaddq_s32(vreinterpretq_s32_f32(self.0), vdupq_n_s32(127));
+            Self(vreinterpretq_f32_s32(vshlq_n_s32::<23>(bits)))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn all_within(self, limit: Self) -> bool {
+        unsafe { vminvq_u32(vcaleq_f32(self.0, limit.0)) == u32::MAX }
+    }
+}
+
+#[allow(unused_unsafe)]
+impl Vector for NeonF64 {
+    type Real = f64;
+    const LANES: usize = 2;
+
+    #[inline(always)]
+    unsafe fn splat(value: f64) -> Self {
+        unsafe { Self(vdupq_n_f64(value)) }
+    }
+
+    #[inline(always)]
+    unsafe fn load(ptr: *const f64) -> Self {
+        unsafe { Self(vld1q_f64(ptr)) }
+    }
+
+    #[inline(always)]
+    unsafe fn store(self, ptr: *mut f64) {
+        unsafe { vst1q_f64(ptr, self.0) }
+    }
+
+    #[inline(always)]
+    unsafe fn add(self, rhs: Self) -> Self {
+        unsafe { Self(vaddq_f64(self.0, rhs.0)) }
+    }
+
+    #[inline(always)]
+    unsafe fn sub(self, rhs: Self) -> Self {
+        unsafe { Self(vsubq_f64(self.0, rhs.0)) }
+    }
+
+    #[inline(always)]
+    unsafe fn mul(self, rhs: Self) -> Self {
+        unsafe { Self(vmulq_f64(self.0, rhs.0)) }
+    }
+
+    #[inline(always)]
+    unsafe fn div(self, rhs: Self) -> Self {
+        unsafe { Self(vdivq_f64(self.0, rhs.0)) }
+    }
+
+    #[inline(always)]
+    unsafe fn sqrt(self) -> Self {
+        unsafe { Self(vsqrtq_f64(self.0)) }
+    }
+
+    #[inline(always)]
+    unsafe fn abs(self) -> Self {
+        unsafe { Self(vabsq_f64(self.0)) }
+    }
+
+    #[inline(always)]
+    unsafe fn neg(self) -> Self {
+        unsafe { Self(vnegq_f64(self.0)) }
+    }
+
+    #[inline(always)]
+    unsafe fn keep_min(self, other: Self) -> Self {
+        unsafe {
+            let nan = veorq_u64(vceqq_f64(self.0, self.0), vdupq_n_u64(u64::MAX));
+            let mask = vorrq_u64(vcltq_f64(other.0, self.0), nan);
+            Self(vbslq_f64(mask, other.0, self.0))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn keep_max(self, other: Self) -> Self {
+        unsafe {
+            let nan = veorq_u64(vceqq_f64(self.0, self.0), vdupq_n_u64(u64::MAX));
+            let mask = vorrq_u64(vcltq_f64(self.0, other.0), nan);
+            Self(vbslq_f64(mask, other.0, self.0))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn pow2(self) -> Self {
+        unsafe {
+            let bits = vaddq_s64(vreinterpretq_s64_f64(self.0), vdupq_n_s64(1023));
+            Self(vreinterpretq_f64_s64(vshlq_n_s64::<52>(bits)))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn all_within(self, limit: Self) -> bool {
+        unsafe {
+            let within = vreinterpretq_u32_u64(vcaleq_f64(self.0, limit.0));
+            vminvq_u32(within) == u32::MAX
+        }
+    }
+}
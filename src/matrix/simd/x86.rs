@@ -0,0 +1,483 @@
+use std::arch::x86_64::*;
+
+use super::{Kernel, Vector};
+
+#[derive(Clone, Copy)]
+pub(super) struct Sse2F32(__m128);
+
+#[derive(Clone, Copy)]
+pub(super) struct Sse2F64(__m128d);
+
+#[derive(Clone, Copy)]
+pub(super) struct Avx2F32(__m256);
+
+#[derive(Clone, Copy)]
+pub(super) struct Avx2F64(__m256d);
+
+#[derive(Clone, Copy)]
+pub(super) struct Avx512F32(__m512);
+
+#[derive(Clone, Copy)]
+pub(super) struct Avx512F64(__m512d);
+
+#[target_feature(enable = "avx2")]
+pub(super) unsafe fn avx2_f32<K: Kernel<f32>>(kernel: K) -> K::Output {
+    unsafe { kernel.run::<Avx2F32>() }
+}
+
+#[target_feature(enable = "avx2")]
+pub(super) unsafe fn avx2_f64<K: Kernel<f64>>(kernel: K) -> K::Output {
+    unsafe { kernel.run::<Avx2F64>() }
+}
+
+#[target_feature(enable = "avx512f")]
+pub(super) unsafe fn avx512_f32<K: Kernel<f32>>(kernel: K) -> K::Output {
+    unsafe { kernel.run::<Avx512F32>() }
+}
+
+#[target_feature(enable = "avx512f")]
+pub(super) unsafe fn avx512_f64<K: Kernel<f64>>(kernel: K) -> K::Output {
+    unsafe { kernel.run::<Avx512F64>() }
+}
+
+// The methods every instruction set spells the same way up to the prefix.
+macro_rules! arithmetic {
+    ($real:ty, $set1:ident, $loadu:ident, $storeu:ident, $add:ident, $sub:ident, $mul:ident,
+     $div:ident, $sqrt:ident) => {
+        type Real = $real;
+
+        #[inline(always)]
+        unsafe fn splat(value: $real) -> Self {
+            unsafe { Self($set1(value)) }
+        }
+
+        #[inline(always)]
+        unsafe fn load(ptr: *const $real) -> Self {
+            unsafe { Self($loadu(ptr)) }
+        }
+
+        #[inline(always)]
+        unsafe fn store(self, ptr: *mut $real) {
+            unsafe { $storeu(ptr, self.0) }
+        }
+
+        #[inline(always)]
+        unsafe fn add(self, rhs: Self) -> Self {
+            unsafe { Self($add(self.0, rhs.0)) }
+        }
+
+        #[inline(always)]
+        unsafe fn sub(self, rhs: Self) -> Self {
+            unsafe { Self($sub(self.0, rhs.0)) }
+        }
+
+        #[inline(always)]
+        unsafe fn mul(self, rhs: Self) -> Self {
+            unsafe { Self($mul(self.0, rhs.0)) }
+        }
+
+        #[inline(always)]
+        unsafe fn div(self, rhs: Self) -> Self {
+            unsafe { Self($div(self.0, rhs.0)) }
+        }
+
+        #[inline(always)]
+        unsafe fn sqrt(self) -> Self {
+            unsafe { Self($sqrt(self.0)) }
+        }
+    };
+}
+
+// SSE2 is part of the x86_64 baseline, so its intrinsics need no unsafe block
+// on their own; the blocks are kept for symmetry with the other levels.
+#[allow(unused_unsafe)]
+impl Vector for Sse2F32 {
+    const LANES: usize = 4;
+
+    arithmetic!(
+        f32,
+        _mm_set1_ps,
+        _mm_loadu_ps,
+        _mm_storeu_ps,
+        _mm_add_ps,
+        _mm_sub_ps,
+        _mm_mul_ps,
+        _mm_div_ps,
+        _mm_sqrt_ps
+    );
+
+    #[inline(always)]
+    unsafe fn abs(self) -> Self {
+        unsafe { Self(_mm_andnot_ps(_mm_set1_ps(-0.0), self.0)) }
+    }
+
+    #[inline(always)]
+    unsafe fn neg(self) -> Self {
+        unsafe { Self(_mm_xor_ps(self.0, _mm_set1_ps(-0.0))) }
+    }
+
+    #[inline(always)]
+    unsafe fn keep_min(self, other: Self) -> Self {
+        unsafe {
+            let mask = _mm_or_ps(
+                _mm_cmplt_ps(other.0, self.0),
+                _mm_cmpunord_ps(self.0, self.0),
+            );
+            Self(_mm_or_ps(
+                _mm_and_ps(mask, other.0),
+                _mm_andnot_ps(mask, self.0),
+            ))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn keep_max(self, other: Self) -> Self {
+        unsafe {
+            let mask = _mm_or_ps(
+                _mm_cmplt_ps(self.0, other.0),
+                _mm_cmpunord_ps(self.0, self.0),
+            );
+            Self(_mm_or_ps(
+                _mm_and_ps(mask, other.0),
+                _mm_andnot_ps(mask, self.0),
+            ))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn pow2(self) -> Self {
+        unsafe {
+            let bits = _mm_add_epi32(_mm_castps_si128(self.0), _mm_set1_epi32(127));
+            Self(_mm_castsi128_ps(_mm_slli_epi32::<23>(bits)))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn all_within(self, limit: Self) -> bool {
+        unsafe { _mm_movemask_ps(_mm_cmple_ps(self.abs().0, limit.0)) == 0xf }
+    }
+}
+
+#[allow(unused_unsafe)]
+impl Vector for Sse2F64 {
+    const LANES: usize = 2;
+
+    arithmetic!(
+        f64,
+        _mm_set1_pd,
+        _mm_loadu_pd,
+        _mm_storeu_pd,
+        _mm_add_pd,
+        _mm_sub_pd,
+        _mm_mul_pd,
+        _mm_div_pd,
+        _mm_sqrt_pd
+    );
+
+    #[inline(always)]
+    unsafe fn abs(self) -> Self {
+        unsafe { Self(_mm_andnot_pd(_mm_set1_pd(-0.0), self.0)) }
+    }
+
+    #[inline(always)]
+    unsafe fn neg(self) -> Self {
+        unsafe { Self(_mm_xor_pd(self.0, _mm_set1_pd(-0.0))) }
+    }
+
+    #[inline(always)]
+    unsafe fn keep_min(self, other: Self) -> Self {
+        unsafe {
+            let mask = _mm_or_pd(
+                _mm_cmplt_pd(other.0, self.0),
+                _mm_cmpunord_pd(self.0, self.0),
+            );
+            Self(_mm_or_pd(
+                _mm_and_pd(mask, other.0),
+                _mm_andnot_pd(mask, self.0),
+            ))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn keep_max(self, other: Self) -> Self {
+        unsafe {
+            let mask = _mm_or_pd(
+                _mm_cmplt_pd(self.0, other.0),
+                _mm_cmpunord_pd(self.0, self.0),
+            );
+            Self(_mm_or_pd(
+                _mm_and_pd(mask, other.0),
+                _mm_andnot_pd(mask, self.0),
+            ))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn pow2(self) -> Self {
+        unsafe {
+            let bits = _mm_add_epi64(_mm_castpd_si128(self.0), _mm_set1_epi64x(1023));
+            Self(_mm_castsi128_pd(_mm_slli_epi64::<52>(bits)))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn all_within(self, limit: Self) -> bool {
+        unsafe { _mm_movemask_pd(_mm_cmple_pd(self.abs().0, limit.0)) == 0b11 }
+    }
+}
+
+impl Vector for Avx2F32 {
+    const LANES: usize = 8;
+
+    arithmetic!(
+        f32,
+        _mm256_set1_ps,
+        _mm256_loadu_ps,
+        _mm256_storeu_ps,
+        _mm256_add_ps,
+        _mm256_sub_ps,
+        _mm256_mul_ps,
+        _mm256_div_ps,
+        _mm256_sqrt_ps
+    );
+
+    #[inline(always)]
+    unsafe fn abs(self) -> Self {
+        unsafe { Self(_mm256_andnot_ps(_mm256_set1_ps(-0.0), self.0)) }
+    }
+
+    #[inline(always)]
+    unsafe fn neg(self) -> Self {
+        unsafe { Self(_mm256_xor_ps(self.0, _mm256_set1_ps(-0.0))) }
+    }
+
+    #[inline(always)]
+    unsafe fn keep_min(self, other: Self) -> Self {
+        unsafe {
+            let mask = _mm256_or_ps(
+                _mm256_cmp_ps::<_CMP_LT_OQ>(other.0, self.0),
+                _mm256_cmp_ps::<_CMP_UNORD_Q>(self.0, self.0),
+            );
+            Self(_mm256_blendv_ps(self.0, other.0, mask))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn keep_max(self, other: Self) -> Self {
+        unsafe {
+            let mask = _mm256_or_ps(
+                _mm256_cmp_ps::<_CMP_LT_OQ>(self.0, other.0),
+                _mm256_cmp_ps::<_CMP_UNORD_Q>(self.0, self.0),
+            );
+            Self(_mm256_blendv_ps(self.0, other.0, mask))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn pow2(self) -> Self {
+        unsafe {
+            let bits = _mm256_add_epi32(_mm256_castps_si256(self.0), _mm256_set1_epi32(127));
+            Self(_mm256_castsi256_ps(_mm256_slli_epi32::<23>(bits)))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn all_within(self, limit: Self) -> bool {
+        unsafe {
+            let within = _mm256_cmp_ps::<_CMP_LE_OQ>(self.abs().0, limit.0);
+            _mm256_movemask_ps(within) == 0xff
+        }
+    }
+}
+
+impl Vector for Avx2F64 {
+    const LANES: usize = 4;
+
+    arithmetic!(
+        f64,
+        _mm256_set1_pd,
+        _mm256_loadu_pd,
+        _mm256_storeu_pd,
+        _mm256_add_pd,
+        _mm256_sub_pd,
+        _mm256_mul_pd,
+        _mm256_div_pd,
+        _mm256_sqrt_pd
+    );
+
+    #[inline(always)]
+    unsafe fn abs(self) -> Self {
+        unsafe { Self(_mm256_andnot_pd(_mm256_set1_pd(-0.0), self.0)) }
+    }
+
+    #[inline(always)]
+    unsafe fn neg(self) -> Self {
+        unsafe { Self(_mm256_xor_pd(self.0, _mm256_set1_pd(-0.0))) }
+    }
+
+    #[inline(always)]
+    unsafe fn keep_min(self, other: Self) -> Self {
+        unsafe {
+            let mask = _mm256_or_pd(
+                _mm256_cmp_pd::<_CMP_LT_OQ>(other.0, self.0),
+                _mm256_cmp_pd::<_CMP_UNORD_Q>(self.0, self.0),
+            );
+            Self(_mm256_blendv_pd(self.0, other.0, mask))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn keep_max(self, other: Self) -> Self {
+        unsafe {
+            let mask = _mm256_or_pd(
+                _mm256_cmp_pd::<_CMP_LT_OQ>(self.0, other.0),
+                _mm256_cmp_pd::<_CMP_UNORD_Q>(self.0, self.0),
+            );
+            Self(_mm256_blendv_pd(self.0, other.0, mask))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn pow2(self) -> Self {
+        unsafe {
+            let bits = _mm256_add_epi64(_mm256_castpd_si256(self.0), _mm256_set1_epi64x(1023));
+            Self(_mm256_castsi256_pd(_mm256_slli_epi64::<52>(bits)))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn all_within(self, limit: Self) -> bool {
+        unsafe {
+            let within = _mm256_cmp_pd::<_CMP_LE_OQ>(self.abs().0, limit.0);
+            _mm256_movemask_pd(within) == 0xf
+        }
+    }
+}
+
+// AVX-512F has no floating point logic instructions, so sign manipulation goes
+// through the integer domain.
+impl Vector for Avx512F32 {
+    const LANES: usize = 16;
+
+    arithmetic!(
+        f32,
+        _mm512_set1_ps,
+        _mm512_loadu_ps,
+        _mm512_storeu_ps,
+        _mm512_add_ps,
+        _mm512_sub_ps,
+        _mm512_mul_ps,
+        _mm512_div_ps,
+        _mm512_sqrt_ps
+    );
+
+    #[inline(always)]
+    unsafe fn abs(self) -> Self {
+        unsafe {
+            let bits = _mm512_and_si512(_mm512_castps_si512(self.0), _mm512_set1_epi32(i32::MAX));
+            Self(_mm512_castsi512_ps(bits))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn neg(self) -> Self {
+        unsafe {
+            let bits = _mm512_xor_si512(_mm512_castps_si512(self.0), _mm512_set1_epi32(i32::MIN));
+            Self(_mm512_castsi512_ps(bits))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn keep_min(self, other: Self) -> Self {
+        unsafe {
+            let mask = _mm512_cmp_ps_mask::<_CMP_LT_OQ>(other.0, self.0)
+                | _mm512_cmp_ps_mask::<_CMP_UNORD_Q>(self.0, self.0);
+            Self(_mm512_mask_blend_ps(mask, self.0, other.0))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn keep_max(self, other: Self) -> Self {
+        unsafe {
+            let mask = _mm512_cmp_ps_mask::<_CMP_LT_OQ>(self.0, other.0)
+                | _mm512_cmp_ps_mask::<_CMP_UNORD_Q>(self.0, self.0);
+            Self(_mm512_mask_blend_ps(mask, self.0, other.0))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn pow2(self) -> Self {
+        unsafe {
+            let bits = _mm512_add_epi32(_mm512_castps_si512(self.0), _mm512_set1_epi32(127));
+            Self(_mm512_castsi512_ps(_mm512_slli_epi32::<23>(bits)))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn all_within(self, limit: Self) -> bool {
+        unsafe { _mm512_cmp_ps_mask::<_CMP_LE_OQ>(self.abs().0, limit.0) == 0xffff }
+    }
+}
+
+impl Vector for Avx512F64 {
+    const LANES: usize = 8;
+
+    arithmetic!(
+        f64,
+        _mm512_set1_pd,
+        _mm512_loadu_pd,
+        _mm512_storeu_pd,
+        _mm512_add_pd,
+        _mm512_sub_pd,
+        _mm512_mul_pd,
+        _mm512_div_pd,
+        _mm512_sqrt_pd
+    );
+
+    #[inline(always)]
+    unsafe fn abs(self) -> Self {
+        unsafe {
+            let bits = _mm512_and_si512(_mm512_castpd_si512(self.0), _mm512_set1_epi64(i64::MAX));
+            Self(_mm512_castsi512_pd(bits))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn neg(self) -> Self {
+        unsafe {
+            let bits = _mm512_xor_si512(_mm512_castpd_si512(self.0), _mm512_set1_epi64(i64::MIN));
+            Self(_mm512_castsi512_pd(bits))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn keep_min(self, other: Self) -> Self {
+        unsafe {
+            let mask = _mm512_cmp_pd_mask::<_CMP_LT_OQ>(other.0, self.0)
+                | _mm512_cmp_pd_mask::<_CMP_UNORD_Q>(self.0, self.0);
+            Self(_mm512_mask_blend_pd(mask, self.0, other.0))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn keep_max(self, other: Self) -> Self {
+        unsafe {
+            let mask = _mm512_cmp_pd_mask::<_CMP_LT_OQ>(self.0, other.0)
+                | _mm512_cmp_pd_mask::<_CMP_UNORD_Q>(self.0, self.0);
+            Self(_mm512_mask_blend_pd(mask, self.0, other.0))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn pow2(self) -> Self {
+        unsafe {
+            let bits = _mm512_add_epi64(_mm512_castpd_si512(self.0), _mm512_set1_epi64(1023));
+            Self(_mm512_castsi512_pd(_mm512_slli_epi64::<52>(bits)))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn all_within(self, limit: Self) -> bool {
+        unsafe { _mm512_cmp_pd_mask::<_CMP_LE_OQ>(self.abs().0, limit.0) == 0xff }
+    }
+}
@@ -0,0 +1,151 @@
+use std::alloc::{self, Layout};
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+use std::ptr::NonNull;
+
+pub(crate) const DEFAULT_ALIGN: usize = 64;
+
+// An aligned, uninitialized buffer. Tracking which elements are live is left to
+// the owner, so moving the buffer never touches the elements.
+pub(crate) struct Storage<T> {
+    ptr: NonNull<T>,
+    capacity: usize,
+    align: usize,
+    _marker: PhantomData<T>,
+}
+
+unsafe impl<T: Send> Send for Storage<T> {}
+unsafe impl<T: Sync> Sync for Storage<T> {}
+
+impl<T> Storage<T> {
+    pub(crate) fn new(align: usize) -> Self {
+        debug_assert!(align.is_power_of_two());
+        Storage {
+            ptr: NonNull::dangling(),
+            capacity: if size_of::<T>() == 0 { usize::MAX } else { 0 },
+            align: align.max(align_of::<T>()),
+            _marker: PhantomData,
+        }
+    }
+
+    pub(crate) fn with_capacity(capacity: usize, align: usize) -> Self {
+        let mut storage = Self::new(align);
+        storage.reallocate(capacity);
+        storage
+    }
+
+    // Takes over the allocation of `data`. Its elements become slots for the
+    // owner to track, and the alignment is that of `T`.
+    pub(crate) fn from_vec(data: Vec<T>) -> Self {
+        let mut data = ManuallyDrop::new(data);
+        if size_of::<T>() == 0 {
+            return Self::new(align_of::<T>());
+        }
+        Storage {
+            ptr: NonNull::new(data.as_mut_ptr()).expect("vector pointers are never null"),
+            capacity: data.capacity(),
+            align: align_of::<T>(),
+            _marker: PhantomData,
+        }
+    }
+
+    // Hands the allocation to a `Vec` whose first `len` slots must be
+    // initialized. Buffers with a larger alignment than `T` are given back,
+    // since a `Vec` could not free them.
+    pub(crate) fn into_vec(self, len: usize) -> Result<Vec<T>, Self> {
+        if size_of::<T>() == 0 || self.align != align_of::<T>() {
+            return Err(self);
+        }
+        debug_assert!(len <= self.capacity);
+        let storage = ManuallyDrop::new(self);
+        Ok(unsafe { Vec::from_raw_parts(storage.ptr.as_ptr(), len, storage.capacity) })
+    }
+
+    #[inline]
+    pub(crate) fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    #[inline]
+    pub(crate) fn align(&self) -> usize {
+        self.align
+    }
+
+    #[inline]
+    pub(crate) fn as_ptr(&self) -> *const T {
+        self.ptr.as_ptr()
+    }
+
+    #[inline]
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr.as_ptr()
+    }
+
+    // Changes the capacity, keeping the first `min(old, new)` slots bitwise.
+    pub(crate) fn reallocate(&mut self, capacity: usize) {
+        if size_of::<T>() == 0 || capacity == self.capacity {
+            return;
+        }
+        if capacity == 0 {
+            unsafe { alloc::dealloc(self.ptr.as_ptr().cast(), self.layout(self.capacity)) };
+            self.ptr = NonNull::dangling();
+            self.capacity = 0;
+            return;
+        }
+        let new_layout = self.layout(capacity);
+        let ptr = unsafe {
+            if self.capacity == 0 {
+                alloc::alloc(new_layout)
+            } else {
+                alloc::realloc(
+                    self.ptr.as_ptr().cast(),
+                    self.layout(self.capacity),
+                    new_layout.size(),
+                )
+            }
+        };
+        match NonNull::new(ptr.cast()) {
+            Some(ptr) => self.ptr = ptr,
+            None => alloc::handle_alloc_error(new_layout),
+        }
+        self.capacity = capacity;
+    }
+
+    fn layout(&self, capacity: usize) -> Layout {
+        size_of::<T>()
+            .checked_mul(capacity)
+            .and_then(|size| Layout::from_size_align(size, self.align).ok())
+            .expect("capacity overflow")
+    }
+}
+
+impl<T> Drop for Storage<T> {
+    fn drop(&mut self) {
+        self.reallocate(0);
+    }
+}
+
+// The column stride for `nrows` rows. Columns at least one alignment unit long
+// are padded to a whole number of units so every column starts aligned;
+// shorter ones are packed, since padding would more than double them.
+pub(crate) fn padded_stride<T>(nrows: usize, align: usize) -> usize {
+    let size = size_of::<T>();
+    if size == 0 || !align.is_multiple_of(size) {
+        return nrows;
+    }
+    let unit = align / size;
+    if nrows < unit {
+        nrows
+    } else {
+        nrows.next_multiple_of(unit)
+    }
+}
+
+// The number of slots needed for `ncols` columns, which is unbounded for
+// zero-sized elements.
+pub(crate) fn buffer_len<T>(col_stride: usize, ncols: usize) -> usize {
+    if size_of::<T>() == 0 {
+        return usize::MAX;
+    }
+    col_stride.checked_mul(ncols).expect("capacity overflow")
+}
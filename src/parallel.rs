@@ -0,0 +1,399 @@
+use std::any::TypeId;
+use std::cell::Cell;
+use std::marker::PhantomData;
+use std::mem::{self, ManuallyDrop};
+use std::ops::Range;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::matrix::{MatMut, MatRef};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parallelism {
+    None,
+    // Split work across at most this many rayon tasks; 0 uses every thread of
+    // the current pool. Without the `rayon` feature this runs serially.
+    Rayon(usize),
+}
+
+#[cfg(feature = "rayon")]
+const DEFAULT: usize = 1;
+#[cfg(not(feature = "rayon"))]
+const DEFAULT: usize = 0;
+
+static GLOBAL: AtomicUsize = AtomicUsize::new(DEFAULT);
+
+thread_local! {
+    static LOCAL: Cell<Option<Parallelism>> = const { Cell::new(None) };
+}
+
+fn encode(par: Parallelism) -> usize {
+    match par {
+        Parallelism::None => 0,
+        Parallelism::Rayon(n) => n.saturating_add(1),
+    }
+}
+
+fn decode(value: usize) -> Parallelism {
+    match value {
+        0 => Parallelism::None,
+        n => Parallelism::Rayon(n - 1),
+    }
+}
+
+pub fn global_parallelism() -> Parallelism {
+    decode(GLOBAL.load(Ordering::Relaxed))
+}
+
+pub fn set_global_parallelism(par: Parallelism) {
+    GLOBAL.store(encode(par), Ordering::Relaxed);
+}
+
+pub fn current_parallelism() -> Parallelism {
+    LOCAL.with(Cell::get).unwrap_or_else(global_parallelism)
+}
+
+// Runs `f` with `par` overriding the global setting on the calling thread.
+pub fn with_parallelism<R>(par: Parallelism, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<Parallelism>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            LOCAL.with(|local| local.set(self.0));
+        }
+    }
+
+    let _restore = Restore(LOCAL.with(|local| local.replace(Some(par))));
+    f()
+}
+
+mod sealed {
+    use num_complex::Complex;
+
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+    impl Sealed for Complex<f32> {}
+    impl Sealed for Complex<f64> {}
+}
+
+// The element types `as_element!` dispatches over. The casts below only ever
+// convert between one of these and a generic `T`, so none of them can be
+// used to reinterpret a type that carries a lifetime.
+pub(crate) trait Element: sealed::Sealed + Copy + 'static {}
+
+impl<E: sealed::Sealed + Copy + 'static> Element for E {}
+
+// `TypeId::of` without the `'static` bound. Lifetimes are erased, which is
+// sound because the ids are only ever compared with those of an `Element`.
+fn type_id<T: ?Sized>() -> TypeId {
+    trait Erased {
+        fn id(&self) -> TypeId
+        where
+            Self: 'static;
+    }
+
+    impl<T: ?Sized> Erased for PhantomData<T> {
+        fn id(&self) -> TypeId
+        where
+            Self: 'static,
+        {
+            TypeId::of::<T>()
+        }
+    }
+
+    let marker = PhantomData::<T>;
+    let erased: &dyn Erased = &marker;
+    unsafe { mem::transmute::<&dyn Erased, &(dyn Erased + 'static)>(erased) }.id()
+}
+
+pub(crate) fn is_type<T, E: Element>() -> bool {
+    type_id::<T>() == TypeId::of::<E>()
+}
+
+pub(crate) fn to_element<T, E: Element>(value: T) -> E {
+    assert!(is_type::<T, E>());
+    unsafe { mem::transmute_copy(&ManuallyDrop::new(value)) }
+}
+
+pub(crate) fn from_element<E: Element, T>(value: E) -> T {
+    assert!(is_type::<T, E>());
+    unsafe { mem::transmute_copy(&value) }
+}
+
+pub(crate) fn cast_vec<E: Element, T>(data: Vec<E>) -> Vec<T> {
+    assert!(is_type::<T, E>());
+    let mut data = ManuallyDrop::new(data);
+    unsafe { Vec::from_raw_parts(data.as_mut_ptr().cast(), data.len(), data.capacity()) }
+}
+
+pub(crate) fn cast_ref<T, E: Element>(a: MatRef<'_, T>) -> MatRef<'_, E> {
+    assert!(is_type::<T, E>());
+    let (nrows, ncols) = a.shape();
+    unsafe {
+        MatRef::from_raw_parts(
+            a.ptr_at(0, 0).cast(),
+            nrows,
+            ncols,
+            a.row_stride(),
+            a.col_stride(),
+        )
+    }
+}
+
+#[cfg(feature = "rayon")]
+pub(crate) fn cast_mut<T, E: Element>(mut a: MatMut<'_, T>) -> MatMut<'_, E> {
+    assert!(is_type::<T, E>());
+    let (nrows, ncols) = a.shape();
+    unsafe {
+        MatMut::from_raw_parts(
+            a.ptr_at_mut(0, 0).cast(),
+            nrows,
+            ncols,
+            a.row_stride(),
+            a.col_stride(),
+        )
+    }
+}
+
+// Evaluates `$body` with `$E` naming `$T` if it is one of the float or complex
+// element types, giving `Some` of the result, and `None` for any other type.
+// Operations generic over any `T` keep their plain bounds and only hand work
+// to the thread pool through this, so enabling `rayon` never changes what
+// compiles.
+macro_rules! as_element {
+    ($T:ty, $E:ident => $body:expr) => {{
+        use ::num_complex::Complex;
+        use $crate::parallel::is_type;
+        if is_type::<$T, f32>() {
+            type $E = f32;
+            Some($body)
+        } else if is_type::<$T, f64>() {
+            type $E = f64;
+            Some($body)
+        } else if is_type::<$T, Complex<f32>>() {
+            type $E = Complex<f32>;
+            Some($body)
+        } else if is_type::<$T, Complex<f64>>() {
+            type $E = Complex<f64>;
+            Some($body)
+        } else {
+            None
+        }
+    }};
+}
+
+pub(crate) use as_element;
+
+// Minimum amount of work, in element operations, worth handing to a task.
+#[cfg(feature = "rayon")]
+const MIN_TASK_WORK: usize = 1 << 15;
+
+// Reductions always combine partial results over chunks of this many
+// elements, so the rounding does not depend on how many threads run them.
+const REDUCE_CHUNK: usize = 1 << 14;
+
+#[cfg(feature = "rayon")]
+pub(crate) fn task_count(work: usize) -> usize {
+    match current_parallelism() {
+        Parallelism::None => 1,
+        Parallelism::Rayon(n) => {
+            let threads = if n == 0 {
+                rayon::current_num_threads()
+            } else {
+                n
+            };
+            threads.min(work / MIN_TASK_WORK).max(1)
+        }
+    }
+}
+
+// Builds the column-major data of an `nrows` x `ncols` matrix, where `weight`
+// estimates the cost of one element.
+pub(crate) fn collect<U, F>(nrows: usize, ncols: usize, weight: usize, f: F) -> Vec<U>
+where
+    U: Send + Sync,
+    F: Fn(usize, usize) -> U + Send + Sync,
+{
+    let size = nrows * ncols;
+    let mut data = Vec::with_capacity(size);
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+
+        let tasks = task_count(size.saturating_mul(weight));
+        if tasks > 1 {
+            (0..size)
+                .into_par_iter()
+                .with_min_len(size.div_ceil(tasks))
+                .map(|index| f(index % nrows, index / nrows))
+                .collect_into_vec(&mut data);
+            return data;
+        }
+    }
+    #[cfg(not(feature = "rayon"))]
+    let _ = weight;
+    for j in 0..ncols {
+        for i in 0..nrows {
+            data.push(f(i, j));
+        }
+    }
+    data
+}
+
+pub(crate) fn for_each_mut<T, F>(dst: MatMut<'_, T>, weight: usize, f: F)
+where
+    T: Send + Sync,
+    F: Fn(usize, usize, &mut T) + Send + Sync,
+{
+    for_each_block_mut(dst, weight, |(i0, j0), mut block| {
+        let (nrows, ncols) = block.shape();
+        for j in 0..ncols {
+            for i in 0..nrows {
+                f(i0 + i, j0 + j, block.at_mut(i, j));
+            }
+        }
+    });
+}
+
+// Splits `dst` into at most one block per task, by columns and then by rows
+// for a single column, and calls `f` with each block and its offset.
+pub(crate) fn for_each_block_mut<T, F>(dst: MatMut<'_, T>, weight: usize, f: F)
+where
+    T: Send + Sync,
+    F: Fn((usize, usize), MatMut<'_, T>) + Send + Sync,
+{
+    #[cfg(feature = "rayon")]
+    let tasks = task_count(dst.size().saturating_mul(weight));
+    #[cfg(not(feature = "rayon"))]
+    let (tasks, _) = (1, weight);
+    split_blocks(dst, (0, 0), tasks, &f);
+}
+
+fn split_blocks<T, F>(dst: MatMut<'_, T>, offset: (usize, usize), tasks: usize, f: &F)
+where
+    T: Send + Sync,
+    F: Fn((usize, usize), MatMut<'_, T>) + Send + Sync,
+{
+    #[cfg(feature = "rayon")]
+    if tasks > 1 {
+        let (nrows, ncols) = dst.shape();
+        let left = tasks / 2;
+        if ncols > 1 {
+            let j = (ncols * left / tasks).max(1);
+            let (a, b) = dst.split_at_col_mut(j);
+            rayon::join(
+                || split_blocks(a, offset, left, f),
+                || split_blocks(b, (offset.0, offset.1 + j), tasks - left, f),
+            );
+            return;
+        }
+        if nrows > 1 {
+            let i = (nrows * left / tasks).max(1);
+            let (a, b) = dst.split_at_row_mut(i);
+            rayon::join(
+                || split_blocks(a, offset, left, f),
+                || split_blocks(b, (offset.0 + i, offset.1), tasks - left, f),
+            );
+            return;
+        }
+    }
+    #[cfg(not(feature = "rayon"))]
+    let _ = tasks;
+    f(offset, dst);
+}
+
+// Calls `f` on every column of `dst`, where `weight` estimates the cost of
+// processing one column.
+pub(crate) fn for_each_col_mut<T, F>(dst: MatMut<'_, T>, weight: usize, f: F)
+where
+    T: Send + Sync,
+    F: Fn(usize, MatMut<'_, T>) + Send + Sync,
+{
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+
+        let ncols = dst.ncols();
+        let tasks = task_count(ncols.saturating_mul(weight));
+        if tasks > 1 {
+            dst.par_col_iter_mut()
+                .with_min_len(ncols.div_ceil(tasks))
+                .enumerate()
+                .for_each(|(j, col)| f(j, col));
+            return;
+        }
+    }
+    #[cfg(not(feature = "rayon"))]
+    let _ = weight;
+    for (j, col) in dst.col_iter_mut().enumerate() {
+        f(j, col);
+    }
+}
+
+// Folds the elements of `a` in column-major order. Each fixed-size chunk is
+// folded from `identity` and the partial results are combined in order.
+pub(crate) fn reduce<T, A, F, G>(a: MatRef<'_, T>, identity: A, fold: F, combine: G) -> A
+where
+    T: Send + Sync,
+    A: Clone + Send + Sync,
+    F: Fn(A, &T) -> A + Send + Sync,
+    G: Fn(A, A) -> A,
+{
+    let init = identity.clone();
+    reduce_chunks(
+        a,
+        identity,
+        |a, range| fold_range(a, range, init.clone(), &fold),
+        combine,
+    )
+}
+
+// Like `reduce`, with `chunk` computing the partial result of one chunk given
+// as a range of column-major indices.
+pub(crate) fn reduce_chunks<T, A, C, G>(a: MatRef<'_, T>, identity: A, chunk: C, combine: G) -> A
+where
+    T: Send + Sync,
+    A: Send + Sync,
+    C: Fn(MatRef<'_, T>, Range<usize>) -> A + Send + Sync,
+    G: Fn(A, A) -> A,
+{
+    let size = a.size();
+    let chunks = size.div_ceil(REDUCE_CHUNK);
+    let chunk = |c: usize| chunk(a, c * REDUCE_CHUNK..size.min((c + 1) * REDUCE_CHUNK));
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+
+        let tasks = task_count(size).min(chunks);
+        if tasks > 1 {
+            let partials: Vec<A> = (0..chunks)
+                .into_par_iter()
+                .with_min_len(chunks.div_ceil(tasks))
+                .map(chunk)
+                .collect();
+            return partials.into_iter().fold(identity, combine);
+        }
+    }
+    (0..chunks).map(chunk).fold(identity, combine)
+}
+
+pub(crate) fn fold_range<T, A>(
+    a: MatRef<'_, T>,
+    range: Range<usize>,
+    init: A,
+    fold: impl Fn(A, &T) -> A,
+) -> A {
+    let nrows = a.nrows();
+    let (mut i, mut j) = (range.start % nrows, range.start / nrows);
+    let mut acc = init;
+    for _ in range {
+        acc = fold(acc, a.at(i, j));
+        i += 1;
+        if i == nrows {
+            i = 0;
+            j += 1;
+        }
+    }
+    acc
+}
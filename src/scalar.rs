@@ -0,0 +1,149 @@
+use std::fmt::Debug;
+use std::ops::Neg;
+
+use num_complex::Complex;
+use num_traits::{Float, NumAssign};
+
+pub trait ComplexField:
+    Copy + PartialEq + Debug + NumAssign + Neg<Output = Self> + Send + Sync + 'static
+{
+    type Real: RealField;
+
+    fn from_real(value: Self::Real) -> Self;
+
+    fn re(self) -> Self::Real;
+
+    fn im(self) -> Self::Real;
+
+    fn conj(self) -> Self;
+
+    fn modulus(self) -> Self::Real;
+
+    fn modulus_sqr(self) -> Self::Real;
+
+    fn scale_real(self, factor: Self::Real) -> Self;
+
+    fn complex_sqrt(self) -> Self;
+
+    #[inline]
+    fn is_real_type() -> bool {
+        false
+    }
+}
+
+pub trait RealField: ComplexField<Real = Self> + Float {
+    fn ulps_distance(self, other: Self) -> u64;
+}
+
+macro_rules! impl_real_field {
+    ($ty:ty, $int:ty) => {
+        impl ComplexField for $ty {
+            type Real = $ty;
+
+            #[inline]
+            fn from_real(value: $ty) -> Self {
+                value
+            }
+
+            #[inline]
+            fn re(self) -> $ty {
+                self
+            }
+
+            #[inline]
+            fn im(self) -> $ty {
+                0.0
+            }
+
+            #[inline]
+            fn conj(self) -> Self {
+                self
+            }
+
+            #[inline]
+            fn modulus(self) -> $ty {
+                <$ty>::abs(self)
+            }
+
+            #[inline]
+            fn modulus_sqr(self) -> $ty {
+                self * self
+            }
+
+            #[inline]
+            fn scale_real(self, factor: $ty) -> Self {
+                self * factor
+            }
+
+            #[inline]
+            fn complex_sqrt(self) -> Self {
+                <$ty>::sqrt(self)
+            }
+
+            #[inline]
+            fn is_real_type() -> bool {
+                true
+            }
+        }
+
+        impl RealField for $ty {
+            fn ulps_distance(self, other: $ty) -> u64 {
+                if self.is_nan() || other.is_nan() {
+                    return u64::MAX;
+                }
+                let ordered = |x: $ty| {
+                    let bits = x.to_bits() as $int;
+                    if bits < 0 { <$int>::MIN - bits } else { bits }
+                };
+                (ordered(self) as i128 - ordered(other) as i128).unsigned_abs() as u64
+            }
+        }
+    };
+}
+
+impl_real_field!(f32, i32);
+impl_real_field!(f64, i64);
+
+impl<T: RealField> ComplexField for Complex<T> {
+    type Real = T;
+
+    #[inline]
+    fn from_real(value: T) -> Self {
+        Complex::new(value, T::zero())
+    }
+
+    #[inline]
+    fn re(self) -> T {
+        self.re
+    }
+
+    #[inline]
+    fn im(self) -> T {
+        self.im
+    }
+
+    #[inline]
+    fn conj(self) -> Self {
+        Complex::new(self.re, -self.im)
+    }
+
+    #[inline]
+    fn modulus(self) -> T {
+        self.re.hypot(self.im)
+    }
+
+    #[inline]
+    fn modulus_sqr(self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+
+    #[inline]
+    fn scale_real(self, factor: T) -> Self {
+        Complex::new(self.re * factor, self.im * factor)
+    }
+
+    #[inline]
+    fn complex_sqrt(self) -> Self {
+        Complex::sqrt(self)
+    }
+}
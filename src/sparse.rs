@@ -0,0 +1,62 @@
+mod cholesky;
+mod col;
+mod lu;
+mod ops;
+mod ordering;
+mod row;
+
+pub use cholesky::{SparseCholesky, SymbolicCholesky};
+pub use lu::{SparseLu, SymbolicLu};
+pub use ordering::{Ordering, amd, colamd};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseColMat<T> {
+    nrows: usize,
+    ncols: usize,
+    col_ptr: Vec<usize>,
+    row_idx: Vec<usize>,
+    values: Vec<T>,
+}
+
+#[derive(Debug)]
+pub struct SparseColMatRef<'a, T> {
+    nrows: usize,
+    ncols: usize,
+    col_ptr: &'a [usize],
+    row_idx: &'a [usize],
+    values: &'a [T],
+}
+
+impl<T> Copy for SparseColMatRef<'_, T> {}
+
+impl<T> Clone for SparseColMatRef<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseRowMat<T> {
+    nrows: usize,
+    ncols: usize,
+    row_ptr: Vec<usize>,
+    col_idx: Vec<usize>,
+    values: Vec<T>,
+}
+
+#[derive(Debug)]
+pub struct SparseRowMatRef<'a, T> {
+    nrows: usize,
+    ncols: usize,
+    row_ptr: &'a [usize],
+    col_idx: &'a [usize],
+    values: &'a [T],
+}
+
+impl<T> Copy for SparseRowMatRef<'_, T> {}
+
+impl<T> Clone for SparseRowMatRef<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
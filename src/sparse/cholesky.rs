@@ -0,0 +1,347 @@
+use num_traits::{Float, Zero};
+
+use super::col::Pattern;
+use super::ordering::{Ordering, amd, check_permutation, invert_permutation};
+use super::{SparseColMat, SparseColMatRef};
+use crate::error::{Error, Result, check_len, check_square};
+use crate::matrix::{Mat, MatMut, MatRef};
+use crate::scalar::ComplexField;
+
+const NONE: usize = usize::MAX;
+
+#[derive(Debug, Clone)]
+pub struct SymbolicCholesky {
+    n: usize,
+    perm: Vec<usize>,
+    perm_inv: Vec<usize>,
+    parent: Vec<usize>,
+    pattern: Pattern,
+    c_ptr: Vec<usize>,
+    c_idx: Vec<usize>,
+    c_map: Vec<(usize, bool)>,
+    l_ptr: Vec<usize>,
+}
+
+fn etree(n: usize, c_ptr: &[usize], c_idx: &[usize]) -> Vec<usize> {
+    let mut parent = vec![NONE; n];
+    let mut ancestor = vec![NONE; n];
+    for k in 0..n {
+        for &row in &c_idx[c_ptr[k]..c_ptr[k + 1]] {
+            let mut i = row;
+            while i != NONE && i < k {
+                let next = ancestor[i];
+                ancestor[i] = k;
+                if next == NONE {
+                    parent[i] = k;
+                }
+                i = next;
+            }
+        }
+    }
+    parent
+}
+
+// Nonzero pattern of row k of L, returned in stack[top..] in topological order.
+fn ereach(
+    k: usize,
+    rows: &[usize],
+    parent: &[usize],
+    stack: &mut [usize],
+    mark: &mut [usize],
+) -> usize {
+    let mut top = stack.len();
+    mark[k] = k;
+    for &row in rows {
+        let mut i = row;
+        let mut len = 0;
+        while mark[i] != k {
+            stack[len] = i;
+            len += 1;
+            mark[i] = k;
+            i = parent[i];
+        }
+        while len > 0 {
+            len -= 1;
+            top -= 1;
+            stack[top] = stack[len];
+        }
+    }
+    top
+}
+
+impl SymbolicCholesky {
+    pub fn new<T>(a: SparseColMatRef<'_, T>, ordering: Ordering) -> Result<Self> {
+        check_square(a.shape())?;
+        let n = a.ncols();
+        let perm = match ordering {
+            Ordering::Natural => (0..n).collect(),
+            Ordering::Amd => amd(a)?,
+            Ordering::Custom(perm) => {
+                check_permutation(&perm, n)?;
+                perm
+            }
+        };
+        let perm_inv = invert_permutation(&perm);
+
+        let pattern = Pattern::of(a);
+
+        let mut c_ptr = vec![0; n + 1];
+        let mut targets = Vec::with_capacity(a.nnz());
+        for j in 0..n {
+            for &i in pattern.col(j) {
+                if i < j {
+                    targets.push(None);
+                    continue;
+                }
+                let (r, c) = (perm_inv[i], perm_inv[j]);
+                let (row, col, conj) = if r <= c { (r, c, false) } else { (c, r, true) };
+                c_ptr[col + 1] += 1;
+                targets.push(Some((row, col, conj)));
+            }
+        }
+        for k in 0..n {
+            c_ptr[k + 1] += c_ptr[k];
+        }
+        let mut next = c_ptr[..n].to_vec();
+        let mut c_idx = vec![0; c_ptr[n]];
+        let mut c_map = Vec::with_capacity(targets.len());
+        for target in targets {
+            match target {
+                Some((row, col, conj)) => {
+                    let p = next[col];
+                    next[col] += 1;
+                    c_idx[p] = row;
+                    c_map.push((p, conj));
+                }
+                None => c_map.push((NONE, false)),
+            }
+        }
+
+        let parent = etree(n, &c_ptr, &c_idx);
+        let mut counts = vec![1; n];
+        let mut stack = vec![0; n];
+        let mut mark = vec![NONE; n];
+        for k in 0..n {
+            let top = ereach(
+                k,
+                &c_idx[c_ptr[k]..c_ptr[k + 1]],
+                &parent,
+                &mut stack,
+                &mut mark,
+            );
+            for &j in &stack[top..] {
+                counts[j] += 1;
+            }
+        }
+        let mut l_ptr = vec![0; n + 1];
+        for k in 0..n {
+            l_ptr[k + 1] = l_ptr[k] + counts[k];
+        }
+
+        Ok(SymbolicCholesky {
+            n,
+            perm,
+            perm_inv,
+            parent,
+            pattern,
+            c_ptr,
+            c_idx,
+            c_map,
+            l_ptr,
+        })
+    }
+
+    #[inline]
+    pub fn dim(&self) -> usize {
+        self.n
+    }
+
+    #[inline]
+    pub fn perm(&self) -> &[usize] {
+        &self.perm
+    }
+
+    #[inline]
+    pub fn perm_inv(&self) -> &[usize] {
+        &self.perm_inv
+    }
+
+    pub fn etree(&self) -> Vec<Option<usize>> {
+        self.parent
+            .iter()
+            .map(|&p| (p != NONE).then_some(p))
+            .collect()
+    }
+
+    #[inline]
+    pub fn l_nnz(&self) -> usize {
+        self.l_ptr[self.n]
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SparseCholesky<T> {
+    symbolic: SymbolicCholesky,
+    l: SparseColMat<T>,
+}
+
+impl<T: ComplexField> SparseCholesky<T> {
+    pub fn new(a: SparseColMatRef<'_, T>, ordering: Ordering) -> Result<Self> {
+        let symbolic = SymbolicCholesky::new(a, ordering)?;
+        Self::with_symbolic(symbolic, a)
+    }
+
+    pub fn with_symbolic(symbolic: SymbolicCholesky, a: SparseColMatRef<'_, T>) -> Result<Self> {
+        let n = symbolic.n;
+        let nnz = symbolic.l_nnz();
+        let mut chol = SparseCholesky {
+            l: SparseColMat {
+                nrows: n,
+                ncols: n,
+                col_ptr: symbolic.l_ptr.clone(),
+                row_idx: vec![0; nnz],
+                values: vec![T::zero(); nnz],
+            },
+            symbolic,
+        };
+        chol.refactor(a)?;
+        Ok(chol)
+    }
+
+    pub fn refactor(&mut self, a: SparseColMatRef<'_, T>) -> Result<()> {
+        let sym = &self.symbolic;
+        sym.pattern.check(a)?;
+        let n = sym.n;
+
+        let mut c_val = vec![T::zero(); sym.c_idx.len()];
+        for (&(p, conj), &value) in sym.c_map.iter().zip(a.values) {
+            if p != NONE {
+                c_val[p] = if conj { value.conj() } else { value };
+            }
+        }
+
+        let l_ptr = &sym.l_ptr;
+        let l_idx = &mut self.l.row_idx;
+        let l_val = &mut self.l.values;
+        let mut next = l_ptr[..n].to_vec();
+        let mut x = vec![T::zero(); n];
+        let mut stack = vec![0; n];
+        let mut mark = vec![NONE; n];
+        for k in 0..n {
+            let range = sym.c_ptr[k]..sym.c_ptr[k + 1];
+            let top = ereach(
+                k,
+                &sym.c_idx[range.clone()],
+                &sym.parent,
+                &mut stack,
+                &mut mark,
+            );
+            for p in range {
+                x[sym.c_idx[p]] = c_val[p];
+            }
+            let mut d = x[k].re();
+            x[k] = T::zero();
+            for &j in &stack[top..] {
+                let y = x[j] / l_val[l_ptr[j]];
+                x[j] = T::zero();
+                for p in l_ptr[j] + 1..next[j] {
+                    x[l_idx[p]] -= l_val[p] * y;
+                }
+                d -= y.modulus_sqr();
+                let p = next[j];
+                next[j] += 1;
+                l_idx[p] = k;
+                l_val[p] = y.conj();
+            }
+            if d <= T::Real::zero() || d.is_nan() {
+                return Err(Error::NotPositiveDefinite { index: sym.perm[k] });
+            }
+            let p = next[k];
+            next[k] += 1;
+            l_idx[p] = k;
+            l_val[p] = T::from_real(d.sqrt());
+        }
+        Ok(())
+    }
+
+    #[inline]
+    pub fn dim(&self) -> usize {
+        self.symbolic.n
+    }
+
+    #[inline]
+    pub fn symbolic(&self) -> &SymbolicCholesky {
+        &self.symbolic
+    }
+
+    #[inline]
+    pub fn l(&self) -> SparseColMatRef<'_, T> {
+        self.l.as_ref()
+    }
+
+    #[inline]
+    pub fn perm(&self) -> &[usize] {
+        &self.symbolic.perm
+    }
+
+    pub fn log_det(&self) -> T::Real {
+        let mut sum = T::Real::zero();
+        for k in 0..self.dim() {
+            let d = self.l.values[self.l.col_ptr[k]].re();
+            sum = sum + d.ln() + d.ln();
+        }
+        sum
+    }
+
+    pub fn solve(&self, b: MatRef<'_, T>) -> Result<Mat<T>> {
+        let mut x = b.to_owned();
+        self.solve_in_place(x.as_mut())?;
+        Ok(x)
+    }
+
+    pub fn solve_in_place(&self, mut b: MatMut<'_, T>) -> Result<()> {
+        let n = self.dim();
+        check_len(n, b.nrows())?;
+        let perm = &self.symbolic.perm;
+        let (l_ptr, l_idx, l_val) = (&self.l.col_ptr, &self.l.row_idx, &self.l.values);
+        let mut x = vec![T::zero(); n];
+        for j in 0..b.ncols() {
+            for k in 0..n {
+                x[k] = *b.at(perm[k], j);
+            }
+            for k in 0..n {
+                let t = x[k] / l_val[l_ptr[k]];
+                x[k] = t;
+                if t.is_zero() {
+                    continue;
+                }
+                for p in l_ptr[k] + 1..l_ptr[k + 1] {
+                    x[l_idx[p]] -= l_val[p] * t;
+                }
+            }
+            for k in (0..n).rev() {
+                let mut t = x[k];
+                for p in l_ptr[k] + 1..l_ptr[k + 1] {
+                    t -= l_val[p].conj() * x[l_idx[p]];
+                }
+                x[k] = t / l_val[l_ptr[k]].conj();
+            }
+            for k in 0..n {
+                *b.at_mut(perm[k], j) = x[k];
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: ComplexField> SparseColMatRef<'_, T> {
+    pub fn cholesky(self, ordering: Ordering) -> Result<SparseCholesky<T>> {
+        SparseCholesky::new(self, ordering)
+    }
+}
+
+impl<T: ComplexField> SparseColMat<T> {
+    pub fn cholesky(&self, ordering: Ordering) -> Result<SparseCholesky<T>> {
+        self.as_ref().cholesky(ordering)
+    }
+}
@@ -0,0 +1,537 @@
+use std::ops::{Add, Range};
+
+use num_traits::{One, Zero};
+
+use super::{SparseColMat, SparseColMatRef, SparseRowMat, SparseRowMatRef};
+use crate::error::{
+    Axis, Error, OrPanic, Result, check_index, check_len, check_range, format_error,
+};
+use crate::matrix::{Mat, MatRef};
+
+pub(super) fn check_structure(
+    nouter: usize,
+    ninner: usize,
+    ptr: &[usize],
+    idx: &[usize],
+    nvalues: usize,
+    inner: Axis,
+) -> Result<()> {
+    let outer = match inner {
+        Axis::Row => "column",
+        Axis::Col => "row",
+    };
+    check_len(nouter + 1, ptr.len())?;
+    check_len(idx.len(), nvalues)?;
+    if ptr[0] != 0 || ptr[nouter] != idx.len() {
+        return Err(format_error(format!(
+            "{} pointers must start at 0 and end at {}, found {}..{}",
+            outer,
+            idx.len(),
+            ptr[0],
+            ptr[nouter]
+        )));
+    }
+    if let Some(k) = (0..nouter).find(|&k| ptr[k] > ptr[k + 1]) {
+        return Err(format_error(format!(
+            "{} pointers decrease at {} {}",
+            outer, outer, k
+        )));
+    }
+    for k in 0..nouter {
+        let indices = &idx[ptr[k]..ptr[k + 1]];
+        for (n, &i) in indices.iter().enumerate() {
+            check_index(inner, i, ninner)?;
+            if n > 0 && indices[n - 1] >= i {
+                return Err(format_error(format!(
+                    "indices of {} {} are not strictly increasing",
+                    outer, k
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+pub(super) fn compress<T: Clone + Add<Output = T>>(
+    nouter: usize,
+    mut entries: Vec<(usize, usize, T)>,
+) -> (Vec<usize>, Vec<usize>, Vec<T>) {
+    entries.sort_by_key(|&(outer, inner, _)| (outer, inner));
+    let mut ptr = vec![0; nouter + 1];
+    let mut idx = Vec::with_capacity(entries.len());
+    let mut values: Vec<T> = Vec::with_capacity(entries.len());
+    let mut last = None;
+    for (outer, inner, value) in entries {
+        if last == Some((outer, inner)) {
+            let sum = values.pop().unwrap() + value;
+            values.push(sum);
+        } else {
+            idx.push(inner);
+            values.push(value);
+            ptr[outer + 1] += 1;
+            last = Some((outer, inner));
+        }
+    }
+    for k in 0..nouter {
+        ptr[k + 1] += ptr[k];
+    }
+    (ptr, idx, values)
+}
+
+pub(super) fn transpose_structure(
+    nouter: usize,
+    ninner: usize,
+    ptr: &[usize],
+    idx: &[usize],
+) -> (Vec<usize>, Vec<usize>, Vec<usize>) {
+    let base = ptr[0];
+    let nnz = ptr[nouter] - base;
+    let mut ptr_t = vec![0; ninner + 1];
+    for &i in &idx[..nnz] {
+        ptr_t[i + 1] += 1;
+    }
+    for i in 0..ninner {
+        ptr_t[i + 1] += ptr_t[i];
+    }
+    let mut next = ptr_t[..ninner].to_vec();
+    let mut idx_t = vec![0; nnz];
+    let mut perm = vec![0; nnz];
+    for k in 0..nouter {
+        for p in ptr[k] - base..ptr[k + 1] - base {
+            let q = &mut next[idx[p]];
+            idx_t[*q] = k;
+            perm[*q] = p;
+            *q += 1;
+        }
+    }
+    (ptr_t, idx_t, perm)
+}
+
+impl<'a, T> SparseColMatRef<'a, T> {
+    #[track_caller]
+    pub fn new(
+        nrows: usize,
+        ncols: usize,
+        col_ptr: &'a [usize],
+        row_idx: &'a [usize],
+        values: &'a [T],
+    ) -> Self {
+        Self::try_new(nrows, ncols, col_ptr, row_idx, values).or_panic()
+    }
+
+    pub fn try_new(
+        nrows: usize,
+        ncols: usize,
+        col_ptr: &'a [usize],
+        row_idx: &'a [usize],
+        values: &'a [T],
+    ) -> Result<Self> {
+        check_structure(ncols, nrows, col_ptr, row_idx, values.len(), Axis::Row)?;
+        Ok(SparseColMatRef {
+            nrows,
+            ncols,
+            col_ptr,
+            row_idx,
+            values,
+        })
+    }
+
+    #[inline]
+    pub fn nrows(self) -> usize {
+        self.nrows
+    }
+
+    #[inline]
+    pub fn ncols(self) -> usize {
+        self.ncols
+    }
+
+    #[inline]
+    pub fn shape(self) -> (usize, usize) {
+        (self.nrows, self.ncols)
+    }
+
+    #[inline]
+    pub fn nnz(self) -> usize {
+        self.values.len()
+    }
+
+    #[inline]
+    pub fn row_indices(self) -> &'a [usize] {
+        self.row_idx
+    }
+
+    #[inline]
+    pub fn values(self) -> &'a [T] {
+        self.values
+    }
+
+    #[inline]
+    #[track_caller]
+    pub fn col_range(self, j: usize) -> Range<usize> {
+        self.try_col_range(j).or_panic()
+    }
+
+    #[inline]
+    pub fn try_col_range(self, j: usize) -> Result<Range<usize>> {
+        check_index(Axis::Col, j, self.ncols)?;
+        let base = self.col_ptr[0];
+        Ok(self.col_ptr[j] - base..self.col_ptr[j + 1] - base)
+    }
+
+    #[inline]
+    #[track_caller]
+    pub fn col(self, j: usize) -> (&'a [usize], &'a [T]) {
+        self.try_col(j).or_panic()
+    }
+
+    #[inline]
+    pub fn try_col(self, j: usize) -> Result<(&'a [usize], &'a [T])> {
+        let range = self.try_col_range(j)?;
+        Ok((&self.row_idx[range.clone()], &self.values[range]))
+    }
+
+    pub fn get(self, i: usize, j: usize) -> Option<&'a T> {
+        if i >= self.nrows || j >= self.ncols {
+            return None;
+        }
+        let (rows, values) = self.col(j);
+        rows.binary_search(&i).ok().map(|p| &values[p])
+    }
+
+    pub fn triplet_iter(self) -> impl Iterator<Item = (usize, usize, &'a T)> {
+        (0..self.ncols).flat_map(move |j| {
+            let (rows, values) = self.col(j);
+            rows.iter().zip(values).map(move |(&i, v)| (i, j, v))
+        })
+    }
+
+    #[inline]
+    pub fn transpose(self) -> SparseRowMatRef<'a, T> {
+        SparseRowMatRef {
+            nrows: self.ncols,
+            ncols: self.nrows,
+            row_ptr: self.col_ptr,
+            col_idx: self.row_idx,
+            values: self.values,
+        }
+    }
+
+    #[track_caller]
+    pub fn cols_range(self, range: Range<usize>) -> SparseColMatRef<'a, T> {
+        self.try_cols_range(range).or_panic()
+    }
+
+    pub fn try_cols_range(self, range: Range<usize>) -> Result<SparseColMatRef<'a, T>> {
+        check_range(Axis::Col, range.start, range.end, self.ncols)?;
+        let base = self.col_ptr[0];
+        let (start, end) = (
+            self.col_ptr[range.start] - base,
+            self.col_ptr[range.end] - base,
+        );
+        Ok(SparseColMatRef {
+            nrows: self.nrows,
+            ncols: range.end - range.start,
+            col_ptr: &self.col_ptr[range.start..=range.end],
+            row_idx: &self.row_idx[start..end],
+            values: &self.values[start..end],
+        })
+    }
+}
+
+impl<T: Clone> SparseColMatRef<'_, T> {
+    pub fn to_owned(self) -> SparseColMat<T> {
+        let base = self.col_ptr[0];
+        SparseColMat {
+            nrows: self.nrows,
+            ncols: self.ncols,
+            col_ptr: self.col_ptr.iter().map(|&p| p - base).collect(),
+            row_idx: self.row_idx.to_vec(),
+            values: self.values.to_vec(),
+        }
+    }
+
+    pub fn to_row_mat(self) -> SparseRowMat<T> {
+        let (row_ptr, col_idx, perm) =
+            transpose_structure(self.ncols, self.nrows, self.col_ptr, self.row_idx);
+        SparseRowMat {
+            nrows: self.nrows,
+            ncols: self.ncols,
+            row_ptr,
+            col_idx,
+            values: perm.iter().map(|&p| self.values[p].clone()).collect(),
+        }
+    }
+}
+
+impl<T: Clone + Zero> SparseColMatRef<'_, T> {
+    pub fn to_dense(self) -> Mat<T> {
+        let mut out = Mat::zeros(self.nrows, self.ncols);
+        for (i, j, value) in self.triplet_iter() {
+            out[(i, j)] = value.clone();
+        }
+        out
+    }
+}
+
+impl<T> SparseColMat<T> {
+    #[track_caller]
+    pub fn new(
+        nrows: usize,
+        ncols: usize,
+        col_ptr: Vec<usize>,
+        row_idx: Vec<usize>,
+        values: Vec<T>,
+    ) -> Self {
+        Self::try_new(nrows, ncols, col_ptr, row_idx, values).or_panic()
+    }
+
+    pub fn try_new(
+        nrows: usize,
+        ncols: usize,
+        col_ptr: Vec<usize>,
+        row_idx: Vec<usize>,
+        values: Vec<T>,
+    ) -> Result<Self> {
+        check_structure(ncols, nrows, &col_ptr, &row_idx, values.len(), Axis::Row)?;
+        Ok(SparseColMat {
+            nrows,
+            ncols,
+            col_ptr,
+            row_idx,
+            values,
+        })
+    }
+
+    pub fn zeros(nrows: usize, ncols: usize) -> Self {
+        SparseColMat {
+            nrows,
+            ncols,
+            col_ptr: vec![0; ncols + 1],
+            row_idx: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub fn as_ref(&self) -> SparseColMatRef<'_, T> {
+        SparseColMatRef {
+            nrows: self.nrows,
+            ncols: self.ncols,
+            col_ptr: &self.col_ptr,
+            row_idx: &self.row_idx,
+            values: &self.values,
+        }
+    }
+
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    #[inline]
+    pub fn shape(&self) -> (usize, usize) {
+        (self.nrows, self.ncols)
+    }
+
+    #[inline]
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    #[inline]
+    pub fn col_ptr(&self) -> &[usize] {
+        &self.col_ptr
+    }
+
+    #[inline]
+    pub fn row_indices(&self) -> &[usize] {
+        &self.row_idx
+    }
+
+    #[inline]
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    #[inline]
+    pub fn values_mut(&mut self) -> &mut [T] {
+        &mut self.values
+    }
+
+    #[inline]
+    #[track_caller]
+    pub fn col(&self, j: usize) -> (&[usize], &[T]) {
+        self.as_ref().col(j)
+    }
+
+    #[inline]
+    pub fn try_col(&self, j: usize) -> Result<(&[usize], &[T])> {
+        self.as_ref().try_col(j)
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        self.as_ref().get(i, j)
+    }
+
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        if i >= self.nrows || j >= self.ncols {
+            return None;
+        }
+        let range = self.col_ptr[j]..self.col_ptr[j + 1];
+        let p = self.row_idx[range.clone()].binary_search(&i).ok()?;
+        Some(&mut self.values[range.start + p])
+    }
+
+    pub fn triplet_iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.as_ref().triplet_iter()
+    }
+
+    #[inline]
+    pub fn transpose(&self) -> SparseRowMatRef<'_, T> {
+        self.as_ref().transpose()
+    }
+
+    pub fn into_transpose(self) -> SparseRowMat<T> {
+        SparseRowMat {
+            nrows: self.ncols,
+            ncols: self.nrows,
+            row_ptr: self.col_ptr,
+            col_idx: self.row_idx,
+            values: self.values,
+        }
+    }
+
+    pub fn into_parts(self) -> (Vec<usize>, Vec<usize>, Vec<T>) {
+        (self.col_ptr, self.row_idx, self.values)
+    }
+
+    #[track_caller]
+    pub fn cols_range(&self, range: Range<usize>) -> SparseColMatRef<'_, T> {
+        self.as_ref().cols_range(range)
+    }
+
+    pub fn try_cols_range(&self, range: Range<usize>) -> Result<SparseColMatRef<'_, T>> {
+        self.as_ref().try_cols_range(range)
+    }
+}
+
+impl<T: Clone + Add<Output = T>> SparseColMat<T> {
+    #[track_caller]
+    pub fn from_triplets(nrows: usize, ncols: usize, triplets: &[(usize, usize, T)]) -> Self {
+        Self::try_from_triplets(nrows, ncols, triplets).or_panic()
+    }
+
+    pub fn try_from_triplets(
+        nrows: usize,
+        ncols: usize,
+        triplets: &[(usize, usize, T)],
+    ) -> Result<Self> {
+        for &(i, j, _) in triplets {
+            check_index(Axis::Row, i, nrows)?;
+            check_index(Axis::Col, j, ncols)?;
+        }
+        let entries = triplets
+            .iter()
+            .map(|(i, j, value)| (*j, *i, value.clone()))
+            .collect();
+        let (col_ptr, row_idx, values) = compress(ncols, entries);
+        Ok(SparseColMat {
+            nrows,
+            ncols,
+            col_ptr,
+            row_idx,
+            values,
+        })
+    }
+}
+
+impl<T: Clone> SparseColMat<T> {
+    pub fn to_row_mat(&self) -> SparseRowMat<T> {
+        self.as_ref().to_row_mat()
+    }
+}
+
+impl<T: Clone + Zero> SparseColMat<T> {
+    pub fn from_dense(mat: MatRef<'_, T>) -> Self {
+        let mut col_ptr = Vec::with_capacity(mat.ncols() + 1);
+        let mut row_idx = Vec::new();
+        let mut values = Vec::new();
+        col_ptr.push(0);
+        for j in 0..mat.ncols() {
+            for i in 0..mat.nrows() {
+                let value = mat.at(i, j);
+                if !value.is_zero() {
+                    row_idx.push(i);
+                    values.push(value.clone());
+                }
+            }
+            col_ptr.push(row_idx.len());
+        }
+        SparseColMat {
+            nrows: mat.nrows(),
+            ncols: mat.ncols(),
+            col_ptr,
+            row_idx,
+            values,
+        }
+    }
+
+    pub fn to_dense(&self) -> Mat<T> {
+        self.as_ref().to_dense()
+    }
+}
+
+impl<T: Clone + One> SparseColMat<T> {
+    pub fn identity(n: usize) -> Self {
+        SparseColMat {
+            nrows: n,
+            ncols: n,
+            col_ptr: (0..=n).collect(),
+            row_idx: (0..n).collect(),
+            values: vec![T::one(); n],
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct Pattern {
+    ncols: usize,
+    col_ptr: Vec<usize>,
+    row_idx: Vec<usize>,
+}
+
+impl Pattern {
+    pub(super) fn of<T>(a: SparseColMatRef<'_, T>) -> Self {
+        let base = a.col_ptr[0];
+        Pattern {
+            ncols: a.ncols,
+            col_ptr: a.col_ptr.iter().map(|&p| p - base).collect(),
+            row_idx: a.row_idx.to_vec(),
+        }
+    }
+
+    pub(super) fn col(&self, j: usize) -> &[usize] {
+        &self.row_idx[self.col_ptr[j]..self.col_ptr[j + 1]]
+    }
+
+    pub(super) fn check<T>(&self, a: SparseColMatRef<'_, T>) -> Result<()> {
+        let base = a.col_ptr[0];
+        let same = a.ncols == self.ncols
+            && a.row_idx == self.row_idx.as_slice()
+            && a.col_ptr
+                .iter()
+                .zip(&self.col_ptr)
+                .all(|(&p, &q)| p - base == q);
+        if same {
+            Ok(())
+        } else {
+            Err(Error::PatternMismatch)
+        }
+    }
+}
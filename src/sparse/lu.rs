@@ -0,0 +1,329 @@
+use num_traits::Zero;
+
+use super::col::Pattern;
+use super::ordering::{Ordering, check_permutation, colamd, invert_permutation};
+use super::{SparseColMat, SparseColMatRef};
+use crate::error::{Error, Result, check_len, check_square};
+use crate::matrix::{Mat, MatMut, MatRef};
+use crate::scalar::ComplexField;
+
+const NONE: usize = usize::MAX;
+
+#[derive(Debug, Clone)]
+pub struct SymbolicLu {
+    n: usize,
+    col_perm: Vec<usize>,
+    pattern: Pattern,
+}
+
+impl SymbolicLu {
+    pub fn new<T>(a: SparseColMatRef<'_, T>, ordering: Ordering) -> Result<Self> {
+        check_square(a.shape())?;
+        let n = a.ncols();
+        let col_perm = match ordering {
+            Ordering::Natural => (0..n).collect(),
+            Ordering::Amd => colamd(a),
+            Ordering::Custom(perm) => {
+                check_permutation(&perm, n)?;
+                perm
+            }
+        };
+        Ok(SymbolicLu {
+            n,
+            col_perm,
+            pattern: Pattern::of(a),
+        })
+    }
+
+    #[inline]
+    pub fn dim(&self) -> usize {
+        self.n
+    }
+
+    #[inline]
+    pub fn col_perm(&self) -> &[usize] {
+        &self.col_perm
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SparseLu<T> {
+    symbolic: SymbolicLu,
+    row_perm: Vec<usize>,
+    l: SparseColMat<T>,
+    u: SparseColMat<T>,
+}
+
+// Rows reachable from `rows` in the graph of the partially computed L, in
+// topological order. Row i leads to the rows of column row_perm_inv[i] of L.
+fn reach(
+    rows: &[usize],
+    l_ptr: &[usize],
+    l_idx: &[usize],
+    pinv: &[usize],
+    marked: &mut [bool],
+    stack: &mut Vec<(usize, usize)>,
+    out: &mut Vec<usize>,
+) {
+    out.clear();
+    let children = |i: usize| match pinv[i] {
+        NONE => 0..0,
+        j => l_ptr[j] + 1..l_ptr[j + 1],
+    };
+    for &root in rows {
+        if marked[root] {
+            continue;
+        }
+        marked[root] = true;
+        stack.push((root, children(root).start));
+        while let Some((node, pos)) = stack.last_mut() {
+            let end = children(*node).end;
+            let child = l_idx[*pos..end].iter().position(|&i| !marked[i]);
+            match child {
+                Some(offset) => {
+                    let i = l_idx[*pos + offset];
+                    *pos += offset + 1;
+                    marked[i] = true;
+                    stack.push((i, children(i).start));
+                }
+                None => {
+                    out.push(*node);
+                    stack.pop();
+                }
+            }
+        }
+    }
+    for &i in out.iter() {
+        marked[i] = false;
+    }
+    out.reverse();
+}
+
+fn sort_columns<T: Copy>(ptr: &[usize], idx: &mut [usize], values: &mut [T]) {
+    let mut entries = Vec::new();
+    for k in 0..ptr.len() - 1 {
+        let range = ptr[k]..ptr[k + 1];
+        entries.clear();
+        entries.extend(
+            idx[range.clone()]
+                .iter()
+                .copied()
+                .zip(values[range.clone()].iter().copied()),
+        );
+        entries.sort_unstable_by_key(|&(i, _)| i);
+        for (p, (i, value)) in range.zip(entries.iter().copied()) {
+            idx[p] = i;
+            values[p] = value;
+        }
+    }
+}
+
+impl<T: ComplexField> SparseLu<T> {
+    pub fn new(a: SparseColMatRef<'_, T>, ordering: Ordering) -> Result<Self> {
+        let symbolic = SymbolicLu::new(a, ordering)?;
+        Self::with_symbolic(symbolic, a)
+    }
+
+    pub fn with_symbolic(symbolic: SymbolicLu, a: SparseColMatRef<'_, T>) -> Result<Self> {
+        let n = symbolic.n;
+        let mut lu = SparseLu {
+            symbolic,
+            row_perm: Vec::new(),
+            l: SparseColMat::zeros(n, n),
+            u: SparseColMat::zeros(n, n),
+        };
+        lu.refactor(a)?;
+        Ok(lu)
+    }
+
+    pub fn refactor(&mut self, a: SparseColMatRef<'_, T>) -> Result<()> {
+        self.symbolic.pattern.check(a)?;
+        let n = self.symbolic.n;
+        let q = &self.symbolic.col_perm;
+
+        let mut pinv = vec![NONE; n];
+        let (mut l_ptr, mut l_idx, mut l_val) = (vec![0], Vec::new(), Vec::new());
+        let (mut u_ptr, mut u_idx, mut u_val) = (vec![0], Vec::new(), Vec::new());
+        let mut x = vec![T::zero(); n];
+        let mut marked = vec![false; n];
+        let mut stack = Vec::new();
+        let mut pattern = Vec::new();
+
+        for (k, &col) in q.iter().enumerate() {
+            let (rows, values) = a.col(col);
+            reach(
+                rows,
+                &l_ptr,
+                &l_idx,
+                &pinv,
+                &mut marked,
+                &mut stack,
+                &mut pattern,
+            );
+            for (&i, &value) in rows.iter().zip(values) {
+                x[i] = value;
+            }
+            for &i in &pattern {
+                let j = pinv[i];
+                if j == NONE {
+                    continue;
+                }
+                let t = x[i];
+                if t.is_zero() {
+                    continue;
+                }
+                for p in l_ptr[j] + 1..l_ptr[j + 1] {
+                    x[l_idx[p]] -= l_val[p] * t;
+                }
+            }
+
+            let mut pivot = NONE;
+            let mut best = T::Real::zero();
+            for &i in &pattern {
+                if pinv[i] == NONE {
+                    let t = x[i].modulus();
+                    if pivot == NONE || t > best {
+                        pivot = i;
+                        best = t;
+                    }
+                } else {
+                    u_idx.push(pinv[i]);
+                    u_val.push(x[i]);
+                }
+            }
+            if pivot == NONE || best <= T::Real::zero() {
+                for &i in &pattern {
+                    x[i] = T::zero();
+                }
+                return Err(Error::Singular { index: col });
+            }
+            if pinv[col] == NONE && x[col].modulus() >= best {
+                pivot = col;
+            }
+
+            let d = x[pivot];
+            u_idx.push(k);
+            u_val.push(d);
+            u_ptr.push(u_idx.len());
+            pinv[pivot] = k;
+            l_idx.push(pivot);
+            l_val.push(T::one());
+            for &i in &pattern {
+                if pinv[i] == NONE {
+                    l_idx.push(i);
+                    l_val.push(x[i] / d);
+                }
+                x[i] = T::zero();
+            }
+            l_ptr.push(l_idx.len());
+        }
+
+        for i in &mut l_idx {
+            *i = pinv[*i];
+        }
+        sort_columns(&l_ptr, &mut l_idx, &mut l_val);
+        sort_columns(&u_ptr, &mut u_idx, &mut u_val);
+        self.row_perm = invert_permutation(&pinv);
+        self.l = SparseColMat {
+            nrows: n,
+            ncols: n,
+            col_ptr: l_ptr,
+            row_idx: l_idx,
+            values: l_val,
+        };
+        self.u = SparseColMat {
+            nrows: n,
+            ncols: n,
+            col_ptr: u_ptr,
+            row_idx: u_idx,
+            values: u_val,
+        };
+        Ok(())
+    }
+
+    #[inline]
+    pub fn dim(&self) -> usize {
+        self.symbolic.n
+    }
+
+    #[inline]
+    pub fn symbolic(&self) -> &SymbolicLu {
+        &self.symbolic
+    }
+
+    #[inline]
+    pub fn l(&self) -> SparseColMatRef<'_, T> {
+        self.l.as_ref()
+    }
+
+    #[inline]
+    pub fn u(&self) -> SparseColMatRef<'_, T> {
+        self.u.as_ref()
+    }
+
+    #[inline]
+    pub fn row_perm(&self) -> &[usize] {
+        &self.row_perm
+    }
+
+    #[inline]
+    pub fn col_perm(&self) -> &[usize] {
+        &self.symbolic.col_perm
+    }
+
+    pub fn solve(&self, b: MatRef<'_, T>) -> Result<Mat<T>> {
+        let mut x = b.to_owned();
+        self.solve_in_place(x.as_mut())?;
+        Ok(x)
+    }
+
+    pub fn solve_in_place(&self, mut b: MatMut<'_, T>) -> Result<()> {
+        let n = self.dim();
+        check_len(n, b.nrows())?;
+        let q = &self.symbolic.col_perm;
+        let (l_ptr, l_idx, l_val) = (&self.l.col_ptr, &self.l.row_idx, &self.l.values);
+        let (u_ptr, u_idx, u_val) = (&self.u.col_ptr, &self.u.row_idx, &self.u.values);
+        let mut x = vec![T::zero(); n];
+        for j in 0..b.ncols() {
+            for (k, &i) in self.row_perm.iter().enumerate() {
+                x[k] = *b.at(i, j);
+            }
+            for k in 0..n {
+                let t = x[k];
+                if t.is_zero() {
+                    continue;
+                }
+                for p in l_ptr[k] + 1..l_ptr[k + 1] {
+                    x[l_idx[p]] -= l_val[p] * t;
+                }
+            }
+            for k in (0..n).rev() {
+                let diag = u_ptr[k + 1] - 1;
+                let t = x[k] / u_val[diag];
+                x[k] = t;
+                if t.is_zero() {
+                    continue;
+                }
+                for p in u_ptr[k]..diag {
+                    x[u_idx[p]] -= u_val[p] * t;
+                }
+            }
+            for (k, &i) in q.iter().enumerate() {
+                *b.at_mut(i, j) = x[k];
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: ComplexField> SparseColMatRef<'_, T> {
+    pub fn lu(self, ordering: Ordering) -> Result<SparseLu<T>> {
+        SparseLu::new(self, ordering)
+    }
+}
+
+impl<T: ComplexField> SparseColMat<T> {
+    pub fn lu(&self, ordering: Ordering) -> Result<SparseLu<T>> {
+        self.as_ref().lu(ordering)
+    }
+}
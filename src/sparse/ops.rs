@@ -0,0 +1,380 @@
+use std::ops::{Add, Mul, Sub};
+
+use num_traits::Zero;
+
+use super::{SparseColMat, SparseColMatRef, SparseRowMat, SparseRowMatRef};
+use crate::error::{Error, OrPanic, Result, check_shape};
+use crate::matrix::{Mat, MatRef};
+
+fn check_product(lhs: (usize, usize), rhs: (usize, usize)) -> Result<()> {
+    if lhs.1 != rhs.0 {
+        return Err(Error::ShapeMismatch { lhs, rhs });
+    }
+    Ok(())
+}
+
+fn merge<T, F>(lhs: SparseColMatRef<'_, T>, rhs: SparseColMatRef<'_, T>, f: F) -> SparseColMat<T>
+where
+    T: Copy + Zero,
+    F: Fn(T, T) -> T,
+{
+    let mut col_ptr = Vec::with_capacity(lhs.ncols() + 1);
+    let mut row_idx = Vec::with_capacity(lhs.nnz().max(rhs.nnz()));
+    let mut values = Vec::with_capacity(lhs.nnz().max(rhs.nnz()));
+    col_ptr.push(0);
+    for j in 0..lhs.ncols() {
+        let (a_rows, a_values) = lhs.col(j);
+        let (b_rows, b_values) = rhs.col(j);
+        let (mut p, mut q) = (0, 0);
+        while p < a_rows.len() || q < b_rows.len() {
+            let a_row = a_rows.get(p).copied().unwrap_or(usize::MAX);
+            let b_row = b_rows.get(q).copied().unwrap_or(usize::MAX);
+            if a_row < b_row {
+                row_idx.push(a_row);
+                values.push(f(a_values[p], T::zero()));
+                p += 1;
+            } else if b_row < a_row {
+                row_idx.push(b_row);
+                values.push(f(T::zero(), b_values[q]));
+                q += 1;
+            } else {
+                row_idx.push(a_row);
+                values.push(f(a_values[p], b_values[q]));
+                p += 1;
+                q += 1;
+            }
+        }
+        col_ptr.push(row_idx.len());
+    }
+    SparseColMat {
+        nrows: lhs.nrows(),
+        ncols: lhs.ncols(),
+        col_ptr,
+        row_idx,
+        values,
+    }
+}
+
+impl<T: Copy + Zero + Add<Output = T>> SparseColMatRef<'_, T> {
+    pub fn try_add(self, rhs: SparseColMatRef<'_, T>) -> Result<SparseColMat<T>> {
+        check_shape(self.shape(), rhs.shape())?;
+        Ok(merge(self, rhs, |a, b| a + b))
+    }
+}
+
+impl<T: Copy + Zero + Sub<Output = T>> SparseColMatRef<'_, T> {
+    pub fn try_sub(self, rhs: SparseColMatRef<'_, T>) -> Result<SparseColMat<T>> {
+        check_shape(self.shape(), rhs.shape())?;
+        Ok(merge(self, rhs, |a, b| a - b))
+    }
+}
+
+impl<T: Copy + Zero + Add<Output = T>> SparseRowMatRef<'_, T> {
+    pub fn try_add(self, rhs: SparseRowMatRef<'_, T>) -> Result<SparseRowMat<T>> {
+        check_shape(self.shape(), rhs.shape())?;
+        Ok(merge(self.transpose(), rhs.transpose(), |a, b| a + b).into_transpose())
+    }
+}
+
+impl<T: Copy + Zero + Sub<Output = T>> SparseRowMatRef<'_, T> {
+    pub fn try_sub(self, rhs: SparseRowMatRef<'_, T>) -> Result<SparseRowMat<T>> {
+        check_shape(self.shape(), rhs.shape())?;
+        Ok(merge(self.transpose(), rhs.transpose(), |a, b| a - b).into_transpose())
+    }
+}
+
+fn sparse_matmul<T>(lhs: SparseColMatRef<'_, T>, rhs: SparseColMatRef<'_, T>) -> SparseColMat<T>
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    let (m, n) = (lhs.nrows(), rhs.ncols());
+    let mut col_ptr = Vec::with_capacity(n + 1);
+    let mut row_idx = Vec::new();
+    let mut values = Vec::new();
+    let mut acc = vec![T::zero(); m];
+    let mut mark = vec![usize::MAX; m];
+    let mut pattern = Vec::new();
+    col_ptr.push(0);
+    for j in 0..n {
+        pattern.clear();
+        let (b_rows, b_values) = rhs.col(j);
+        for (&k, &b) in b_rows.iter().zip(b_values) {
+            let (a_rows, a_values) = lhs.col(k);
+            for (&i, &a) in a_rows.iter().zip(a_values) {
+                if mark[i] != j {
+                    mark[i] = j;
+                    acc[i] = a * b;
+                    pattern.push(i);
+                } else {
+                    acc[i] = acc[i] + a * b;
+                }
+            }
+        }
+        pattern.sort_unstable();
+        for &i in &pattern {
+            row_idx.push(i);
+            values.push(acc[i]);
+        }
+        col_ptr.push(row_idx.len());
+    }
+    SparseColMat {
+        nrows: m,
+        ncols: n,
+        col_ptr,
+        row_idx,
+        values,
+    }
+}
+
+impl<T> SparseColMatRef<'_, T>
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    pub fn try_mul(self, rhs: SparseColMatRef<'_, T>) -> Result<SparseColMat<T>> {
+        check_product(self.shape(), rhs.shape())?;
+        Ok(sparse_matmul(self, rhs))
+    }
+
+    pub fn try_mul_dense(self, rhs: MatRef<'_, T>) -> Result<Mat<T>> {
+        check_product(self.shape(), rhs.shape())?;
+        let mut out = Mat::zeros(self.nrows(), rhs.ncols());
+        for c in 0..rhs.ncols() {
+            for k in 0..self.ncols() {
+                let b = rhs[(k, c)];
+                let (rows, values) = self.col(k);
+                for (&i, &a) in rows.iter().zip(values) {
+                    out[(i, c)] = out[(i, c)] + a * b;
+                }
+            }
+        }
+        Ok(out)
+    }
+}
+
+impl<T> SparseRowMatRef<'_, T>
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    pub fn try_mul(self, rhs: SparseRowMatRef<'_, T>) -> Result<SparseRowMat<T>> {
+        check_product(self.shape(), rhs.shape())?;
+        Ok(sparse_matmul(rhs.transpose(), self.transpose()).into_transpose())
+    }
+
+    pub fn try_mul_dense(self, rhs: MatRef<'_, T>) -> Result<Mat<T>> {
+        check_product(self.shape(), rhs.shape())?;
+        let mut out = Mat::zeros(self.nrows(), rhs.ncols());
+        for c in 0..rhs.ncols() {
+            for i in 0..self.nrows() {
+                let (cols, values) = self.row(i);
+                out[(i, c)] = cols
+                    .iter()
+                    .zip(values)
+                    .fold(T::zero(), |acc, (&k, &a)| acc + a * rhs[(k, c)]);
+            }
+        }
+        Ok(out)
+    }
+}
+
+macro_rules! impl_sparse_forward {
+    ($Owned:ident, $try_fn:ident, $rhs:ty, $out:ty, [$($bound:tt)+]) => {
+        impl<T: $($bound)+> $Owned<T> {
+            pub fn $try_fn(&self, rhs: $rhs) -> Result<$out> {
+                self.as_ref().$try_fn(rhs)
+            }
+        }
+    };
+}
+
+impl_sparse_forward!(
+    SparseColMat,
+    try_add,
+    SparseColMatRef<'_, T>,
+    SparseColMat<T>,
+    [Copy + Zero + Add<Output = T>]
+);
+impl_sparse_forward!(
+    SparseColMat,
+    try_sub,
+    SparseColMatRef<'_, T>,
+    SparseColMat<T>,
+    [Copy + Zero + Sub<Output = T>]
+);
+impl_sparse_forward!(
+    SparseColMat,
+    try_mul,
+    SparseColMatRef<'_, T>,
+    SparseColMat<T>,
+    [Copy + Zero + Add<Output = T> + Mul<Output = T>]
+);
+impl_sparse_forward!(
+    SparseColMat,
+    try_mul_dense,
+    MatRef<'_, T>,
+    Mat<T>,
+    [Copy + Zero + Add<Output = T> + Mul<Output = T>]
+);
+impl_sparse_forward!(
+    SparseRowMat,
+    try_add,
+    SparseRowMatRef<'_, T>,
+    SparseRowMat<T>,
+    [Copy + Zero + Add<Output = T>]
+);
+impl_sparse_forward!(
+    SparseRowMat,
+    try_sub,
+    SparseRowMatRef<'_, T>,
+    SparseRowMat<T>,
+    [Copy + Zero + Sub<Output = T>]
+);
+impl_sparse_forward!(
+    SparseRowMat,
+    try_mul,
+    SparseRowMatRef<'_, T>,
+    SparseRowMat<T>,
+    [Copy + Zero + Add<Output = T> + Mul<Output = T>]
+);
+impl_sparse_forward!(
+    SparseRowMat,
+    try_mul_dense,
+    MatRef<'_, T>,
+    Mat<T>,
+    [Copy + Zero + Add<Output = T> + Mul<Output = T>]
+);
+
+macro_rules! impl_sparse_binop {
+    ($OpTrait:ident, $op_fn:ident, $try_fn:ident, $Owned:ident, $Ref:ident, [$($bound:tt)+]) => {
+        impl<T: $($bound)+> $OpTrait<$Ref<'_, T>> for $Ref<'_, T> {
+            type Output = $Owned<T>;
+            #[track_caller]
+            fn $op_fn(self, rhs: $Ref<'_, T>) -> $Owned<T> {
+                self.$try_fn(rhs).or_panic()
+            }
+        }
+
+        impl<T: $($bound)+> $OpTrait<&$Owned<T>> for &$Owned<T> {
+            type Output = $Owned<T>;
+            #[track_caller]
+            fn $op_fn(self, rhs: &$Owned<T>) -> $Owned<T> {
+                self.as_ref().$try_fn(rhs.as_ref()).or_panic()
+            }
+        }
+
+        impl<T: $($bound)+> $OpTrait<&$Owned<T>> for $Ref<'_, T> {
+            type Output = $Owned<T>;
+            #[track_caller]
+            fn $op_fn(self, rhs: &$Owned<T>) -> $Owned<T> {
+                self.$try_fn(rhs.as_ref()).or_panic()
+            }
+        }
+
+        impl<T: $($bound)+> $OpTrait<$Ref<'_, T>> for &$Owned<T> {
+            type Output = $Owned<T>;
+            #[track_caller]
+            fn $op_fn(self, rhs: $Ref<'_, T>) -> $Owned<T> {
+                self.as_ref().$try_fn(rhs).or_panic()
+            }
+        }
+    };
+}
+
+impl_sparse_binop!(
+    Add,
+    add,
+    try_add,
+    SparseColMat,
+    SparseColMatRef,
+    [Copy + Zero + Add<Output = T>]
+);
+impl_sparse_binop!(
+    Sub,
+    sub,
+    try_sub,
+    SparseColMat,
+    SparseColMatRef,
+    [Copy + Zero + Sub<Output = T>]
+);
+impl_sparse_binop!(
+    Mul,
+    mul,
+    try_mul,
+    SparseColMat,
+    SparseColMatRef,
+    [Copy + Zero + Add<Output = T> + Mul<Output = T>]
+);
+impl_sparse_binop!(
+    Add,
+    add,
+    try_add,
+    SparseRowMat,
+    SparseRowMatRef,
+    [Copy + Zero + Add<Output = T>]
+);
+impl_sparse_binop!(
+    Sub,
+    sub,
+    try_sub,
+    SparseRowMat,
+    SparseRowMatRef,
+    [Copy + Zero + Sub<Output = T>]
+);
+impl_sparse_binop!(
+    Mul,
+    mul,
+    try_mul,
+    SparseRowMat,
+    SparseRowMatRef,
+    [Copy + Zero + Add<Output = T> + Mul<Output = T>]
+);
+
+macro_rules! impl_sparse_dense_mul {
+    ($Owned:ident, $Ref:ident) => {
+        impl<T> Mul<MatRef<'_, T>> for $Ref<'_, T>
+        where
+            T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+        {
+            type Output = Mat<T>;
+            #[track_caller]
+            fn mul(self, rhs: MatRef<'_, T>) -> Mat<T> {
+                self.try_mul_dense(rhs).or_panic()
+            }
+        }
+
+        impl<T> Mul<&Mat<T>> for $Ref<'_, T>
+        where
+            T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+        {
+            type Output = Mat<T>;
+            #[track_caller]
+            fn mul(self, rhs: &Mat<T>) -> Mat<T> {
+                self.try_mul_dense(rhs.as_ref()).or_panic()
+            }
+        }
+
+        impl<T> Mul<MatRef<'_, T>> for &$Owned<T>
+        where
+            T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+        {
+            type Output = Mat<T>;
+            #[track_caller]
+            fn mul(self, rhs: MatRef<'_, T>) -> Mat<T> {
+                self.as_ref().try_mul_dense(rhs).or_panic()
+            }
+        }
+
+        impl<T> Mul<&Mat<T>> for &$Owned<T>
+        where
+            T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+        {
+            type Output = Mat<T>;
+            #[track_caller]
+            fn mul(self, rhs: &Mat<T>) -> Mat<T> {
+                self.as_ref().try_mul_dense(rhs.as_ref()).or_panic()
+            }
+        }
+    };
+}
+
+impl_sparse_dense_mul!(SparseColMat, SparseColMatRef);
+impl_sparse_dense_mul!(SparseRowMat, SparseRowMatRef);
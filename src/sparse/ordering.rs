@@ -0,0 +1,167 @@
+use std::collections::BTreeSet;
+
+use super::SparseColMatRef;
+use crate::error::{Result, check_len, check_square, format_error};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Ordering {
+    Natural,
+    #[default]
+    Amd,
+    Custom(Vec<usize>),
+}
+
+pub(super) fn check_permutation(perm: &[usize], n: usize) -> Result<()> {
+    check_len(n, perm.len())?;
+    let mut seen = vec![false; n];
+    for &p in perm {
+        if p >= n || seen[p] {
+            return Err(format_error(format!(
+                "ordering is not a permutation of 0..{}",
+                n
+            )));
+        }
+        seen[p] = true;
+    }
+    Ok(())
+}
+
+pub(super) fn invert_permutation(perm: &[usize]) -> Vec<usize> {
+    let mut inv = vec![0; perm.len()];
+    for (k, &p) in perm.iter().enumerate() {
+        inv[p] = k;
+    }
+    inv
+}
+
+pub fn amd<T>(a: SparseColMatRef<'_, T>) -> Result<Vec<usize>> {
+    check_square(a.shape())?;
+    let n = a.ncols();
+    let mut adj = vec![Vec::new(); n];
+    for j in 0..n {
+        for &i in a.col(j).0 {
+            if i != j {
+                adj[i].push(j);
+                adj[j].push(i);
+            }
+        }
+    }
+    for list in &mut adj {
+        list.sort_unstable();
+        list.dedup();
+    }
+    Ok(minimum_degree(adj, Vec::new()))
+}
+
+pub fn colamd<T>(a: SparseColMatRef<'_, T>) -> Vec<usize> {
+    let mut rows = vec![Vec::new(); a.nrows()];
+    for j in 0..a.ncols() {
+        for &i in a.col(j).0 {
+            rows[i].push(j);
+        }
+    }
+    rows.retain(|row| row.len() > 1);
+    minimum_degree(vec![Vec::new(); a.ncols()], rows)
+}
+
+// Approximate minimum degree on a quotient graph. Variables are 0..n; the
+// element created by eliminating variable p reuses id p, and the initial
+// elements (cliques, e.g. the rows of A for an AᵀA ordering) are n.. onwards.
+fn minimum_degree(mut adj: Vec<Vec<usize>>, cliques: Vec<Vec<usize>>) -> Vec<usize> {
+    let n = adj.len();
+    let nelem = n + cliques.len();
+    let mut members: Vec<Vec<usize>> = vec![Vec::new(); n];
+    members.extend(cliques);
+    let mut elems: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for (e, vars) in members.iter().enumerate().skip(n) {
+        for &v in vars {
+            elems[v].push(e);
+        }
+    }
+
+    let mut eliminated = vec![false; n];
+    let mut absorbed = vec![false; nelem];
+    let mut degree: Vec<usize> = (0..n)
+        .map(|i| {
+            let d = adj[i].len()
+                + elems[i]
+                    .iter()
+                    .map(|&e| members[e].len() - 1)
+                    .sum::<usize>();
+            d.min(n.saturating_sub(1))
+        })
+        .collect();
+    let mut queue: BTreeSet<(usize, usize)> = (0..n).map(|i| (degree[i], i)).collect();
+    let mut mark = vec![usize::MAX; n];
+    let mut stamp = vec![usize::MAX; nelem];
+    let mut outside = vec![0; nelem];
+    let mut perm = Vec::with_capacity(n);
+
+    for step in 0..n {
+        let (_, p) = queue.pop_first().unwrap();
+        eliminated[p] = true;
+        perm.push(p);
+
+        let mut lp = Vec::new();
+        mark[p] = step;
+        for &v in &adj[p] {
+            if !eliminated[v] && mark[v] != step {
+                mark[v] = step;
+                lp.push(v);
+            }
+        }
+        for &e in &elems[p] {
+            if absorbed[e] {
+                continue;
+            }
+            for &v in &members[e] {
+                if !eliminated[v] && mark[v] != step {
+                    mark[v] = step;
+                    lp.push(v);
+                }
+            }
+            absorbed[e] = true;
+            members[e] = Vec::new();
+        }
+        adj[p] = Vec::new();
+        elems[p] = Vec::new();
+
+        for &i in &lp {
+            queue.remove(&(degree[i], i));
+            adj[i].retain(|&v| !eliminated[v] && mark[v] != step);
+            elems[i].retain(|&e| !absorbed[e]);
+        }
+
+        for &i in &lp {
+            for &e in &elems[i] {
+                if stamp[e] != step {
+                    stamp[e] = step;
+                    outside[e] = members[e].len();
+                }
+                outside[e] -= 1;
+            }
+        }
+        for &i in &lp {
+            for &e in &elems[i] {
+                if outside[e] == 0 && !absorbed[e] {
+                    absorbed[e] = true;
+                    members[e] = Vec::new();
+                }
+            }
+        }
+
+        let remaining = n - step - 1;
+        for &i in &lp {
+            elems[i].retain(|&e| !absorbed[e]);
+            let external: usize = elems[i].iter().map(|&e| outside[e]).sum();
+            let d = (adj[i].len() + lp.len() - 1 + external)
+                .min(degree[i] + lp.len() - 1)
+                .min(remaining - 1);
+            elems[i].push(p);
+            degree[i] = d;
+            queue.insert((d, i));
+        }
+        members[p] = lp;
+    }
+    perm
+}
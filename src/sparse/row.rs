@@ -0,0 +1,343 @@
+use std::ops::{Add, Range};
+
+use num_traits::{One, Zero};
+
+use super::col::{check_structure, compress, transpose_structure};
+use super::{SparseColMat, SparseColMatRef, SparseRowMat, SparseRowMatRef};
+use crate::error::{Axis, OrPanic, Result, check_index, check_range};
+use crate::matrix::{Mat, MatRef};
+
+impl<'a, T> SparseRowMatRef<'a, T> {
+    #[track_caller]
+    pub fn new(
+        nrows: usize,
+        ncols: usize,
+        row_ptr: &'a [usize],
+        col_idx: &'a [usize],
+        values: &'a [T],
+    ) -> Self {
+        Self::try_new(nrows, ncols, row_ptr, col_idx, values).or_panic()
+    }
+
+    pub fn try_new(
+        nrows: usize,
+        ncols: usize,
+        row_ptr: &'a [usize],
+        col_idx: &'a [usize],
+        values: &'a [T],
+    ) -> Result<Self> {
+        check_structure(nrows, ncols, row_ptr, col_idx, values.len(), Axis::Col)?;
+        Ok(SparseRowMatRef {
+            nrows,
+            ncols,
+            row_ptr,
+            col_idx,
+            values,
+        })
+    }
+
+    #[inline]
+    pub fn nrows(self) -> usize {
+        self.nrows
+    }
+
+    #[inline]
+    pub fn ncols(self) -> usize {
+        self.ncols
+    }
+
+    #[inline]
+    pub fn shape(self) -> (usize, usize) {
+        (self.nrows, self.ncols)
+    }
+
+    #[inline]
+    pub fn nnz(self) -> usize {
+        self.values.len()
+    }
+
+    #[inline]
+    pub fn col_indices(self) -> &'a [usize] {
+        self.col_idx
+    }
+
+    #[inline]
+    pub fn values(self) -> &'a [T] {
+        self.values
+    }
+
+    #[inline]
+    #[track_caller]
+    pub fn row_range(self, i: usize) -> Range<usize> {
+        self.try_row_range(i).or_panic()
+    }
+
+    #[inline]
+    pub fn try_row_range(self, i: usize) -> Result<Range<usize>> {
+        check_index(Axis::Row, i, self.nrows)?;
+        let base = self.row_ptr[0];
+        Ok(self.row_ptr[i] - base..self.row_ptr[i + 1] - base)
+    }
+
+    #[inline]
+    #[track_caller]
+    pub fn row(self, i: usize) -> (&'a [usize], &'a [T]) {
+        self.try_row(i).or_panic()
+    }
+
+    #[inline]
+    pub fn try_row(self, i: usize) -> Result<(&'a [usize], &'a [T])> {
+        let range = self.try_row_range(i)?;
+        Ok((&self.col_idx[range.clone()], &self.values[range]))
+    }
+
+    pub fn get(self, i: usize, j: usize) -> Option<&'a T> {
+        self.transpose().get(j, i)
+    }
+
+    pub fn triplet_iter(self) -> impl Iterator<Item = (usize, usize, &'a T)> {
+        self.transpose().triplet_iter().map(|(j, i, v)| (i, j, v))
+    }
+
+    #[inline]
+    pub fn transpose(self) -> SparseColMatRef<'a, T> {
+        SparseColMatRef {
+            nrows: self.ncols,
+            ncols: self.nrows,
+            col_ptr: self.row_ptr,
+            row_idx: self.col_idx,
+            values: self.values,
+        }
+    }
+
+    #[track_caller]
+    pub fn rows_range(self, range: Range<usize>) -> SparseRowMatRef<'a, T> {
+        self.try_rows_range(range).or_panic()
+    }
+
+    pub fn try_rows_range(self, range: Range<usize>) -> Result<SparseRowMatRef<'a, T>> {
+        check_range(Axis::Row, range.start, range.end, self.nrows)?;
+        Ok(self.transpose().try_cols_range(range)?.transpose())
+    }
+}
+
+impl<T: Clone> SparseRowMatRef<'_, T> {
+    pub fn to_owned(self) -> SparseRowMat<T> {
+        self.transpose().to_owned().into_transpose()
+    }
+
+    pub fn to_col_mat(self) -> SparseColMat<T> {
+        let (col_ptr, row_idx, perm) =
+            transpose_structure(self.nrows, self.ncols, self.row_ptr, self.col_idx);
+        SparseColMat {
+            nrows: self.nrows,
+            ncols: self.ncols,
+            col_ptr,
+            row_idx,
+            values: perm.iter().map(|&p| self.values[p].clone()).collect(),
+        }
+    }
+}
+
+impl<T: Clone + Zero> SparseRowMatRef<'_, T> {
+    pub fn to_dense(self) -> Mat<T> {
+        let mut out = Mat::zeros(self.nrows, self.ncols);
+        for (i, j, value) in self.triplet_iter() {
+            out[(i, j)] = value.clone();
+        }
+        out
+    }
+}
+
+impl<T> SparseRowMat<T> {
+    #[track_caller]
+    pub fn new(
+        nrows: usize,
+        ncols: usize,
+        row_ptr: Vec<usize>,
+        col_idx: Vec<usize>,
+        values: Vec<T>,
+    ) -> Self {
+        Self::try_new(nrows, ncols, row_ptr, col_idx, values).or_panic()
+    }
+
+    pub fn try_new(
+        nrows: usize,
+        ncols: usize,
+        row_ptr: Vec<usize>,
+        col_idx: Vec<usize>,
+        values: Vec<T>,
+    ) -> Result<Self> {
+        check_structure(nrows, ncols, &row_ptr, &col_idx, values.len(), Axis::Col)?;
+        Ok(SparseRowMat {
+            nrows,
+            ncols,
+            row_ptr,
+            col_idx,
+            values,
+        })
+    }
+
+    pub fn zeros(nrows: usize, ncols: usize) -> Self {
+        SparseColMat::zeros(ncols, nrows).into_transpose()
+    }
+
+    #[inline]
+    pub fn as_ref(&self) -> SparseRowMatRef<'_, T> {
+        SparseRowMatRef {
+            nrows: self.nrows,
+            ncols: self.ncols,
+            row_ptr: &self.row_ptr,
+            col_idx: &self.col_idx,
+            values: &self.values,
+        }
+    }
+
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    #[inline]
+    pub fn shape(&self) -> (usize, usize) {
+        (self.nrows, self.ncols)
+    }
+
+    #[inline]
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    #[inline]
+    pub fn row_ptr(&self) -> &[usize] {
+        &self.row_ptr
+    }
+
+    #[inline]
+    pub fn col_indices(&self) -> &[usize] {
+        &self.col_idx
+    }
+
+    #[inline]
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    #[inline]
+    pub fn values_mut(&mut self) -> &mut [T] {
+        &mut self.values
+    }
+
+    #[inline]
+    #[track_caller]
+    pub fn row(&self, i: usize) -> (&[usize], &[T]) {
+        self.as_ref().row(i)
+    }
+
+    #[inline]
+    pub fn try_row(&self, i: usize) -> Result<(&[usize], &[T])> {
+        self.as_ref().try_row(i)
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        self.as_ref().get(i, j)
+    }
+
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        if i >= self.nrows || j >= self.ncols {
+            return None;
+        }
+        let range = self.row_ptr[i]..self.row_ptr[i + 1];
+        let p = self.col_idx[range.clone()].binary_search(&j).ok()?;
+        Some(&mut self.values[range.start + p])
+    }
+
+    pub fn triplet_iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.as_ref().triplet_iter()
+    }
+
+    #[inline]
+    pub fn transpose(&self) -> SparseColMatRef<'_, T> {
+        self.as_ref().transpose()
+    }
+
+    pub fn into_transpose(self) -> SparseColMat<T> {
+        SparseColMat {
+            nrows: self.ncols,
+            ncols: self.nrows,
+            col_ptr: self.row_ptr,
+            row_idx: self.col_idx,
+            values: self.values,
+        }
+    }
+
+    pub fn into_parts(self) -> (Vec<usize>, Vec<usize>, Vec<T>) {
+        (self.row_ptr, self.col_idx, self.values)
+    }
+
+    #[track_caller]
+    pub fn rows_range(&self, range: Range<usize>) -> SparseRowMatRef<'_, T> {
+        self.as_ref().rows_range(range)
+    }
+
+    pub fn try_rows_range(&self, range: Range<usize>) -> Result<SparseRowMatRef<'_, T>> {
+        self.as_ref().try_rows_range(range)
+    }
+}
+
+impl<T: Clone + Add<Output = T>> SparseRowMat<T> {
+    #[track_caller]
+    pub fn from_triplets(nrows: usize, ncols: usize, triplets: &[(usize, usize, T)]) -> Self {
+        Self::try_from_triplets(nrows, ncols, triplets).or_panic()
+    }
+
+    pub fn try_from_triplets(
+        nrows: usize,
+        ncols: usize,
+        triplets: &[(usize, usize, T)],
+    ) -> Result<Self> {
+        for &(i, j, _) in triplets {
+            check_index(Axis::Row, i, nrows)?;
+            check_index(Axis::Col, j, ncols)?;
+        }
+        let entries = triplets
+            .iter()
+            .map(|(i, j, value)| (*i, *j, value.clone()))
+            .collect();
+        let (row_ptr, col_idx, values) = compress(nrows, entries);
+        Ok(SparseRowMat {
+            nrows,
+            ncols,
+            row_ptr,
+            col_idx,
+            values,
+        })
+    }
+}
+
+impl<T: Clone> SparseRowMat<T> {
+    pub fn to_col_mat(&self) -> SparseColMat<T> {
+        self.as_ref().to_col_mat()
+    }
+}
+
+impl<T: Clone + Zero> SparseRowMat<T> {
+    pub fn from_dense(mat: MatRef<'_, T>) -> Self {
+        SparseColMat::from_dense(mat.transpose()).into_transpose()
+    }
+
+    pub fn to_dense(&self) -> Mat<T> {
+        self.as_ref().to_dense()
+    }
+}
+
+impl<T: Clone + One> SparseRowMat<T> {
+    pub fn identity(n: usize) -> Self {
+        SparseColMat::identity(n).into_transpose()
+    }
+}
@@ -43,7 +43,7 @@ fn mean_single() {
 }
 
 #[test]
-#[should_panic(expected = "cannot compute mean of empty matrix")]
+#[should_panic(expected = "operation requires a non-empty matrix")]
 fn mean_empty_panics() {
     let a: Mat<f64> = Mat::new();
     let _ = a.mean();
@@ -77,7 +77,7 @@ fn min_negative() {
 }
 
 #[test]
-#[should_panic(expected = "cannot compute min of empty matrix")]
+#[should_panic(expected = "operation requires a non-empty matrix")]
 fn min_empty_panics() {
     let a: Mat<i32> = Mat::new();
     let _ = a.min();
@@ -276,14 +276,14 @@ fn sort_cols_by_row_second() {
 }
 
 #[test]
-#[should_panic(expected = "column index 2 out of bounds")]
+#[should_panic(expected = "Column index 2 out of bounds for 2 columns")]
 fn sort_rows_by_col_out_of_bounds() {
     let a = mat![[1, 2], [3, 4]];
     let _ = a.sort_rows_by_col(2);
 }
 
 #[test]
-#[should_panic(expected = "row index 2 out of bounds")]
+#[should_panic(expected = "Row index 2 out of bounds for 2 rows")]
 fn sort_cols_by_row_out_of_bounds() {
     let a = mat![[1, 2], [3, 4]];
     let _ = a.sort_cols_by_row(2);
@@ -0,0 +1,137 @@
+mod common;
+
+use aris::{Complex, Mat, RealField, assert_mat_approx_eq, mat};
+
+use common::c;
+
+#[test]
+fn approx_eq_absolute_tolerance() {
+    let a: Mat<f64> = mat![[0.0, 1.0], [2.0, 3.0]];
+    let b: Mat<f64> = mat![[1e-10, 1.0], [2.0, 3.0 - 1e-10]];
+    assert!(a.approx_eq(b.as_ref(), 1e-9, 0.0));
+    assert!(!a.approx_eq(b.as_ref(), 1e-11, 0.0));
+}
+
+#[test]
+fn approx_eq_relative_tolerance() {
+    let a: Mat<f64> = mat![[1e12, -5e8]];
+    let b: Mat<f64> = mat![[1e12 + 1.0, -5e8 - 0.4]];
+    assert!(a.approx_eq(b.as_ref(), 0.0, 1e-9));
+    assert!(!a.approx_eq(b.as_ref(), 0.0, 1e-13));
+    assert!(!a.approx_eq(b.as_ref(), 1e-3, 0.0));
+}
+
+#[test]
+fn approx_eq_shape_mismatch_is_false() {
+    let a: Mat<f64> = Mat::zeros(2, 3);
+    let b: Mat<f64> = Mat::zeros(3, 2);
+    assert!(!a.approx_eq(b.as_ref(), 1.0, 1.0));
+    assert!(!a.ulps_eq(b.as_ref(), 100));
+    assert!(!a.nan_eq(b.as_ref()));
+}
+
+#[test]
+fn approx_eq_special_values() {
+    let inf: Mat<f64> = mat![[f64::INFINITY, f64::NEG_INFINITY]];
+    assert!(inf.approx_eq(inf.as_ref(), 0.0, 0.0));
+    let flipped: Mat<f64> = mat![[f64::NEG_INFINITY, f64::INFINITY]];
+    assert!(!inf.approx_eq(flipped.as_ref(), 1.0, 1.0));
+    let finite: Mat<f64> = mat![[1e300, -1e300]];
+    assert!(!inf.approx_eq(finite.as_ref(), 1.0, 1.0));
+    let nan: Mat<f64> = mat![[f64::NAN]];
+    assert!(!nan.approx_eq(nan.as_ref(), 1.0, 1.0));
+}
+
+#[test]
+fn first_approx_mismatch_reports_index() {
+    let a: Mat<f64> = Mat::from_fn(3, 4, |i, j| (i + j) as f64);
+    let mut b = a.clone();
+    b[(2, 1)] += 0.5;
+    b[(0, 3)] += 0.5;
+    assert_eq!(a.first_approx_mismatch(a.as_ref(), 0.0, 0.0), None);
+    assert_eq!(a.first_approx_mismatch(b.as_ref(), 1e-3, 0.0), Some((2, 1)));
+    assert_eq!(a.first_approx_mismatch(b.as_ref(), 1.0, 0.0), None);
+}
+
+#[test]
+fn approx_eq_complex_uses_modulus() {
+    let a = mat![[c(1.0, 1.0), c(0.0, -2.0)]];
+    let b = mat![[c(1.0 + 3e-9, 1.0 - 4e-9), c(0.0, -2.0)]];
+    assert!(a.approx_eq(b.as_ref(), 5.1e-9, 0.0));
+    assert!(!a.approx_eq(b.as_ref(), 4.9e-9, 0.0));
+}
+
+#[test]
+fn ulps_distance_crosses_zero() {
+    assert_eq!(0.0f64.ulps_distance(-0.0), 0);
+    assert_eq!(1.0f64.ulps_distance(1.0 + f64::EPSILON), 1);
+    assert_eq!(1.0f32.ulps_distance(1.0 - f32::EPSILON / 2.0), 1);
+    let tiny = f64::from_bits(1);
+    assert_eq!(tiny.ulps_distance(-tiny), 2);
+    assert_eq!(f64::NAN.ulps_distance(f64::NAN), u64::MAX);
+}
+
+#[test]
+fn ulps_eq_real_and_complex() {
+    let a: Mat<f64> = mat![[1.0, 0.1 + 0.2]];
+    let b: Mat<f64> = mat![[1.0, 0.3]];
+    assert!(a.ulps_eq(b.as_ref(), 1));
+    assert!(!a.ulps_eq(b.as_ref(), 0));
+
+    let z = mat![[Complex::new(1.0f32, 2.0)]];
+    let w = mat![[Complex::new(1.0f32, 2.0 + 2.0 * f32::EPSILON)]];
+    assert!(z.ulps_eq(w.as_ref(), 1));
+    assert!(!z.ulps_eq(w.as_ref(), 0));
+}
+
+#[test]
+fn nan_eq_treats_nan_as_equal() {
+    let a: Mat<f64> = mat![[f64::NAN, 1.0], [2.0, f64::INFINITY]];
+    let b = a.clone();
+    assert_ne!(a, b);
+    assert!(a.nan_eq(b.as_ref()));
+    let c: Mat<f64> = mat![[f64::NAN, 1.0], [f64::NAN, f64::INFINITY]];
+    assert!(!a.nan_eq(c.as_ref()));
+}
+
+#[test]
+fn nan_eq_complex_parts() {
+    let a = mat![[c(f64::NAN, 1.0)]];
+    assert!(a.nan_eq(mat![[c(f64::NAN, 1.0)]].as_ref()));
+    assert!(!a.nan_eq(mat![[c(1.0, f64::NAN)]].as_ref()));
+}
+
+#[test]
+fn assert_macro_accepts_views_and_tolerances() {
+    let a: Mat<f64> = Mat::from_fn(3, 3, |i, j| (i * 3 + j) as f64);
+    let b = a.map(|&x| x + 1e-12);
+    assert_mat_approx_eq!(a, b);
+    assert_mat_approx_eq!(a.as_ref(), b.view(0, 0, 3, 3));
+    assert_mat_approx_eq!(a.transpose(), b.transpose(), 1e-11);
+    assert_mat_approx_eq!(a, b, abs_tol = 1e-11, rel_tol = 0.0);
+    let mut m = b.clone();
+    assert_mat_approx_eq!(m.as_mut(), a);
+}
+
+#[test]
+fn assert_macro_complex() {
+    let a = mat![[c(1.0, 2.0), c(3.0, -4.0)]];
+    let b = a.map(|&z| z * c(1.0, 1e-12));
+    assert_mat_approx_eq!(a, b, 1e-9);
+}
+
+#[test]
+#[should_panic(expected = "index: (1, 0)\n   left: 3.0\n  right: 3.5")]
+fn assert_macro_reports_offending_element() {
+    let a: Mat<f64> = mat![[1.0, 2.0], [3.0, 4.0]];
+    let b: Mat<f64> = mat![[1.0, 2.0], [3.5, 4.0]];
+    assert_mat_approx_eq!(a, b, 1e-6);
+}
+
+#[test]
+#[should_panic(expected = "shape mismatch: (2, 2) vs (2, 1)")]
+fn assert_macro_reports_shape_mismatch() {
+    let a: Mat<f64> = Mat::zeros(2, 2);
+    let b: Mat<f64> = Mat::zeros(2, 1);
+    assert_mat_approx_eq!(a, b);
+}
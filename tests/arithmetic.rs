@@ -535,6 +535,6 @@ fn sub_ref_mat_ref_mat() {
 #[should_panic(expected = "shape mismatch")]
 fn sub_shape_mismatch() {
     let a = mat![[1, 2], [3, 4]];
-    let b = mat![[1], [2]];
+    let b = mat![[1], [2], [3]];
     let _ = &a - &b;
 }
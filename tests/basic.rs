@@ -35,7 +35,7 @@ fn copy_from_full_matrix() {
 }
 
 #[test]
-#[should_panic(expected = "Shape mismatch")]
+#[should_panic(expected = "shape mismatch: (2, 2) vs (1, 3)")]
 fn copy_from_shape_mismatch() {
     let a = mat![[1, 2, 3]];
     let mut b = Mat::zeros(2, 2);
@@ -0,0 +1,114 @@
+mod common;
+
+use aris::{Mat, col, mat, row};
+
+use common::c;
+
+#[test]
+fn hadamard_same_shape() {
+    let a = mat![[1, 2], [3, 4]];
+    let b = mat![[5, 6], [7, 8]];
+    assert_eq!(a.hadamard(b.as_ref()), mat![[5, 12], [21, 32]]);
+    assert_eq!(a.elem_mul(b.as_ref()), a.hadamard(b.as_ref()));
+}
+
+#[test]
+fn elem_div_and_rem() {
+    let a = mat![[10, 21], [32, 43]];
+    let b = mat![[3, 4], [5, 6]];
+    assert_eq!(a.elem_div(b.as_ref()), mat![[3, 5], [6, 7]]);
+    assert_eq!(a.elem_rem(b.as_ref()), mat![[1, 1], [2, 1]]);
+}
+
+#[test]
+fn elem_pow_float_and_unsigned() {
+    let a: Mat<f64> = mat![[2.0, 9.0], [4.0, 1.0]];
+    let b: Mat<f64> = mat![[3.0, 0.5], [-1.0, 7.0]];
+    assert_eq!(a.elem_pow(b.as_ref()), mat![[8.0, 3.0], [0.25, 1.0]]);
+    let u: Mat<u32> = mat![[2, 3]];
+    assert_eq!(u.elem_pow(mat![[10u32, 2]].as_ref()), mat![[1024, 9]]);
+}
+
+#[test]
+fn subtract_column_means() {
+    let a: Mat<f64> = mat![[1.0, 10.0], [3.0, 20.0], [5.0, 30.0]];
+    let centered = &a - &a.mean_cols();
+    assert_eq!(centered, mat![[-2.0, -10.0], [0.0, 0.0], [2.0, 10.0]]);
+}
+
+#[test]
+fn add_column_vector_broadcasts_across_columns() {
+    let a = mat![[1, 2, 3], [4, 5, 6]];
+    let v = col![10, 20];
+    assert_eq!(&a + &v, mat![[11, 12, 13], [24, 25, 26]]);
+    assert_eq!(&v + &a, mat![[11, 12, 13], [24, 25, 26]]);
+}
+
+#[test]
+fn row_and_column_vectors_form_outer_grid() {
+    let r = row![1, 2, 3];
+    let v = col![10, 20];
+    assert_eq!(&v + &r, mat![[11, 12, 13], [21, 22, 23]]);
+    assert_eq!(v.hadamard(r.as_ref()), mat![[10, 20, 30], [20, 40, 60]]);
+}
+
+#[test]
+fn scalar_matrix_broadcasts() {
+    let a = mat![[1, 2], [3, 4]];
+    let s = mat![[100]];
+    assert_eq!(&a - &s, mat![[-99, -98], [-97, -96]]);
+    assert_eq!(a.elem_div(mat![[2]].as_ref()), mat![[0, 1], [1, 2]]);
+}
+
+#[test]
+fn broadcast_with_views() {
+    let a = mat![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    let first_row = a.view(0, 0, 1, 3);
+    let diff = a.as_ref() - first_row;
+    assert_eq!(diff, mat![[0, 0, 0], [3, 3, 3], [6, 6, 6]]);
+    let last_col = a.view(0, 2, 3, 1);
+    assert_eq!(
+        a.as_ref().elem_rem(last_col),
+        mat![[1, 2, 0], [4, 5, 0], [7, 8, 0]]
+    );
+}
+
+#[test]
+fn broadcast_complex() {
+    let a = mat![[c(1.0, 1.0), c(2.0, 0.0)], [c(0.0, 1.0), c(3.0, -1.0)]];
+    let scale = row![c(0.0, 1.0), c(2.0, 0.0)];
+    assert_eq!(
+        a.hadamard(scale.as_ref()),
+        mat![[c(-1.0, 1.0), c(4.0, 0.0)], [c(-1.0, 0.0), c(6.0, -2.0)]]
+    );
+}
+
+#[test]
+fn add_assign_broadcasts_rhs() {
+    let mut a = mat![[1, 2, 3], [4, 5, 6]];
+    a += &row![10, 20, 30];
+    assert_eq!(a, mat![[11, 22, 33], [14, 25, 36]]);
+    a -= &col![1, 2];
+    assert_eq!(a, mat![[10, 21, 32], [12, 23, 34]]);
+}
+
+#[test]
+#[should_panic(expected = "shape mismatch: (2, 1) vs (2, 3)")]
+fn add_assign_cannot_grow_lhs() {
+    let mut v = col![1, 2];
+    v += &mat![[1, 2, 3], [4, 5, 6]];
+}
+
+#[test]
+#[should_panic(expected = "shape mismatch: (2, 3) vs (3, 1)")]
+fn incompatible_broadcast_panics() {
+    let a = mat![[1, 2, 3], [4, 5, 6]];
+    let _ = a.hadamard(col![1, 2, 3].as_ref());
+}
+
+#[test]
+fn broadcast_empty_dimension() {
+    let a: Mat<f64> = Mat::zeros(0, 3);
+    let r: Mat<f64> = row![1.0, 2.0, 3.0];
+    assert_eq!((&a + &r).shape(), (0, 3));
+}
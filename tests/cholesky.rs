@@ -0,0 +1,193 @@
+mod common;
+
+use aris::linalg::Cholesky;
+use aris::{Complex, Error, Mat, mat};
+
+use common::c;
+
+fn spd(n: usize) -> Mat<f64> {
+    let b = Mat::from_fn(n, n, |i, j| ((i * 7 + j * 3) % 5) as f64 - 2.0);
+    b.transpose() * &b + Mat::identity(n) * (n as f64)
+}
+
+fn max_abs_diff(a: &Mat<f64>, b: &Mat<f64>) -> f64 {
+    (a - b).abs().max()
+}
+
+#[test]
+fn cholesky_reconstructs() {
+    let a = spd(5);
+    let chol = a.cholesky();
+    let l = chol.l().to_owned();
+    assert!(l.is_lower_triangular());
+    assert!(max_abs_diff(&(&l * l.transpose()), &a) < 1e-12);
+}
+
+#[test]
+fn cholesky_known_factor() {
+    let a = mat![
+        [4.0, 12.0, -16.0],
+        [12.0, 37.0, -43.0],
+        [-16.0, -43.0, 98.0]
+    ];
+    let l = Cholesky::new(a.as_ref()).into_l();
+    assert!(
+        max_abs_diff(
+            &l,
+            &mat![[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]]
+        ) < 1e-12
+    );
+}
+
+#[test]
+fn cholesky_reads_lower_triangle_only() {
+    let mut a = spd(4);
+    a[(0, 3)] = 1000.0;
+    a[(1, 2)] = -1000.0;
+    let chol = a.cholesky();
+    let reference = spd(4).cholesky();
+    assert_eq!(chol.l(), reference.l());
+}
+
+#[test]
+fn cholesky_not_positive_definite() {
+    let a = mat![[1.0, 2.0], [2.0, 1.0]];
+    assert!(matches!(
+        a.try_cholesky(),
+        Err(Error::NotPositiveDefinite { index: 1 })
+    ));
+    let b = mat![[-1.0, 0.0], [0.0, 1.0]];
+    assert!(matches!(
+        b.try_cholesky(),
+        Err(Error::NotPositiveDefinite { index: 0 })
+    ));
+}
+
+#[test]
+#[should_panic(expected = "not positive definite")]
+fn cholesky_indefinite_panics() {
+    let a = mat![[1.0, 2.0], [2.0, 1.0]];
+    let _ = a.cholesky();
+}
+
+#[test]
+fn cholesky_solve() {
+    let a = spd(6);
+    let b = Mat::from_fn(6, 2, |i, j| (i + 3 * j) as f64);
+    let x = a.cholesky().solve(b.as_ref()).unwrap();
+    assert!(max_abs_diff(&(&a * &x), &b) < 1e-10);
+}
+
+#[test]
+fn cholesky_det_and_log_det() {
+    let a: Mat<f64> = mat![[4.0, 2.0], [2.0, 3.0]];
+    let chol = a.cholesky();
+    assert!((chol.det() - 8.0).abs() < 1e-12);
+    assert!((chol.log_det() - 8.0f64.ln()).abs() < 1e-12);
+}
+
+#[test]
+fn cholesky_inverse() {
+    let a = spd(5);
+    let inv = a.cholesky().inverse();
+    assert!(max_abs_diff(&(&a * &inv), &Mat::identity(5)) < 1e-12);
+}
+
+#[test]
+fn cholesky_rank_one_update() {
+    let a = spd(5);
+    let x = Mat::from_fn(5, 1, |i, _| i as f64 - 1.5);
+    let mut chol = a.cholesky();
+    chol.rank_one_update(x.as_ref()).unwrap();
+    let expected = &a + &x * x.transpose();
+    let l = chol.l().to_owned();
+    assert!(max_abs_diff(&(&l * l.transpose()), &expected) < 1e-10);
+}
+
+#[test]
+fn cholesky_rank_one_downdate() {
+    let a = spd(5);
+    let x = Mat::from_fn(5, 1, |i, _| 0.3 * i as f64);
+    let updated = &a + &x * x.transpose();
+    let mut chol = updated.cholesky();
+    chol.rank_one_downdate(x.as_ref()).unwrap();
+    let reference = a.cholesky();
+    assert!(max_abs_diff(&chol.l().to_owned(), &reference.l().to_owned()) < 1e-10);
+}
+
+#[test]
+fn cholesky_downdate_loses_definiteness() {
+    let a: Mat<f64> = Mat::identity(3);
+    let x = mat![[0.0], [2.0], [0.0]];
+    let mut chol = a.cholesky();
+    assert!(matches!(
+        chol.rank_one_downdate(x.as_ref()),
+        Err(Error::NotPositiveDefinite { index: 1 })
+    ));
+    assert_eq!(chol.l(), Mat::<f64>::identity(3).as_ref());
+}
+
+#[test]
+fn cholesky_complex_hermitian() {
+    let a = mat![[c(4.0, 0.0), c(1.0, 2.0)], [c(1.0, -2.0), c(6.0, 0.0)]];
+    let chol = a.cholesky();
+    let l = chol.l().to_owned();
+    assert!((&l * l.adjoint() - &a).norm().max() < 1e-12);
+    assert!((chol.det() - 19.0).abs() < 1e-12);
+
+    let b = mat![[c(1.0, 1.0)], [c(0.0, -1.0)]];
+    let x = chol.solve(b.as_ref()).unwrap();
+    assert!((&a * &x - &b).norm().max() < 1e-12);
+}
+
+#[test]
+fn cholesky_complex_update() {
+    let a = mat![[c(4.0, 0.0), c(1.0, 2.0)], [c(1.0, -2.0), c(6.0, 0.0)]];
+    let x: Mat<Complex<f64>> = mat![[c(0.5, -1.0)], [c(2.0, 0.25)]];
+    let mut chol = a.cholesky();
+    chol.rank_one_update(x.as_ref()).unwrap();
+    let l = chol.l().to_owned();
+    let expected = &a + &x * x.adjoint();
+    assert!((&l * l.adjoint() - &expected).norm().max() < 1e-12);
+}
+
+#[test]
+fn cholesky_update_rejects_nan() {
+    let a = spd(3);
+    let x = mat![[1.0], [f64::NAN], [0.0]];
+    let mut chol = a.cholesky();
+    let before = chol.l().to_owned();
+    assert!(matches!(
+        chol.rank_one_update(x.as_ref()),
+        Err(Error::NotPositiveDefinite { index: 1 })
+    ));
+    assert_eq!(chol.l(), before.as_ref());
+}
+
+#[test]
+fn cholesky_shape_errors() {
+    let mut chol = spd(3).cholesky();
+    assert_eq!(
+        chol.solve(Mat::<f64>::zeros(2, 1).as_ref()).err(),
+        Some(Error::ShapeMismatch {
+            lhs: (2, 1),
+            rhs: (3, 1)
+        })
+    );
+    assert_eq!(
+        chol.rank_one_update(Mat::<f64>::zeros(3, 2).as_ref()).err(),
+        Some(Error::ShapeMismatch {
+            lhs: (3, 2),
+            rhs: (3, 1)
+        })
+    );
+}
+
+#[test]
+fn cholesky_non_square() {
+    let a: Mat<f64> = Mat::zeros(3, 2);
+    assert_eq!(
+        a.try_cholesky().err(),
+        Some(Error::NotSquare { nrows: 3, ncols: 2 })
+    );
+}
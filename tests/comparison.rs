@@ -35,3 +35,145 @@ fn any_empty() {
     let a: Mat<bool> = Mat::new();
     assert!(!a.any());
 }
+
+#[test]
+fn eq_and_ne_elem() {
+    let a = mat![[1, 2], [3, 4]];
+    let b = mat![[1, 0], [3, 0]];
+    assert_eq!(a.eq_elem(b.as_ref()), mat![[true, false], [true, false]]);
+    assert_eq!(a.ne_elem(b.as_ref()), mat![[false, true], [false, true]]);
+}
+
+#[test]
+fn ordering_against_matrix() {
+    let a = mat![[1.0, 5.0], [3.0, 2.0]];
+    let b = mat![[2.0, 5.0], [1.0, 2.5]];
+    assert_eq!(a.lt(b.as_ref()), mat![[true, false], [false, true]]);
+    assert_eq!(a.le(b.as_ref()), mat![[true, true], [false, true]]);
+    assert_eq!(a.gt(b.as_ref()), mat![[false, false], [true, false]]);
+    assert_eq!(a.ge(b.as_ref()), mat![[false, true], [true, false]]);
+}
+
+#[test]
+fn ordering_against_scalar() {
+    let a = mat![[1, 5, 3], [4, 2, 6]];
+    assert_eq!(
+        a.gt_scalar(3),
+        mat![[false, true, false], [true, false, true]]
+    );
+    assert_eq!(
+        a.le_scalar(2),
+        mat![[true, false, false], [false, true, false]]
+    );
+    assert_eq!(
+        a.eq_scalar(4),
+        mat![[false, false, false], [true, false, false]]
+    );
+    assert_eq!(a.ne_scalar(4).count_true(), 5);
+    assert_eq!(a.lt_scalar(1).count_true(), 0);
+    assert_eq!(a.ge_scalar(1).count_true(), 6);
+}
+
+#[test]
+fn comparison_with_nan_is_false() {
+    let a = mat![[f64::NAN, 1.0]];
+    assert_eq!(a.eq_elem(a.as_ref()), mat![[false, true]]);
+    assert_eq!(a.ne_scalar(f64::NAN), mat![[true, true]]);
+    assert_eq!(a.lt_scalar(2.0), mat![[false, true]]);
+}
+
+#[test]
+fn comparison_broadcasts_row_vector() {
+    let a = mat![[1, 5, 3], [4, 2, 6]];
+    let thresholds = mat![[2, 4, 6]];
+    assert_eq!(
+        a.ge(thresholds.as_ref()),
+        mat![[false, true, false], [true, false, true]]
+    );
+}
+
+#[test]
+fn logical_operators() {
+    let a = mat![[true, true], [false, false]];
+    let b = mat![[true, false], [true, false]];
+    assert_eq!(&a & &b, mat![[true, false], [false, false]]);
+    assert_eq!(&a | &b, mat![[true, true], [true, false]]);
+    assert_eq!(&a ^ &b, mat![[false, true], [true, false]]);
+    assert_eq!(!&a, mat![[false, false], [true, true]]);
+    assert_eq!(!a.as_ref(), mat![[false, false], [true, true]]);
+}
+
+#[test]
+fn logical_assign_operators() {
+    let mut a = mat![[true, true], [false, false]];
+    a &= &mat![[true, false], [true, false]];
+    assert_eq!(a, mat![[true, false], [false, false]]);
+    a |= &mat![[false], [true]];
+    assert_eq!(a, mat![[true, false], [true, true]]);
+    a ^= &mat![[true, true]];
+    assert_eq!(a, mat![[false, true], [false, false]]);
+}
+
+#[test]
+fn combine_masks_for_range_filter() {
+    let a = mat![[1, 5, 3], [4, 2, 6]];
+    let in_range = a.gt_scalar(1) & a.lt_scalar(5);
+    assert_eq!(in_range, mat![[false, false, true], [true, true, false]]);
+    assert_eq!(in_range.count_true(), 3);
+}
+
+#[test]
+fn count_true_on_views() {
+    let a = mat![[true, false, true], [true, true, false]];
+    assert_eq!(a.count_true(), 4);
+    assert_eq!(a.view(0, 1, 2, 2).count_true(), 2);
+    assert_eq!(Mat::<bool>::new().count_true(), 0);
+}
+
+#[test]
+fn select_and_where() {
+    let mask = mat![[true, false], [false, true]];
+    let a = mat![[1, 2], [3, 4]];
+    let b = mat![[10, 20], [30, 40]];
+    assert_eq!(
+        Mat::select(mask.as_ref(), a.as_ref(), b.as_ref()),
+        mat![[1, 20], [30, 4]]
+    );
+    assert_eq!(mask.where_(b.as_ref(), a.as_ref()), mat![[10, 2], [3, 40]]);
+}
+
+#[test]
+fn where_broadcasts_scalar_branches() {
+    let a = mat![[-1.0, 2.0], [3.0, -4.0]];
+    let clipped = a.lt_scalar(0.0).where_(mat![[0.0]].as_ref(), a.as_ref());
+    assert_eq!(clipped, mat![[0.0, 2.0], [3.0, 0.0]]);
+}
+
+#[test]
+fn masked_fill_and_assign() {
+    let mut a = mat![[1, -2, 3], [-4, 5, -6]];
+    let negative = a.lt_scalar(0);
+    a.masked_fill(negative.as_ref(), 0);
+    assert_eq!(a, mat![[1, 0, 3], [0, 5, 0]]);
+
+    let mut b = mat![[1, 2, 3], [4, 5, 6]];
+    let mask = mat![[true, false, true]];
+    b.masked_assign(mask.as_ref(), mat![[7], [8]].as_ref());
+    assert_eq!(b, mat![[7, 2, 7], [8, 5, 8]]);
+}
+
+#[test]
+fn masked_fill_on_view() {
+    let mut a = mat![[1, 2, 3], [4, 5, 6]];
+    let mut v = a.view_mut(0, 1, 2, 2);
+    let mask = v.gt_scalar(4);
+    v.masked_fill(mask.as_ref(), -1);
+    assert_eq!(a, mat![[1, 2, 3], [4, -1, -1]]);
+}
+
+#[test]
+#[should_panic(expected = "shape mismatch: (2, 2) vs (1, 3)")]
+fn masked_fill_shape_mismatch() {
+    let mut a = mat![[1, 2], [3, 4]];
+    a.masked_fill(mat![[true, false, true]].as_ref(), 0);
+}
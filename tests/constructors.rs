@@ -223,7 +223,7 @@ fn from_rows_panics_on_inconsistent_lengths() {
 }
 
 #[test]
-#[should_panic(expected = "Data length")]
+#[should_panic(expected = "length mismatch: expected 6 elements, found 3")]
 fn from_vec_col_panics_on_wrong_length() {
     Mat::from_vec_col(2, 3, vec![1, 2, 3]);
 }
@@ -255,7 +255,7 @@ fn from_vec_row_converts_to_vec_col() {
 }
 
 #[test]
-#[should_panic(expected = "Data length")]
+#[should_panic(expected = "length mismatch: expected 6 elements, found 5")]
 fn from_vec_row_panics_on_wrong_length() {
     Mat::from_vec_row(2, 3, vec![1, 2, 3, 4, 5]);
 }
@@ -466,7 +466,7 @@ fn from_iter_1x5() {
 }
 
 #[test]
-#[should_panic(expected = "Iterator produced 5 elements, expected 6")]
+#[should_panic(expected = "length mismatch: expected 6 elements, found 5")]
 fn from_iter_insufficient_elements() {
     Mat::from_iter(2, 3, 0..5);
 }
@@ -0,0 +1,214 @@
+use std::io::Cursor;
+
+use aris::io::{
+    CsvOptions, Missing, load_csv, read_csv, read_csv_with_header, save_csv, write_csv,
+};
+use aris::{Error, Mat, mat};
+
+fn read<T: std::str::FromStr + Clone>(text: &str, options: &CsvOptions<T>) -> aris::Result<Mat<T>> {
+    read_csv(Cursor::new(text), options)
+}
+
+#[test]
+fn read_basic_csv() {
+    let a: Mat<f64> = read("1,2,3\n4,5,6.5\n", &CsvOptions::default()).unwrap();
+    assert_eq!(a, mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.5]]);
+}
+
+#[test]
+fn read_tsv_with_crlf_and_spaces() {
+    let a: Mat<i32> = read("1\t -2\r\n 3\t4 \r\n", &CsvOptions::tsv()).unwrap();
+    assert_eq!(a, mat![[1, -2], [3, 4]]);
+}
+
+#[test]
+fn read_custom_delimiter() {
+    let options = CsvOptions {
+        delimiter: ';',
+        ..CsvOptions::default()
+    };
+    let a: Mat<u8> = read("1;2\n3;4\n", &options).unwrap();
+    assert_eq!(a, mat![[1, 2], [3, 4]]);
+}
+
+#[test]
+fn read_skips_header_comments_and_blank_lines() {
+    let text = "# exported data\nx,y\n\n1,2\n# middle\n3,4\n";
+    let options = CsvOptions {
+        has_header: true,
+        comment: Some('#'),
+        ..CsvOptions::default()
+    };
+    let a: Mat<i64> = read(text, &options).unwrap();
+    assert_eq!(a, mat![[1, 2], [3, 4]]);
+}
+
+#[test]
+fn read_captures_header() {
+    let text = "time,\"value, raw\",\"say \"\"hi\"\"\"\n0,1.5,2\n1,2.5,3\n";
+    let (header, a) =
+        read_csv_with_header::<f32, _>(Cursor::new(text), &CsvOptions::default()).unwrap();
+    assert_eq!(header, vec!["time", "value, raw", "say \"hi\""]);
+    assert_eq!(a, mat![[0.0, 1.5, 2.0], [1.0, 2.5, 3.0]]);
+}
+
+#[test]
+fn header_only_gives_empty_rows() {
+    let options = CsvOptions::<f64> {
+        has_header: true,
+        ..CsvOptions::default()
+    };
+    let a = read("a,b,c\n", &options).unwrap();
+    assert_eq!(a.shape(), (0, 3));
+    let e = read::<f64>("", &CsvOptions::default()).unwrap();
+    assert_eq!(e.shape(), (0, 0));
+}
+
+#[test]
+fn read_quoted_numbers() {
+    let a: Mat<f64> = read("\"1.5\", \"2\"\n3,\"4\"\n", &CsvOptions::default()).unwrap();
+    assert_eq!(a, mat![[1.5, 2.0], [3.0, 4.0]]);
+}
+
+#[test]
+fn missing_values_fill_with_nan() {
+    let options = CsvOptions {
+        missing: Missing::Fill(f64::NAN),
+        ..CsvOptions::default()
+    };
+    let a = read("1,,3\n,5,\n", &options).unwrap();
+    let expected: Mat<f64> = mat![[1.0, f64::NAN, 3.0], [f64::NAN, 5.0, f64::NAN]];
+    assert!(a.nan_eq(expected.as_ref()));
+}
+
+#[test]
+fn missing_values_error_by_default() {
+    let err = read::<f64>("1,2\n3, \n", &CsvOptions::default()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid format: line 2: missing value in column 2"
+    );
+}
+
+#[test]
+fn read_rejects_ragged_rows() {
+    let err = read::<i32>("1,2,3\n4,5\n", &CsvOptions::default()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid format: line 2: expected 3 fields, found 2"
+    );
+    let options = CsvOptions::<i32> {
+        has_header: true,
+        ..CsvOptions::default()
+    };
+    let err = read("a,b\n1,2,3\n", &options).unwrap_err();
+    assert!(err.to_string().contains("expected 2 fields, found 3"));
+}
+
+#[test]
+fn read_rejects_invalid_values() {
+    let err = read::<i32>("1,2\n3,x4\n", &CsvOptions::default()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid format: line 2: invalid value 'x4' in column 2"
+    );
+    let err = read::<i32>("\"1,2\n", &CsvOptions::default()).unwrap_err();
+    assert!(matches!(err, Error::Format { .. }));
+}
+
+#[test]
+fn write_csv_and_tsv() {
+    let a: Mat<f64> = mat![[1.0, -2.5], [0.1, 3.0]];
+    let mut buf = Vec::new();
+    write_csv(&mut buf, a.as_ref(), &CsvOptions::default(), None).unwrap();
+    assert_eq!(String::from_utf8(buf).unwrap(), "1,-2.5\n0.1,3\n");
+    let mut buf = Vec::new();
+    write_csv(
+        &mut buf,
+        a.transpose(),
+        &CsvOptions::tsv(),
+        Some(&["a b", "c\td"]),
+    )
+    .unwrap();
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        "a b\t\"c\td\"\n1\t0.1\n-2.5\t3\n"
+    );
+}
+
+#[test]
+fn write_rejects_header_length_mismatch() {
+    let a: Mat<i32> = Mat::zeros(2, 2);
+    let err = write_csv(Vec::new(), a.as_ref(), &CsvOptions::default(), Some(&["x"])).unwrap_err();
+    assert_eq!(
+        err,
+        Error::LengthMismatch {
+            part: None,
+            expected: 2,
+            found: 1
+        }
+    );
+}
+
+#[test]
+fn round_trip_with_header() {
+    let a = Mat::from_fn(5, 3, |i, j| (i as f64) * 0.3 - (j as f64) / 7.0);
+    let mut buf = Vec::new();
+    write_csv(
+        &mut buf,
+        a.as_ref(),
+        &CsvOptions::default(),
+        Some(&["x", "y,z", "\"w\""]),
+    )
+    .unwrap();
+    let (header, b) =
+        read_csv_with_header::<f64, _>(Cursor::new(buf), &CsvOptions::default()).unwrap();
+    assert_eq!(header, vec!["x", "y,z", "\"w\""]);
+    assert_eq!(a, b);
+}
+
+#[test]
+fn round_trip_quotes_fields_with_delimiters() {
+    let cells = [
+        ["a,b", "c"],
+        ["say \"hi\"", " padded "],
+        ["tab\there", "\"a\",\"b\""],
+    ];
+    let a = Mat::from_fn(3, 2, |i, j| cells[i][j].to_string());
+    for options in [CsvOptions::default(), CsvOptions::tsv()] {
+        let mut buf = Vec::new();
+        write_csv(&mut buf, a.as_ref(), &options, None).unwrap();
+        let b: Mat<String> = read_csv(Cursor::new(buf), &options).unwrap();
+        assert_eq!(a, b);
+    }
+
+    let mut buf = Vec::new();
+    write_csv(
+        &mut buf,
+        mat![["a,b".to_string(), "c".to_string()]].as_ref(),
+        &CsvOptions::default(),
+        None,
+    )
+    .unwrap();
+    assert_eq!(String::from_utf8(buf).unwrap(), "\"a,b\",c\n");
+}
+
+#[test]
+fn round_trip_many_rows() {
+    let a = Mat::from_fn(1000, 3, |i, j| (i * 3 + j) as i64 - 500);
+    let mut buf = Vec::new();
+    write_csv(&mut buf, a.as_ref(), &CsvOptions::default(), None).unwrap();
+    let b: Mat<i64> = read_csv(Cursor::new(buf), &CsvOptions::default()).unwrap();
+    assert_eq!(a, b);
+    assert_eq!(b.as_slice(), a.as_slice());
+}
+
+#[test]
+fn save_and_load_file() {
+    let path = std::env::temp_dir().join(format!("aris-csv-{}.tsv", std::process::id()));
+    let a = Mat::from_fn(4, 6, |i, j| (i * 6 + j) as i32 - 10);
+    save_csv(&path, a.view(1, 1, 3, 4), &CsvOptions::tsv(), None).unwrap();
+    let b: Mat<i32> = load_csv(&path, &CsvOptions::tsv()).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(b, a.view(1, 1, 3, 4));
+}
@@ -0,0 +1,255 @@
+mod common;
+
+use aris::linalg::{ComplexSchur, Eigen, Hessenberg, RealSchur};
+use aris::{Complex, Error, Mat, mat};
+
+use common::c;
+
+fn test_matrix(n: usize) -> Mat<f64> {
+    Mat::from_fn(n, n, |i, j| ((i * 7 + j * 13 + i * j) % 11) as f64 - 5.0)
+}
+
+fn complexify(a: &Mat<f64>) -> Mat<Complex<f64>> {
+    a.map(|&x| c(x, 0.0))
+}
+
+fn assert_eigenpairs(
+    a: &Mat<Complex<f64>>,
+    values: &Mat<Complex<f64>>,
+    vectors: &Mat<Complex<f64>>,
+    tol: f64,
+) {
+    let n = a.nrows();
+    let lambda = Mat::from_fn(
+        n,
+        n,
+        |i, j| if i == j { values[(i, 0)] } else { c(0.0, 0.0) },
+    );
+    assert!((a * vectors - vectors * &lambda).norm().max() < tol);
+    for k in 0..n {
+        let norm: f64 = (0..n).map(|i| vectors[(i, k)].norm_sqr()).sum();
+        assert!((norm - 1.0).abs() < 1e-12);
+    }
+}
+
+fn sorted_by_re_im(values: &Mat<Complex<f64>>) -> Vec<Complex<f64>> {
+    let mut v: Vec<_> = (0..values.nrows()).map(|i| values[(i, 0)]).collect();
+    v.sort_by(|a, b| {
+        a.re.partial_cmp(&b.re)
+            .unwrap()
+            .then(a.im.partial_cmp(&b.im).unwrap())
+    });
+    v
+}
+
+#[test]
+fn hessenberg_reconstructs() {
+    let a = test_matrix(7);
+    let hess = Hessenberg::new(a.as_ref());
+    let h = hess.h();
+    let q = hess.q();
+    for j in 0..7 {
+        for i in j + 2..7 {
+            assert_eq!(h[(i, j)], 0.0);
+        }
+    }
+    assert!((&q * &h * q.transpose() - &a).abs().max() < 1e-12);
+    assert!((q.transpose() * &q - Mat::identity(7)).abs().max() < 1e-12);
+}
+
+#[test]
+fn hessenberg_complex_reconstructs() {
+    let a = Mat::from_fn(5, 5, |i, j| c((i + 2 * j) as f64 - 3.0, (i * j % 3) as f64));
+    let hess = a.hessenberg();
+    let h = hess.h();
+    let q = hess.q();
+    let qh = q.adjoint();
+    assert!((&q * &h * &qh - &a).norm().max() < 1e-12);
+}
+
+#[test]
+fn real_schur_is_quasi_triangular() {
+    let a = test_matrix(8);
+    let schur = RealSchur::new(a.as_ref()).unwrap();
+    let t = schur.t().to_owned();
+    let z = schur.z().to_owned();
+    for j in 0..8 {
+        for i in j + 2..8 {
+            assert_eq!(t[(i, j)], 0.0);
+        }
+    }
+    for j in 0..7 {
+        if t[(j + 1, j)] != 0.0 {
+            assert!(j == 0 || t[(j, j - 1)] == 0.0);
+            assert!(j + 2 >= 8 || t[(j + 2, j + 1)] == 0.0);
+        }
+    }
+    assert!((&z * &t * z.transpose() - &a).abs().max() < 1e-10);
+    assert!((z.transpose() * &z - Mat::identity(8)).abs().max() < 1e-12);
+}
+
+#[test]
+fn real_schur_real_eigenvalues_are_triangular() {
+    let a: Mat<f64> = mat![[4.0, 1.0, 2.0], [1.0, 3.0, 0.0], [2.0, 0.0, 1.0]];
+    let schur = RealSchur::new(a.as_ref()).unwrap();
+    let t = schur.t();
+    assert!(t.is_upper_triangular());
+    let values = schur.eigenvalues();
+    for i in 0..3 {
+        assert_eq!(values[(i, 0)].im, 0.0);
+    }
+}
+
+#[test]
+fn complex_schur_is_triangular() {
+    let a = Mat::from_fn(6, 6, |i, j| {
+        c(
+            ((i * 5 + j * 3) % 7) as f64 - 3.0,
+            ((i + j) % 4) as f64 - 1.5,
+        )
+    });
+    let schur = ComplexSchur::new(a.as_ref()).unwrap();
+    let t = schur.t().to_owned();
+    let z = schur.z().to_owned();
+    for j in 0..6 {
+        for i in j + 1..6 {
+            assert_eq!(t[(i, j)], c(0.0, 0.0));
+        }
+    }
+    let zh = z.adjoint();
+    assert!((&z * &t * &zh - &a).norm().max() < 1e-10);
+    assert!((&zh * &z - Mat::identity(6)).norm().max() < 1e-12);
+}
+
+#[test]
+fn real_schur_to_complex_schur() {
+    let a = test_matrix(6);
+    let schur = RealSchur::new(a.as_ref()).unwrap().into_complex();
+    let t = schur.t().to_owned();
+    let z = schur.z().to_owned();
+    assert!(t.is_upper_triangular());
+    let zh = z.adjoint();
+    assert!((&z * &t * &zh - complexify(&a)).norm().max() < 1e-10);
+}
+
+#[test]
+fn eig_rotation_has_complex_pair() {
+    let a: Mat<f64> = mat![[0.0, -1.0], [1.0, 0.0]];
+    let values = sorted_by_re_im(&a.eigvals().unwrap());
+    assert!((values[0] - c(0.0, -1.0)).norm() < 1e-14);
+    assert!((values[1] - c(0.0, 1.0)).norm() < 1e-14);
+}
+
+#[test]
+fn eig_triangular_eigenvalues() {
+    let a: Mat<f64> = mat![[1.0, 2.0, 3.0], [0.0, 4.0, 5.0], [0.0, 0.0, 6.0]];
+    let values = sorted_by_re_im(&a.eigvals().unwrap());
+    assert_eq!(values, vec![c(1.0, 0.0), c(4.0, 0.0), c(6.0, 0.0)]);
+}
+
+#[test]
+fn eig_real_matrix() {
+    let a = test_matrix(9);
+    let eig = a.eig().unwrap();
+    let values = eig.eigenvalues().to_owned();
+    let vectors = eig.eigenvectors().unwrap().to_owned();
+    assert_eigenpairs(&complexify(&a), &values, &vectors, 1e-9);
+}
+
+#[test]
+fn eig_real_matrix_conjugate_pairs() {
+    let a = test_matrix(9);
+    let eig = Eigen::new(a.as_ref()).unwrap();
+    let values = eig.eigenvalues();
+    let vectors = eig.eigenvectors().unwrap();
+    let mut k = 0;
+    while k < 9 {
+        if values[(k, 0)].im == 0.0 {
+            for i in 0..9 {
+                assert_eq!(vectors[(i, k)].im, 0.0);
+            }
+            k += 1;
+        } else {
+            assert_eq!(values[(k + 1, 0)], values[(k, 0)].conj());
+            for i in 0..9 {
+                assert_eq!(vectors[(i, k + 1)], vectors[(i, k)].conj());
+            }
+            k += 2;
+        }
+    }
+}
+
+#[test]
+fn eig_complex_matrix() {
+    let a = Mat::from_fn(7, 7, |i, j| {
+        c(
+            ((i * 3 + j * 5) % 8) as f64 - 4.0,
+            ((i * j) % 5) as f64 - 2.0,
+        )
+    });
+    let eig = a.eig().unwrap();
+    let values = eig.eigenvalues().to_owned();
+    let vectors = eig.eigenvectors().unwrap().to_owned();
+    assert_eigenpairs(&a, &values, &vectors, 1e-9);
+}
+
+#[test]
+fn eig_matches_trace_and_eigvals() {
+    let a = test_matrix(10);
+    let values = a.eigvals().unwrap();
+    let sum = (0..10).fold(c(0.0, 0.0), |acc, i| acc + values[(i, 0)]);
+    let trace: f64 = (0..10).map(|i| a[(i, i)]).sum();
+    assert!((sum - c(trace, 0.0)).norm() < 1e-9);
+    let full = sorted_by_re_im(&a.eig().unwrap().eigenvalues().to_owned());
+    let only = sorted_by_re_im(&values);
+    for (x, y) in full.iter().zip(&only) {
+        assert!((x - y).norm() < 1e-12);
+    }
+}
+
+#[test]
+fn eig_defective_matrix() {
+    let a: Mat<f64> = mat![[2.0, 1.0], [0.0, 2.0]];
+    let eig = a.eig().unwrap();
+    let values = eig.eigenvalues().to_owned();
+    let vectors = eig.eigenvectors().unwrap().to_owned();
+    assert_eq!(values, mat![[c(2.0, 0.0)], [c(2.0, 0.0)]]);
+    assert_eigenpairs(&complexify(&a), &values, &vectors, 1e-12);
+}
+
+#[test]
+fn eig_companion_matrix_roots() {
+    let a: Mat<f64> = mat![[6.0, -11.0, 6.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]];
+    let values = sorted_by_re_im(&a.eigvals().unwrap());
+    for (value, root) in values.iter().zip([1.0, 2.0, 3.0]) {
+        assert!((value - c(root, 0.0)).norm() < 1e-12);
+    }
+}
+
+#[test]
+fn eig_empty_and_scalar() {
+    let empty: Mat<f64> = Mat::zeros(0, 0);
+    assert_eq!(empty.eigvals().unwrap().nrows(), 0);
+    let a: Mat<f64> = mat![[5.0]];
+    let eig = a.eig().unwrap();
+    assert_eq!(eig.eigenvalues()[(0, 0)], c(5.0, 0.0));
+    assert_eq!(eig.eigenvectors().unwrap()[(0, 0)], c(1.0, 0.0));
+}
+
+#[test]
+fn eig_non_square_is_an_error() {
+    let a: Mat<f64> = Mat::zeros(2, 3);
+    let not_square = Error::NotSquare { nrows: 2, ncols: 3 };
+    assert_eq!(a.eig().err(), Some(not_square.clone()));
+    assert_eq!(a.eigvals().err(), Some(not_square.clone()));
+    assert_eq!(RealSchur::new(a.as_ref()).err(), Some(not_square.clone()));
+    assert_eq!(a.try_hessenberg().err(), Some(not_square.clone()));
+    assert_eq!(a.eigh().err(), Some(not_square));
+}
+
+#[test]
+#[should_panic(expected = "matrix must be square, found 2x3")]
+fn hessenberg_non_square_panics() {
+    let a: Mat<f64> = Mat::zeros(2, 3);
+    let _ = a.hessenberg();
+}
@@ -0,0 +1,204 @@
+use aris::{Axis, Error, Mat, MatRef, Part, mat, try_matmul_into};
+
+#[test]
+fn try_constructors_report_length_mismatch() {
+    assert_eq!(
+        Mat::try_from_vec_col(2, 3, vec![1, 2, 3]).unwrap_err(),
+        Error::LengthMismatch {
+            part: None,
+            expected: 6,
+            found: 3
+        }
+    );
+    assert_eq!(
+        Mat::try_from_rows(&[&[1, 2, 3][..], &[4, 5][..]]).unwrap_err(),
+        Error::LengthMismatch {
+            part: Some(Part::Row(1)),
+            expected: 3,
+            found: 2
+        }
+    );
+    assert!(Mat::try_from_iter(2, 2, 0..3).is_err());
+    assert_eq!(
+        Mat::try_from_vec_row(2, 2, vec![1, 2, 3, 4]).unwrap(),
+        mat![[1, 2], [3, 4]]
+    );
+}
+
+#[test]
+fn try_from_nested_vec_and_cols() {
+    let ok = Mat::try_from_nested_vec(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    assert_eq!(ok, mat![[1, 2], [3, 4]]);
+    assert!(Mat::try_from_nested_vec(vec![vec![1, 2], vec![3]]).is_err());
+    assert_eq!(
+        Mat::try_from_cols(&[&[1, 2][..], &[3][..]]).unwrap_err(),
+        Error::LengthMismatch {
+            part: Some(Part::Col(1)),
+            expected: 2,
+            found: 1
+        }
+    );
+}
+
+#[test]
+fn try_views_report_bounds() {
+    let a = mat![[1, 2, 3], [4, 5, 6]];
+    assert_eq!(
+        a.try_row(2).unwrap_err(),
+        Error::IndexOutOfBounds {
+            axis: Axis::Row,
+            index: 2,
+            len: 2
+        }
+    );
+    assert_eq!(a.try_col(2).unwrap(), mat![[3], [6]]);
+    assert_eq!(
+        a.try_view(1, 1, 1, 3).unwrap_err(),
+        Error::RangeOutOfBounds {
+            axis: Axis::Col,
+            start: 1,
+            end: 4,
+            len: 3
+        }
+    );
+    assert!(a.try_rows_range(0..3).is_err());
+    assert!(a.as_ref().try_split_at_col(4).is_err());
+    assert_eq!(a.try_split_at_col(3).unwrap().1.shape(), (2, 0));
+}
+
+#[test]
+fn try_mutable_views() {
+    let mut a: Mat<i32> = Mat::zeros(2, 2);
+    assert!(a.try_row_mut(5).is_err());
+    assert!(a.try_view_mut(0, 0, 3, 1).is_err());
+    a.try_col_mut(1).unwrap().fill(7);
+    assert_eq!(a, mat![[0, 7], [0, 7]]);
+    assert!(a.try_swap_rows(0, 2).is_err());
+    a.try_swap_cols(0, 1).unwrap();
+    assert_eq!(a, mat![[7, 0], [7, 0]]);
+}
+
+#[test]
+fn try_arithmetic_reports_shape_mismatch() {
+    let a = mat![[1, 2, 3], [4, 5, 6]];
+    let b = mat![[1, 2], [3, 4]];
+    assert_eq!(
+        a.try_add(b.as_ref()).unwrap_err(),
+        Error::ShapeMismatch {
+            lhs: (2, 3),
+            rhs: (2, 2)
+        }
+    );
+    assert_eq!(
+        a.try_sub(mat![[1, 1, 1]].as_ref()).unwrap(),
+        mat![[0, 1, 2], [3, 4, 5]]
+    );
+    assert!(a.try_mul(a.as_ref()).is_err());
+    assert_eq!(b.try_mul(a.as_ref()).unwrap(), &b * &a);
+}
+
+#[test]
+fn try_assign_ops_leave_lhs_untouched_on_error() {
+    let mut a = mat![[1, 2], [3, 4]];
+    assert!(a.try_add_assign(mat![[1, 2, 3]].as_ref()).is_err());
+    assert_eq!(a, mat![[1, 2], [3, 4]]);
+    a.try_sub_assign(mat![[1], [2]].as_ref()).unwrap();
+    assert_eq!(a, mat![[0, 1], [1, 2]]);
+}
+
+#[test]
+fn try_matmul_into_checks_destination() {
+    let a: Mat<f64> = Mat::identity(3);
+    let mut dst: Mat<f64> = Mat::zeros(3, 2);
+    assert_eq!(
+        try_matmul_into(dst.as_mut(), 1.0, a.as_ref(), a.as_ref(), 0.0),
+        Err(Error::ShapeMismatch {
+            lhs: (3, 2),
+            rhs: (3, 3)
+        })
+    );
+}
+
+#[test]
+fn try_reductions_report_empty_matrix() {
+    let e: Mat<f64> = Mat::zeros(0, 3);
+    assert_eq!(e.try_mean(), Err(Error::EmptyMatrix));
+    assert_eq!(e.try_min(), Err(Error::EmptyMatrix));
+    assert_eq!(e.try_argmax(), Err(Error::EmptyMatrix));
+    assert_eq!(e.try_max_cols(), Err(Error::EmptyMatrix));
+    let a: Mat<f64> = mat![[1.0, 5.0], [3.0, -2.0]];
+    assert_eq!(a.try_min_max(), Ok((-2.0, 5.0)));
+    assert_eq!(a.try_std_dev().unwrap(), a.std_dev());
+}
+
+#[test]
+fn try_structural_ops() {
+    let a = mat![[1, 2], [3, 4]];
+    assert!(a.try_reshape(3, 1).is_err());
+    assert_eq!(a.try_reshape(1, 4).unwrap(), mat![[1, 3, 2, 4]]);
+    assert!(a.try_insert_row(3, &[0, 0]).is_err());
+    assert!(a.try_append_col(&[0]).is_err());
+    assert!(a.try_remove_col(2).is_err());
+    assert!(a.try_take_rows(&[0, 2]).is_err());
+    assert!(a.try_sort_rows_by_col(2).is_err());
+}
+
+#[test]
+fn try_stacking_and_blocks() {
+    let a = mat![[1, 2]];
+    let b = mat![[3, 4, 5]];
+    assert!(Mat::try_vstack(&[a.as_ref(), b.as_ref()]).is_err());
+    assert_eq!(
+        Mat::try_hstack(&[a.as_ref(), b.as_ref()]).unwrap(),
+        mat![[1, 2, 3, 4, 5]]
+    );
+    let blocks: &[&[MatRef<'_, i32>]] = &[&[a.as_ref(), b.as_ref()], &[b.as_ref(), a.as_ref()]];
+    assert!(Mat::try_from_blocks(blocks).is_err());
+    let mut c = mat![[1, 2], [3, 4]];
+    assert!(c.try_truncate(3, 1).is_err());
+    c.try_truncate(1, 1).unwrap();
+    assert_eq!(c, mat![[1]]);
+}
+
+#[test]
+fn try_masks_and_copy() {
+    let a = mat![[1, 2], [3, 4]];
+    assert!(a.try_lt(mat![[1, 2, 3]].as_ref()).is_err());
+    let mask = a.gt_scalar(2);
+    assert!(Mat::try_select(mask.as_ref(), a.as_ref(), mat![[0, 0, 0]].as_ref()).is_err());
+    let mut b = a.clone();
+    assert!(
+        b.try_masked_fill(mat![[true, false, true]].as_ref(), 0)
+            .is_err()
+    );
+    assert!(b.try_copy_from(mat![[1, 2]].as_ref()).is_err());
+    assert!(b.try_zip_map(mat![[1]].as_ref(), |x, y| x + y).is_err());
+}
+
+#[test]
+fn error_messages() {
+    let err = Mat::<i32>::try_from_vec_col(2, 2, vec![1]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "length mismatch: expected 4 elements, found 1"
+    );
+    let (a, b) = (mat![[1]], mat![[2]]);
+    let blocks = [a.as_ref(), b.as_ref()];
+    let err = Mat::try_from_blocks(&[&blocks[..], &blocks[..1]]).unwrap_err();
+    assert_eq!(err.to_string(), "Block row 1 has 1 blocks, expected 2");
+    let err = mat![[1]].try_col(3).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Column index 3 out of bounds for 1 columns"
+    );
+    let boxed: Box<dyn std::error::Error> = Box::new(Error::EmptyMatrix);
+    assert_eq!(boxed.to_string(), "operation requires a non-empty matrix");
+}
+
+#[test]
+#[should_panic(expected = "shape mismatch: (2, 2) vs (3, 3)")]
+fn panicking_version_uses_error_message() {
+    let a = mat![[1, 2], [3, 4]];
+    let b = mat![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    let _ = &a + &b;
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "faer")]
+
+use aris::{Mat, MatMut, MatRef, mat};
+
+fn sample() -> Mat<f64> {
+    Mat::from_fn(9, 4, |i, j| (10 * i + j) as f64)
+}
+
+fn assert_same(view: faer::MatRef<'_, f64>, matrix: MatRef<'_, f64>) {
+    assert_eq!((view.nrows(), view.ncols()), matrix.shape());
+    assert_eq!(
+        (view.row_stride(), view.col_stride()),
+        (matrix.row_stride(), matrix.col_stride())
+    );
+    for j in 0..matrix.ncols() {
+        for i in 0..matrix.nrows() {
+            assert!(std::ptr::eq(&view[(i, j)], &matrix[(i, j)]));
+        }
+    }
+}
+
+#[test]
+fn mat_ref_to_faer_shares_memory() {
+    let a = sample();
+    for view in [
+        a.as_ref(),
+        a.transpose(),
+        a.view(2, 1, 4, 2),
+        a.reverse_rows(),
+        a.reverse_rows().reverse_cols().transpose(),
+    ] {
+        assert_same(view.into(), view);
+    }
+}
+
+#[test]
+fn faer_to_mat_ref() {
+    let b = faer::Mat::from_fn(4, 3, |i, j| (i + 4 * j) as f64);
+    let m = MatRef::from(b.as_ref().reverse_rows());
+    assert_eq!(m.row_stride(), -1);
+    assert_eq!(m[(0, 2)], 11.0);
+    assert_same(b.as_ref().transpose(), MatRef::from(b.as_ref().transpose()));
+}
+
+#[test]
+fn mutable_views_write_through() {
+    let mut a = sample();
+    let mut view: faer::MatMut<'_, f64> = a.as_mut().reverse_cols_mut().into();
+    view[(0, 0)] = -1.0;
+    assert_eq!(a[(0, 3)], -1.0);
+
+    let mut b = faer::Mat::<f64>::zeros(3, 3);
+    let mut m = MatMut::from(b.as_mut().transpose_mut());
+    m[(0, 2)] = 4.0;
+    assert_eq!(b[(2, 0)], 4.0);
+}
+
+#[test]
+fn owned_conversions() {
+    let a = mat![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+    let b = faer::Mat::from(a.clone());
+    assert_eq!((b.nrows(), b.ncols()), (3, 2));
+    assert_eq!(b[(2, 1)], 6.0);
+    assert_eq!(Mat::from(b), a);
+}
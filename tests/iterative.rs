@@ -0,0 +1,408 @@
+mod common;
+
+use std::ops::ControlFlow;
+
+use aris::iterative::{
+    FnOperator, IncompleteCholesky, IncompleteLu, IterativeOptions, Jacobi, LinearOperator,
+    bicgstab, cg, gmres, lsqr, minres,
+};
+use aris::{Complex, Error, Mat, MatMut, MatRef, SparseColMat, SparseRowMat, mat};
+
+use common::c;
+
+fn grid(m: usize, wind: f64) -> SparseColMat<f64> {
+    let n = m * m;
+    let mut triplets = Vec::new();
+    for x in 0..m {
+        for y in 0..m {
+            let k = x * m + y;
+            triplets.push((k, k, 4.0));
+            if x + 1 < m {
+                triplets.push((k, k + m, -1.0 + wind));
+                triplets.push((k + m, k, -1.0 - wind));
+            }
+            if y + 1 < m {
+                triplets.push((k, k + 1, -1.0));
+                triplets.push((k + 1, k, -1.0));
+            }
+        }
+    }
+    SparseColMat::from_triplets(n, n, &triplets)
+}
+
+fn rhs(n: usize, k: usize) -> Mat<f64> {
+    Mat::from_fn(n, k, |i, j| ((i * 5 + j * 3) % 7) as f64 - 3.0)
+}
+
+fn residual(a: &SparseColMat<f64>, x: &Mat<f64>, b: &Mat<f64>) -> f64 {
+    let mut ax = Mat::zeros(b.nrows(), b.ncols());
+    a.apply(ax.as_mut(), x.as_ref());
+    (&ax - b).abs().max()
+}
+
+fn options<'a>(rel_tol: f64) -> IterativeOptions<'a, f64> {
+    IterativeOptions {
+        rel_tol,
+        ..IterativeOptions::default()
+    }
+}
+
+#[test]
+fn cg_solves_sparse_laplacian() {
+    let a = grid(10, 0.0);
+    let b = rhs(100, 1);
+    let mut x = Mat::zeros(100, 1);
+    let info = cg(x.as_mut(), &a, b.as_ref(), options(1e-10)).unwrap();
+    assert!(info.iterations > 0 && info.iterations <= 100);
+    assert!(info.residual_norm <= 1e-10 * b.frobenius_norm());
+    assert!(residual(&a, &x, &b) < 1e-8);
+}
+
+#[test]
+fn preconditioners_reduce_cg_iterations() {
+    let a = grid(12, 0.0);
+    let b = rhs(144, 1);
+    let plain = cg(Mat::zeros(144, 1).as_mut(), &a, b.as_ref(), options(1e-10)).unwrap();
+
+    let ic = IncompleteCholesky::new(a.as_ref()).unwrap();
+    let mut x = Mat::zeros(144, 1);
+    let opts = IterativeOptions {
+        preconditioner: Some(&ic),
+        ..options(1e-10)
+    };
+    let with_ic = cg(x.as_mut(), &a, b.as_ref(), opts).unwrap();
+    assert!(with_ic.iterations < plain.iterations);
+    assert!(residual(&a, &x, &b) < 1e-8);
+
+    let mut y = Mat::zeros(144, 1);
+    let opts = IterativeOptions {
+        preconditioner: Some(&ic),
+        ..options(1e-10)
+    };
+    let minres_ic = minres(y.as_mut(), &a, b.as_ref(), opts).unwrap();
+    assert!(minres_ic.iterations < plain.iterations);
+    assert!(residual(&a, &y, &b) < 1e-7);
+
+    let jacobi = Jacobi::from_sparse(a.as_ref()).unwrap();
+    let opts = IterativeOptions {
+        preconditioner: Some(&jacobi),
+        ..options(1e-10)
+    };
+    let with_jacobi = cg(Mat::zeros(144, 1).as_mut(), &a, b.as_ref(), opts).unwrap();
+    assert!(with_jacobi.iterations <= plain.iterations);
+}
+
+#[test]
+fn dense_operators_and_multiple_right_hand_sides() {
+    let a = grid(4, 0.0).to_dense();
+    let b = rhs(16, 3);
+    let mut x = Mat::zeros(16, 3);
+    cg(x.as_mut(), &a, b.as_ref(), options(1e-12)).unwrap();
+    assert!((&a * &x - &b).abs().max() < 1e-10);
+
+    let mut y = Mat::zeros(16, 3);
+    let view: MatRef<'_, f64> = a.as_ref();
+    let info = minres(y.as_mut(), &view, b.as_ref(), options(1e-12)).unwrap();
+    assert!(info.iterations <= 16);
+    assert!((&x - &y).abs().max() < 1e-8);
+}
+
+#[test]
+fn iteration_limit_reports_not_converged() {
+    let a = grid(10, 0.0);
+    let b = rhs(100, 1);
+    let mut x = Mat::zeros(100, 1);
+    let opts = IterativeOptions {
+        max_iter: 3,
+        ..options(1e-12)
+    };
+    let err = cg(x.as_mut(), &a, b.as_ref(), opts).unwrap_err();
+    assert_eq!(err, Error::NotConverged { iterations: 3 });
+    assert!(residual(&a, &x, &b) < b.abs().max());
+}
+
+#[test]
+fn callback_observes_and_stops_iterations() {
+    let a = grid(8, 0.0);
+    let b = rhs(64, 2);
+    let mut history = Vec::new();
+    let mut record = |it: &aris::iterative::Iteration<f64>| {
+        history.push((it.column, it.iteration, it.residual_norm));
+        ControlFlow::Continue(())
+    };
+    let opts = IterativeOptions {
+        callback: Some(&mut record),
+        ..options(1e-10)
+    };
+    let info = cg(Mat::zeros(64, 2).as_mut(), &a, b.as_ref(), opts).unwrap();
+    assert!(history.iter().any(|&(col, _, _)| col == 1));
+    let first: Vec<_> = history.iter().filter(|h| h.0 == 0).collect();
+    assert_eq!(first.last().unwrap().1, first.len());
+    assert!(history.iter().map(|h| h.1).max().unwrap() == info.iterations);
+
+    let mut stop = |it: &aris::iterative::Iteration<f64>| {
+        if it.iteration == 2 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    };
+    let opts = IterativeOptions {
+        callback: Some(&mut stop),
+        ..options(1e-10)
+    };
+    let err = gmres(Mat::zeros(64, 2).as_mut(), &a, b.as_ref(), 10, opts).unwrap_err();
+    assert_eq!(err, Error::NotConverged { iterations: 2 });
+}
+
+#[test]
+fn cg_rejects_indefinite_operator() {
+    let a = mat![[1.0, 0.0], [0.0, -1.0]];
+    let b = mat![[1.0], [1.0]];
+    let err = cg(Mat::zeros(2, 1).as_mut(), &a, b.as_ref(), options(1e-12)).unwrap_err();
+    assert!(matches!(err, Error::NotPositiveDefinite { .. }));
+}
+
+#[test]
+fn minres_solves_symmetric_indefinite_system() {
+    let a = mat![
+        [2.0, 1.0, 0.0, 0.0],
+        [1.0, -3.0, 1.0, 0.0],
+        [0.0, 1.0, 1.0, 2.0],
+        [0.0, 0.0, 2.0, -1.0]
+    ];
+    let b = mat![[1.0], [2.0], [3.0], [4.0]];
+    let mut x = Mat::zeros(4, 1);
+    minres(x.as_mut(), &a, b.as_ref(), options(1e-12)).unwrap();
+    assert!((&a * &x - &b).abs().max() < 1e-10);
+}
+
+#[test]
+fn gmres_solves_nonsymmetric_system_with_restarts() {
+    let a = grid(10, 0.4);
+    let b = rhs(100, 1);
+    let mut x = Mat::zeros(100, 1);
+    let info = gmres(x.as_mut(), &a, b.as_ref(), 5, options(1e-10)).unwrap();
+    assert!(info.iterations > 5);
+    assert!(residual(&a, &x, &b) < 1e-8);
+
+    let ilu = IncompleteLu::new(a.as_ref()).unwrap();
+    let opts = IterativeOptions {
+        preconditioner: Some(&ilu),
+        ..options(1e-10)
+    };
+    let mut y = Mat::zeros(100, 1);
+    let preconditioned = gmres(y.as_mut(), &a, b.as_ref(), 5, opts).unwrap();
+    assert!(preconditioned.iterations < info.iterations);
+    assert!(residual(&a, &y, &b) < 1e-8);
+}
+
+#[test]
+fn gmres_rejects_zero_restart() {
+    let a = grid(3, 0.4);
+    let b = rhs(9, 1);
+    let mut x = Mat::zeros(9, 1);
+    assert_eq!(
+        gmres(x.as_mut(), &a, b.as_ref(), 0, options(1e-10)).unwrap_err(),
+        Error::Unsupported {
+            operation: "GMRES with a zero restart length"
+        }
+    );
+}
+
+#[test]
+fn bicgstab_solves_nonsymmetric_system() {
+    let a = grid(10, 0.3);
+    let b = rhs(100, 2);
+    let mut x = Mat::zeros(100, 2);
+    bicgstab(x.as_mut(), &a, b.as_ref(), options(1e-10)).unwrap();
+    assert!(residual(&a, &x, &b) < 1e-8);
+
+    let ilu = IncompleteLu::new(a.as_ref()).unwrap();
+    let opts = IterativeOptions {
+        preconditioner: Some(&ilu),
+        ..options(1e-10)
+    };
+    let mut y = Mat::zeros(100, 2);
+    bicgstab(y.as_mut(), &a, b.as_ref(), opts).unwrap();
+    assert!((&x - &y).abs().max() < 1e-7);
+}
+
+#[test]
+fn lsqr_finds_least_squares_solution() {
+    let a = Mat::from_fn(8, 3, |i, j| ((i + 1) as f64).powi(j as i32));
+    let b = Mat::from_fn(8, 1, |i, _| (i as f64).sin());
+    let mut x = Mat::zeros(3, 1);
+    lsqr(x.as_mut(), &a, b.as_ref(), options(1e-12)).unwrap();
+
+    let normal = a.transpose() * &a;
+    let expected = normal
+        .cholesky()
+        .solve((a.transpose() * &b).as_ref())
+        .unwrap();
+    assert!((&x - &expected).abs().max() < 1e-8);
+
+    let sparse = SparseRowMat::from_dense(a.as_ref());
+    let mut y = Mat::zeros(3, 1);
+    lsqr(y.as_mut(), &sparse, b.as_ref(), options(1e-12)).unwrap();
+    assert!((&y - &expected).abs().max() < 1e-8);
+}
+
+#[test]
+fn closure_operator_is_matrix_free() {
+    let n = 50;
+    let laplacian_1d = |mut out: MatMut<'_, f64>, x: MatRef<'_, f64>| {
+        for j in 0..x.ncols() {
+            for i in 0..n {
+                let mut v = 2.0 * x[(i, j)];
+                if i > 0 {
+                    v -= x[(i - 1, j)];
+                }
+                if i + 1 < n {
+                    v -= x[(i + 1, j)];
+                }
+                out[(i, j)] = v;
+            }
+        }
+    };
+    let op = FnOperator::new(n, n, laplacian_1d);
+    let b = rhs(n, 1);
+    let mut x = Mat::zeros(n, 1);
+    cg(x.as_mut(), &op, b.as_ref(), options(1e-12)).unwrap();
+    let mut ax = Mat::zeros(n, 1);
+    op.apply(ax.as_mut(), x.as_ref());
+    assert!((&ax - &b).abs().max() < 1e-8);
+
+    let err = lsqr(Mat::zeros(n, 1).as_mut(), &op, b.as_ref(), options(1e-8)).unwrap_err();
+    assert_eq!(
+        err,
+        Error::Unsupported {
+            operation: "adjoint application"
+        }
+    );
+    let symmetric = FnOperator::new(n, n, laplacian_1d).with_adjoint(laplacian_1d);
+    let mut y = Mat::zeros(n, 1);
+    lsqr(y.as_mut(), &symmetric, b.as_ref(), options(1e-12)).unwrap();
+    assert!((&x - &y).abs().max() < 1e-6);
+}
+
+#[test]
+fn complex_hermitian_and_general_systems() {
+    let a = mat![
+        [c(4.0, 0.0), c(1.0, 1.0), c(0.0, 0.0)],
+        [c(1.0, -1.0), c(5.0, 0.0), c(0.0, 2.0)],
+        [c(0.0, 0.0), c(0.0, -2.0), c(6.0, 0.0)]
+    ];
+    let b = mat![[c(1.0, 0.0)], [c(0.0, 1.0)], [c(2.0, -1.0)]];
+    let opts = || IterativeOptions {
+        rel_tol: 1e-12,
+        ..IterativeOptions::default()
+    };
+    let check = |x: &Mat<Complex<f64>>| (&a * x - &b).max_abs_norm() < 1e-10;
+
+    let mut x = Mat::zeros(3, 1);
+    cg(x.as_mut(), &a, b.as_ref(), opts()).unwrap();
+    assert!(check(&x));
+    let mut x = Mat::zeros(3, 1);
+    minres(x.as_mut(), &a, b.as_ref(), opts()).unwrap();
+    assert!(check(&x));
+    let mut x = Mat::zeros(3, 1);
+    gmres(x.as_mut(), &a, b.as_ref(), 2, opts()).unwrap();
+    assert!(check(&x));
+    let mut x = Mat::zeros(3, 1);
+    bicgstab(x.as_mut(), &a, b.as_ref(), opts()).unwrap();
+    assert!(check(&x));
+    let mut x = Mat::zeros(3, 1);
+    lsqr(x.as_mut(), &a, b.as_ref(), opts()).unwrap();
+    assert!(check(&x));
+}
+
+#[test]
+fn incomplete_factorizations_are_exact_without_fill() {
+    let mut triplets = Vec::new();
+    for k in 0..6 {
+        triplets.push((k, k, 3.0));
+        if k > 0 {
+            triplets.push((k, k - 1, -1.0));
+            triplets.push((k - 1, k, -2.0));
+        }
+    }
+    let a = SparseColMat::from_triplets(6, 6, &triplets);
+    let b = rhs(6, 1);
+    let ilu = IncompleteLu::new(a.as_ref()).unwrap();
+    let mut x = Mat::zeros(6, 1);
+    ilu.apply(x.as_mut(), b.as_ref());
+    assert!(residual(&a, &x, &b) < 1e-12);
+
+    let sym = grid(1, 0.0);
+    assert!(IncompleteCholesky::new(sym.as_ref()).is_ok());
+    let tridiagonal = SparseColMat::from_dense(
+        mat![[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]].as_ref(),
+    );
+    let ic = IncompleteCholesky::new(tridiagonal.as_ref()).unwrap();
+    let exact = tridiagonal.to_dense().cholesky();
+    assert!((ic.l().to_dense() - exact.l().to_owned()).abs().max() < 1e-14);
+}
+
+#[test]
+fn preconditioner_construction_errors() {
+    let a = SparseColMat::from_dense(mat![[1.0, 2.0], [3.0, 0.0]].as_ref());
+    assert_eq!(
+        Jacobi::from_sparse(a.as_ref()),
+        Err(Error::Singular { index: 1 })
+    );
+    assert_eq!(
+        IncompleteLu::new(a.as_ref()).unwrap_err(),
+        Error::Singular { index: 1 }
+    );
+    let indefinite = SparseColMat::from_dense(mat![[1.0, 2.0], [2.0, 1.0]].as_ref());
+    assert_eq!(
+        IncompleteCholesky::new(indefinite.as_ref()).unwrap_err(),
+        Error::NotPositiveDefinite { index: 1 }
+    );
+    assert!(Jacobi::from_dense(mat![[1.0, 2.0, 3.0]].as_ref()).is_err());
+}
+
+#[test]
+fn shape_errors_are_reported() {
+    let a = grid(3, 0.0);
+    let b = rhs(9, 1);
+    let err = cg(Mat::zeros(8, 1).as_mut(), &a, b.as_ref(), options(1e-8)).unwrap_err();
+    assert_eq!(
+        err,
+        Error::ShapeMismatch {
+            lhs: (8, 1),
+            rhs: (9, 1)
+        }
+    );
+    let rect = Mat::<f64>::zeros(9, 4);
+    assert_eq!(
+        gmres(
+            Mat::zeros(4, 1).as_mut(),
+            &rect,
+            b.as_ref(),
+            3,
+            options(1e-8)
+        )
+        .unwrap_err(),
+        Error::NotSquare { nrows: 9, ncols: 4 }
+    );
+    let jacobi = Jacobi::new(&[1.0, 2.0]).unwrap();
+    let opts = IterativeOptions {
+        preconditioner: Some(&jacobi),
+        ..options(1e-8)
+    };
+    assert!(bicgstab(Mat::zeros(9, 1).as_mut(), &a, b.as_ref(), opts).is_err());
+}
+
+#[test]
+fn initial_guess_is_used() {
+    let a = grid(5, 0.0);
+    let b = rhs(25, 1);
+    let mut x = Mat::zeros(25, 1);
+    cg(x.as_mut(), &a, b.as_ref(), options(1e-12)).unwrap();
+    let info = cg(x.as_mut(), &a, b.as_ref(), options(1e-10)).unwrap();
+    assert_eq!(info.iterations, 0);
+    let info = bicgstab(x.as_mut(), &a, b.as_ref(), options(1e-10)).unwrap();
+    assert_eq!(info.iterations, 0);
+}
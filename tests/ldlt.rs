@@ -0,0 +1,216 @@
+mod common;
+
+use aris::linalg::Ldlt;
+use aris::{Complex, Error, Mat, mat};
+
+use common::c;
+
+fn max_abs_diff(a: &Mat<f64>, b: &Mat<f64>) -> f64 {
+    (a - b).abs().max()
+}
+
+fn indefinite(n: usize) -> Mat<f64> {
+    Mat::from_fn(n, n, |i, j| {
+        let (i, j) = (i.min(j), i.max(j));
+        (((i * 5 + j * 3) % 7) as f64 - 3.0) * if i == j { 0.1 } else { 1.0 }
+    })
+}
+
+fn assert_reconstructs(a: &Mat<f64>, ldlt: &Ldlt<f64>) {
+    let l = ldlt.l();
+    let pap = ldlt.p() * a * ldlt.p().transpose();
+    let ldl = &l * &ldlt.d() * l.transpose();
+    assert!(max_abs_diff(&pap, &ldl) < 1e-10);
+}
+
+#[test]
+fn ldlt_reconstructs_indefinite() {
+    let a = indefinite(7);
+    let ldlt = a.ldlt();
+    assert!(ldlt.l().is_lower_triangular());
+    assert_reconstructs(&a, &ldlt);
+}
+
+#[test]
+fn ldlt_uses_two_by_two_pivot() {
+    let a: Mat<f64> = mat![[0.0, 1.0], [1.0, 0.0]];
+    let ldlt = Ldlt::new(a.as_ref());
+    assert_eq!(ldlt.block_sizes(), &[2]);
+    assert!((ldlt.det() - (-1.0)).abs() < 1e-12);
+    assert_reconstructs(&a, &ldlt);
+}
+
+#[test]
+fn ldlt_solve() {
+    let a = indefinite(8);
+    let b = Mat::from_fn(8, 2, |i, j| (i as f64) - (j as f64));
+    let x = a.ldlt().solve(b.as_ref()).unwrap();
+    assert!(max_abs_diff(&(&a * &x), &b) < 1e-9);
+}
+
+#[test]
+fn ldlt_det_matches_lu() {
+    let a = indefinite(6);
+    let det = a.ldlt().det();
+    assert!((det - a.det()).abs() < 1e-9 * det.abs().max(1.0));
+}
+
+#[test]
+fn ldlt_log_det() {
+    let a: Mat<f64> = mat![[2.0, 0.0], [0.0, -3.0]];
+    let (sign, log_abs) = a.ldlt().log_det();
+    assert_eq!(sign, -1.0);
+    assert!((log_abs - 6.0f64.ln()).abs() < 1e-12);
+}
+
+#[test]
+fn ldlt_inverse() {
+    let a = indefinite(5);
+    let inv = a.ldlt().inverse().unwrap();
+    assert!(max_abs_diff(&(&a * &inv), &Mat::identity(5)) < 1e-10);
+}
+
+#[test]
+fn ldlt_singular() {
+    let a = mat![[1.0, 1.0], [1.0, 1.0]];
+    let ldlt = a.ldlt();
+    assert!(ldlt.is_singular());
+    assert!(matches!(ldlt.inverse(), Err(Error::Singular { .. })));
+    assert_eq!(ldlt.log_det().0, 0.0);
+}
+
+#[test]
+fn ldlt_rank_one_update() {
+    let a = indefinite(6);
+    let x = Mat::from_fn(6, 1, |i, _| 0.5 * i as f64 - 1.0);
+    let mut ldlt = a.ldlt();
+    ldlt.rank_one_update(x.as_ref()).unwrap();
+    let expected = &a + &x * x.transpose();
+    assert_reconstructs(&expected, &ldlt);
+    let b = Mat::from_fn(6, 1, |i, _| i as f64);
+    let sol = ldlt.solve(b.as_ref()).unwrap();
+    assert!(max_abs_diff(&(&expected * &sol), &b) < 1e-9);
+}
+
+#[test]
+fn ldlt_rank_one_downdate() {
+    let a = indefinite(5);
+    let x = Mat::from_fn(5, 1, |i, _| (i as f64).sin());
+    let mut ldlt = a.ldlt();
+    ldlt.rank_one_downdate(x.as_ref()).unwrap();
+    assert_reconstructs(&(&a - &x * x.transpose()), &ldlt);
+}
+
+#[test]
+fn ldlt_update_zero_block() {
+    let a = mat![[0.0, 2.0, 0.0], [2.0, 0.0, 1.0], [0.0, 1.0, 3.0]];
+    let x = mat![[1.0], [0.0], [1.0]];
+    let mut ldlt = a.ldlt();
+    ldlt.rank_one_update(x.as_ref()).unwrap();
+    assert_reconstructs(&(&a + &x * x.transpose()), &ldlt);
+}
+
+#[test]
+fn ldlt_complex_hermitian() {
+    let a: Mat<Complex<f64>> = mat![
+        [c(0.0, 0.0), c(1.0, 2.0), c(0.5, 0.0)],
+        [c(1.0, -2.0), c(-1.0, 0.0), c(0.0, 1.0)],
+        [c(0.5, 0.0), c(0.0, -1.0), c(2.0, 0.0)]
+    ];
+    let ldlt = a.ldlt();
+    let l = ldlt.l();
+    let pap = ldlt.p() * &a * ldlt.p().adjoint();
+    assert!((&l * &ldlt.d() * l.adjoint() - &pap).norm().max() < 1e-12);
+
+    let b = mat![[c(1.0, 0.0)], [c(0.0, 1.0)], [c(-1.0, 1.0)]];
+    let x = ldlt.solve(b.as_ref()).unwrap();
+    assert!((&a * &x - &b).norm().max() < 1e-12);
+}
+
+#[test]
+fn ldlt_downdate_through_two_by_two_block() {
+    let a: Mat<f64> = mat![[1.0, 2.0], [2.0, 1.0]];
+    let y = mat![[1.0], [0.5]];
+    let mut ldlt = a.ldlt();
+    assert_eq!(ldlt.block_sizes(), &[2]);
+    ldlt.rank_one_downdate(y.as_ref()).unwrap();
+    let expected = mat![[0.0, 1.5], [1.5, 0.75]];
+    assert_reconstructs(&expected, &ldlt);
+    assert!((ldlt.det() - (-2.25)).abs() < 1e-12);
+}
+
+#[test]
+fn ldlt_update_near_zero_diagonal_is_accurate() {
+    let a: Mat<f64> = mat![[1e-9, 1.0, 0.3], [1.0, 1e-9, 0.2], [0.3, 0.2, 1e-9]];
+    let x = mat![[0.7], [-0.4], [1.1]];
+    let mut ldlt = a.ldlt();
+    assert!(ldlt.block_sizes().contains(&2));
+    ldlt.rank_one_update(x.as_ref()).unwrap();
+
+    let expected = &a + &x * x.transpose();
+    assert_reconstructs(&expected, &ldlt);
+    let b = mat![[1.0], [2.0], [3.0]];
+    let sol = ldlt.solve(b.as_ref()).unwrap();
+    let fresh = expected.ldlt().solve(b.as_ref()).unwrap();
+    assert!(max_abs_diff(&(&expected * &sol), &b) < 1e-13);
+    assert!(max_abs_diff(&sol, &fresh) < 1e-12);
+}
+
+#[test]
+fn ldlt_update_changing_inertia() {
+    // Diagonal pivots, but the downdate makes the leading entry vanish.
+    let a: Mat<f64> = mat![[1.0, 1.0], [1.0, -1.0]];
+    let y = mat![[1.0], [0.0]];
+    let mut ldlt = a.ldlt();
+    assert_eq!(ldlt.block_sizes(), &[1, 1]);
+    ldlt.rank_one_downdate(y.as_ref()).unwrap();
+    assert_reconstructs(&mat![[0.0, 1.0], [1.0, -1.0]], &ldlt);
+}
+
+#[test]
+fn ldlt_modification_to_singular_matrix() {
+    let a: Mat<f64> = mat![[-1.0, 0.0], [0.0, 2.0]];
+    let mut ldlt = a.ldlt();
+    assert!(matches!(
+        ldlt.rank_one_update(mat![[1.0], [0.0]].as_ref()),
+        Err(Error::Singular { .. })
+    ));
+    assert_eq!(ldlt.d(), a);
+}
+
+#[test]
+fn ldlt_definite_update_keeps_pivots() {
+    for sign in [1.0, -1.0] {
+        let b = Mat::from_fn(5, 5, |i, j| ((i * 3 + j) % 4) as f64 - 1.5);
+        let a = (b.transpose() * &b + Mat::identity(5) * 5.0) * sign;
+        let x = Mat::from_fn(5, 1, |i, _| 0.25 * i as f64);
+        let mut ldlt = a.ldlt();
+        let perm = ldlt.permutation().to_vec();
+        ldlt.rank_one_update(x.as_ref()).unwrap();
+        assert_eq!(ldlt.permutation(), perm.as_slice());
+        assert_reconstructs(&(&a + &x * x.transpose()), &ldlt);
+    }
+}
+
+#[test]
+fn ldlt_shape_errors() {
+    let a: Mat<f64> = Mat::zeros(2, 3);
+    assert_eq!(
+        Ldlt::try_new(a.as_ref()).err().unwrap(),
+        Error::NotSquare { nrows: 2, ncols: 3 }
+    );
+    assert!(a.try_ldlt().is_err());
+    let mut ldlt = indefinite(3).ldlt();
+    let mismatch = Error::ShapeMismatch {
+        lhs: (2, 1),
+        rhs: (3, 1),
+    };
+    assert_eq!(
+        ldlt.solve(Mat::zeros(2, 1).as_ref()).err(),
+        Some(mismatch.clone())
+    );
+    assert_eq!(
+        ldlt.rank_one_update(Mat::zeros(2, 1).as_ref()),
+        Err(mismatch)
+    );
+}
@@ -0,0 +1,197 @@
+mod common;
+
+use aris::linalg::Lu;
+use aris::{Complex, Error, Mat, mat};
+
+use common::c;
+
+fn assert_close(a: &Mat<f64>, b: &Mat<f64>, tol: f64) {
+    assert_eq!(a.shape(), b.shape());
+    for j in 0..a.ncols() {
+        for i in 0..a.nrows() {
+            assert!(
+                (a[(i, j)] - b[(i, j)]).abs() < tol,
+                "mismatch at ({}, {}): {} vs {}",
+                i,
+                j,
+                a[(i, j)],
+                b[(i, j)]
+            );
+        }
+    }
+}
+
+fn test_matrix(n: usize) -> Mat<f64> {
+    Mat::from_fn(n, n, |i, j| {
+        let base = ((i * 37 + j * 11) % 19) as f64 - 9.0;
+        if i == j { base + 3.0 * n as f64 } else { base }
+    })
+}
+
+#[test]
+fn lu_reconstructs_pa() {
+    let a = mat![[2.0, 1.0, 1.0], [4.0, -6.0, 0.0], [-2.0, 7.0, 2.0]];
+    let lu = a.lu();
+    assert!(lu.l().is_lower_triangular());
+    assert!(lu.u().is_upper_triangular());
+    assert_close(&(lu.p() * &a), &(lu.l() * lu.u()), 1e-12);
+}
+
+#[test]
+fn lu_partial_pivoting_chooses_largest() {
+    let a = mat![[1.0, 2.0], [3.0, 4.0]];
+    let lu = Lu::new(a.as_ref());
+    assert_eq!(lu.permutation(), &[1, 0]);
+    assert_eq!(lu.u()[(0, 0)], 3.0);
+}
+
+#[test]
+fn lu_solve_vector() {
+    let a = mat![[3.0, 2.0, -1.0], [2.0, -2.0, 4.0], [-1.0, 0.5, -1.0]];
+    let b = mat![[1.0], [-2.0], [0.0]];
+    let x = a.lu().solve(b.as_ref()).unwrap();
+    assert_close(&x, &mat![[1.0], [-2.0], [-2.0]], 1e-12);
+}
+
+#[test]
+fn lu_solve_multiple_rhs() {
+    let a = test_matrix(7);
+    let b = Mat::from_fn(7, 3, |i, j| (i + j) as f64);
+    let x = a.lu().solve(b.as_ref()).unwrap();
+    assert_close(&(&a * &x), &b, 1e-10);
+}
+
+#[test]
+fn lu_solve_in_place_on_view() {
+    let a = mat![[4.0, 1.0], [2.0, 3.0]];
+    let mut rhs = mat![[0.0, 5.0], [0.0, 5.0]];
+    a.lu().solve_in_place(rhs.view_mut(0, 1, 2, 1)).unwrap();
+    assert_close(&rhs, &mat![[0.0, 1.0], [0.0, 1.0]], 1e-12);
+}
+
+#[test]
+fn lu_blocked_large() {
+    let a = test_matrix(100);
+    let lu = a.lu();
+    assert_close(&(lu.p() * &a), &(lu.l() * lu.u()), 1e-9);
+    let b = Mat::from_fn(100, 2, |i, j| (i as f64).sin() + j as f64);
+    let x = lu.solve(b.as_ref()).unwrap();
+    assert_close(&(&a * &x), &b, 1e-9);
+}
+
+#[test]
+fn lu_det() {
+    let a: Mat<f64> = mat![[1.0, 2.0], [3.0, 4.0]];
+    assert!((a.det() - (-2.0)).abs() < 1e-12);
+    let b: Mat<f64> = mat![[2.0, 0.0, 0.0], [0.0, 3.0, 0.0], [0.0, 0.0, 4.0]];
+    assert!((b.det() - 24.0).abs() < 1e-12);
+}
+
+#[test]
+fn lu_det_singular_is_zero() {
+    let a = mat![[1.0, 2.0], [2.0, 4.0]];
+    assert_eq!(a.det(), 0.0);
+}
+
+#[test]
+fn lu_inverse() {
+    let a = mat![[4.0, 7.0], [2.0, 6.0]];
+    let inv = a.inverse().unwrap();
+    assert_close(&inv, &mat![[0.6, -0.7], [-0.2, 0.4]], 1e-12);
+    assert_close(&(&a * &inv), &Mat::identity(2), 1e-12);
+}
+
+#[test]
+fn lu_singular_reported() {
+    let a = mat![[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 0.0, 1.0]];
+    let lu = a.lu();
+    assert!(lu.is_singular());
+    assert!(matches!(lu.inverse(), Err(Error::Singular { .. })));
+    let b = mat![[1.0], [2.0], [3.0]];
+    assert!(lu.solve(b.as_ref()).is_err());
+    assert_eq!(lu.rcond(), 0.0);
+}
+
+#[test]
+fn lu_rcond_identity() {
+    let a: Mat<f64> = Mat::identity(5);
+    assert!((a.lu().rcond() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn lu_rcond_matches_exact() {
+    let a = mat![[1.0, 2.0], [3.0, 4.0]];
+    let inv = a.inverse().unwrap();
+    let norm1 = |m: &Mat<f64>| {
+        (0..m.ncols())
+            .map(|j| (0..m.nrows()).map(|i| m[(i, j)].abs()).sum::<f64>())
+            .fold(0.0, f64::max)
+    };
+    let exact = 1.0 / (norm1(&a) * norm1(&inv));
+    assert!((a.lu().rcond() - exact).abs() < 1e-12);
+}
+
+#[test]
+fn lu_rcond_ill_conditioned() {
+    let a = mat![[1.0, 1.0], [1.0, 1.0 + 1e-10]];
+    assert!(a.lu().rcond() < 1e-9);
+}
+
+#[test]
+fn lu_complex_solve_and_det() {
+    let a = mat![[c(1.0, 1.0), c(2.0, 0.0)], [c(0.0, -1.0), c(3.0, 2.0)]];
+    let det = a.det();
+    let expected = c(1.0, 1.0) * c(3.0, 2.0) - c(2.0, 0.0) * c(0.0, -1.0);
+    assert!((det - expected).norm() < 1e-12);
+
+    let b = mat![[c(1.0, 0.0)], [c(0.0, 1.0)]];
+    let x = a.lu().solve(b.as_ref()).unwrap();
+    let r = &a * &x - &b;
+    assert!(r.norm().max() < 1e-12);
+}
+
+#[test]
+fn lu_complex_inverse_and_rcond() {
+    let a: Mat<Complex<f64>> = Mat::from_fn(6, 6, |i, j| {
+        c(((i * 3 + j) % 5) as f64, (i as f64) - (j as f64))
+            + if i == j { c(10.0, 0.0) } else { c(0.0, 0.0) }
+    });
+    let inv = a.inverse().unwrap();
+    let err = (&a * &inv - Mat::identity(6)).norm().max();
+    assert!(err < 1e-12);
+    let rcond = a.lu().rcond();
+    assert!(rcond > 0.0 && rcond <= 1.0);
+}
+
+#[test]
+fn lu_empty() {
+    let a: Mat<f64> = Mat::new();
+    assert_eq!(a.det(), 1.0);
+    assert_eq!(a.inverse().unwrap().shape(), (0, 0));
+}
+
+#[test]
+#[should_panic(expected = "square")]
+fn lu_non_square_panics() {
+    let a: Mat<f64> = Mat::zeros(2, 3);
+    let _ = a.lu();
+}
+
+#[test]
+fn lu_shape_errors() {
+    let a: Mat<f64> = Mat::zeros(2, 3);
+    let not_square = Error::NotSquare { nrows: 2, ncols: 3 };
+    assert_eq!(Lu::try_new(a.as_ref()).err(), Some(not_square.clone()));
+    assert_eq!(a.try_det().err(), Some(not_square.clone()));
+    assert_eq!(a.inverse().err(), Some(not_square));
+
+    let lu = mat![[2.0, 1.0], [1.0, 3.0]].lu();
+    let mut b: Mat<f64> = Mat::zeros(3, 1);
+    let mismatch = Error::ShapeMismatch {
+        lhs: (3, 1),
+        rhs: (2, 1),
+    };
+    assert_eq!(lu.solve_in_place(b.as_mut()), Err(mismatch.clone()));
+    assert_eq!(lu.solve_adjoint_in_place(b.as_mut()), Err(mismatch));
+    assert_eq!(mat![[2.0, 1.0], [1.0, 3.0]].try_det(), Ok(5.0));
+}
@@ -0,0 +1,164 @@
+mod common;
+
+use aris::{Mat, mat, matmul_into};
+
+use common::c;
+
+fn naive(a: &Mat<f64>, b: &Mat<f64>) -> Mat<f64> {
+    Mat::from_fn(a.nrows(), b.ncols(), |i, j| {
+        (0..a.ncols()).map(|p| a[(i, p)] * b[(p, j)]).sum()
+    })
+}
+
+fn assert_close(a: &Mat<f64>, b: &Mat<f64>) {
+    assert_eq!(a.shape(), b.shape());
+    for j in 0..a.ncols() {
+        for i in 0..a.nrows() {
+            assert!(
+                (a[(i, j)] - b[(i, j)]).abs() < 1e-9,
+                "mismatch at ({}, {}): {} vs {}",
+                i,
+                j,
+                a[(i, j)],
+                b[(i, j)]
+            );
+        }
+    }
+}
+
+#[test]
+fn mul_mat_mat() {
+    let a = mat![[1, 2], [3, 4]];
+    let b = mat![[5, 6], [7, 8]];
+    assert_eq!(&a * &b, mat![[19, 22], [43, 50]]);
+}
+
+#[test]
+fn mul_rectangular() {
+    let a = mat![[1, 2, 3], [4, 5, 6]];
+    let b = mat![[7, 8], [9, 10], [11, 12]];
+    assert_eq!(a * b, mat![[58, 64], [139, 154]]);
+}
+
+#[test]
+fn mul_matrix_vector() {
+    let a = mat![[1, 2], [3, 4], [5, 6]];
+    let x = mat![[1], [-1]];
+    assert_eq!(&a * &x, mat![[-1], [-1], [-1]]);
+}
+
+#[test]
+fn mul_row_vector_matrix() {
+    let x = mat![[1, 1, 1]];
+    let a = mat![[1, 2], [3, 4], [5, 6]];
+    assert_eq!(&x * &a, mat![[9, 12]]);
+}
+
+#[test]
+fn mul_matref_matmut() {
+    let a = mat![[1, 2], [3, 4]];
+    let mut b = mat![[1, 0], [0, 1]];
+    let bm = b.as_mut();
+    assert_eq!(a.as_ref() * bm, a);
+}
+
+#[test]
+fn mul_transposed_view() {
+    let a = mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    let b = mat![[1.0, 0.0, 2.0], [0.0, 1.0, 3.0]];
+    let expected = naive(&a.transpose().to_owned(), &b);
+    assert_close(&(a.transpose() * b.as_ref()), &expected);
+}
+
+#[test]
+fn mul_reversed_views() {
+    let a = Mat::from_fn(5, 4, |i, j| (i * 4 + j) as f64);
+    let b = Mat::from_fn(4, 3, |i, j| (i as f64) - (j as f64) * 0.5);
+    let ar = a.reverse_rows();
+    let bc = b.reverse_cols();
+    let expected = naive(&ar.to_owned(), &bc.to_owned());
+    assert_close(&(ar * bc), &expected);
+}
+
+#[test]
+fn mul_large_blocked() {
+    let a = Mat::from_fn(131, 277, |i, j| ((i * 7 + j * 3) % 11) as f64 - 5.0);
+    let b = Mat::from_fn(277, 70, |i, j| ((i * 5 + j * 13) % 17) as f64 * 0.25);
+    assert_close(&(&a * &b), &naive(&a, &b));
+}
+
+#[test]
+fn mul_large_transposed() {
+    let a = Mat::from_fn(300, 41, |i, j| ((i + 2 * j) % 9) as f64);
+    let b = Mat::from_fn(300, 37, |i, j| ((3 * i + j) % 5) as f64 - 2.0);
+    let expected = naive(&a.transpose().to_owned(), &b);
+    assert_close(&(a.transpose() * &b), &expected);
+}
+
+#[test]
+fn mul_complex() {
+    let a = mat![[c(1.0, 1.0), c(0.0, 2.0)]];
+    let b = mat![[c(1.0, -1.0)], [c(3.0, 0.0)]];
+    assert_eq!(a * b, mat![[c(2.0, 6.0)]]);
+}
+
+#[test]
+fn mul_empty_inner_dimension() {
+    let a: Mat<i32> = Mat::zeros(3, 0);
+    let b: Mat<i32> = Mat::zeros(0, 2);
+    assert_eq!(&a * &b, Mat::zeros(3, 2));
+}
+
+#[test]
+#[should_panic(expected = "shape mismatch")]
+fn mul_shape_mismatch() {
+    let a = mat![[1, 2], [3, 4]];
+    let b = mat![[1, 2, 3]];
+    let _ = &a * &b;
+}
+
+#[test]
+fn matmul_into_alpha_beta() {
+    let a = mat![[1.0, 2.0], [3.0, 4.0]];
+    let b = mat![[1.0, 1.0], [1.0, 1.0]];
+    let mut c = mat![[1.0, 2.0], [3.0, 4.0]];
+    matmul_into(c.as_mut(), 2.0, a.as_ref(), b.as_ref(), -1.0);
+    assert_eq!(c, mat![[5.0, 4.0], [11.0, 10.0]]);
+}
+
+#[test]
+fn matmul_into_beta_zero_ignores_nan() {
+    let a = mat![[1.0, 2.0], [3.0, 4.0]];
+    let b = Mat::identity(2);
+    let mut c = mat![[f64::NAN, f64::NAN], [f64::NAN, f64::NAN]];
+    matmul_into(c.as_mut(), 1.0, a.as_ref(), b.as_ref(), 0.0);
+    assert_eq!(c, a);
+}
+
+#[test]
+fn matmul_into_transposed_destination() {
+    let a = Mat::from_fn(6, 9, |i, j| (i as f64) * 0.5 - j as f64);
+    let b = Mat::from_fn(9, 7, |i, j| ((i + j) % 4) as f64);
+    let mut c: Mat<f64> = Mat::zeros(7, 6);
+    matmul_into(c.transpose_mut(), 1.0, a.as_ref(), b.as_ref(), 0.0);
+    assert_close(&c.transpose().to_owned(), &naive(&a, &b));
+}
+
+#[test]
+fn matmul_into_sub_view() {
+    let a = mat![[1, 2], [3, 4]];
+    let mut c = Mat::full(3, 3, 1);
+    matmul_into(c.view_mut(1, 1, 2, 2), 1, a.as_ref(), a.as_ref(), 1);
+    assert_eq!(c, mat![[1, 1, 1], [1, 8, 11], [1, 16, 23]]);
+}
+
+#[test]
+fn matrix_vector_keeps_non_finite_entries() {
+    let a = mat![[f64::INFINITY, 1.0], [f64::NAN, 2.0]];
+    let x = mat![[0.0], [1.0]];
+    let xx = mat![[0.0, 0.0], [1.0, 1.0]];
+    let y = &a * &x;
+    let yy = &a * &xx;
+    assert!(y[(0, 0)].is_nan() && y[(1, 0)].is_nan());
+    assert!(yy[(0, 0)].is_nan() && yy[(1, 0)].is_nan());
+}
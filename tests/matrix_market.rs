@@ -0,0 +1,274 @@
+mod common;
+
+use std::io::Cursor;
+
+use aris::io::{MtxElement, MtxFormat, MtxSymmetry, load_mtx, read_mtx, save_mtx, write_mtx};
+use aris::{Complex, Error, Mat, mat};
+
+use common::c;
+
+fn read<T: MtxElement>(text: &str) -> aris::Result<Mat<T>> {
+    read_mtx(Cursor::new(text))
+}
+
+fn write<T: MtxElement>(mat: &Mat<T>, format: MtxFormat, symmetry: MtxSymmetry) -> String {
+    let mut buf = Vec::new();
+    write_mtx(&mut buf, mat.as_ref(), format, symmetry).unwrap();
+    String::from_utf8(buf).unwrap()
+}
+
+#[test]
+fn read_array_general() {
+    let text = "%%MatrixMarket matrix array real general\n% comment\n2 3\n1\n4\n2\n5\n3\n6.5\n";
+    let a: Mat<f64> = read(text).unwrap();
+    assert_eq!(a, mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.5]]);
+}
+
+#[test]
+fn read_coordinate_general_sums_duplicates() {
+    let text = "%%MatrixMarket matrix coordinate real general\n\
+                3 3 4\n\
+                1 1 1.5\n\
+                3 2 -2e1\n\
+                1 1 0.5\n\
+                2 3 4\n";
+    let a: Mat<f64> = read(text).unwrap();
+    assert_eq!(a, mat![[2.0, 0.0, 0.0], [0.0, 0.0, 4.0], [0.0, -20.0, 0.0]]);
+}
+
+#[test]
+fn read_symmetric_expands_lower_triangle() {
+    let text =
+        "%%MatrixMarket matrix coordinate integer symmetric\n3 3 4\n1 1 2\n2 1 -1\n3 2 -1\n3 3 2\n";
+    let a: Mat<i32> = read(text).unwrap();
+    assert_eq!(a, mat![[2, -1, 0], [-1, 0, -1], [0, -1, 2]]);
+    let text = "%%MatrixMarket matrix array real symmetric\n2 2\n1\n2\n3\n";
+    let b: Mat<f64> = read(text).unwrap();
+    assert_eq!(b, mat![[1.0, 2.0], [2.0, 3.0]]);
+}
+
+#[test]
+fn read_skew_symmetric() {
+    let text = "%%MatrixMarket matrix array real skew-symmetric\n3 3\n1\n2\n3\n";
+    let a: Mat<f64> = read(text).unwrap();
+    assert_eq!(
+        a,
+        mat![[0.0, -1.0, -2.0], [1.0, 0.0, -3.0], [2.0, 3.0, 0.0]]
+    );
+}
+
+#[test]
+fn read_complex_hermitian() {
+    let text =
+        "%%MatrixMarket matrix coordinate complex hermitian\n2 2 3\n1 1 1 0\n2 1 2 3\n2 2 4 0\n";
+    let a: Mat<Complex<f64>> = read(text).unwrap();
+    assert_eq!(
+        a,
+        mat![[c(1.0, 0.0), c(2.0, -3.0)], [c(2.0, 3.0), c(4.0, 0.0)]]
+    );
+}
+
+#[test]
+fn read_pattern() {
+    let text = "%%MatrixMarket matrix coordinate pattern general\n2 3 2\n1 3\n2 1\n";
+    let a: Mat<f64> = read(text).unwrap();
+    assert_eq!(a, mat![[0.0, 0.0, 1.0], [1.0, 0.0, 0.0]]);
+    let b: Mat<bool> = read(text).unwrap();
+    assert_eq!(b, mat![[false, false, true], [true, false, false]]);
+}
+
+#[test]
+fn read_header_is_case_insensitive_and_widens_fields() {
+    let text = "%%MatrixMarket MATRIX Coordinate Integer General\n1 2 1\n1 2 7\n";
+    let a: Mat<f64> = read(text).unwrap();
+    assert_eq!(a, mat![[0.0, 7.0]]);
+    let z: Mat<Complex<f32>> = read(text).unwrap();
+    assert_eq!(z, mat![[Complex::new(0.0, 0.0), Complex::new(7.0, 0.0)]]);
+}
+
+#[test]
+fn read_rejects_malformed_headers() {
+    let err = read::<f64>("2 2\n1\n2\n3\n4\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid format: line 1: missing '%%MatrixMarket' banner"
+    );
+    let err = read::<f64>("%%MatrixMarket vector coordinate real general\n").unwrap_err();
+    assert!(err.to_string().contains("unsupported object 'vector'"));
+    let err = read::<f64>("%%MatrixMarket matrix dense real general\n").unwrap_err();
+    assert!(err.to_string().contains("unknown format 'dense'"));
+    let err = read::<f64>("%%MatrixMarket matrix array pattern general\n").unwrap_err();
+    assert!(err.to_string().contains("not a valid combination"));
+    let err = read::<f64>("%%MatrixMarket matrix array real hermitian\n").unwrap_err();
+    assert!(err.to_string().contains("not a valid combination"));
+    let err = read::<f64>("%%MatrixMarket matrix array real\n").unwrap_err();
+    assert!(matches!(err, Error::Format { .. }));
+}
+
+#[test]
+fn read_rejects_bad_sizes_and_entries() {
+    let err = read::<f64>("%%MatrixMarket matrix coordinate real general\n% c\n2 2\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid format: line 3: invalid size line '2 2', expected 'rows cols entries'"
+    );
+    let err =
+        read::<f64>("%%MatrixMarket matrix coordinate real general\n2 2 1\n3 1 1.0\n").unwrap_err();
+    assert!(err.to_string().contains("line 3: index '3' out of range"));
+    let err =
+        read::<f64>("%%MatrixMarket matrix coordinate real general\n2 2 1\n1 1 abc\n").unwrap_err();
+    assert!(err.to_string().contains("invalid real value 'abc'"));
+    let err = read::<f64>("%%MatrixMarket matrix array real symmetric\n2 3\n").unwrap_err();
+    assert!(err.to_string().contains("must be square"));
+}
+
+#[test]
+fn read_rejects_oversized_header() {
+    let err =
+        read::<f64>("%%MatrixMarket matrix coordinate real general\n4000000000 4000000000 0\n")
+            .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid format: line 2: a 4000000000x4000000000 matrix is too large"
+    );
+    let text = format!(
+        "%%MatrixMarket matrix coordinate real general\n{} 2 0\n",
+        usize::MAX
+    );
+    assert!(matches!(read::<f64>(&text), Err(Error::Format { .. })));
+}
+
+#[test]
+fn read_rejects_integer_overflow() {
+    let text = "%%MatrixMarket matrix coordinate integer general\n1 1 2\n1 1 100\n1 1 100\n";
+    let err = read::<i8>(text).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid format: line 4: entry overflows the element type"
+    );
+    assert_eq!(read::<i16>(text).unwrap(), mat![[200]]);
+
+    let text = "%%MatrixMarket matrix array integer skew-symmetric\n2 2\n-128\n";
+    let err = read::<i8>(text).unwrap_err();
+    assert!(err.to_string().contains("line 3: entry overflows"));
+    let text = "%%MatrixMarket matrix coordinate integer skew-symmetric\n2 2 1\n2 1 -128\n";
+    assert!(read::<i8>(text).is_err());
+}
+
+#[test]
+fn read_rejects_truncated_and_trailing_data() {
+    let err = read::<f64>("%%MatrixMarket matrix array real general\n2 1\n1\n").unwrap_err();
+    assert!(err.to_string().contains("unexpected end of file"));
+    let err = read::<f64>("%%MatrixMarket matrix array real general\n1 1\n1\n2\n").unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("line 4: unexpected data after the last entry")
+    );
+}
+
+#[test]
+fn read_rejects_incompatible_element_type() {
+    let text = "%%MatrixMarket matrix array real general\n1 1\n1.5\n";
+    let err = read::<i32>(text).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid format: cannot load a real matrix into elements of type i32"
+    );
+    let text = "%%MatrixMarket matrix array complex general\n1 1\n1 2\n";
+    assert!(read::<f64>(text).is_err());
+}
+
+#[test]
+fn write_array_and_coordinate() {
+    let a: Mat<f64> = mat![[1.0, 0.0], [0.0, -2.5]];
+    assert_eq!(
+        write(&a, MtxFormat::Array, MtxSymmetry::General),
+        "%%MatrixMarket matrix array real general\n2 2\n1.0\n0.0\n0.0\n-2.5\n"
+    );
+    assert_eq!(
+        write(&a, MtxFormat::Coordinate, MtxSymmetry::General),
+        "%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1.0\n2 2 -2.5\n"
+    );
+    let p = mat![[false, true], [true, false]];
+    assert_eq!(
+        write(&p, MtxFormat::Coordinate, MtxSymmetry::Symmetric),
+        "%%MatrixMarket matrix coordinate pattern symmetric\n2 2 1\n2 1\n"
+    );
+}
+
+#[test]
+fn write_symmetric_round_trips() {
+    let a: Mat<i64> = mat![[4, 1, 0], [1, 5, 2], [0, 2, 6]];
+    let text = write(&a, MtxFormat::Coordinate, MtxSymmetry::Symmetric);
+    assert!(text.starts_with("%%MatrixMarket matrix coordinate integer symmetric\n3 3 5\n"));
+    assert_eq!(read::<i64>(&text).unwrap(), a);
+    let s: Mat<f64> = mat![[0.0, -1.5], [1.5, 0.0]];
+    let text = write(&s, MtxFormat::Array, MtxSymmetry::SkewSymmetric);
+    assert_eq!(
+        text,
+        "%%MatrixMarket matrix array real skew-symmetric\n2 2\n1.5\n"
+    );
+    assert_eq!(read::<f64>(&text).unwrap(), s);
+    let h = mat![[c(1.0, 0.0), c(0.0, -2.0)], [c(0.0, 2.0), c(3.0, 0.0)]];
+    let text = write(&h, MtxFormat::Coordinate, MtxSymmetry::Hermitian);
+    assert_eq!(read::<Complex<f64>>(&text).unwrap(), h);
+}
+
+#[test]
+fn write_rejects_invalid_symmetry() {
+    let a: Mat<f64> = mat![[1.0, 2.0], [3.0, 4.0]];
+    let mut buf = Vec::new();
+    let err = write_mtx(
+        &mut buf,
+        a.as_ref(),
+        MtxFormat::Array,
+        MtxSymmetry::Symmetric,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid format: matrix is not symmetric: mismatch at (1, 0)"
+    );
+    let err = write_mtx(
+        &mut buf,
+        a.as_ref(),
+        MtxFormat::Coordinate,
+        MtxSymmetry::Hermitian,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("not a valid combination"));
+    let p = mat![[true]];
+    assert!(write_mtx(&mut buf, p.as_ref(), MtxFormat::Array, MtxSymmetry::General).is_err());
+    let r: Mat<f64> = Mat::zeros(2, 3);
+    assert!(
+        write_mtx(
+            &mut buf,
+            r.as_ref(),
+            MtxFormat::Array,
+            MtxSymmetry::Symmetric
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn save_and_load_file() {
+    let path = std::env::temp_dir().join(format!("aris-mtx-{}.mtx", std::process::id()));
+    let a = Mat::from_fn(6, 4, |i, j| {
+        if (i + j) % 3 == 0 {
+            0.1 * (i as f64) - j as f64
+        } else {
+            0.0
+        }
+    });
+    save_mtx(
+        &path,
+        a.view(1, 0, 5, 4),
+        MtxFormat::Coordinate,
+        MtxSymmetry::General,
+    )
+    .unwrap();
+    let b: Mat<f64> = load_mtx(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(b, a.view(1, 0, 5, 4));
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "nalgebra")]
+
+use aris::{Error, Mat, MatMut, MatRef, mat};
+use nalgebra::{DMatrix, DMatrixView, DMatrixViewMut, Dyn, U1, dmatrix};
+
+fn sample() -> Mat<f64> {
+    Mat::from_fn(9, 4, |i, j| (10 * i + j) as f64)
+}
+
+#[test]
+fn mat_ref_to_view_shares_memory() {
+    let a = sample();
+    let view = DMatrixView::<f64, Dyn, Dyn>::try_from(a.view(1, 1, 6, 3)).unwrap();
+    assert_eq!(view.shape(), (6, 3));
+    assert_eq!(view.strides(), (1, 9));
+    assert!(std::ptr::eq(&view[(0, 0)], &a[(1, 1)]));
+    assert_eq!(view[(5, 2)], a[(6, 3)]);
+
+    let view = DMatrixView::<f64, Dyn, Dyn>::try_from(a.transpose()).unwrap();
+    assert_eq!(view.strides(), (9, 1));
+    assert_eq!(view[(3, 8)], 83.0);
+}
+
+#[test]
+fn fixed_unit_row_stride() {
+    let a = sample();
+    let view: DMatrixView<'_, f64> = a.as_ref().try_into().unwrap();
+    assert_eq!(view[(8, 3)], 83.0);
+    assert_eq!(
+        DMatrixView::<f64, U1, Dyn>::try_from(a.transpose()).err(),
+        Some(Error::Unsupported {
+            operation: "strides other than those of the nalgebra view type"
+        })
+    );
+}
+
+#[test]
+fn negative_strides_are_rejected() {
+    let a = sample();
+    assert_eq!(
+        DMatrixView::<f64, Dyn, Dyn>::try_from(a.reverse_rows()).err(),
+        Some(Error::Unsupported {
+            operation: "negative strides in nalgebra views"
+        })
+    );
+}
+
+#[test]
+fn view_to_mat_ref() {
+    let b = dmatrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+    let m = MatRef::from(b.view((0, 1), (2, 2)));
+    assert_eq!((m.row_stride(), m.col_stride()), (1, 2));
+    assert_eq!(m, mat![[2.0, 3.0], [5.0, 6.0]]);
+    let t = b.transpose();
+    let m = MatRef::from(t.as_view::<Dyn, Dyn, Dyn, Dyn>());
+    assert_eq!(m[(2, 1)], 6.0);
+}
+
+#[test]
+fn mutable_views_write_through() {
+    let mut a = sample();
+    let mut view = DMatrixViewMut::<f64, Dyn, Dyn>::try_from(a.transpose_mut()).unwrap();
+    view[(3, 8)] = -1.0;
+    assert_eq!(a[(8, 3)], -1.0);
+
+    let mut b = DMatrix::<f64>::zeros(3, 2);
+    let mut m = MatMut::from(b.view_mut((1, 0), (2, 2)));
+    m[(1, 1)] = 5.0;
+    assert_eq!(b[(2, 1)], 5.0);
+}
+
+#[test]
+fn owned_conversions_move_elements() {
+    let a = sample();
+    let b = DMatrix::from(a.clone());
+    assert_eq!(b.shape(), (9, 4));
+    assert_eq!(b[(8, 3)], 83.0);
+    assert_eq!(Mat::from(b), a);
+
+    let strings = Mat::from_fn(2, 3, |i, j| format!("{i}{j}"));
+    let b = DMatrix::from(strings.clone());
+    assert_eq!(Mat::from(b), strings);
+}
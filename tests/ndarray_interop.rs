@@ -0,0 +1,93 @@
+#![cfg(feature = "ndarray")]
+
+use aris::{Mat, MatMut, MatRef, mat};
+use ndarray::{Array2, ArrayView2, ArrayViewMut2, ShapeBuilder, array, s};
+
+fn sample() -> Mat<f64> {
+    Mat::from_fn(9, 4, |i, j| (10 * i + j) as f64)
+}
+
+fn assert_same(view: ArrayView2<'_, f64>, matrix: MatRef<'_, f64>) {
+    assert_eq!(view.dim(), matrix.shape());
+    assert_eq!(view.strides(), &[matrix.row_stride(), matrix.col_stride()]);
+    for ((i, j), x) in view.indexed_iter() {
+        assert_eq!(*x, matrix[(i, j)]);
+        assert!(std::ptr::eq(x, &matrix[(i, j)]));
+    }
+}
+
+#[test]
+fn mat_ref_to_array_view_shares_memory() {
+    let a = sample();
+    assert_same(a.as_ref().into(), a.as_ref());
+    assert_same(a.transpose().into(), a.transpose());
+    assert_same(a.view(2, 1, 5, 3).into(), a.view(2, 1, 5, 3));
+}
+
+#[test]
+fn negative_strides_are_preserved() {
+    let a = sample();
+    for view in [
+        a.reverse_rows(),
+        a.reverse_cols(),
+        a.reverse_rows().reverse_cols(),
+        a.transpose().reverse_rows(),
+    ] {
+        assert_same(view.into(), view);
+    }
+}
+
+#[test]
+fn array_view_to_mat_ref() {
+    let a = array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    let m = MatRef::from(a.view());
+    assert_eq!((m.row_stride(), m.col_stride()), (3, 1));
+    assert_eq!(m, mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+    let b = Array2::from_shape_fn((6, 5), |(i, j)| (i * 5 + j) as f64);
+    let sliced = b.slice(s![..;-2, 1..;2]);
+    let m = MatRef::from(sliced);
+    assert_eq!((m.row_stride(), m.col_stride()), (-10, 2));
+    assert_same(sliced, m);
+}
+
+#[test]
+fn mutable_views_write_through() {
+    let mut a = sample();
+    let mut view: ArrayViewMut2<'_, f64> = a.as_mut().reverse_rows_mut().into();
+    view[(0, 1)] = -1.0;
+    view.column_mut(3).fill(0.0);
+    assert_eq!(a[(8, 1)], -1.0);
+    assert!((0..9).all(|i| a[(i, 3)] == 0.0));
+
+    let mut b = Array2::<f64>::zeros((3, 4).f());
+    let mut m = MatMut::from(b.view_mut());
+    m[(2, 3)] = 7.0;
+    m.row_mut(0).fill(1.0);
+    assert_eq!(b[(2, 3)], 7.0);
+    assert_eq!(b.row(0).sum(), 4.0);
+}
+
+#[test]
+fn empty_views() {
+    let a = Mat::<f64>::zeros(0, 3);
+    let view: ArrayView2<'_, f64> = a.as_ref().into();
+    assert_eq!(view.dim(), (0, 3));
+    let m = MatRef::from(view);
+    assert_eq!(m.shape(), (0, 3));
+}
+
+#[test]
+fn owned_conversions() {
+    let a = sample();
+    let array = Array2::from(a.clone());
+    // Padding is dropped when the columns move into a packed Vec.
+    assert_eq!(array.strides(), &[1, 9]);
+    assert_eq!(array[(8, 3)], 83.0);
+    assert_eq!(Mat::from(array), a);
+
+    let strings = Mat::from_fn(3, 2, |i, j| format!("{i}{j}"));
+    let array = Array2::from(strings);
+    assert_eq!(array[(2, 1)], "21");
+    assert_eq!(Mat::from(array.reversed_axes())[(1, 2)], "21");
+}
@@ -0,0 +1,171 @@
+mod common;
+
+use aris::linalg::Norm;
+use aris::{Error, Mat, col, mat, row};
+
+use common::c;
+
+#[test]
+fn frobenius_norm() {
+    let a: Mat<f64> = mat![[1.0, 2.0], [3.0, 4.0]];
+    assert!((a.frobenius_norm() - 30.0f64.sqrt()).abs() < 1e-14);
+}
+
+#[test]
+fn frobenius_norm_does_not_overflow() {
+    let a: Mat<f64> = mat![[3e200, 4e200]];
+    assert!((a.frobenius_norm() / 5e200 - 1.0).abs() < 1e-14);
+    let b: Mat<f64> = mat![[3e-200], [4e-200]];
+    assert!((b.frobenius_norm() / 5e-200 - 1.0).abs() < 1e-14);
+}
+
+#[test]
+fn one_and_inf_norms() {
+    let a: Mat<f64> = mat![[1.0, -2.0, 3.0], [-4.0, 5.0, -6.0]];
+    assert_eq!(a.one_norm(), 9.0);
+    assert_eq!(a.inf_norm(), 15.0);
+    assert_eq!(a.transpose().one_norm(), 15.0);
+    assert_eq!(a.max_abs_norm(), 6.0);
+}
+
+#[test]
+fn norms_propagate_nan() {
+    let a: Mat<f64> = mat![[f64::NAN, 1.0], [2.0, 3.0]];
+    assert!(a.one_norm().is_nan());
+    assert!(a.inf_norm().is_nan());
+    assert!(a.max_abs_norm().is_nan());
+    assert!(a.frobenius_norm().is_nan());
+    let b: Mat<f64> = mat![[1.0, f64::INFINITY], [f64::NAN, 3.0]];
+    assert!(b.max_abs_norm().is_nan());
+
+    let x: Mat<f64> = col![f64::NAN, 2.0];
+    for p in [1.0, 2.0, 3.0, f64::INFINITY] {
+        assert!(x.vector_norm(p).is_nan(), "p = {p}");
+    }
+    assert!(x.transpose().vector_norm(1.0).is_nan());
+
+    let zeros = Mat::zeros(2, 2);
+    for kind in [Norm::One, Norm::Inf, Norm::MaxAbs] {
+        let d = a.distance(zeros.as_ref(), kind).unwrap();
+        assert!(d.is_nan(), "{kind:?}");
+    }
+    let d = x.distance(col![0.0, 0.0].as_ref(), Norm::P(1.0)).unwrap();
+    assert!(d.is_nan());
+}
+
+#[test]
+fn spectral_and_nuclear_norms() {
+    let a: Mat<f64> = mat![[3.0, 0.0], [0.0, -4.0]];
+    assert!((a.spectral_norm().unwrap() - 4.0).abs() < 1e-14);
+    assert!((a.nuclear_norm().unwrap() - 7.0).abs() < 1e-14);
+}
+
+#[test]
+fn norm_inequalities() {
+    let a = Mat::from_fn(5, 4, |i, j| ((i * 3 + j * 7) % 5) as f64 - 2.0);
+    let two = a.spectral_norm().unwrap();
+    let fro = a.frobenius_norm();
+    let nuclear = a.nuclear_norm().unwrap();
+    assert!(two <= fro + 1e-12);
+    assert!(fro <= nuclear + 1e-12);
+    assert!(two * two <= a.one_norm() * a.inf_norm() + 1e-10);
+}
+
+#[test]
+fn complex_norms() {
+    let a = mat![[c(3.0, 4.0), c(0.0, 1.0)], [c(0.0, 0.0), c(-1.0, 0.0)]];
+    assert_eq!(a.max_abs_norm(), 5.0);
+    assert_eq!(a.one_norm(), 5.0);
+    assert_eq!(a.inf_norm(), 6.0);
+    assert!((a.frobenius_norm() - 27.0f64.sqrt()).abs() < 1e-14);
+}
+
+#[test]
+fn vector_p_norms() {
+    let v: Mat<f64> = col![3.0, -4.0, 0.0];
+    assert_eq!(v.vector_norm(1.0), 7.0);
+    assert!((v.vector_norm(2.0) - 5.0).abs() < 1e-14);
+    assert_eq!(v.vector_norm(f64::INFINITY), 4.0);
+    let expected = (27.0f64 + 64.0).cbrt();
+    assert!((v.vector_norm(3.0) - expected).abs() < 1e-14);
+    let r: Mat<f64> = row![3.0, -4.0, 0.0];
+    assert_eq!(r.vector_norm(1.0), 7.0);
+}
+
+#[test]
+fn vector_p_norm_scales_large_values() {
+    let v: Mat<f64> = row![1e300, 1e300];
+    let expected = 1e300 * 2.0f64.powf(1.0 / 3.0);
+    assert!((v.vector_norm(3.0) / expected - 1.0).abs() < 1e-14);
+}
+
+#[test]
+#[should_panic(expected = "vector norm of a matrix with several rows and columns")]
+fn vector_norm_rejects_matrix() {
+    let a: Mat<f64> = Mat::zeros(2, 2);
+    let _ = a.vector_norm(2.0);
+}
+
+#[test]
+fn try_vector_norm_errors() {
+    let a: Mat<f64> = Mat::zeros(2, 2);
+    assert!(a.try_vector_norm(2.0).is_err());
+    assert!(a.norm_with(Norm::P(2.0)).is_err());
+    let v: Mat<f64> = col![3.0, 4.0];
+    assert_eq!(v.try_vector_norm(2.0), Ok(5.0));
+    for p in [0.0, -1.0, f64::NAN] {
+        assert_eq!(
+            v.try_vector_norm(p),
+            Err(Error::Unsupported {
+                operation: "p-norm with p <= 0"
+            })
+        );
+    }
+}
+
+#[test]
+fn norm_with_dispatches() {
+    let a: Mat<f64> = mat![[1.0, -2.0], [3.0, 4.0]];
+    assert_eq!(a.norm_with(Norm::One).unwrap(), a.one_norm());
+    assert_eq!(a.norm_with(Norm::Inf).unwrap(), a.inf_norm());
+    assert_eq!(a.norm_with(Norm::MaxAbs).unwrap(), 4.0);
+    assert_eq!(a.norm_with(Norm::Frobenius).unwrap(), a.frobenius_norm());
+    let v: Mat<f64> = col![1.0, 1.0];
+    assert!((v.norm_with(Norm::P(2.0)).unwrap() - 2.0f64.sqrt()).abs() < 1e-14);
+}
+
+#[test]
+fn distance_between_matrices() {
+    let a: Mat<f64> = mat![[1.0, 2.0], [3.0, 4.0]];
+    let b: Mat<f64> = mat![[1.0, 2.0], [3.0, 7.0]];
+    assert_eq!(a.distance(b.as_ref(), Norm::MaxAbs).unwrap(), 3.0);
+    assert!((a.distance(b.as_ref(), Norm::Frobenius).unwrap() - 3.0).abs() < 1e-14);
+    assert!((a.distance(b.as_ref(), Norm::Spectral).unwrap() - 3.0).abs() < 1e-14);
+    assert_eq!(a.distance(a.as_ref(), Norm::Nuclear).unwrap(), 0.0);
+}
+
+#[test]
+fn distance_shape_mismatch() {
+    let a: Mat<f64> = Mat::zeros(2, 2);
+    let b: Mat<f64> = Mat::zeros(2, 3);
+    assert_eq!(
+        a.distance(b.as_ref(), Norm::One),
+        Err(Error::ShapeMismatch {
+            lhs: (2, 2),
+            rhs: (2, 3)
+        })
+    );
+}
+
+#[test]
+fn norms_of_views_and_empty() {
+    let a: Mat<f64> = mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+    let v = a.view(1, 1, 2, 2);
+    assert_eq!(v.one_norm(), 15.0);
+    assert_eq!(v.transpose().inf_norm(), 15.0);
+    assert_eq!(v.inf_norm(), 17.0);
+    let empty: Mat<f64> = Mat::zeros(0, 3);
+    assert_eq!(empty.frobenius_norm(), 0.0);
+    assert_eq!(empty.one_norm(), 0.0);
+    assert_eq!(empty.inf_norm(), 0.0);
+}
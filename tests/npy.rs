@@ -0,0 +1,241 @@
+use std::io::Cursor;
+
+use aris::io::{NpyElement, load_npy, read_npy, save_npy, write_npy};
+use aris::{Complex, Error, Mat, mat};
+
+fn npy_bytes(major: u8, header: &str, data: &[u8]) -> Vec<u8> {
+    let prefix = if major == 1 { 10 } else { 12 };
+    let mut header = header.to_owned();
+    while !(prefix + header.len() + 1).is_multiple_of(64) {
+        header.push(' ');
+    }
+    header.push('\n');
+    let mut out = b"\x93NUMPY".to_vec();
+    out.extend_from_slice(&[major, 0]);
+    if major == 1 {
+        out.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    } else {
+        out.extend_from_slice(&(header.len() as u32).to_le_bytes());
+    }
+    out.extend_from_slice(header.as_bytes());
+    out.extend_from_slice(data);
+    out
+}
+
+fn round_trip<T: NpyElement>(mat: &Mat<T>) -> Mat<T> {
+    let mut buf = Vec::new();
+    write_npy(&mut buf, mat.as_ref()).unwrap();
+    read_npy(Cursor::new(buf)).unwrap()
+}
+
+#[test]
+fn round_trip_f64() {
+    let a: Mat<f64> = mat![[1.0, -2.5, 3.0], [4.0, 5.0, f64::INFINITY]];
+    assert_eq!(round_trip(&a), a);
+}
+
+#[test]
+fn round_trip_integer_and_bool_dtypes() {
+    let a = Mat::from_fn(3, 2, |i, j| (i as i8) - (j as i8) * 3);
+    assert_eq!(round_trip(&a), a);
+    let b = Mat::from_fn(2, 2, |i, j| u16::MAX - (i + j) as u16);
+    assert_eq!(round_trip(&b), b);
+    let c = Mat::from_fn(2, 3, |i, j| (i * 3 + j) as u64 * 1_000_000_000_000);
+    assert_eq!(round_trip(&c), c);
+    let d = Mat::from_fn(2, 2, |i, j| -((i * 2 + j) as isize));
+    assert_eq!(round_trip(&d), d);
+    let e = mat![[true, false], [false, true]];
+    assert_eq!(round_trip(&e), e);
+}
+
+#[test]
+fn round_trip_complex() {
+    let a = mat![[Complex::new(1.0f32, -1.0), Complex::new(0.5, 2.0)]];
+    assert_eq!(round_trip(&a), a);
+    let b = mat![[Complex::new(1.0f64, 2.0)], [Complex::new(-3.0, 0.25)]];
+    assert_eq!(round_trip(&b), b);
+}
+
+#[test]
+fn written_header_matches_numpy_layout() {
+    let a: Mat<f64> = Mat::zeros(2, 3);
+    let mut buf = Vec::new();
+    write_npy(&mut buf, a.as_ref()).unwrap();
+    assert_eq!(&buf[..8], b"\x93NUMPY\x01\x00");
+    let header_len = u16::from_le_bytes([buf[8], buf[9]]) as usize;
+    assert!((10 + header_len).is_multiple_of(64));
+    let header = std::str::from_utf8(&buf[10..10 + header_len]).unwrap();
+    assert!(header.starts_with("{'descr': '<f8', 'fortran_order': True, 'shape': (2, 3), }"));
+    assert!(header.ends_with('\n'));
+    assert_eq!(buf.len(), 10 + header_len + 6 * 8);
+}
+
+#[test]
+fn write_view_honors_strides() {
+    let a = Mat::from_fn(4, 5, |i, j| (10 * i + j) as i32);
+    let mut buf = Vec::new();
+    write_npy(&mut buf, a.view(1, 2, 2, 3).transpose()).unwrap();
+    let b: Mat<i32> = read_npy(Cursor::new(buf)).unwrap();
+    assert_eq!(b, mat![[12, 22], [13, 23], [14, 24]]);
+}
+
+#[test]
+fn read_c_order() {
+    let data: Vec<u8> = (1..=6i32).flat_map(|x| x.to_le_bytes()).collect();
+    let bytes = npy_bytes(
+        1,
+        "{'descr': '<i4', 'fortran_order': False, 'shape': (2, 3), }",
+        &data,
+    );
+    let a: Mat<i32> = read_npy(Cursor::new(bytes)).unwrap();
+    assert_eq!(a, mat![[1, 2, 3], [4, 5, 6]]);
+}
+
+#[test]
+fn read_fortran_order_big_endian() {
+    let data: Vec<u8> = [1.0f64, 4.0, 2.0, 5.0, 3.0, 6.0]
+        .iter()
+        .flat_map(|x| x.to_be_bytes())
+        .collect();
+    let bytes = npy_bytes(
+        1,
+        "{'descr': '>f8', 'fortran_order': True, 'shape': (2, 3), }",
+        &data,
+    );
+    let a: Mat<f64> = read_npy(Cursor::new(bytes)).unwrap();
+    assert_eq!(a, mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+}
+
+#[test]
+fn read_big_endian_complex() {
+    let data: Vec<u8> = [1.0f32, -2.0, 3.5, 0.0]
+        .iter()
+        .flat_map(|x| x.to_be_bytes())
+        .collect();
+    let bytes = npy_bytes(
+        1,
+        "{'descr': '>c8', 'fortran_order': False, 'shape': (1, 2), }",
+        &data,
+    );
+    let a: Mat<Complex<f32>> = read_npy(Cursor::new(bytes)).unwrap();
+    assert_eq!(a, mat![[Complex::new(1.0, -2.0), Complex::new(3.5, 0.0)]]);
+}
+
+#[test]
+fn read_version_2_and_3_headers() {
+    let data: Vec<u8> = [7u16, 8, 9].iter().flat_map(|x| x.to_le_bytes()).collect();
+    let header = "{'descr': '<u2', 'fortran_order': False, 'shape': (3,), }";
+    let a: Mat<u16> = read_npy(Cursor::new(npy_bytes(2, header, &data))).unwrap();
+    assert_eq!(a, mat![[7], [8], [9]]);
+    let header = "{\"shape\": (1, 3), \"fortran_order\": True, \"descr\": \"<u2\"}";
+    let b: Mat<u16> = read_npy(Cursor::new(npy_bytes(3, header, &data))).unwrap();
+    assert_eq!(b, mat![[7, 8, 9]]);
+}
+
+#[test]
+fn read_scalar_and_empty_shapes() {
+    let bytes = npy_bytes(
+        1,
+        "{'descr': '|u1', 'fortran_order': False, 'shape': (), }",
+        &[42],
+    );
+    let a: Mat<u8> = read_npy(Cursor::new(bytes)).unwrap();
+    assert_eq!(a, mat![[42u8]]);
+    let bytes = npy_bytes(
+        1,
+        "{'descr': '<f4', 'fortran_order': False, 'shape': (0, 5), }",
+        &[],
+    );
+    let e: Mat<f32> = read_npy(Cursor::new(bytes)).unwrap();
+    assert_eq!(e.shape(), (0, 5));
+}
+
+#[test]
+fn read_rejects_dtype_mismatch() {
+    let bytes = npy_bytes(
+        1,
+        "{'descr': '<f4', 'fortran_order': False, 'shape': (1,), }",
+        &[0; 4],
+    );
+    let err = read_npy::<f64, _>(Cursor::new(bytes)).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid format: dtype mismatch: expected '<f8', found '<f4'"
+    );
+}
+
+#[test]
+fn read_rejects_malformed_input() {
+    let err = read_npy::<f64, _>(Cursor::new(b"PK\x03\x04garbage".to_vec())).unwrap_err();
+    assert!(matches!(err, Error::Format { .. }));
+    let bytes = npy_bytes(
+        1,
+        "{'descr': '<i4', 'fortran_order': False, 'shape': (2, 2, 2), }",
+        &[0; 32],
+    );
+    assert!(read_npy::<i32, _>(Cursor::new(bytes)).is_err());
+    let bytes = npy_bytes(1, "{'descr': '<i4', 'shape': (2,), }", &[0; 8]);
+    let err = read_npy::<i32, _>(Cursor::new(bytes)).unwrap_err();
+    assert!(err.to_string().contains("missing 'fortran_order'"));
+}
+
+#[test]
+fn read_truncated_data_is_io_error() {
+    let bytes = npy_bytes(
+        1,
+        "{'descr': '<i8', 'fortran_order': True, 'shape': (3, 3), }",
+        &[0; 40],
+    );
+    let err = read_npy::<i64, _>(Cursor::new(bytes)).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Io {
+            kind: std::io::ErrorKind::UnexpectedEof,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn save_and_load_file() {
+    let path = std::env::temp_dir().join(format!("aris-npy-{}.npy", std::process::id()));
+    let a = Mat::from_fn(20, 30, |i, j| (i as f64).sin() + j as f64);
+    save_npy(&path, a.as_ref()).unwrap();
+    let b: Mat<f64> = load_npy(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(a, b);
+}
+
+#[cfg(feature = "npz")]
+mod npz {
+    use std::io::Cursor;
+
+    use aris::io::{read_npz, read_npz_entry, write_npz, write_npz_compressed};
+    use aris::{Mat, mat};
+
+    #[test]
+    fn round_trip_archive() {
+        let a: Mat<f64> = mat![[1.0, 2.0], [3.0, 4.0]];
+        let b: Mat<f64> = Mat::from_fn(3, 1, |i, _| i as f64);
+        let mut buf = Cursor::new(Vec::new());
+        write_npz(&mut buf, &[("a", a.as_ref()), ("b", b.as_ref())]).unwrap();
+        let arrays = read_npz::<f64, _>(Cursor::new(buf.into_inner())).unwrap();
+        assert_eq!(arrays, vec![("a".to_owned(), a), ("b".to_owned(), b)]);
+    }
+
+    #[test]
+    fn compressed_archive_and_lookup() {
+        let a: Mat<i32> = Mat::from_fn(50, 40, |i, j| (i * j) as i32);
+        let mut buf = Cursor::new(Vec::new());
+        write_npz_compressed(&mut buf, &[("x", a.as_ref())]).unwrap();
+        let bytes = buf.into_inner();
+        assert!(bytes.len() < 50 * 40 * 4);
+        let x: Mat<i32> = read_npz_entry(Cursor::new(bytes.clone()), "x").unwrap();
+        assert_eq!(x, a);
+        let err = read_npz_entry::<i32, _>(Cursor::new(bytes), "y").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid format: no array named 'y' in archive"
+        );
+    }
+}
@@ -0,0 +1,377 @@
+#![cfg(feature = "rayon")]
+
+use aris::parallel::{
+    current_parallelism, global_parallelism, set_global_parallelism, with_parallelism,
+};
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Mul, MulAssign};
+
+use aris::{Mat, Parallelism, matmul_into};
+use num_traits::{One, Zero};
+use rayon::prelude::*;
+
+const SETTINGS: [Parallelism; 4] = [
+    Parallelism::None,
+    Parallelism::Rayon(1),
+    Parallelism::Rayon(3),
+    Parallelism::Rayon(0),
+];
+
+// Values spanning many magnitudes, so that any change in summation order
+// shows up in the rounding.
+fn noisy(nrows: usize, ncols: usize, seed: u64) -> Mat<f64> {
+    let mut state = seed;
+    Mat::from_fn(nrows, ncols, |_, _| {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let unit = (state >> 11) as f64 / (1u64 << 53) as f64;
+        let exponent = ((state >> 3) % 24) as i32 - 12;
+        (unit - 0.5) * 10f64.powi(exponent)
+    })
+}
+
+fn uniform(nrows: usize, ncols: usize, seed: u64) -> Mat<f64> {
+    noisy(nrows, ncols, seed).map(|x| x.signum() * x.abs().log10().rem_euclid(1.0))
+}
+
+fn under_each<R: PartialEq + std::fmt::Debug>(f: impl Fn() -> R) -> R {
+    let expected = with_parallelism(Parallelism::None, &f);
+    for par in SETTINGS {
+        assert_eq!(with_parallelism(par, &f), expected, "{par:?}");
+    }
+    expected
+}
+
+#[test]
+fn test_with_parallelism_is_scoped() {
+    with_parallelism(Parallelism::Rayon(1), check_scoping);
+}
+
+fn check_scoping() {
+    let outer = current_parallelism();
+    let inner = with_parallelism(Parallelism::Rayon(2), || {
+        let nested = with_parallelism(Parallelism::None, current_parallelism);
+        (current_parallelism(), nested)
+    });
+    assert_eq!(inner, (Parallelism::Rayon(2), Parallelism::None));
+    assert_eq!(current_parallelism(), outer);
+
+    let result = std::panic::catch_unwind(|| {
+        with_parallelism(Parallelism::Rayon(5), || panic!("boom"));
+    });
+    assert!(result.is_err());
+    assert_eq!(current_parallelism(), outer);
+}
+
+#[test]
+fn test_global_parallelism() {
+    assert_eq!(global_parallelism(), Parallelism::Rayon(0));
+    set_global_parallelism(Parallelism::Rayon(4));
+    assert_eq!(global_parallelism(), Parallelism::Rayon(4));
+    with_parallelism(Parallelism::None, || {
+        assert_eq!(current_parallelism(), Parallelism::None);
+    });
+    set_global_parallelism(Parallelism::Rayon(0));
+}
+
+#[test]
+fn test_par_col_iter() {
+    let a = noisy(37, 300, 1);
+    let sums: Vec<f64> = a.par_col_iter().map(|col| col.to_owned().sum()).collect();
+    let expected: Vec<f64> = a.col_iter().map(|col| col.to_owned().sum()).collect();
+    assert_eq!(sums, expected);
+
+    let reversed: Vec<f64> = a
+        .as_ref()
+        .reverse_cols()
+        .par_col_iter()
+        .map(|col| *col.get(0, 0).unwrap())
+        .collect();
+    let expected: Vec<f64> = (0..300).rev().map(|j| a[(0, j)]).collect();
+    assert_eq!(reversed, expected);
+    assert_eq!(a.par_col_iter().rev().count(), 300);
+}
+
+#[test]
+fn test_par_col_iter_mut() {
+    let mut a = Mat::<usize>::zeros(5, 1000);
+    a.par_col_iter_mut().enumerate().for_each(|(j, mut col)| {
+        for i in 0..5 {
+            col[(i, 0)] = i + 10 * j;
+        }
+    });
+    assert_eq!(a, Mat::from_fn(5, 1000, |i, j| i + 10 * j));
+
+    let mut b = Mat::<usize>::zeros(1000, 5);
+    b.transpose_mut()
+        .par_col_iter_mut()
+        .enumerate()
+        .for_each(|(i, mut row)| row.fill(i));
+    assert_eq!(b, Mat::from_fn(1000, 5, |i, _| i));
+}
+
+#[test]
+fn test_sum_is_deterministic() {
+    let a = noisy(501, 397, 2);
+    let sum = under_each(|| a.sum().to_bits());
+    assert_eq!(sum, a.sum().to_bits());
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(5)
+        .build()
+        .unwrap();
+    let pooled = pool.install(|| with_parallelism(Parallelism::Rayon(0), || a.sum()));
+    assert_eq!(pooled.to_bits(), sum);
+    under_each(|| a.mean().to_bits());
+    under_each(|| a.variance().to_bits());
+    under_each(|| a.prod().to_bits());
+}
+
+// f64 sums accumulate element k into lane k % 8 and then fold the lanes
+// pairwise, whatever the thread count or instruction set.
+#[test]
+fn test_small_sum_matches_lane_order() {
+    let a = noisy(40, 30, 3);
+    let mut lanes = [0.0; 8];
+    for j in 0..30 {
+        for i in 0..40 {
+            lanes[(i + 40 * j) % 8] += a[(i, j)];
+        }
+    }
+    for width in [4, 2, 1] {
+        for k in 0..width {
+            lanes[k] += lanes[k + width];
+        }
+    }
+    assert_eq!(under_each(|| a.sum().to_bits()), lanes[0].to_bits());
+}
+
+#[test]
+fn test_axis_sums() {
+    let a = noisy(300, 400, 4);
+    let cols = under_each(|| a.sum_cols());
+    let rows = under_each(|| a.sum_rows());
+    for j in 0..400 {
+        let mut sum = 0.0;
+        for i in 0..300 {
+            sum += a[(i, j)];
+        }
+        assert_eq!(cols[(0, j)], sum);
+    }
+    for i in 0..300 {
+        let mut sum = 0.0;
+        for j in 0..400 {
+            sum += a[(i, j)];
+        }
+        assert_eq!(rows[(i, 0)], sum);
+    }
+}
+
+#[test]
+fn test_min_max() {
+    let mut a = noisy(256, 300, 5);
+    a[(17, 100)] = -1e20;
+    a[(200, 250)] = 1e20;
+    // First element of the second reduction chunk.
+    a[(0, 64)] = f64::NAN;
+    let (min, max) = under_each(|| a.min_max());
+    assert_eq!((min, max), (-1e20, 1e20));
+    assert_eq!(under_each(|| a.min()), -1e20);
+    assert_eq!(under_each(|| a.max()), 1e20);
+
+    a[(0, 0)] = f64::NAN;
+    for par in SETTINGS {
+        let (min, max) = with_parallelism(par, || a.min_max());
+        assert!(min.is_nan() && max.is_nan());
+    }
+}
+
+#[test]
+fn test_elementwise_ops() {
+    let a = noisy(400, 300, 6);
+    let b = noisy(400, 300, 7);
+    let row = noisy(1, 300, 8);
+    under_each(|| &a + &b);
+    under_each(|| &a - row.as_ref());
+    under_each(|| -&a);
+    under_each(|| &a * 2.5);
+    under_each(|| a.hadamard(b.as_ref()));
+    under_each(|| a.gt(b.as_ref()));
+
+    let sum = with_parallelism(Parallelism::Rayon(3), || {
+        let mut c = a.clone();
+        c += &b;
+        c *= 0.5;
+        c
+    });
+    assert_eq!(
+        sum,
+        Mat::from_fn(400, 300, |i, j| (a[(i, j)] + b[(i, j)]) * 0.5)
+    );
+}
+
+#[test]
+fn test_par_map() {
+    let a = noisy(300, 200, 9);
+    let b = noisy(300, 200, 10);
+    assert_eq!(a.par_map(|x| x.abs()), a.map(|x| x.abs()));
+    assert_eq!(
+        a.par_zip_map(b.as_ref(), |x, y| x.max(*y)),
+        a.zip_map(b.as_ref(), |x, y| x.max(*y))
+    );
+    assert!(a.try_par_zip_map(b.transpose(), |x, y| x + y).is_err());
+}
+
+#[test]
+fn test_matmul_is_deterministic() {
+    let a = noisy(150, 90, 11);
+    let b = noisy(90, 130, 12);
+    let product = under_each(|| &a * &b);
+    let mut expected = Mat::zeros(150, 130);
+    with_parallelism(Parallelism::None, || {
+        matmul_into(expected.as_mut(), 1.0, a.as_ref(), b.as_ref(), 0.0);
+    });
+    assert_eq!(product, expected);
+
+    let x = noisy(90, 1, 13);
+    under_each(|| &a * &x);
+    under_each(|| b.transpose() * a.transpose());
+}
+
+#[test]
+fn test_matmul_accumulates_into_view() {
+    let a = noisy(120, 80, 14);
+    let b = noisy(80, 100, 15);
+    let initial = noisy(200, 200, 16);
+    let result = under_each(|| {
+        let mut c = initial.clone();
+        matmul_into(
+            c.view_mut(50, 60, 120, 100),
+            -1.0,
+            a.as_ref(),
+            b.as_ref(),
+            0.5,
+        );
+        c
+    });
+    let product = with_parallelism(Parallelism::None, || &a * &b);
+    for j in 0..200 {
+        for i in 0..200 {
+            let expected = if (50..170).contains(&i) && (60..160).contains(&j) {
+                0.5 * initial[(i, j)] - product[(i - 50, j - 60)]
+            } else {
+                initial[(i, j)]
+            };
+            assert!((result[(i, j)] - expected).abs() <= 1e-9 * expected.abs().max(1.0));
+        }
+    }
+}
+
+#[test]
+fn test_lu_is_deterministic() {
+    let n = 120;
+    let a = uniform(n, n, 17);
+    let b = uniform(n, 40, 18);
+    let factors = under_each(|| a.lu().factors().to_owned());
+    let x = under_each(|| a.lu().solve(b.as_ref()).unwrap());
+    assert_eq!(factors, a.lu().factors().to_owned());
+    let residual = &a * &x - &b;
+    assert!(residual.max_abs_norm() < 1e-6);
+}
+
+#[test]
+fn test_cholesky_is_deterministic() {
+    let n = 120;
+    let g = uniform(n, n, 19);
+    let a = &g * g.transpose() + Mat::<f64>::identity(n);
+    let l = under_each(|| a.cholesky().l().to_owned());
+    let reconstructed = &l * l.transpose();
+    assert!((reconstructed - &a).max_abs_norm() < 1e-6 * a.max_abs_norm());
+
+    let b = uniform(n, 30, 20);
+    let x = under_each(|| a.cholesky().solve(b.as_ref()).unwrap());
+    assert!((&a * &x - &b).max_abs_norm() < 1e-6);
+}
+
+// Neither `Send` nor `Sync`, so this only compiles if the generic operations
+// keep their plain bounds with the `rayon` feature enabled.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+struct Local(i64, PhantomData<*const ()>);
+
+fn local(x: i64) -> Local {
+    Local(x, PhantomData)
+}
+
+impl Add for Local {
+    type Output = Local;
+    fn add(self, rhs: Local) -> Local {
+        local(self.0 + rhs.0)
+    }
+}
+
+impl Mul for Local {
+    type Output = Local;
+    fn mul(self, rhs: Local) -> Local {
+        local(self.0 * rhs.0)
+    }
+}
+
+impl AddAssign for Local {
+    fn add_assign(&mut self, rhs: Local) {
+        self.0 += rhs.0;
+    }
+}
+
+impl MulAssign for Local {
+    fn mul_assign(&mut self, rhs: Local) {
+        self.0 *= rhs.0;
+    }
+}
+
+impl Zero for Local {
+    fn zero() -> Local {
+        local(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl One for Local {
+    fn one() -> Local {
+        local(1)
+    }
+}
+
+#[test]
+fn test_non_send_elements() {
+    let a = Mat::from_fn(300, 200, |i, j| local((i as i64 - j as i64) % 7));
+    let b = Mat::from_fn(200, 3, |i, j| local((i + j) as i64 % 3));
+    let sum = under_each(|| a.sum());
+    let mut expected = local(0);
+    for j in 0..200 {
+        for i in 0..300 {
+            expected += a[(i, j)];
+        }
+    }
+    assert_eq!(sum, expected);
+    under_each(|| a.sum_cols());
+    assert_eq!(under_each(|| a.min_max()), (local(-6), local(6)));
+    under_each(|| &a + &a);
+    under_each(|| a.hadamard(a.as_ref()));
+    under_each(|| &a * local(2));
+    let product = under_each(|| &a * &b);
+    assert_eq!(
+        product[(1, 0)],
+        (0..200).fold(local(0), |acc, k| acc + a[(1, k)] * b[(k, 0)])
+    );
+
+    let mut c = a.clone();
+    with_parallelism(Parallelism::Rayon(3), || {
+        c += &a;
+        c *= local(3);
+    });
+    assert_eq!(c, &a * local(6));
+}
@@ -0,0 +1,209 @@
+mod common;
+
+use aris::linalg::{ColPivQr, Qr};
+use aris::{Axis, Complex, Error, Mat, mat};
+
+use common::c;
+
+fn max_abs_diff(a: &Mat<f64>, b: &Mat<f64>) -> f64 {
+    assert_eq!(a.shape(), b.shape());
+    (a - b).abs().max()
+}
+
+fn max_abs_diff_c(a: &Mat<Complex<f64>>, b: &Mat<Complex<f64>>) -> f64 {
+    assert_eq!(a.shape(), b.shape());
+    (a - b).norm().max()
+}
+
+fn tall_matrix() -> Mat<f64> {
+    Mat::from_fn(6, 4, |i, j| {
+        ((i * 5 + j * 3) % 7) as f64 - 3.0 + if i == j { 4.0 } else { 0.0 }
+    })
+}
+
+#[test]
+fn qr_reconstructs() {
+    let a = tall_matrix();
+    let qr = a.qr();
+    let q = qr.q_thin();
+    let r = qr.r();
+    assert_eq!(q.shape(), (6, 4));
+    assert_eq!(r.shape(), (4, 4));
+    assert!(r.is_upper_triangular());
+    assert!(max_abs_diff(&(&q * &r), &a) < 1e-12);
+}
+
+#[test]
+fn qr_thin_q_orthonormal_columns() {
+    let a = tall_matrix();
+    let q = a.qr().q_thin();
+    assert!(max_abs_diff(&(q.transpose() * &q), &Mat::identity(4)) < 1e-12);
+}
+
+#[test]
+fn qr_full_q_orthogonal() {
+    let a = tall_matrix();
+    let qr = Qr::new(a.as_ref());
+    let q = qr.q_full();
+    assert_eq!(q.shape(), (6, 6));
+    assert!(max_abs_diff(&(q.transpose() * &q), &Mat::identity(6)) < 1e-12);
+    let mut r_full = Mat::zeros(6, 4);
+    r_full.view_mut(0, 0, 4, 4).copy_from(qr.r().as_ref());
+    assert!(max_abs_diff(&(&q * &r_full), &a) < 1e-12);
+}
+
+#[test]
+fn qr_wide_matrix() {
+    let a = tall_matrix().transpose().to_owned();
+    let qr = a.qr();
+    assert_eq!(qr.q_thin().shape(), (4, 4));
+    assert_eq!(qr.r().shape(), (4, 6));
+    assert!(max_abs_diff(&(qr.q_thin() * qr.r()), &a) < 1e-12);
+}
+
+#[test]
+fn qr_apply_q_round_trip() {
+    let a = tall_matrix();
+    let qr = a.qr();
+    let b = Mat::from_fn(6, 2, |i, j| (i * 2 + j) as f64);
+    let mut y = b.clone();
+    qr.apply_q_adjoint(y.as_mut());
+    qr.apply_q(y.as_mut());
+    assert!(max_abs_diff(&y, &b) < 1e-12);
+}
+
+#[test]
+fn qr_least_squares_line_fit() {
+    let xs = [0.0, 1.0, 2.0, 3.0, 4.0];
+    let a = Mat::from_fn(5, 2, |i, j| if j == 0 { 1.0 } else { xs[i] });
+    let b = Mat::from_fn(5, 1, |i, _| 1.0 + 2.0 * xs[i]);
+    let x = a.qr().solve_least_squares(b.as_ref()).unwrap();
+    assert!(max_abs_diff(&x, &mat![[1.0], [2.0]]) < 1e-12);
+}
+
+#[test]
+fn qr_least_squares_normal_equations() {
+    let a = tall_matrix();
+    let b = Mat::from_fn(6, 1, |i, _| (i as f64).cos());
+    let x = a.qr().solve_least_squares(b.as_ref()).unwrap();
+    let residual = &a * &x - &b;
+    let normal = a.transpose() * &residual;
+    assert!(normal.abs().max() < 1e-12);
+}
+
+#[test]
+fn qr_least_squares_rank_deficient_errors() {
+    let a = mat![[1.0, 2.0], [0.0, 0.0], [0.0, 0.0]];
+    let b = mat![[1.0], [2.0], [3.0]];
+    assert!(a.qr().solve_least_squares(b.as_ref()).is_err());
+}
+
+#[test]
+fn qr_complex_reconstructs() {
+    let a: Mat<Complex<f64>> = Mat::from_fn(5, 3, |i, j| {
+        c((i + j) as f64 - 2.0, (i as f64) * 0.5 - j as f64)
+    });
+    let qr = a.qr();
+    let q = qr.q_thin();
+    assert!(max_abs_diff_c(&(&q * &qr.r()), &a) < 1e-12);
+    assert!(max_abs_diff_c(&(q.adjoint() * &q), &Mat::identity(3)) < 1e-12);
+}
+
+#[test]
+fn qr_complex_least_squares() {
+    let a: Mat<Complex<f64>> = Mat::from_fn(6, 2, |i, j| c(1.0 + (i * j) as f64, j as f64));
+    let x_true = mat![[c(1.0, -1.0)], [c(0.5, 2.0)]];
+    let b = &a * &x_true;
+    let x = a.qr().solve_least_squares(b.as_ref()).unwrap();
+    assert!(max_abs_diff_c(&x, &x_true) < 1e-10);
+}
+
+#[test]
+fn col_piv_qr_reconstructs() {
+    let a = tall_matrix();
+    let qr = ColPivQr::new(a.as_ref());
+    let ap = &a * &qr.p();
+    assert!(max_abs_diff(&(qr.q_thin() * qr.r()), &ap) < 1e-12);
+}
+
+#[test]
+fn col_piv_qr_diagonal_non_increasing() {
+    let a = Mat::from_fn(8, 5, |i, j| ((i * 7 + j * 2) % 9) as f64 * (j + 1) as f64);
+    let r = a.col_piv_qr().r();
+    for k in 1..5 {
+        assert!(r[(k, k)].abs() <= r[(k - 1, k - 1)].abs() + 1e-12);
+    }
+}
+
+#[test]
+fn col_piv_qr_rank() {
+    let a = mat![
+        [1.0, 2.0, 3.0],
+        [2.0, 4.0, 6.0],
+        [1.0, 0.0, 1.0],
+        [0.0, 1.0, 1.0]
+    ];
+    assert_eq!(a.col_piv_qr().rank(), 2);
+    assert_eq!(tall_matrix().col_piv_qr().rank(), 4);
+    let z: Mat<f64> = Mat::zeros(3, 3);
+    assert_eq!(z.col_piv_qr().rank(), 0);
+}
+
+#[test]
+fn col_piv_qr_rank_with_tolerance() {
+    let a = mat![[1.0, 0.0], [0.0, 1e-8]];
+    let qr = a.col_piv_qr();
+    assert_eq!(qr.rank(), 2);
+    assert_eq!(qr.rank_with_tolerance(1e-6), 1);
+}
+
+#[test]
+fn col_piv_qr_rank_deficient_least_squares() {
+    let a = mat![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+    let b = mat![[1.0], [2.0], [3.0]];
+    let x = a.col_piv_qr().solve_least_squares(b.as_ref()).unwrap();
+    assert!(max_abs_diff(&(&a * &x), &b) < 1e-12);
+}
+
+#[test]
+fn col_piv_qr_complex_rank() {
+    let u = mat![[c(1.0, 1.0)], [c(0.0, 2.0)], [c(-1.0, 0.5)]];
+    let v = mat![[c(1.0, 0.0), c(2.0, -1.0), c(0.0, 3.0)]];
+    let a = &u * &v;
+    assert_eq!(a.col_piv_qr().rank(), 1);
+}
+
+#[test]
+fn least_squares_shape_errors() {
+    let a: Mat<f64> = mat![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+    let b: Mat<f64> = Mat::zeros(2, 1);
+    let mismatch = Error::ShapeMismatch {
+        lhs: (2, 1),
+        rhs: (3, 1),
+    };
+    assert_eq!(
+        a.qr().solve_least_squares(b.as_ref()).err(),
+        Some(mismatch.clone())
+    );
+    assert_eq!(
+        a.col_piv_qr().solve_least_squares(b.as_ref()).err(),
+        Some(mismatch.clone())
+    );
+    assert_eq!(a.qr().try_apply_q(Mat::zeros(2, 1).as_mut()), Err(mismatch));
+    assert_eq!(
+        a.col_piv_qr()
+            .solve_least_squares_with_rank(Mat::zeros(3, 1).as_ref(), 3)
+            .err(),
+        Some(Error::IndexOutOfBounds {
+            axis: Axis::Row,
+            index: 3,
+            len: 2
+        })
+    );
+    assert!(
+        a.transpose()
+            .qr()
+            .solve_least_squares(Mat::zeros(2, 1).as_ref())
+            .is_err()
+    );
+}
@@ -0,0 +1,180 @@
+mod common;
+
+use aris::linalg::SelfAdjointEigen;
+use aris::{Complex, Mat, mat};
+
+use common::c;
+
+fn covariance(n: usize) -> Mat<f64> {
+    let x = Mat::from_fn(n + 3, n, |i, j| {
+        ((i * 7 + j * 13) % 11) as f64 - 5.0 + 0.1 * j as f64
+    });
+    x.transpose() * &x
+}
+
+fn assert_decomposes(a: &Mat<f64>, eig: &SelfAdjointEigen<f64>, tol: f64) {
+    let v = eig.eigenvectors().unwrap().to_owned();
+    let values = eig.eigenvalues();
+    let k = values.nrows();
+    let lambda = Mat::from_fn(k, k, |i, j| if i == j { values[(i, 0)] } else { 0.0 });
+    assert!((a * &v - &v * &lambda).abs().max() < tol);
+    assert!((v.transpose() * &v - Mat::identity(k)).abs().max() < tol);
+}
+
+#[test]
+fn eigh_diagonal() {
+    let a = mat![[3.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 2.0]];
+    let eig = a.eigh().unwrap();
+    assert_eq!(eig.eigenvalues().to_owned(), mat![[1.0], [2.0], [3.0]]);
+}
+
+#[test]
+fn eigh_two_by_two() {
+    let a: Mat<f64> = mat![[2.0, 1.0], [1.0, 2.0]];
+    let eig = a.eigh().unwrap();
+    let values = eig.eigenvalues();
+    assert!((values[(0, 0)] - 1.0).abs() < 1e-12);
+    assert!((values[(1, 0)] - 3.0).abs() < 1e-12);
+    assert_decomposes(&a, &eig, 1e-12);
+}
+
+#[test]
+fn eigh_covariance() {
+    let a = covariance(12);
+    let eig = a.eigh().unwrap();
+    let values = eig.eigenvalues();
+    for i in 1..values.nrows() {
+        assert!(values[(i - 1, 0)] <= values[(i, 0)]);
+    }
+    assert_decomposes(&a, &eig, 1e-9);
+}
+
+#[test]
+fn eigh_repeated_eigenvalues() {
+    let a: Mat<f64> = Mat::identity(4) * 2.0;
+    let eig = a.eigh().unwrap();
+    assert_eq!(eig.eigenvalues().to_owned(), Mat::full(4, 1, 2.0));
+    assert_decomposes(&a, &eig, 1e-12);
+}
+
+#[test]
+fn eigh_uses_lower_triangle() {
+    let mut a = covariance(5);
+    let reference = a.eigvalsh().unwrap();
+    a[(0, 4)] = 1e6;
+    let values = a.eigvalsh().unwrap();
+    assert!((values - reference).abs().max() < 1e-9);
+}
+
+#[test]
+fn eigvalsh_matches_full() {
+    let a = covariance(9);
+    let values = a.eigvalsh().unwrap();
+    let eig = a.eigh().unwrap();
+    assert!((&values - eig.eigenvalues()).abs().max() < 1e-9);
+    assert!(
+        SelfAdjointEigen::values_only(a.as_ref())
+            .unwrap()
+            .eigenvectors()
+            .is_none()
+    );
+}
+
+#[test]
+fn eigh_trace_and_det() {
+    let a = covariance(6);
+    let values = a.eigvalsh().unwrap();
+    assert!((values.sum() - a.diag_iter().sum::<f64>()).abs() < 1e-8);
+    assert!((values.prod() - a.det()).abs() < 1e-8 * a.det().abs());
+}
+
+#[test]
+fn eigh_subset_values() {
+    let a = covariance(10);
+    let all = a.eigvalsh().unwrap();
+    let eig = SelfAdjointEigen::subset(a.as_ref(), 3..7, false).unwrap();
+    assert!(eig.eigenvectors().is_none());
+    assert!(
+        (eig.eigenvalues().to_owned() - all.rows_range(3..7))
+            .abs()
+            .max()
+            < 1e-12
+    );
+}
+
+#[test]
+fn eigh_subset_vectors() {
+    let a = covariance(10);
+    let eig = SelfAdjointEigen::subset(a.as_ref(), 7..10, true).unwrap();
+    assert_eq!(eig.eigenvectors().unwrap().shape(), (10, 3));
+    assert_decomposes(&a, &eig, 1e-8);
+}
+
+#[test]
+fn eigh_subset_clustered_vectors() {
+    let a = mat![
+        [2.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0]
+    ];
+    let eig = SelfAdjointEigen::subset(a.as_ref(), 0..3, true).unwrap();
+    assert_decomposes(&a, &eig, 1e-8);
+}
+
+#[test]
+fn eigh_empty_subset() {
+    let a = covariance(4);
+    let eig = SelfAdjointEigen::subset(a.as_ref(), 2..2, true).unwrap();
+    assert_eq!(eig.eigenvalues().shape(), (0, 1));
+    assert_eq!(eig.eigenvectors().unwrap().shape(), (4, 0));
+}
+
+#[test]
+fn eigh_complex_hermitian() {
+    let a: Mat<Complex<f64>> = mat![
+        [c(2.0, 0.0), c(0.0, 1.0), c(1.0, -1.0)],
+        [c(0.0, -1.0), c(3.0, 0.0), c(0.5, 0.0)],
+        [c(1.0, 1.0), c(0.5, 0.0), c(1.0, 0.0)]
+    ];
+    let eig = a.eigh().unwrap();
+    let v = eig.eigenvectors().unwrap().to_owned();
+    let lambda = Mat::from_fn(3, 3, |i, j| {
+        if i == j {
+            c(eig.eigenvalues()[(i, 0)], 0.0)
+        } else {
+            c(0.0, 0.0)
+        }
+    });
+    assert!((&a * &v - &v * &lambda).norm().max() < 1e-12);
+    assert!((v.adjoint() * &v - Mat::identity(3)).norm().max() < 1e-12);
+}
+
+#[test]
+fn eigh_complex_subset_vectors() {
+    let a: Mat<Complex<f64>> = Mat::from_fn(6, 6, |i, j| {
+        if i == j {
+            c(i as f64, 0.0)
+        } else if i > j {
+            c((i + j) as f64 * 0.1, (i as f64 - j as f64) * 0.2)
+        } else {
+            c((i + j) as f64 * 0.1, -(j as f64 - i as f64) * 0.2)
+        }
+    });
+    assert!(a.is_hermitian());
+    let eig = SelfAdjointEigen::subset(a.as_ref(), 1..4, true).unwrap();
+    let v = eig.eigenvectors().unwrap().to_owned();
+    for k in 0..3 {
+        let vk = v.col(k).to_owned();
+        let r = &a * &vk - &vk * c(eig.eigenvalues()[(k, 0)], 0.0);
+        assert!(r.norm().max() < 1e-9);
+    }
+}
+
+#[test]
+fn eigh_f32() {
+    let a: Mat<f32> = mat![[4.0, 1.0], [1.0, 3.0]];
+    let values = a.eigvalsh().unwrap();
+    let expected = (7.0 - 5.0f32.sqrt()) / 2.0;
+    assert!((values[(0, 0)] - expected).abs() < 1e-5);
+}
@@ -0,0 +1,124 @@
+#![cfg(feature = "serde")]
+
+mod common;
+
+use aris::{Complex, Mat, mat};
+
+use common::c;
+
+#[test]
+fn json_round_trip() {
+    let a: Mat<f64> = mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    let json = serde_json::to_string(&a).unwrap();
+    assert_eq!(
+        json,
+        r#"{"nrows":2,"ncols":3,"data":[1.0,4.0,2.0,5.0,3.0,6.0]}"#
+    );
+    let b: Mat<f64> = serde_json::from_str(&json).unwrap();
+    assert_eq!(a, b);
+}
+
+#[test]
+fn serialize_view_honors_strides() {
+    let a = Mat::from_fn(4, 5, |i, j| (10 * i + j) as i32);
+    let v = a.view(1, 2, 2, 3);
+    let json = serde_json::to_string(&v).unwrap();
+    assert_eq!(json, r#"{"nrows":2,"ncols":3,"data":[12,22,13,23,14,24]}"#);
+    let b: Mat<i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(b, v);
+}
+
+#[test]
+fn serialize_transposed_view() {
+    let a = mat![[1, 2, 3], [4, 5, 6]];
+    let json = serde_json::to_string(&a.transpose()).unwrap();
+    let b: Mat<i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(b, mat![[1, 4], [2, 5], [3, 6]]);
+}
+
+#[test]
+fn serialize_mat_mut() {
+    let mut a = mat![[1, 2], [3, 4]];
+    let json = serde_json::to_string(&a.as_mut()).unwrap();
+    assert_eq!(json, serde_json::to_string(&a).unwrap());
+}
+
+#[test]
+fn serialize_padded_matrix_skips_padding() {
+    let mut a = mat![[1, 2], [3, 4]];
+    a.truncate(1, 2);
+    let json = serde_json::to_string(&a).unwrap();
+    assert_eq!(json, r#"{"nrows":1,"ncols":2,"data":[1,2]}"#);
+}
+
+#[test]
+fn deserialize_rejects_wrong_length() {
+    let err = serde_json::from_str::<Mat<f64>>(r#"{"nrows":2,"ncols":2,"data":[1.0,2.0,3.0]}"#)
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("length mismatch: expected 4 elements, found 3")
+    );
+}
+
+#[test]
+fn deserialize_nested_rows() {
+    let a: Mat<i32> = serde_json::from_str("[[1, 2, 3], [4, 5, 6]]").unwrap();
+    assert_eq!(a, mat![[1, 2, 3], [4, 5, 6]]);
+    let e: Mat<i32> = serde_json::from_str("[]").unwrap();
+    assert_eq!(e.shape(), (0, 0));
+}
+
+#[test]
+fn deserialize_rejects_ragged_rows() {
+    let err = serde_json::from_str::<Mat<i32>>("[[1, 2], [3]]").unwrap_err();
+    assert!(err.to_string().contains("Row 1 has 1 elements, expected 2"));
+}
+
+#[test]
+fn deserialize_field_errors() {
+    let missing = serde_json::from_str::<Mat<i32>>(r#"{"nrows":1,"data":[1]}"#).unwrap_err();
+    assert!(missing.to_string().contains("missing field `ncols`"));
+    let unknown = serde_json::from_str::<Mat<i32>>(r#"{"nrows":1,"ncols":1,"cols":1,"data":[1]}"#)
+        .unwrap_err();
+    assert!(unknown.to_string().contains("unknown field `cols`"));
+    let duplicate =
+        serde_json::from_str::<Mat<i32>>(r#"{"nrows":1,"nrows":1,"ncols":1,"data":[1]}"#)
+            .unwrap_err();
+    assert!(duplicate.to_string().contains("duplicate field `nrows`"));
+    assert!(serde_json::from_str::<Mat<i32>>("3").is_err());
+}
+
+#[test]
+fn complex_round_trip() {
+    let a = mat![[c(1.0, -1.0), c(0.5, 2.0)]];
+    let json = serde_json::to_string(&a).unwrap();
+    assert_eq!(
+        json,
+        r#"{"nrows":1,"ncols":2,"data":[[1.0,-1.0],[0.5,2.0]]}"#
+    );
+    let b: Mat<Complex<f64>> = serde_json::from_str(&json).unwrap();
+    assert_eq!(a, b);
+}
+
+#[test]
+fn empty_matrix_round_trip() {
+    let a: Mat<f64> = Mat::zeros(0, 3);
+    let json = serde_json::to_string(&a).unwrap();
+    let b: Mat<f64> = serde_json::from_str(&json).unwrap();
+    assert_eq!(b.shape(), (0, 3));
+}
+
+#[test]
+fn bincode_round_trip() {
+    let a = Mat::from_fn(3, 4, |i, j| (i as f32) - (j as f32) * 0.5);
+    let bytes = bincode::serialize(&a.view(0, 1, 3, 3)).unwrap();
+    let b: Mat<f32> = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(b, a.view(0, 1, 3, 3));
+}
+
+#[test]
+fn bincode_rejects_wrong_length() {
+    let bytes = bincode::serialize(&(2usize, 2usize, vec![1i32, 2, 3])).unwrap();
+    assert!(bincode::deserialize::<Mat<i32>>(&bytes).is_err());
+}
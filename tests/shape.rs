@@ -77,7 +77,7 @@ fn from_blocks_horizontal_concat() {
 }
 
 #[test]
-#[should_panic(expected = "Block row 1")]
+#[should_panic(expected = "Block row 1 has 1 blocks, expected 2")]
 fn from_blocks_inconsistent_block_count() {
     let a = mat![[1]];
     let b = mat![[2]];
@@ -86,7 +86,7 @@ fn from_blocks_inconsistent_block_count() {
 }
 
 #[test]
-#[should_panic(expected = "columns")]
+#[should_panic(expected = "shape mismatch: (2, 2) vs (1, 3)")]
 fn from_blocks_inconsistent_col_widths() {
     let a = mat![[1, 2], [3, 4]];
     let b = mat![[5, 6, 7]];
@@ -94,7 +94,7 @@ fn from_blocks_inconsistent_col_widths() {
 }
 
 #[test]
-#[should_panic(expected = "rows")]
+#[should_panic(expected = "shape mismatch: (2, 2) vs (1, 1)")]
 fn from_blocks_inconsistent_row_heights() {
     let a = mat![[1, 2], [3, 4]];
     let b = mat![[5]];
@@ -133,7 +133,7 @@ fn hstack_empty() {
 }
 
 #[test]
-#[should_panic(expected = "rows")]
+#[should_panic(expected = "shape mismatch: (2, 2) vs (1, 2)")]
 fn hstack_mismatched_rows() {
     let a = mat![[1, 2], [3, 4]];
     let b = mat![[5, 6]];
@@ -179,14 +179,14 @@ fn insert_col_middle() {
 }
 
 #[test]
-#[should_panic(expected = "Column insert index")]
+#[should_panic(expected = "Column index 3 out of bounds for 2 columns")]
 fn insert_col_out_of_bounds() {
     let m = mat![[1, 2], [3, 4]];
     m.insert_col(3, &[5, 6]);
 }
 
 #[test]
-#[should_panic(expected = "Column length")]
+#[should_panic(expected = "length mismatch: expected 2 elements, found 1")]
 fn insert_col_wrong_length() {
     let m = mat![[1, 2], [3, 4]];
     m.insert_col(0, &[5]);
@@ -214,14 +214,14 @@ fn insert_row_middle() {
 }
 
 #[test]
-#[should_panic(expected = "Row insert index")]
+#[should_panic(expected = "Row index 3 out of bounds for 2 rows")]
 fn insert_row_out_of_bounds() {
     let m = mat![[1, 2], [3, 4]];
     m.insert_row(3, &[5, 6]);
 }
 
 #[test]
-#[should_panic(expected = "Row length")]
+#[should_panic(expected = "length mismatch: expected 2 elements, found 3")]
 fn insert_row_wrong_length() {
     let m = mat![[1, 2], [3, 4]];
     m.insert_row(0, &[5, 6, 7]);
@@ -317,7 +317,7 @@ fn reshape_2x3_to_6x1() {
 }
 
 #[test]
-#[should_panic(expected = "Cannot reshape")]
+#[should_panic(expected = "shape mismatch: (2, 2) vs (3, 3)")]
 fn reshape_mismatched_size() {
     let m = mat![[1, 2], [3, 4]];
     m.reshape(3, 3);
@@ -422,7 +422,7 @@ fn swap_cols_basic() {
 }
 
 #[test]
-#[should_panic(expected = "Column indices")]
+#[should_panic(expected = "Column index 2 out of bounds for 2 columns")]
 fn swap_cols_out_of_bounds() {
     let mut m = mat![[1, 2], [3, 4]];
     m.swap_cols(0, 2);
@@ -448,7 +448,7 @@ fn swap_rows_basic() {
 }
 
 #[test]
-#[should_panic(expected = "Row indices")]
+#[should_panic(expected = "Row index 2 out of bounds for 2 rows")]
 fn swap_rows_out_of_bounds() {
     let mut m = mat![[1, 2], [3, 4]];
     m.swap_rows(0, 2);
@@ -497,7 +497,7 @@ fn transpose_reverse_rows_is_reverse_cols_transpose() {
 }
 
 #[test]
-#[should_panic(expected = "Cannot truncate")]
+#[should_panic(expected = "shape mismatch: (2, 2) vs (3, 2)")]
 fn truncate_larger_panics() {
     let mut m = mat![[1, 2], [3, 4]];
     m.truncate(3, 2);
@@ -537,7 +537,7 @@ fn vstack_empty() {
 }
 
 #[test]
-#[should_panic(expected = "columns")]
+#[should_panic(expected = "shape mismatch: (1, 2) vs (1, 3)")]
 fn vstack_mismatched_cols() {
     let a = mat![[1, 2]];
     let b = mat![[3, 4, 5]];
@@ -0,0 +1,336 @@
+use aris::matrix::{set_simd_level, simd_level};
+use aris::{Complex, Mat, RealField, SimdLevel};
+
+const LEVELS: [SimdLevel; 5] = [
+    SimdLevel::Scalar,
+    SimdLevel::Sse2,
+    SimdLevel::Avx2,
+    SimdLevel::Avx512,
+    SimdLevel::Neon,
+];
+
+fn values(nrows: usize, ncols: usize, seed: u64) -> Mat<f64> {
+    let mut state = seed;
+    Mat::from_fn(nrows, ncols, |_, _| {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let unit = (state >> 11) as f64 / (1u64 << 53) as f64;
+        let exponent = ((state >> 3) % 12) as i32 - 6;
+        (unit - 0.5) * 10f64.powi(exponent)
+    })
+}
+
+fn values_f32(nrows: usize, ncols: usize, seed: u64) -> Mat<f32> {
+    values(nrows, ncols, seed).map(|&x| x as f32)
+}
+
+fn complex(nrows: usize, ncols: usize, seed: u64) -> Mat<Complex<f64>> {
+    let re = values(nrows, ncols, seed);
+    let im = values(nrows, ncols, seed + 1);
+    re.zip_map(im.as_ref(), |&re, &im| Complex::new(re, im))
+}
+
+// Column-major elements, skipping any column padding.
+fn elements<T>(a: &Mat<T>) -> impl Iterator<Item = &T> {
+    a.enumerate().map(|(_, x)| x)
+}
+
+// Runs `f` at every level the CPU supports and checks that they agree with the
+// scalar kernels bit for bit.
+fn under_each_level<R: PartialEq + std::fmt::Debug>(f: impl Fn() -> R) -> R {
+    let default = simd_level();
+    set_simd_level(SimdLevel::Scalar).unwrap();
+    let expected = f();
+    for level in LEVELS.into_iter().filter(|level| level.is_supported()) {
+        set_simd_level(level).unwrap();
+        assert_eq!(f(), expected, "{level:?}");
+    }
+    set_simd_level(default).unwrap();
+    expected
+}
+
+fn bits<const N: usize>(values: [f64; N]) -> [u64; N] {
+    values.map(f64::to_bits)
+}
+
+#[test]
+fn test_levels() {
+    assert!(simd_level().is_supported());
+    assert!(SimdLevel::Scalar.is_supported());
+    let default = simd_level();
+    for level in LEVELS {
+        assert_eq!(set_simd_level(level).is_ok(), level.is_supported());
+    }
+    set_simd_level(default).unwrap();
+    #[cfg(target_arch = "x86_64")]
+    assert!(SimdLevel::Sse2.is_supported() && !SimdLevel::Neon.is_supported());
+    #[cfg(target_arch = "aarch64")]
+    assert!(!SimdLevel::Avx2.is_supported());
+}
+
+#[test]
+fn test_elementwise_matches_scalar_arithmetic() {
+    // Every length up to a few vectors, so that each tail size is covered.
+    for n in 0..70 {
+        let a = values(n, 3, n as u64);
+        let b = values(n, 3, n as u64 + 100);
+        let ops = under_each_level(|| {
+            (
+                &a + &b,
+                &a - &b,
+                a.hadamard(b.as_ref()),
+                a.elem_div(b.as_ref()),
+                -&a,
+                &a * 0.3,
+                &a / 0.3,
+            )
+        });
+        assert_eq!(ops.0, a.zip_map(b.as_ref(), |x, y| x + y));
+        assert_eq!(ops.1, a.zip_map(b.as_ref(), |x, y| x - y));
+        assert_eq!(ops.2, a.zip_map(b.as_ref(), |x, y| x * y));
+        assert_eq!(ops.3, a.zip_map(b.as_ref(), |x, y| x / y));
+        assert_eq!(ops.4, a.map(|x| -x));
+        assert_eq!(ops.5, a.map(|x| x * 0.3));
+        assert_eq!(ops.6, a.map(|x| x / 0.3));
+    }
+}
+
+#[test]
+fn test_assign_ops() {
+    let a = values(37, 11, 1);
+    let b = values(37, 11, 2);
+    let result = under_each_level(|| {
+        let mut c = a.clone();
+        c += &b;
+        c *= 1.5;
+        c -= &a;
+        c /= 3.0;
+        c
+    });
+    assert_eq!(
+        result,
+        a.zip_map(b.as_ref(), |x, y| ((x + y) * 1.5 - x) / 3.0)
+    );
+
+    let mut c = Mat::<f64>::zeros(50, 20);
+    c.view_mut(3, 2, 37, 11).copy_from(a.as_ref());
+    let mut view = c.view_mut(3, 2, 37, 11);
+    view += b.as_ref();
+    assert_eq!(c.view(3, 2, 37, 11).to_owned(), &a + &b);
+    assert_eq!(c.view(0, 0, 3, 20).to_owned(), Mat::zeros(3, 20));
+    assert_eq!(c.view(40, 0, 10, 20).to_owned(), Mat::zeros(10, 20));
+}
+
+#[test]
+fn test_strided_views_match_contiguous() {
+    let a = values(45, 33, 3);
+    let b = values(45, 33, 4);
+    let (at, bt) = (a.transpose(), b.transpose());
+    let (ac, bc) = (at.to_owned(), bt.to_owned());
+    assert_eq!(at + bt, &ac + &bc);
+    assert_eq!(at.hadamard(bt), ac.hadamard(bc.as_ref()));
+    assert_eq!(-at, -&ac);
+    assert_eq!(at.exp(), ac.exp());
+    assert_eq!(
+        at.sqrt().map(|x| x.to_bits()),
+        ac.sqrt().map(|x| x.to_bits())
+    );
+
+    let sub = a.view(5, 4, 30, 20);
+    let owned = sub.to_owned();
+    assert_eq!(sub * 2.0, &owned * 2.0);
+    assert_eq!(sub.abs(), owned.abs());
+    assert_eq!(sub.to_owned().sum().to_bits(), owned.sum().to_bits());
+
+    let reversed = a.reverse_rows();
+    assert_eq!(reversed.exp(), reversed.to_owned().exp());
+    assert_eq!(
+        a.transpose().to_owned().sum().to_bits(),
+        Mat::from_fn(33, 45, |i, j| a[(j, i)]).sum().to_bits()
+    );
+}
+
+#[test]
+fn test_sum_is_identical_across_levels_and_layouts() {
+    let a = values(1001, 37, 5);
+    let sum = under_each_level(|| a.sum().to_bits());
+    // A strided copy goes through the scalar lane loop.
+    let strided = Mat::from_fn(37, 1001, |i, j| a[(j, i)]);
+    let transposed = strided.transpose().to_owned();
+    assert_eq!(transposed.sum().to_bits(), sum);
+    let naive: f64 = elements(&a).sum();
+    assert!((f64::from_bits(sum) - naive).abs() <= 1e-9 * naive.abs().max(1.0));
+
+    let f = values_f32(333, 71, 6);
+    let sum = under_each_level(|| f.sum().to_bits());
+    let naive: f64 = elements(&f).map(|&x| x as f64).sum();
+    assert!((f32::from_bits(sum) as f64 - naive).abs() <= 1e-3 * naive.abs().max(1.0));
+}
+
+#[test]
+fn test_complex_kernels() {
+    let a = complex(67, 9, 7);
+    let b = complex(67, 9, 9);
+    let (sum, diff, neg) = under_each_level(|| (&a + &b, &a - &b, -&a));
+    assert_eq!(sum, a.zip_map(b.as_ref(), |x, y| x + y));
+    assert_eq!(diff, a.zip_map(b.as_ref(), |x, y| x - y));
+    assert_eq!(neg, a.map(|x| -x));
+
+    let total = under_each_level(|| a.sum());
+    let naive: Complex<f64> = elements(&a).sum();
+    assert!((total - naive).norm() <= 1e-9 * naive.norm());
+    assert_eq!(a.transpose().to_owned().transpose().to_owned().sum(), total);
+
+    let f = a.map(|x| Complex::new(x.re as f32, x.im as f32));
+    let total = under_each_level(|| f.sum());
+    let naive: Complex<f32> = elements(&f).sum();
+    assert!((total - naive).norm() <= 1e-3 * naive.norm().max(1.0));
+}
+
+#[test]
+fn test_min_max_skips_nan() {
+    let mut a = values(129, 17, 10);
+    a[(3, 0)] = f64::NAN;
+    a[(100, 5)] = f64::NAN;
+    a[(64, 16)] = -1e9;
+    a[(0, 7)] = 1e9;
+    let bounds = under_each_level(|| a.min_max());
+    assert_eq!(bounds, (-1e9, 1e9));
+    assert_eq!(a.transpose().to_owned().min_max(), bounds);
+
+    a[(1, 1)] = f64::NEG_INFINITY;
+    assert_eq!(under_each_level(|| a.min()), f64::NEG_INFINITY);
+
+    // Lanes that only ever see NaN do not poison the result.
+    let mut b = Mat::<f32>::full(40, 1, f32::NAN);
+    b[(0, 0)] = 0.5;
+    b[(17, 0)] = 3.0;
+    b[(39, 0)] = -2.0;
+    assert_eq!(under_each_level(|| b.min_max()), (-2.0, 3.0));
+    b[(0, 0)] = f32::NAN;
+    let (min, max) = b.min_max();
+    assert!(min.is_nan() && max.is_nan());
+}
+
+#[test]
+fn test_sqrt_and_abs_are_exact() {
+    let a = values(99, 7, 11).abs();
+    let b = values(99, 7, 12);
+    let (sqrt, abs) = under_each_level(|| (a.sqrt(), b.abs()));
+    assert_eq!(sqrt, a.map(|x| x.sqrt()));
+    assert_eq!(abs, b.map(|x| x.abs()));
+
+    let special = Mat::from_vec_col(5, 1, vec![-0.0, -1.0, f64::INFINITY, f64::NAN, 4.0]);
+    let sqrt = special.sqrt();
+    assert_eq!(
+        bits([sqrt[(0, 0)], sqrt[(2, 0)], sqrt[(4, 0)]]),
+        bits([-0.0, f64::INFINITY, 2.0])
+    );
+    assert!(sqrt[(1, 0)].is_nan() && sqrt[(3, 0)].is_nan());
+    assert_eq!(special.abs()[(0, 0)].to_bits(), 0.0f64.to_bits());
+}
+
+#[test]
+fn test_exp_accuracy() {
+    let a = Mat::from_fn(2000, 3, |i, j| {
+        (i as f64 - 1000.0) * 0.35 + j as f64 * 0.001
+    });
+    let exp = under_each_level(|| a.exp());
+    for (x, y) in elements(&a).zip(elements(&exp)) {
+        assert!(y.ulps_distance(x.exp()) <= 1, "exp({x}) = {y}");
+    }
+
+    let f = Mat::from_fn(1000, 2, |i, j| (i as f32 - 500.0) * 0.17 + j as f32 * 0.01);
+    let exp = under_each_level(|| f.exp());
+    for (x, y) in elements(&f).zip(elements(&exp)) {
+        assert!(y.ulps_distance(x.exp()) <= 1, "exp({x}) = {y}");
+    }
+}
+
+#[test]
+fn test_exp_special_values() {
+    let inputs = [
+        0.0,
+        -0.0,
+        1.0,
+        708.0,
+        -708.0,
+        709.5,
+        710.0,
+        -740.0,
+        -800.0,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+        f64::MIN_POSITIVE,
+    ];
+    let a = Mat::from_vec_col(inputs.len(), 1, inputs.to_vec());
+    let exp = under_each_level(|| a.exp().map(|x| x.to_bits()));
+    for (x, y) in inputs.iter().zip(elements(&exp)) {
+        let y = f64::from_bits(*y);
+        if x.is_nan() {
+            assert!(y.is_nan());
+        } else if x.abs() > 708.0 {
+            // Outside the polynomial's range the standard library is used.
+            assert_eq!(y.to_bits(), x.exp().to_bits(), "exp({x})");
+        } else {
+            assert!(y.ulps_distance(x.exp()) <= 1, "exp({x}) = {y}");
+        }
+    }
+    assert_eq!(exp[(0, 0)], 1.0f64.to_bits());
+    assert_eq!(exp[(1, 0)], 1.0f64.to_bits());
+
+    let f = Mat::from_vec_col(4, 1, vec![86.0f32, 89.0, -87.0, -104.0]);
+    let exp = f.exp();
+    assert!(exp[(0, 0)].ulps_distance(86.0f32.exp()) <= 1);
+    assert_eq!(exp[(1, 0)], f32::INFINITY);
+    assert_eq!(exp[(2, 0)].to_bits(), (-87.0f32).exp().to_bits());
+    assert_eq!(exp[(3, 0)].to_bits(), (-104.0f32).exp().to_bits());
+}
+
+#[test]
+fn test_f32_elementwise() {
+    for n in [1, 7, 16, 31, 33, 100] {
+        let a = values_f32(n, 5, n as u64);
+        let b = values_f32(n, 5, n as u64 + 1);
+        let ops = under_each_level(|| (&a + &b, a.elem_div(b.as_ref()), &a * 3.0, a.abs().sqrt()));
+        assert_eq!(ops.0, a.zip_map(b.as_ref(), |x, y| x + y));
+        assert_eq!(ops.1, a.zip_map(b.as_ref(), |x, y| x / y));
+        assert_eq!(ops.2, a.map(|x| x * 3.0));
+        assert_eq!(ops.3, a.map(|x| x.abs().sqrt()));
+    }
+}
+
+#[test]
+fn test_broadcasting_and_integers_use_generic_path() {
+    let a = values(20, 6, 13);
+    let row = values(1, 6, 14);
+    assert_eq!(
+        &a + &row,
+        Mat::from_fn(20, 6, |i, j| a[(i, j)] + row[(0, j)])
+    );
+    let m = Mat::from_fn(9, 4, |i, j| (i * 4 + j) as i64);
+    assert_eq!((&m + &m).sum(), 2 * m.sum());
+    assert_eq!(m.min_max(), (0, 35));
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+struct Tagged<'a>(f64, &'a str);
+
+impl std::ops::Add for Tagged<'_> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Tagged(self.0 + rhs.0, self.1)
+    }
+}
+
+#[test]
+fn test_borrowing_elements_use_generic_path() {
+    let tag = String::from("tag");
+    let a = Mat::from_fn(5, 3, |i, j| Tagged((i + j) as f64, &tag));
+    let b = &a + &a;
+    assert_eq!(b[(4, 2)], Tagged(12.0, "tag"));
+    assert_eq!(a.min_max(), (Tagged(0.0, "tag"), Tagged(6.0, "tag")));
+}
@@ -0,0 +1,164 @@
+use aris::{Error, Mat, MatMut, MatRef};
+
+#[test]
+fn col_major_slice_view() {
+    let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let v = MatRef::from_slice_col_major(&data, 2, 3);
+    assert_eq!(v.shape(), (2, 3));
+    assert_eq!((v.row_stride(), v.col_stride()), (1, 2));
+    assert_eq!(v, Mat::from_rows(&[&[1.0, 3.0, 5.0], &[2.0, 4.0, 6.0]]));
+    assert_eq!(v.to_owned().sum(), 21.0);
+}
+
+#[test]
+fn col_major_length_must_match() {
+    let data = [1, 2, 3, 4, 5];
+    assert_eq!(
+        MatRef::try_from_slice_col_major(&data, 2, 3).err(),
+        Some(Error::LengthMismatch {
+            part: None,
+            expected: 6,
+            found: 5
+        })
+    );
+    assert!(MatRef::try_from_slice_col_major(&data, 2, 2).is_err());
+    assert!(MatRef::try_from_slice_col_major(&data, usize::MAX, 2).is_err());
+    assert_eq!(
+        MatRef::from_slice_col_major(&data[..0], 0, 4).shape(),
+        (0, 4)
+    );
+}
+
+#[test]
+fn row_major_buffer_through_strides() {
+    let data = [1, 2, 3, 4, 5, 6];
+    let v = MatRef::from_slice_with_strides(&data, 2, 3, 3, 1);
+    assert_eq!(v, Mat::from_rows(&[&[1, 2, 3], &[4, 5, 6]]));
+    assert_eq!(v.transpose(), MatRef::from_slice_col_major(&data, 3, 2));
+}
+
+#[test]
+fn padded_columns_and_longer_buffers() {
+    // Leading dimension 4 with only three rows in use, as in BLAS.
+    let data: Vec<i32> = (0..11).collect();
+    let v = MatRef::from_slice_with_strides(&data, 3, 3, 1, 4);
+    assert_eq!(v, Mat::from_rows(&[&[0, 4, 8], &[1, 5, 9], &[2, 6, 10]]));
+    let v = MatRef::from_slice_with_strides(&data, 2, 2, 2, 5);
+    assert_eq!(v, Mat::from_rows(&[&[0, 5], &[2, 7]]));
+}
+
+#[test]
+fn negative_strides_start_from_the_highest_address() {
+    let data = [1, 2, 3, 4, 5, 6];
+    let v = MatRef::from_slice_with_strides(&data, 2, 3, -1, 2);
+    assert_eq!(v, Mat::from_rows(&[&[2, 4, 6], &[1, 3, 5]]));
+    let v = MatRef::from_slice_with_strides(&data, 2, 3, 3, -1);
+    assert_eq!(v, Mat::from_rows(&[&[3, 2, 1], &[6, 5, 4]]));
+    let v = MatRef::from_slice_with_strides(&data, 2, 3, -1, -2);
+    assert_eq!(v, Mat::from_rows(&[&[6, 4, 2], &[5, 3, 1]]));
+    assert_eq!(
+        v.reverse_rows().reverse_cols(),
+        MatRef::from_slice_col_major(&data, 2, 3)
+    );
+}
+
+#[test]
+fn strided_view_must_fit_the_buffer() {
+    let data = [0.0; 10];
+    assert_eq!(
+        MatRef::try_from_slice_with_strides(&data, 3, 3, 1, 4).err(),
+        Some(Error::BufferTooSmall {
+            required: 11,
+            len: 10
+        })
+    );
+    assert_eq!(
+        MatRef::try_from_slice_with_strides(&data, 3, 3, -1, -4).err(),
+        Some(Error::BufferTooSmall {
+            required: 11,
+            len: 10
+        })
+    );
+    assert!(MatRef::try_from_slice_with_strides(&data, 3, 2, isize::MAX, 1).is_err());
+    assert!(MatRef::try_from_slice_with_strides(&data, 2, 2, isize::MIN, isize::MIN).is_err());
+    assert!(MatRef::try_from_slice_with_strides(&data, usize::MAX, 1, 1, 1).is_err());
+}
+
+#[test]
+#[should_panic(expected = "buffer of 4 elements is too small for a view spanning 5")]
+fn strided_view_panics_when_out_of_bounds() {
+    MatRef::from_slice_with_strides(&[1, 2, 3, 4], 2, 2, 1, 3);
+}
+
+#[test]
+fn empty_views_accept_any_strides() {
+    let data: [f64; 0] = [];
+    let v = MatRef::from_slice_with_strides(&data, 0, 5, isize::MAX, isize::MIN);
+    assert_eq!(v.shape(), (0, 5));
+    let v = MatMut::<f64>::from_slice_with_strides(&mut [], 3, 0, 0, 0);
+    assert_eq!(v.shape(), (3, 0));
+}
+
+#[test]
+fn shared_views_may_repeat_elements() {
+    let data = [1, 2, 3];
+    let v = MatRef::from_slice_with_strides(&data, 4, 3, 0, 1);
+    assert_eq!(v, Mat::from_fn(4, 3, |_, j| j as i32 + 1));
+    let v = MatRef::from_slice_with_strides(&data, 2, 2, 1, 1);
+    assert_eq!(v, Mat::from_rows(&[&[1, 2], &[2, 3]]));
+}
+
+#[test]
+fn mutable_views_write_through() {
+    let mut data = vec![0.0; 8];
+    let mut v = MatMut::from_slice_col_major(&mut data, 2, 4);
+    v.fill_with_fn(|i, j| (i + 2 * j) as f64);
+    v[(1, 3)] = 10.0;
+    assert_eq!(data, [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 10.0]);
+
+    let mut data = [1, 2, 3, 4, 5, 6];
+    let mut v = MatMut::from_slice_with_strides(&mut data, 2, 3, -3, 1);
+    v[(0, 0)] = 40;
+    v.row_mut(1).fill(0);
+    assert_eq!(data, [0, 0, 0, 40, 5, 6]);
+}
+
+#[test]
+fn mutable_views_reject_aliasing_strides() {
+    let mut data = [0; 12];
+    let overlap = |row_stride, col_stride| Error::OverlappingStrides {
+        row_stride,
+        col_stride,
+    };
+    assert_eq!(
+        MatMut::try_from_slice_with_strides(&mut data, 3, 2, 0, 1).err(),
+        Some(overlap(0, 1))
+    );
+    assert_eq!(
+        MatMut::try_from_slice_with_strides(&mut data, 3, 3, 1, 2).err(),
+        Some(overlap(1, 2))
+    );
+    assert_eq!(
+        MatMut::try_from_slice_with_strides(&mut data, 2, 2, -2, 2).err(),
+        Some(overlap(-2, 2))
+    );
+    assert!(MatMut::try_from_slice_with_strides(&mut data, 3, 4, 1, 3).is_ok());
+    assert!(MatMut::try_from_slice_with_strides(&mut data, 3, 4, -4, -1).is_ok());
+    assert!(MatMut::try_from_slice_with_strides(&mut data, 1, 4, 0, 3).is_ok());
+    assert!(MatMut::try_from_slice_with_strides(&mut data, 12, 1, 1, 0).is_ok());
+    assert!(MatMut::try_from_slice_with_strides(&mut data, 1, 1, 0, 0).is_ok());
+}
+
+#[test]
+fn raw_parts_round_trip() {
+    let mut data: Vec<f64> = (0..36).map(|k| k as f64).collect();
+    let ptr = data.as_mut_ptr();
+    let v = unsafe { MatRef::from_raw_parts(ptr, 9, 4, 1, 9) };
+    assert_eq!(v, Mat::from_fn(9, 4, |i, j| (i + 9 * j) as f64));
+
+    // The second column, read backwards.
+    let mut w = unsafe { MatMut::from_raw_parts(ptr.add(17), 9, 1, -1, 0) };
+    w[(0, 0)] = -1.0;
+    w[(8, 0)] = -2.0;
+    assert_eq!((data[17], data[9]), (-1.0, -2.0));
+}
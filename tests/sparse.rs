@@ -0,0 +1,225 @@
+use aris::{Axis, Error, Mat, SparseColMat, SparseColMatRef, SparseRowMat, mat};
+
+fn sample() -> Mat<f64> {
+    mat![
+        [4.0, 0.0, 0.0, 1.0],
+        [0.0, 0.0, 2.0, 0.0],
+        [-1.0, 3.0, 0.0, 0.0]
+    ]
+}
+
+#[test]
+fn triplets_sum_duplicates_and_sort() {
+    let a = SparseColMat::from_triplets(
+        3,
+        3,
+        &[
+            (2, 0, 1.0),
+            (0, 0, 2.0),
+            (2, 0, 0.5),
+            (1, 2, -1.0),
+            (0, 1, 3.0),
+        ],
+    );
+    assert_eq!(a.nnz(), 4);
+    assert_eq!(a.col_ptr(), &[0, 2, 3, 4]);
+    assert_eq!(a.row_indices(), &[0, 2, 0, 1]);
+    assert_eq!(a.values(), &[2.0, 1.5, 3.0, -1.0]);
+    assert_eq!(
+        a.to_dense(),
+        mat![[2.0, 3.0, 0.0], [0.0, 0.0, -1.0], [1.5, 0.0, 0.0]]
+    );
+}
+
+#[test]
+fn row_triplets_sum_duplicates() {
+    let a = SparseRowMat::from_triplets(2, 3, &[(1, 2, 1), (0, 1, 2), (1, 2, 4), (1, 0, 7)]);
+    assert_eq!(a.row_ptr(), &[0, 1, 3]);
+    assert_eq!(a.col_indices(), &[1, 0, 2]);
+    assert_eq!(a.values(), &[2, 7, 5]);
+    assert_eq!(a.to_dense(), mat![[0, 2, 0], [7, 0, 5]]);
+}
+
+#[test]
+fn triplets_out_of_bounds() {
+    let err = SparseColMat::try_from_triplets(2, 2, &[(0, 0, 1.0), (2, 1, 1.0)]).unwrap_err();
+    assert_eq!(
+        err,
+        Error::IndexOutOfBounds {
+            axis: Axis::Row,
+            index: 2,
+            len: 2
+        }
+    );
+    assert!(SparseRowMat::try_from_triplets(2, 2, &[(0, 5, 1.0)]).is_err());
+}
+
+#[test]
+fn dense_round_trip() {
+    let dense = sample();
+    let csc = SparseColMat::from_dense(dense.as_ref());
+    assert_eq!(csc.nnz(), 5);
+    assert_eq!(csc.to_dense(), dense);
+    let csr = SparseRowMat::from_dense(dense.as_ref());
+    assert_eq!(csr.row_ptr(), &[0, 2, 3, 5]);
+    assert_eq!(csr.to_dense(), dense);
+}
+
+#[test]
+fn convert_between_formats() {
+    let dense = sample();
+    let csc = SparseColMat::from_dense(dense.as_ref());
+    let csr = csc.to_row_mat();
+    assert_eq!(csr, SparseRowMat::from_dense(dense.as_ref()));
+    assert_eq!(csr.to_col_mat(), csc);
+}
+
+#[test]
+fn transpose_is_zero_copy() {
+    let csc = SparseColMat::from_dense(sample().as_ref());
+    let t = csc.transpose();
+    assert_eq!(t.shape(), (4, 3));
+    assert_eq!(t.values().as_ptr(), csc.values().as_ptr());
+    assert_eq!(t.to_dense(), sample().transpose());
+    let owned = csc.clone().into_transpose();
+    assert_eq!(owned.to_dense(), sample().transpose());
+    assert_eq!(owned.transpose().to_owned(), csc);
+}
+
+#[test]
+fn get_and_get_mut() {
+    let mut a = SparseColMat::from_dense(sample().as_ref());
+    assert_eq!(a.get(2, 1), Some(&3.0));
+    assert_eq!(a.get(1, 1), None);
+    assert_eq!(a.get(5, 0), None);
+    *a.get_mut(1, 2).unwrap() = 9.0;
+    assert_eq!(a.to_dense()[(1, 2)], 9.0);
+    assert!(a.get_mut(1, 1).is_none());
+    let r = SparseRowMat::from_dense(sample().as_ref());
+    assert_eq!(r.get(0, 3), Some(&1.0));
+}
+
+#[test]
+fn column_slice_views() {
+    let dense = sample();
+    let csc = SparseColMat::from_dense(dense.as_ref());
+    let view = csc.cols_range(1..3);
+    assert_eq!(view.shape(), (3, 2));
+    assert_eq!(view.nnz(), 2);
+    assert_eq!(view.col(0), (&[2usize][..], &[3.0][..]));
+    assert_eq!(view.col_range(1), 1..2);
+    assert_eq!(view.to_dense(), dense.cols_range(1..3));
+    let nested = view.cols_range(1..2);
+    assert_eq!(nested.to_owned().col_ptr(), &[0, 1]);
+    assert!(csc.try_cols_range(2..5).is_err());
+    let rows = SparseRowMat::from_dense(dense.as_ref());
+    assert_eq!(rows.rows_range(1..3).to_dense(), dense.rows_range(1..3));
+}
+
+#[test]
+fn sparse_add_and_sub() {
+    let a = SparseColMat::from_triplets(2, 2, &[(0, 0, 1.0), (1, 1, 2.0)]);
+    let b = SparseColMat::from_triplets(2, 2, &[(0, 0, 3.0), (0, 1, -1.0)]);
+    let sum = &a + &b;
+    assert_eq!(sum.to_dense(), mat![[4.0, -1.0], [0.0, 2.0]]);
+    assert_eq!((&a - &b).to_dense(), mat![[-2.0, 1.0], [0.0, 2.0]]);
+    let ra = a.to_row_mat();
+    let rb = b.to_row_mat();
+    assert_eq!((&ra + &rb).to_dense(), sum.to_dense());
+    assert_eq!(
+        (ra.as_ref() - &rb).to_dense(),
+        mat![[-2.0, 1.0], [0.0, 2.0]]
+    );
+}
+
+#[test]
+fn sparse_dense_product() {
+    let dense = sample();
+    let x: Mat<f64> = Mat::from_fn(4, 2, |i, j| (i + 2 * j) as f64 - 1.0);
+    let expected = &dense * &x;
+    let csc = SparseColMat::from_dense(dense.as_ref());
+    assert_eq!(&csc * &x, expected);
+    assert_eq!(csc.as_ref() * x.as_ref(), expected);
+    let csr = csc.to_row_mat();
+    assert_eq!(&csr * x.as_ref(), expected);
+    assert_eq!(csr.as_ref() * &x, expected);
+}
+
+#[test]
+fn sparse_sparse_product() {
+    let a = sample();
+    let b: Mat<f64> = mat![[1.0, 0.0], [0.0, 2.0], [0.0, 0.0], [5.0, -1.0]];
+    let expected = &a * &b;
+    let ca = SparseColMat::from_dense(a.as_ref());
+    let cb = SparseColMat::from_dense(b.as_ref());
+    let c = &ca * &cb;
+    assert_eq!(c.to_dense(), expected);
+    for j in 0..c.ncols() {
+        assert!(c.col(j).0.windows(2).all(|w| w[0] < w[1]));
+    }
+    let r = &ca.to_row_mat() * &cb.to_row_mat();
+    assert_eq!(r.to_dense(), expected);
+    assert_eq!(r.shape(), (3, 2));
+}
+
+#[test]
+fn products_report_shape_mismatch() {
+    let a = SparseColMat::<f64>::zeros(3, 4);
+    let b = SparseColMat::<f64>::zeros(3, 4);
+    assert_eq!(
+        a.try_mul(b.as_ref()).unwrap_err(),
+        Error::ShapeMismatch {
+            lhs: (3, 4),
+            rhs: (3, 4)
+        }
+    );
+    let x: Mat<f64> = Mat::zeros(2, 1);
+    assert!(a.try_mul_dense(x.as_ref()).is_err());
+    assert!(
+        a.to_row_mat()
+            .try_add(SparseRowMat::zeros(4, 3).as_ref())
+            .is_err()
+    );
+}
+
+#[test]
+#[should_panic(expected = "shape mismatch: (3, 4) vs (4, 3)")]
+fn add_panics_on_shape_mismatch() {
+    let _ = &SparseColMat::<f64>::zeros(3, 4) + &SparseColMat::<f64>::zeros(4, 3);
+}
+
+#[test]
+fn validated_raw_construction() {
+    let col_ptr = [0, 1, 3];
+    let row_idx = [1, 0, 2];
+    let values = [1.0, 2.0, 3.0];
+    let a = SparseColMatRef::try_new(3, 2, &col_ptr, &row_idx, &values).unwrap();
+    assert_eq!(a.to_dense(), mat![[0.0, 2.0], [1.0, 0.0], [0.0, 3.0]]);
+    let unsorted = [1, 2, 0];
+    let err = SparseColMatRef::try_new(3, 2, &col_ptr, &unsorted, &values).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid format: indices of column 1 are not strictly increasing"
+    );
+    assert!(SparseColMat::try_new(3, 2, vec![0, 1], vec![0], vec![1.0]).is_err());
+    assert!(SparseColMat::try_new(3, 2, vec![0, 2, 1], vec![0, 1], vec![1.0, 2.0]).is_err());
+    assert!(SparseRowMat::try_new(2, 2, vec![0, 1, 1], vec![2], vec![1.0]).is_err());
+}
+
+#[test]
+fn identity_and_triplet_iter() {
+    let eye = SparseColMat::<i32>::identity(3);
+    assert_eq!(eye.to_dense(), Mat::identity(3));
+    let a = SparseRowMat::from_dense(sample().as_ref());
+    let entries: Vec<_> = a.triplet_iter().map(|(i, j, &v)| (i, j, v)).collect();
+    assert_eq!(
+        entries,
+        vec![
+            (0, 0, 4.0),
+            (0, 3, 1.0),
+            (1, 2, 2.0),
+            (2, 0, -1.0),
+            (2, 1, 3.0)
+        ]
+    );
+}
@@ -0,0 +1,291 @@
+mod common;
+
+use aris::sparse::{Ordering, SparseCholesky, SparseLu, SymbolicCholesky, SymbolicLu, amd, colamd};
+use aris::{Complex, Error, Mat, SparseColMat, mat};
+
+use common::c;
+
+fn laplacian(m: usize) -> SparseColMat<f64> {
+    let n = m * m;
+    let mut triplets = Vec::new();
+    for x in 0..m {
+        for y in 0..m {
+            let k = x * m + y;
+            triplets.push((k, k, 4.0));
+            if x + 1 < m {
+                triplets.push((k, k + m, -1.0));
+                triplets.push((k + m, k, -1.0));
+            }
+            if y + 1 < m {
+                triplets.push((k, k + 1, -1.0));
+                triplets.push((k + 1, k, -1.0));
+            }
+        }
+    }
+    SparseColMat::from_triplets(n, n, &triplets)
+}
+
+fn arrow(n: usize) -> SparseColMat<f64> {
+    let mut triplets = vec![(0, 0, n as f64)];
+    for k in 1..n {
+        triplets.push((k, k, 2.0));
+        triplets.push((0, k, 1.0));
+        triplets.push((k, 0, 1.0));
+    }
+    SparseColMat::from_triplets(n, n, &triplets)
+}
+
+fn unsymmetric() -> SparseColMat<f64> {
+    SparseColMat::from_dense(
+        mat![
+            [0.0, 2.0, 0.0, 1.0, 0.0],
+            [3.0, 0.0, 0.0, 0.0, 4.0],
+            [0.0, 1.0, 5.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0, 0.0, 2.0],
+            [0.0, 0.0, 2.0, 6.0, 0.0]
+        ]
+        .as_ref(),
+    )
+}
+
+fn rhs(n: usize, k: usize) -> Mat<f64> {
+    Mat::from_fn(n, k, |i, j| ((i * 5 + j * 3) % 7) as f64 - 3.0)
+}
+
+fn max_abs_diff(a: &Mat<f64>, b: &Mat<f64>) -> f64 {
+    (a - b).abs().max()
+}
+
+fn is_permutation(perm: &[usize]) -> bool {
+    let mut sorted = perm.to_vec();
+    sorted.sort_unstable();
+    sorted.iter().copied().eq(0..perm.len())
+}
+
+#[test]
+fn cholesky_solves_laplacian() {
+    let a = laplacian(6);
+    let b = rhs(36, 3);
+    let chol = a.cholesky(Ordering::Amd).unwrap();
+    let x = chol.solve(b.as_ref()).unwrap();
+    assert!(max_abs_diff(&(a.to_dense() * &x), &b) < 1e-12);
+}
+
+#[test]
+fn cholesky_factor_reconstructs_permuted_matrix() {
+    let a = laplacian(4);
+    let chol = a.cholesky(Ordering::Amd).unwrap();
+    let l = chol.l().to_dense();
+    assert!(l.is_lower_triangular());
+    let perm = chol.perm();
+    let dense = a.to_dense();
+    let permuted = Mat::from_fn(16, 16, |i, j| dense[(perm[i], perm[j])]);
+    assert!(max_abs_diff(&(&l * l.transpose()), &permuted) < 1e-12);
+}
+
+#[test]
+fn cholesky_uses_lower_triangle_only() {
+    let full = laplacian(3);
+    let lower = SparseColMat::from_triplets(
+        9,
+        9,
+        &full
+            .triplet_iter()
+            .filter(|&(i, j, _)| i >= j)
+            .map(|(i, j, &v)| (i, j, v))
+            .collect::<Vec<_>>(),
+    );
+    let b = rhs(9, 1);
+    let x_full = full.cholesky(Ordering::Natural).unwrap().solve(b.as_ref());
+    let x_lower = lower.cholesky(Ordering::Amd).unwrap().solve(b.as_ref());
+    assert!(max_abs_diff(&x_full.unwrap(), &x_lower.unwrap()) < 1e-12);
+}
+
+#[test]
+fn amd_reduces_fill_on_arrow_matrix() {
+    let a = arrow(20);
+    let natural = SymbolicCholesky::new(a.as_ref(), Ordering::Natural).unwrap();
+    let ordered = SymbolicCholesky::new(a.as_ref(), Ordering::Amd).unwrap();
+    assert_eq!(natural.l_nnz(), 20 * 21 / 2);
+    assert_eq!(ordered.l_nnz(), 2 * 20 - 1);
+    assert!(!ordered.perm()[..18].contains(&0));
+    assert!(is_permutation(ordered.perm()));
+}
+
+#[test]
+fn orderings_are_permutations() {
+    let a = laplacian(5);
+    let p = amd(a.as_ref()).unwrap();
+    assert!(is_permutation(&p));
+    let q = colamd(unsymmetric().as_ref());
+    assert!(is_permutation(&q));
+    let r = SparseColMat::<f64>::zeros(3, 5);
+    assert!(is_permutation(&colamd(r.as_ref())));
+    assert_eq!(
+        amd(r.as_ref()),
+        Err(Error::NotSquare { nrows: 3, ncols: 5 })
+    );
+}
+
+#[test]
+fn elimination_tree_of_tridiagonal_is_a_path() {
+    let mut triplets = Vec::new();
+    for k in 0..5 {
+        triplets.push((k, k, 2.0));
+        if k > 0 {
+            triplets.push((k, k - 1, -1.0));
+        }
+    }
+    let a = SparseColMat::from_triplets(5, 5, &triplets);
+    let symbolic = SymbolicCholesky::new(a.as_ref(), Ordering::Natural).unwrap();
+    assert_eq!(
+        symbolic.etree(),
+        vec![Some(1), Some(2), Some(3), Some(4), None]
+    );
+    assert_eq!(symbolic.l_nnz(), 9);
+}
+
+#[test]
+fn symbolic_cholesky_is_reused_across_refactorizations() {
+    let a = laplacian(4);
+    let symbolic = SymbolicCholesky::new(a.as_ref(), Ordering::Amd).unwrap();
+    let mut chol = SparseCholesky::with_symbolic(symbolic, a.as_ref()).unwrap();
+
+    let mut shifted = a.clone();
+    for k in 0..16 {
+        *shifted.get_mut(k, k).unwrap() += 1.0;
+    }
+    chol.refactor(shifted.as_ref()).unwrap();
+    let b = rhs(16, 2);
+    let x = chol.solve(b.as_ref()).unwrap();
+    assert!(max_abs_diff(&(shifted.to_dense() * &x), &b) < 1e-12);
+
+    let other = laplacian(3);
+    assert!(chol.refactor(other.as_ref()).is_err());
+    let pruned = SparseColMat::from_triplets(16, 16, &[(0, 0, 1.0)]);
+    assert_eq!(chol.refactor(pruned.as_ref()), Err(Error::PatternMismatch));
+}
+
+#[test]
+fn cholesky_rejects_indefinite_matrix() {
+    let a = SparseColMat::from_dense(mat![[1.0, 2.0], [2.0, 1.0]].as_ref());
+    let err = a.cholesky(Ordering::Natural).unwrap_err();
+    assert_eq!(err, Error::NotPositiveDefinite { index: 1 });
+}
+
+#[test]
+fn cholesky_log_det_matches_dense() {
+    let a = laplacian(3);
+    let sparse = a.cholesky(Ordering::Amd).unwrap().log_det();
+    let dense = a.to_dense().cholesky().log_det();
+    assert!((sparse - dense).abs() < 1e-12);
+}
+
+#[test]
+fn complex_hermitian_cholesky() {
+    let dense = mat![
+        [c(4.0, 0.0), c(1.0, 1.0), c(0.0, 0.0)],
+        [c(1.0, -1.0), c(5.0, 0.0), c(0.0, 2.0)],
+        [c(0.0, 0.0), c(0.0, -2.0), c(6.0, 0.0)]
+    ];
+    let a = SparseColMat::from_dense(dense.as_ref());
+    let b = mat![[c(1.0, 0.0)], [c(0.0, 1.0)], [c(2.0, -1.0)]];
+    let x = a
+        .cholesky(Ordering::Amd)
+        .unwrap()
+        .solve(b.as_ref())
+        .unwrap();
+    let residual: Mat<Complex<f64>> = &dense * &x - &b;
+    assert!(residual.max_abs_norm() < 1e-12);
+}
+
+#[test]
+fn lu_solves_with_pivoting() {
+    let a = unsymmetric();
+    let b = rhs(5, 2);
+    for ordering in [Ordering::Natural, Ordering::Amd] {
+        let lu = a.lu(ordering).unwrap();
+        let x = lu.solve(b.as_ref()).unwrap();
+        assert!(max_abs_diff(&(a.to_dense() * &x), &b) < 1e-12);
+    }
+}
+
+#[test]
+fn lu_factors_reconstruct_permuted_matrix() {
+    let a = unsymmetric();
+    let lu = a.lu(Ordering::Amd).unwrap();
+    let l = lu.l().to_dense();
+    let u = lu.u().to_dense();
+    assert!(l.is_lower_triangular());
+    assert!(u.is_upper_triangular());
+    assert!((0..5).all(|k| l[(k, k)] == 1.0));
+    assert!(l.max_abs_norm() <= 1.0);
+    let (p, q) = (lu.row_perm(), lu.col_perm());
+    let dense = a.to_dense();
+    let permuted = Mat::from_fn(5, 5, |i, j| dense[(p[i], q[j])]);
+    assert!(max_abs_diff(&(&l * &u), &permuted) < 1e-12);
+}
+
+#[test]
+fn lu_reports_singular_matrix() {
+    let a =
+        SparseColMat::from_dense(mat![[1.0, 2.0, 0.0], [2.0, 4.0, 0.0], [0.0, 0.0, 1.0]].as_ref());
+    let err = a.lu(Ordering::Natural).unwrap_err();
+    assert_eq!(err, Error::Singular { index: 1 });
+    let empty_col = SparseColMat::from_triplets(2, 2, &[(0, 0, 1.0), (1, 0, 1.0)]);
+    assert!(empty_col.lu(Ordering::Amd).is_err());
+}
+
+#[test]
+fn symbolic_lu_is_reused_across_refactorizations() {
+    let a = unsymmetric();
+    let symbolic = SymbolicLu::new(a.as_ref(), Ordering::Amd).unwrap();
+    let mut lu = SparseLu::with_symbolic(symbolic.clone(), a.as_ref()).unwrap();
+    assert_eq!(lu.col_perm(), symbolic.col_perm());
+
+    let mut scaled = a.clone();
+    for (k, v) in scaled.values_mut().iter_mut().enumerate() {
+        *v *= 1.0 + k as f64;
+    }
+    lu.refactor(scaled.as_ref()).unwrap();
+    let b = rhs(5, 1);
+    let x = lu.solve(b.as_ref()).unwrap();
+    assert!(max_abs_diff(&(scaled.to_dense() * &x), &b) < 1e-12);
+    assert_eq!(
+        lu.refactor(laplacian(2).as_ref()),
+        Err(Error::PatternMismatch)
+    );
+}
+
+#[test]
+fn solve_in_place_into_strided_view() {
+    let a = laplacian(3);
+    let lu = a.lu(Ordering::Amd).unwrap();
+    let chol = a.cholesky(Ordering::Amd).unwrap();
+    let b = rhs(9, 2);
+    let mut wide = Mat::zeros(9, 4);
+    wide.view_mut(0, 1, 9, 2).copy_from(b.as_ref());
+    lu.solve_in_place(wide.view_mut(0, 1, 9, 2)).unwrap();
+    let mut x = b.clone();
+    chol.solve_in_place(x.as_mut()).unwrap();
+    assert!(max_abs_diff(&wide.view(0, 1, 9, 2).to_owned(), &x) < 1e-12);
+    assert!(wide.col(0).max_abs_norm() == 0.0);
+    assert_eq!(
+        chol.solve(rhs(4, 1).as_ref()),
+        Err(Error::LengthMismatch {
+            part: None,
+            expected: 9,
+            found: 4
+        })
+    );
+}
+
+#[test]
+fn custom_ordering_is_validated() {
+    let a = laplacian(2);
+    let symbolic = SymbolicCholesky::new(a.as_ref(), Ordering::Custom(vec![3, 1, 2, 0])).unwrap();
+    assert_eq!(symbolic.perm(), &[3, 1, 2, 0]);
+    assert_eq!(symbolic.perm_inv(), &[3, 1, 2, 0]);
+    assert!(SymbolicLu::new(a.as_ref(), Ordering::Custom(vec![0, 1, 1, 2])).is_err());
+    assert!(SymbolicCholesky::new(a.as_ref(), Ordering::Custom(vec![0, 1])).is_err());
+}
@@ -0,0 +1,345 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use aris::{Error, Mat, mat};
+
+fn addr<T>(x: &T) -> usize {
+    x as *const T as usize
+}
+
+// Counts how many of its clones have been dropped.
+#[derive(Clone)]
+struct Tracked(Rc<Cell<usize>>);
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+// A matrix whose columns start on 64-byte boundaries.
+fn padded(nrows: usize, ncols: usize, f: impl Fn(usize, usize) -> f64) -> Mat<f64> {
+    let mut m = Mat::with_capacity_aligned(nrows, ncols, 64);
+    for j in 0..ncols {
+        let col: Vec<f64> = (0..nrows).map(|i| f(i, j)).collect();
+        m.push_col(&col);
+    }
+    m
+}
+
+#[test]
+fn columns_are_packed_by_default() {
+    let m = Mat::from_fn(9, 3, |i, j| (i + 9 * j) as f64);
+    assert_eq!(m.alignment(), 64);
+    assert_eq!(m.col_stride(), 9);
+    assert!(m.is_contiguous());
+    assert_eq!(addr(&m[(0, 0)]) % 64, 0);
+    assert_eq!(
+        m.as_slice(),
+        &(0..27).map(|k| k as f64).collect::<Vec<_>>()[..]
+    );
+    assert_eq!(Mat::<f64>::zeros(9, 2).as_slice(), &[0.0; 18]);
+    assert_eq!(Mat::<f32>::with_capacity(17, 2).capacity(), (17, 2));
+}
+
+#[test]
+fn aligned_columns_are_padded() {
+    let m = padded(10, 3, |i, j| (i + 10 * j) as f64);
+    assert_eq!(m.alignment(), 64);
+    assert_eq!(m.col_stride(), 16);
+    for j in 0..3 {
+        assert_eq!(addr(&m[(0, j)]) % 64, 0);
+        for i in 0..10 {
+            assert_eq!(m[(i, j)], (i + 10 * j) as f64);
+        }
+    }
+    assert_eq!(m.clone().col_stride(), 16);
+    assert_eq!(
+        Mat::<f32>::with_capacity_aligned(17, 2, 64).capacity(),
+        (32, 2)
+    );
+    assert_eq!(
+        Mat::<u8>::with_capacity_aligned(64, 2, 64).capacity(),
+        (64, 2)
+    );
+}
+
+#[test]
+fn short_columns_stay_packed() {
+    let m = padded(2, 3, |i, j| (3 * i + j + 1) as f64);
+    assert_eq!(m.col_stride(), 2);
+    assert!(m.is_contiguous());
+    assert_eq!(m.as_slice(), &[1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+    assert_eq!(addr(&m[(0, 0)]) % 64, 0);
+}
+
+#[test]
+fn as_slice_requires_contiguous_storage() {
+    let mut m = padded(9, 2, |_, _| 0.0);
+    assert!(!m.is_contiguous());
+    assert_eq!(
+        m.try_as_slice(),
+        Err(Error::Unsupported {
+            operation: "slice over padded columns"
+        })
+    );
+    assert!(m.try_as_slice_mut().is_err());
+
+    // A single column has no padding to skip.
+    let mut c = padded(9, 1, |_, _| 0.0);
+    c.as_slice_mut()[8] = 1.0;
+    assert_eq!(c.as_slice().len(), 9);
+    assert_eq!(c[(8, 0)], 1.0);
+
+    let m = padded(16, 3, |_, _| 0.0);
+    assert!(m.is_contiguous());
+    assert_eq!(m.as_slice().len(), 48);
+}
+
+#[test]
+fn push_col_fills_reserved_capacity_in_place() {
+    let mut m = Mat::with_capacity(20, 8);
+    assert_eq!(m.shape(), (0, 0));
+    assert_eq!(m.capacity(), (20, 8));
+    let col: Vec<f64> = (0..20).map(|i| i as f64).collect();
+    m.push_col(&col);
+    let first = addr(&m[(0, 0)]);
+    for _ in 1..8 {
+        m.push_col(&col);
+    }
+    assert_eq!(m.shape(), (20, 8));
+    assert_eq!(addr(&m[(0, 0)]), first);
+    assert_eq!(m.capacity(), (20, 8));
+    assert!(m.is_contiguous());
+
+    m.push_col(&col);
+    assert_eq!(m.capacity(), (20, 16));
+    assert!((0..9).all(|j| m[(19, j)] == 19.0));
+}
+
+#[test]
+fn push_row_uses_column_padding() {
+    let mut m = padded(10, 3, |i, j| (i + 10 * j) as f64);
+    let first = addr(&m[(0, 0)]);
+    for k in 0..6 {
+        m.push_row(&[100.0 + k as f64, 200.0, 300.0]);
+    }
+    assert_eq!(m.shape(), (16, 3));
+    assert_eq!(m.col_stride(), 16);
+    assert_eq!(addr(&m[(0, 0)]), first);
+    assert_eq!(m[(15, 0)], 105.0);
+    assert_eq!(m[(9, 2)], 29.0);
+    assert_eq!(m[(10, 2)], 300.0);
+}
+
+#[test]
+fn packed_matrices_stay_contiguous() {
+    let mut m = mat![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+    m.truncate(2, 2);
+    assert_eq!(m.as_slice(), &[1.0, 3.0, 2.0, 4.0]);
+
+    let mut m = Mat::new();
+    for i in 0..3 {
+        m.push_row(&[i, 10 + i]);
+    }
+    assert_eq!(m.as_slice(), &[0, 1, 2, 10, 11, 12]);
+
+    let mut m = mat![[1.0, 2.0], [3.0, 4.0]];
+    m.resize(3, 2, 0.0);
+    assert_eq!(m.as_slice(), &[1.0, 3.0, 0.0, 2.0, 4.0, 0.0]);
+    m.resize(1, 3, 9.0);
+    assert_eq!(m.as_slice(), &[1.0, 2.0, 9.0]);
+
+    // Rows are added within the reserved buffer by moving the columns.
+    let mut m = Mat::with_capacity(4, 3);
+    m.push_row(&[1, 2, 3]);
+    let first = addr(&m[(0, 0)]);
+    m.push_row(&[4, 5, 6]);
+    assert_eq!(m.as_slice(), &[1, 4, 2, 5, 3, 6]);
+    assert_eq!(addr(&m[(0, 0)]), first);
+
+    let mut m = Mat::with_capacity(4, 3);
+    m.push_col(&[1, 2]);
+    m.push_col(&[3, 4]);
+    assert_eq!(m.as_slice(), &[1, 2, 3, 4]);
+}
+
+#[test]
+fn push_row_grows_stride_geometrically() {
+    let mut m = Mat::with_capacity_aligned(0, 0, 64);
+    let mut strides = Vec::new();
+    for i in 0..100 {
+        m.push_row(&[i, 2 * i, 3 * i]);
+        if strides.last() != Some(&m.col_stride()) {
+            strides.push(m.col_stride());
+        }
+    }
+    assert_eq!(m.shape(), (100, 3));
+    assert!(strides.len() <= 8, "{strides:?}");
+    for i in 0..100 {
+        assert_eq!((m[(i, 0)], m[(i, 1)], m[(i, 2)]), (i, 2 * i, 3 * i));
+    }
+}
+
+#[test]
+fn push_into_empty_matrix_sets_shape() {
+    let mut m = Mat::new();
+    m.push_col(&[1, 2, 3]);
+    m.push_col(&[4, 5, 6]);
+    assert_eq!(m, Mat::from_rows(&[&[1, 4], &[2, 5], &[3, 6]]));
+
+    let mut m = Mat::new();
+    m.push_row(&[1, 2]);
+    m.push_row(&[3, 4]);
+    assert_eq!(m, Mat::from_rows(&[&[1, 2], &[3, 4]]));
+}
+
+#[test]
+fn push_length_mismatch() {
+    let mut m = Mat::<i32>::zeros(2, 3);
+    assert_eq!(
+        m.try_push_col(&[1, 2, 3]),
+        Err(Error::LengthMismatch {
+            part: None,
+            expected: 2,
+            found: 3
+        })
+    );
+    assert_eq!(
+        m.try_push_row(&[1, 2]),
+        Err(Error::LengthMismatch {
+            part: None,
+            expected: 3,
+            found: 2
+        })
+    );
+    assert_eq!(m, Mat::zeros(2, 3));
+}
+
+#[test]
+fn resize_and_truncate_keep_the_buffer() {
+    let mut m = padded(12, 4, |i, j| (i + 100 * j) as f64);
+    let first = addr(&m[(0, 0)]);
+    m.resize(16, 4, -1.0);
+    assert_eq!(addr(&m[(0, 0)]), first);
+    assert_eq!(m[(11, 3)], 311.0);
+    assert_eq!(m[(12, 3)], -1.0);
+
+    m.truncate(5, 2);
+    assert_eq!(m.shape(), (5, 2));
+    assert_eq!(m.capacity(), (16, 4));
+    m.resize(8, 4, 0.5);
+    assert_eq!(addr(&m[(0, 0)]), first);
+    assert_eq!(m[(4, 1)], 104.0);
+    assert_eq!(m[(5, 1)], 0.5);
+    assert_eq!(m[(7, 3)], 0.5);
+}
+
+#[test]
+fn elements_are_dropped_exactly_once() {
+    let drops = Rc::new(Cell::new(0));
+    let item = Tracked(drops.clone());
+    let mut m = Mat::full(10, 4, item.clone());
+    m.push_row(&[item.clone(), item.clone(), item.clone(), item.clone()]);
+    m.push_col(&vec![item.clone(); 11]);
+    assert_eq!(drops.get(), 16);
+    drops.set(0);
+
+    m.truncate(6, 3);
+    assert_eq!(drops.get(), 55 - 18);
+    m.resize(20, 2, item.clone());
+    assert_eq!(drops.get(), 55 - 18 + 6 + 1);
+    drops.set(0);
+
+    let c = m.clone();
+    drop(m);
+    assert_eq!(drops.get(), 40);
+    drop(c);
+    assert_eq!(drops.get(), 80);
+}
+
+#[test]
+fn custom_alignment() {
+    let mut m = Mat::with_capacity_aligned(5, 3, 256);
+    assert_eq!(m.alignment(), 256);
+    for j in 0..3 {
+        m.push_col(&[j as f64; 40]);
+    }
+    assert_eq!(m.col_stride(), 64);
+    assert!((0..3).all(|j| addr(&m[(0, j)]) % 256 == 0));
+    assert_eq!(m.clone().alignment(), 256);
+
+    assert_eq!(
+        Mat::<f64>::try_with_capacity_aligned(2, 2, 48).err(),
+        Some(Error::InvalidAlignment { align: 48 })
+    );
+    assert_eq!(Mat::<u64>::with_capacity_aligned(2, 2, 1).alignment(), 8);
+}
+
+#[test]
+fn shrink_to_fit_and_reserve() {
+    let mut m = Mat::with_capacity(64, 16);
+    m.push_col(&[1.0, 2.0, 3.0]);
+    m.push_col(&[4.0, 5.0, 6.0]);
+    assert_eq!(m.col_stride(), 3);
+    m.shrink_to_fit();
+    assert_eq!(m.capacity(), (3, 2));
+    assert_eq!(m.as_slice(), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+    m.reserve(10);
+    m.reserve_rows(30);
+    let first = addr(&m[(0, 0)]);
+    for i in 0..30 {
+        m.push_row(&[i as f64, -(i as f64)]);
+    }
+    for _ in 0..10 {
+        m.push_col(&[0.5; 33]);
+    }
+    assert_eq!(addr(&m[(0, 0)]), first);
+    assert_eq!(m.shape(), (33, 12));
+    assert_eq!(m.as_slice()[..4], [1.0, 2.0, 3.0, 0.0]);
+    assert_eq!(m[(32, 1)], -29.0);
+}
+
+#[test]
+fn from_vec_col_takes_the_buffer() {
+    let data: Vec<String> = (0..30).map(|k| k.to_string()).collect();
+    let buffer = data.as_ptr() as usize;
+    let mut m = Mat::from_vec_col(10, 3, data);
+    assert_eq!(m.col_stride(), 10);
+    assert_eq!(addr(&m[(0, 0)]), buffer);
+    assert_eq!(m[(9, 2)], "29");
+    m.push_col(&vec!["x".to_string(); 10]);
+    m.truncate(4, 4);
+    let data = m.into_vec_col();
+    assert_eq!(data.len(), 16);
+    assert_eq!((data[4].as_str(), data[15].as_str()), ("10", "x"));
+
+    let data: Vec<f64> = (0..6).map(|k| k as f64).collect();
+    let buffer = data.as_ptr() as usize;
+    let data = Mat::from_vec_col(3, 2, data).into_vec_col();
+    assert_eq!(data.as_ptr() as usize, buffer);
+    assert_eq!(data, [0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+
+    // Aligned matrices copy their elements out.
+    let m = padded(9, 2, |i, j| (i + 9 * j) as f64);
+    assert_eq!(
+        m.into_vec_col(),
+        (0..18).map(|k| k as f64).collect::<Vec<_>>()
+    );
+
+    let m = Mat::from_vec_col(9, 3, (0..27).map(|k| k as f64).collect());
+    assert_eq!(m.col_stride(), 9);
+    assert_eq!(m[(8, 2)], 26.0);
+    assert_eq!(m.sum(), (0..27).sum::<i32>() as f64);
+}
+
+#[test]
+fn zero_sized_elements() {
+    let mut m = Mat::full(1000, 1000, ());
+    m.push_row(&[(); 1000]);
+    m.push_col(&[(); 1001]);
+    assert_eq!(m.shape(), (1001, 1001));
+    m.resize(3, 4, ());
+    assert_eq!(m.shape(), (3, 4));
+}
@@ -0,0 +1,247 @@
+mod common;
+
+use aris::linalg::Svd;
+use aris::{Complex, Error, Mat, mat};
+
+use common::c;
+
+fn test_matrix(m: usize, n: usize) -> Mat<f64> {
+    Mat::from_fn(m, n, |i, j| ((i * 7 + j * 13 + i * j) % 11) as f64 - 5.0)
+}
+
+fn sigma_matrix(s: &Mat<f64>, rows: usize, cols: usize) -> Mat<f64> {
+    Mat::from_fn(rows, cols, |i, j| if i == j { s[(i, 0)] } else { 0.0 })
+}
+
+fn assert_reconstructs(a: &Mat<f64>, svd: &Svd<f64>, tol: f64) {
+    let u = svd.u().unwrap().to_owned();
+    let v = svd.v().unwrap().to_owned();
+    let s = svd.singular_values().to_owned();
+    let sigma = sigma_matrix(&s, u.ncols(), v.ncols());
+    assert!((&u * &sigma * v.transpose() - a).abs().max() < tol);
+    assert!((u.transpose() * &u - Mat::identity(u.ncols())).abs().max() < tol);
+    assert!((v.transpose() * &v - Mat::identity(v.ncols())).abs().max() < tol);
+    for i in 1..s.nrows() {
+        assert!(s[(i - 1, 0)] >= s[(i, 0)]);
+    }
+}
+
+#[test]
+fn svd_thin_tall() {
+    let a = test_matrix(8, 5);
+    let svd = a.svd().unwrap();
+    assert_eq!(svd.u().unwrap().shape(), (8, 5));
+    assert_eq!(svd.v().unwrap().shape(), (5, 5));
+    assert_reconstructs(&a, &svd, 1e-12);
+}
+
+#[test]
+fn svd_thin_wide() {
+    let a = test_matrix(4, 9);
+    let svd = a.svd().unwrap();
+    assert_eq!(svd.u().unwrap().shape(), (4, 4));
+    assert_eq!(svd.v().unwrap().shape(), (9, 4));
+    assert_reconstructs(&a, &svd, 1e-12);
+}
+
+#[test]
+fn svd_full_shapes() {
+    let a = test_matrix(7, 3);
+    let svd = a.svd_full().unwrap();
+    assert_eq!(svd.u().unwrap().shape(), (7, 7));
+    assert_eq!(svd.v().unwrap().shape(), (3, 3));
+    assert_reconstructs(&a, &svd, 1e-12);
+
+    let b = test_matrix(3, 6);
+    let svd = b.svd_full().unwrap();
+    assert_eq!(svd.u().unwrap().shape(), (3, 3));
+    assert_eq!(svd.v().unwrap().shape(), (6, 6));
+    assert_reconstructs(&b, &svd, 1e-12);
+}
+
+#[test]
+fn svd_known_values() {
+    let a: Mat<f64> = mat![[3.0, 0.0], [0.0, -4.0], [0.0, 0.0]];
+    let s = a.singular_values().unwrap();
+    assert!((s[(0, 0)] - 4.0).abs() < 1e-14);
+    assert!((s[(1, 0)] - 3.0).abs() < 1e-14);
+}
+
+#[test]
+fn svd_values_only_matches_full() {
+    let a = test_matrix(6, 6);
+    let values = Svd::values_only(a.as_ref()).unwrap();
+    assert!(values.u().is_none() && values.v().is_none());
+    let full = a.svd().unwrap();
+    let diff = values.singular_values().to_owned() - full.singular_values().to_owned();
+    assert!(diff.abs().max() < 1e-12);
+}
+
+#[test]
+fn svd_rank_deficient() {
+    let a: Mat<f64> = mat![
+        [1.0, 2.0, 3.0],
+        [2.0, 4.0, 6.0],
+        [1.0, 0.0, 1.0],
+        [0.0, 2.0, 2.0]
+    ];
+    let svd = a.svd().unwrap();
+    assert_reconstructs(&a, &svd, 1e-12);
+    assert_eq!(svd.rank(svd.default_tolerance()), 2);
+    assert_eq!(a.rank(1e-10).unwrap(), 2);
+}
+
+#[test]
+fn svd_zero_matrix() {
+    let a: Mat<f64> = Mat::zeros(4, 3);
+    let svd = a.svd().unwrap();
+    assert_eq!(svd.singular_values().to_owned(), Mat::zeros(3, 1));
+    assert_reconstructs(&a, &svd, 1e-14);
+    assert_eq!(a.rank(0.0).unwrap(), 0);
+}
+
+#[test]
+fn svd_complex() {
+    let a = Mat::from_fn(5, 4, |i, j| {
+        c((i + 2 * j) as f64 - 3.0, ((i * j) % 3) as f64 - 1.0)
+    });
+    let svd = a.svd().unwrap();
+    let u = svd.u().unwrap().to_owned();
+    let v = svd.v().unwrap().to_owned();
+    let s = svd.singular_values();
+    let sigma = Mat::from_fn(4, 4, |i, j| {
+        if i == j {
+            c(s[(i, 0)], 0.0)
+        } else {
+            c(0.0, 0.0)
+        }
+    });
+    assert!((&u * &sigma * v.adjoint() - &a).norm().max() < 1e-12);
+    assert!(
+        (u.adjoint() * &u - Mat::<Complex<f64>>::identity(4))
+            .norm()
+            .max()
+            < 1e-12
+    );
+}
+
+#[test]
+fn pinv_of_invertible_is_inverse() {
+    let a: Mat<f64> = mat![[4.0, 7.0], [2.0, 6.0]];
+    let p = a.pinv().unwrap();
+    assert!((&a * &p - Mat::identity(2)).abs().max() < 1e-12);
+}
+
+#[test]
+fn pinv_moore_penrose_conditions() {
+    let a: Mat<f64> = mat![
+        [1.0, 2.0, 3.0],
+        [2.0, 4.0, 6.0],
+        [1.0, 0.0, 1.0],
+        [0.0, 2.0, 2.0]
+    ];
+    let p = a.pinv().unwrap();
+    assert_eq!(p.shape(), (3, 4));
+    assert!((&a * &p * &a - &a).abs().max() < 1e-12);
+    assert!((&p * &a * &p - &p).abs().max() < 1e-12);
+    let ap = &a * &p;
+    assert!((&ap - ap.transpose()).abs().max() < 1e-12);
+    let pa = &p * &a;
+    assert!((&pa - pa.transpose()).abs().max() < 1e-12);
+}
+
+#[test]
+fn null_space_and_range() {
+    let a: Mat<f64> = mat![[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]];
+    let null = a.null_space().unwrap();
+    assert_eq!(null.shape(), (3, 2));
+    assert!((&a * &null).abs().max() < 1e-12);
+    assert!((null.transpose() * &null - Mat::identity(2)).abs().max() < 1e-12);
+
+    let range = a.range().unwrap();
+    assert_eq!(range.shape(), (2, 1));
+    let projected = &range * (range.transpose() * &a);
+    assert!((projected - &a).abs().max() < 1e-12);
+}
+
+#[test]
+fn spectral_norm_and_cond() {
+    let a: Mat<f64> = mat![[2.0, 0.0], [0.0, 0.5]];
+    assert!((a.spectral_norm().unwrap() - 2.0).abs() < 1e-14);
+    assert!((a.cond().unwrap() - 4.0).abs() < 1e-13);
+    let singular: Mat<f64> = mat![[1.0, 1.0], [1.0, 1.0]];
+    let svd = Svd::values_only(singular.as_ref()).unwrap();
+    assert!(svd.cond() > 1e15);
+    let zero: Mat<f64> = Mat::zeros(2, 2);
+    assert_eq!(zero.cond().unwrap(), f64::INFINITY);
+}
+
+#[test]
+fn svd_extreme_magnitudes() {
+    let tiny: Mat<f64> = mat![[1e-300, 0.0], [0.0, 1e-300]];
+    let svd = tiny.svd().unwrap();
+    assert!((svd.spectral_norm() / 1e-300 - 1.0).abs() < 1e-14);
+    assert_eq!(svd.rank(svd.default_tolerance()), 2);
+    assert!((svd.cond() - 1.0).abs() < 1e-14);
+    assert!((svd.pinv()[(0, 0)] / 1e300 - 1.0).abs() < 1e-14);
+
+    let subnormal: Mat<f64> = mat![[3e-310, 0.0], [0.0, 1e-310]];
+    let s = subnormal.singular_values().unwrap();
+    assert!((s[(0, 0)] / 3e-310 - 1.0).abs() < 1e-4);
+    assert!((s[(1, 0)] / 1e-310 - 1.0).abs() < 1e-4);
+
+    let huge: Mat<f64> = mat![[1e300, 1e300], [1e300, 1e300]];
+    let svd = huge.svd().unwrap();
+    let s = svd.singular_values();
+    assert!((s[(0, 0)] / 2e300 - 1.0).abs() < 1e-14);
+    assert!(s[(1, 0)] < 1e286);
+    assert_eq!(svd.rank(svd.default_tolerance()), 1);
+    let scaled = &huge * 1e-300;
+    assert_reconstructs(&scaled, &scaled.svd().unwrap(), 1e-12);
+    let u = svd.u().unwrap().to_owned();
+    assert!((u.transpose() * &u - Mat::identity(2)).abs().max() < 1e-12);
+
+    let z: Mat<Complex<f64>> = mat![
+        [c(1e-300, 1e-300), c(0.0, 0.0)],
+        [c(0.0, 0.0), c(0.0, 2e-300)]
+    ];
+    let s = z.singular_values().unwrap();
+    assert!((s[(0, 0)] / 2e-300 - 1.0).abs() < 1e-14);
+    assert!((s[(1, 0)] / (2.0f64.sqrt() * 1e-300) - 1.0).abs() < 1e-14);
+}
+
+#[test]
+fn svd_empty() {
+    let a: Mat<f64> = Mat::zeros(3, 0);
+    let svd = a.svd().unwrap();
+    assert_eq!(svd.singular_values().nrows(), 0);
+    assert_eq!(svd.u().unwrap().shape(), (3, 0));
+    assert_eq!(a.spectral_norm().unwrap(), 0.0);
+}
+
+#[test]
+#[should_panic(expected = "operation not supported: pseudoinverse without singular vectors")]
+fn pinv_without_vectors_panics() {
+    let a: Mat<f64> = Mat::identity(2);
+    let _ = Svd::values_only(a.as_ref()).unwrap().pinv();
+}
+
+#[test]
+fn subspaces_without_vectors_are_errors() {
+    let a: Mat<f64> = mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    let values = Svd::values_only(a.as_ref()).unwrap();
+    assert!(values.try_pinv().is_err());
+    assert!(values.try_range().is_err());
+    assert!(values.try_null_space().is_err());
+    let thin = a.svd().unwrap();
+    assert_eq!(
+        thin.try_null_space().err(),
+        Some(Error::Unsupported {
+            operation: "null space of a wide matrix from a thin SVD"
+        })
+    );
+    assert_eq!(
+        a.svd_full().unwrap().try_null_space().unwrap().shape(),
+        (3, 1)
+    );
+}
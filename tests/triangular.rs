@@ -0,0 +1,203 @@
+mod common;
+
+use aris::linalg::{
+    Diag, Op, lower_triangular_matmul_into, solve_lower_triangular, solve_upper_triangular,
+    try_lower_triangular_matmul_into, try_upper_triangular_matmul_into,
+    upper_triangular_matmul_into,
+};
+use aris::{Complex, Error, Mat, mat};
+
+use common::c;
+
+fn lower(n: usize) -> Mat<f64> {
+    Mat::from_fn(n, n, |i, j| {
+        if i == j {
+            2.0 + i as f64
+        } else if i > j {
+            ((i * 3 + j) % 5) as f64 - 2.0
+        } else {
+            f64::NAN
+        }
+    })
+}
+
+fn rhs(n: usize, k: usize) -> Mat<f64> {
+    Mat::from_fn(n, k, |i, j| (i + 2 * j) as f64 - 1.5)
+}
+
+#[test]
+fn solve_lower_multiple_columns() {
+    let a = lower(6);
+    let b = rhs(6, 3);
+    let mut x = b.clone();
+    solve_lower_triangular(a.as_ref(), x.as_mut(), Diag::NonUnit, Op::Plain).unwrap();
+    let l = a.map(|&v| if v.is_nan() { 0.0 } else { v });
+    assert!((&l * &x - &b).abs().max() < 1e-12);
+}
+
+#[test]
+fn solve_upper_ignores_lower_half() {
+    let a = lower(5).transpose().to_owned();
+    let b = rhs(5, 2);
+    let mut x = b.clone();
+    solve_upper_triangular(a.as_ref(), x.as_mut(), Diag::NonUnit, Op::Plain).unwrap();
+    let u = a.map(|&v| if v.is_nan() { 0.0 } else { v });
+    assert!((&u * &x - &b).abs().max() < 1e-12);
+}
+
+#[test]
+fn solve_unit_diagonal() {
+    let a: Mat<f64> = mat![
+        [100.0, f64::NAN, f64::NAN],
+        [2.0, 100.0, f64::NAN],
+        [3.0, 4.0, 100.0]
+    ];
+    let b: Mat<f64> = mat![[1.0], [4.0], [15.0]];
+    let mut x = b.clone();
+    solve_lower_triangular(a.as_ref(), x.as_mut(), Diag::Unit, Op::Plain).unwrap();
+    assert_eq!(x, mat![[1.0], [2.0], [4.0]]);
+}
+
+#[test]
+fn solve_lower_transposed() {
+    let a = lower(5);
+    let l = a.map(|&v| if v.is_nan() { 0.0 } else { v });
+    let b = rhs(5, 2);
+    let mut x = b.clone();
+    solve_lower_triangular(a.as_ref(), x.as_mut(), Diag::NonUnit, Op::Transpose).unwrap();
+    assert!((l.transpose() * &x - &b).abs().max() < 1e-12);
+}
+
+#[test]
+fn solve_upper_transposed_unit() {
+    let u: Mat<f64> = mat![[1.0, 2.0, 3.0], [0.0, 1.0, 4.0], [0.0, 0.0, 1.0]];
+    let b: Mat<f64> = mat![[1.0, 0.0], [2.0, 1.0], [3.0, 5.0]];
+    let mut x = b.clone();
+    solve_upper_triangular(u.as_ref(), x.as_mut(), Diag::Unit, Op::Transpose).unwrap();
+    assert!((u.transpose() * &x - &b).abs().max() < 1e-14);
+}
+
+#[test]
+fn solve_complex_adjoint() {
+    let l = mat![
+        [c(2.0, 1.0), c(0.0, 0.0), c(0.0, 0.0)],
+        [c(1.0, -1.0), c(3.0, 0.0), c(0.0, 0.0)],
+        [c(0.0, 2.0), c(-1.0, 1.0), c(1.0, -1.0)]
+    ];
+    let b = mat![[c(1.0, 0.0)], [c(0.0, 1.0)], [c(2.0, -1.0)]];
+    let mut x = b.clone();
+    solve_lower_triangular(l.as_ref(), x.as_mut(), Diag::NonUnit, Op::Adjoint).unwrap();
+    assert!((l.adjoint() * &x - &b).norm().max() < 1e-14);
+
+    let mut y = b.clone();
+    solve_lower_triangular(l.as_ref(), y.as_mut(), Diag::NonUnit, Op::Transpose).unwrap();
+    let lt: Mat<Complex<f64>> = l.transpose().to_owned();
+    assert!((lt * &y - &b).norm().max() < 1e-14);
+}
+
+#[test]
+fn solve_singular_leaves_rhs_untouched() {
+    let a: Mat<f64> = mat![[1.0, 0.0, 0.0], [2.0, 0.0, 0.0], [3.0, 4.0, 5.0]];
+    let b: Mat<f64> = mat![[1.0], [2.0], [3.0]];
+    let mut x = b.clone();
+    let err = solve_lower_triangular(a.as_ref(), x.as_mut(), Diag::NonUnit, Op::Plain);
+    assert_eq!(err, Err(Error::Singular { index: 1 }));
+    assert_eq!(x, b);
+    solve_lower_triangular(a.as_ref(), x.as_mut(), Diag::Unit, Op::Plain).unwrap();
+}
+
+#[test]
+fn solve_into_strided_view() {
+    let a = lower(4);
+    let l = a.map(|&v| if v.is_nan() { 0.0 } else { v });
+    let mut big: Mat<f64> = Mat::from_fn(4, 6, |i, j| (i * j) as f64 + 1.0);
+    let b = big.view(0, 1, 4, 3).to_owned();
+    solve_lower_triangular(
+        a.as_ref(),
+        big.view_mut(0, 1, 4, 3),
+        Diag::NonUnit,
+        Op::Plain,
+    )
+    .unwrap();
+    assert!((&l * big.view(0, 1, 4, 3).to_owned() - &b).abs().max() < 1e-12);
+    assert_eq!(big[(2, 0)], 1.0);
+    assert_eq!(big[(2, 4)], 9.0);
+}
+
+#[test]
+fn solve_shape_errors() {
+    let a: Mat<f64> = Mat::identity(3);
+    let mut b: Mat<f64> = Mat::zeros(2, 1);
+    assert_eq!(
+        solve_upper_triangular(a.as_ref(), b.as_mut(), Diag::NonUnit, Op::Plain),
+        Err(Error::ShapeMismatch {
+            lhs: (2, 1),
+            rhs: (3, 1)
+        })
+    );
+    let wide: Mat<f64> = Mat::zeros(2, 3);
+    assert_eq!(
+        solve_lower_triangular(wide.as_ref(), b.as_mut(), Diag::Unit, Op::Plain),
+        Err(Error::NotSquare { nrows: 2, ncols: 3 })
+    );
+}
+
+#[test]
+fn try_matmul_shape_errors() {
+    let a: Mat<f64> = Mat::identity(3);
+    let b: Mat<f64> = Mat::zeros(3, 2);
+    let mut dst: Mat<f64> = Mat::zeros(3, 3);
+    assert_eq!(
+        try_lower_triangular_matmul_into(dst.as_mut(), a.as_ref(), Diag::Unit, b.as_ref()),
+        Err(Error::ShapeMismatch {
+            lhs: (3, 3),
+            rhs: (3, 2)
+        })
+    );
+    let mut dst: Mat<f64> = Mat::zeros(3, 2);
+    assert!(
+        try_upper_triangular_matmul_into(dst.as_mut(), a.as_ref(), Diag::Unit, b.as_ref()).is_ok()
+    );
+}
+
+#[test]
+#[should_panic(expected = "matrix must be square, found 3x2")]
+fn matmul_non_square_panics() {
+    let a: Mat<f64> = Mat::zeros(3, 2);
+    let b: Mat<f64> = Mat::zeros(2, 2);
+    let mut dst: Mat<f64> = Mat::zeros(3, 2);
+    upper_triangular_matmul_into(dst.as_mut(), a.as_ref(), Diag::NonUnit, b.as_ref());
+}
+
+#[test]
+fn lower_matmul_reads_only_lower_half() {
+    let a = lower(5);
+    let l = a.map(|&v| if v.is_nan() { 0.0 } else { v });
+    let b = rhs(5, 3);
+    let mut dst = Mat::full(5, 3, f64::NAN);
+    lower_triangular_matmul_into(dst.as_mut(), a.as_ref(), Diag::NonUnit, b.as_ref());
+    assert!((dst - &l * &b).abs().max() < 1e-12);
+}
+
+#[test]
+fn upper_matmul_unit_diagonal() {
+    let a: Mat<f64> = mat![
+        [9.0, 2.0, 3.0],
+        [f64::NAN, 9.0, 4.0],
+        [f64::NAN, f64::NAN, 9.0]
+    ];
+    let b: Mat<f64> = mat![[1.0], [1.0], [1.0]];
+    let mut dst = Mat::zeros(3, 1);
+    upper_triangular_matmul_into(dst.as_mut(), a.as_ref(), Diag::Unit, b.as_ref());
+    assert_eq!(dst, mat![[6.0], [5.0], [1.0]]);
+}
+
+#[test]
+fn matmul_then_solve_roundtrip() {
+    let a = lower(6).transpose().to_owned();
+    let b = rhs(6, 4);
+    let mut y = Mat::zeros(6, 4);
+    upper_triangular_matmul_into(y.as_mut(), a.as_ref(), Diag::NonUnit, b.as_ref());
+    solve_upper_triangular(a.as_ref(), y.as_mut(), Diag::NonUnit, Op::Plain).unwrap();
+    assert!((y - &b).abs().max() < 1e-12);
+}
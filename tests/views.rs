@@ -601,7 +601,7 @@ fn split_at_col_mut_modifies_independently() {
 }
 
 #[test]
-#[should_panic(expected = "Split index")]
+#[should_panic(expected = "Column index 3 out of bounds for 2 columns")]
 fn split_at_col_out_of_bounds() {
     let m = mat![[1, 2], [3, 4]];
     m.split_at_col(3);
@@ -652,7 +652,7 @@ fn split_at_row_mut_modifies_independently() {
 }
 
 #[test]
-#[should_panic(expected = "Split index")]
+#[should_panic(expected = "Row index 3 out of bounds for 2 rows")]
 fn split_at_row_out_of_bounds() {
     let m = mat![[1, 2], [3, 4]];
     m.split_at_row(3);